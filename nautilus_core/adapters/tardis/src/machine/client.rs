@@ -144,7 +144,7 @@ where
                     });
 
                     if let Some(info) = info {
-                        if let Some(data) = parse_tardis_ws_message(msg, info) {
+                        for data in parse_tardis_ws_message(msg, info) {
                             yield data;
                         }
                     }
@@ -175,7 +175,9 @@ pub fn determine_instrument_info(
         WsMessage::TradeBar(msg) => {
             TardisInstrumentKey::new(Ustr::from(&msg.symbol), msg.exchange.clone())
         }
-        WsMessage::DerivativeTicker(_) => return None,
+        WsMessage::DerivativeTicker(msg) => {
+            TardisInstrumentKey::new(Ustr::from(&msg.symbol), msg.exchange.clone())
+        }
         WsMessage::Disconnect(_) => return None,
     };
     if let Some(inst) = instrument_map.get(&key) {
@@ -19,8 +19,8 @@ use chrono::{DateTime, Utc};
 use nautilus_core::UnixNanos;
 use nautilus_model::{
     data::{
-        Bar, BarType, BookOrder, Data, OrderBookDelta, OrderBookDeltas, OrderBookDeltas_API,
-        QuoteTick, TradeTick,
+        Bar, BarType, BookOrder, Data, FundingRateUpdate, IndexPriceUpdate, MarkPriceUpdate,
+        OrderBookDelta, OrderBookDeltas, OrderBookDeltas_API, QuoteTick, TradeTick,
     },
     enums::{AggregationSource, BookAction, OrderSide, RecordFlag},
     identifiers::{InstrumentId, TradeId},
@@ -29,54 +29,62 @@ use nautilus_model::{
 use uuid::Uuid;
 
 use super::{
-    message::{BarMsg, BookChangeMsg, BookLevel, BookSnapshotMsg, TradeMsg, WsMessage},
+    message::{
+        BarMsg, BookChangeMsg, BookLevel, BookSnapshotMsg, DerivativeTickerMsg, TradeMsg, WsMessage,
+    },
     types::InstrumentMiniInfo,
 };
 use crate::parse::{parse_aggressor_side, parse_bar_spec, parse_book_action};
 
+/// Parses the given Tardis Machine `msg` into Nautilus data.
+///
+/// A derivative ticker message can produce a mark price, index price and funding rate update,
+/// so more than one data item may be returned.
 #[must_use]
-pub fn parse_tardis_ws_message(msg: WsMessage, info: Arc<InstrumentMiniInfo>) -> Option<Data> {
+pub fn parse_tardis_ws_message(msg: WsMessage, info: Arc<InstrumentMiniInfo>) -> Vec<Data> {
     match msg {
         WsMessage::BookChange(msg) => {
             if msg.bids.is_empty() && msg.asks.is_empty() {
                 // Skip empty book changes - these are valid messages but contain no actionable data
-                return None;
+                return Vec::new();
             }
-            Some(Data::Deltas(parse_book_change_msg_as_deltas(
+            vec![Data::Deltas(parse_book_change_msg_as_deltas(
                 msg,
                 info.price_precision,
                 info.size_precision,
                 info.instrument_id,
-            )))
+            ))]
         }
         WsMessage::BookSnapshot(msg) => match msg.bids.len() {
-            1 => Some(Data::Quote(parse_book_snapshot_msg_as_quote(
+            1 => vec![Data::Quote(parse_book_snapshot_msg_as_quote(
                 msg,
                 info.price_precision,
                 info.size_precision,
                 info.instrument_id,
-            ))),
-            _ => Some(Data::Deltas(parse_book_snapshot_msg_as_deltas(
+            ))],
+            _ => vec![Data::Deltas(parse_book_snapshot_msg_as_deltas(
                 msg,
                 info.price_precision,
                 info.size_precision,
                 info.instrument_id,
-            ))),
+            ))],
         },
-        WsMessage::Trade(msg) => Some(Data::Trade(parse_trade_msg(
+        WsMessage::Trade(msg) => vec![Data::Trade(parse_trade_msg(
             msg,
             info.price_precision,
             info.size_precision,
             info.instrument_id,
-        ))),
-        WsMessage::TradeBar(msg) => Some(Data::Bar(parse_bar_msg(
+        ))],
+        WsMessage::TradeBar(msg) => vec![Data::Bar(parse_bar_msg(
             msg,
             info.price_precision,
             info.size_precision,
             info.instrument_id,
-        ))),
-        WsMessage::DerivativeTicker(_) => None,
-        WsMessage::Disconnect(_) => None,
+        ))],
+        WsMessage::DerivativeTicker(msg) => {
+            parse_derivative_ticker_msg(&msg, info.price_precision, info.instrument_id)
+        }
+        WsMessage::Disconnect(_) => Vec::new(),
     }
 }
 
@@ -284,6 +292,78 @@ pub fn parse_bar_msg(
     Bar::new(bar_type, open, high, low, close, volume, ts_event, ts_init)
 }
 
+/// Parses the mark price, index price and funding rate provided in a derivative ticker message.
+#[must_use]
+pub fn parse_derivative_ticker_msg(
+    msg: &DerivativeTickerMsg,
+    price_precision: u8,
+    instrument_id: InstrumentId,
+) -> Vec<Data> {
+    let mark_price = parse_derivative_ticker_msg_as_mark_price(msg, price_precision, instrument_id)
+        .map(Data::MarkPrice);
+    let index_price =
+        parse_derivative_ticker_msg_as_index_price(msg, price_precision, instrument_id)
+            .map(Data::IndexPrice);
+    let funding_rate =
+        parse_derivative_ticker_msg_as_funding_rate(msg, instrument_id).map(Data::FundingRate);
+
+    [mark_price, index_price, funding_rate]
+        .into_iter()
+        .flatten()
+        .collect()
+}
+
+/// Parses the mark price from a derivative ticker message, if one was provided.
+#[must_use]
+pub fn parse_derivative_ticker_msg_as_mark_price(
+    msg: &DerivativeTickerMsg,
+    price_precision: u8,
+    instrument_id: InstrumentId,
+) -> Option<MarkPriceUpdate> {
+    let value = Price::new(msg.mark_price?, price_precision);
+    let ts_event = UnixNanos::from(msg.timestamp);
+    let ts_init = UnixNanos::from(msg.local_timestamp);
+
+    Some(MarkPriceUpdate::new(
+        instrument_id,
+        value,
+        ts_event,
+        ts_init,
+    ))
+}
+
+/// Parses the index price from a derivative ticker message, if one was provided.
+#[must_use]
+pub fn parse_derivative_ticker_msg_as_index_price(
+    msg: &DerivativeTickerMsg,
+    price_precision: u8,
+    instrument_id: InstrumentId,
+) -> Option<IndexPriceUpdate> {
+    let value = Price::new(msg.index_price?, price_precision);
+    let ts_event = UnixNanos::from(msg.timestamp);
+    let ts_init = UnixNanos::from(msg.local_timestamp);
+
+    Some(IndexPriceUpdate::new(
+        instrument_id,
+        value,
+        ts_event,
+        ts_init,
+    ))
+}
+
+/// Parses the funding rate from a derivative ticker message, if one was provided.
+#[must_use]
+pub fn parse_derivative_ticker_msg_as_funding_rate(
+    msg: &DerivativeTickerMsg,
+    instrument_id: InstrumentId,
+) -> Option<FundingRateUpdate> {
+    let rate = msg.funding_rate?;
+    let ts_event = UnixNanos::from(msg.timestamp);
+    let ts_init = UnixNanos::from(msg.local_timestamp);
+
+    FundingRateUpdate::new_checked(instrument_id, rate, ts_event, ts_init).ok()
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
@@ -436,4 +516,60 @@ mod tests {
         assert_eq!(bar.ts_event, UnixNanos::from(1572009100000000000));
         assert_eq!(bar.ts_init, UnixNanos::from(1572009100369000000));
     }
+
+    #[rstest]
+    fn test_parse_derivative_ticker_message() {
+        let json_data = load_test_json("derivative_ticker.json");
+        let msg: DerivativeTickerMsg = serde_json::from_str(&json_data).unwrap();
+
+        let price_precision = 2;
+        let instrument_id = InstrumentId::from("BTC-PERPETUAL.DERIBIT");
+        let mark_price =
+            parse_derivative_ticker_msg_as_mark_price(&msg, price_precision, instrument_id)
+                .unwrap();
+        let index_price =
+            parse_derivative_ticker_msg_as_index_price(&msg, price_precision, instrument_id)
+                .unwrap();
+        let funding_rate =
+            parse_derivative_ticker_msg_as_funding_rate(&msg, instrument_id).unwrap();
+
+        assert_eq!(mark_price.instrument_id, instrument_id);
+        assert_eq!(mark_price.value, Price::from("7987.56"));
+        assert_eq!(mark_price.ts_event, UnixNanos::from(1571830469302000000));
+        assert_eq!(mark_price.ts_init, UnixNanos::from(1571830469416000000));
+        assert_eq!(index_price.value, Price::from("7989.28"));
+        assert_eq!(funding_rate.rate, -0.00001568);
+        assert_eq!(funding_rate.ts_event, UnixNanos::from(1571830469302000000));
+    }
+
+    #[rstest]
+    fn test_parse_derivative_ticker_message_without_funding_rate() {
+        let json_data = load_test_json("derivative_ticker.json");
+        let mut msg: DerivativeTickerMsg = serde_json::from_str(&json_data).unwrap();
+        msg.funding_rate = None;
+
+        let instrument_id = InstrumentId::from("BTC-PERPETUAL.DERIBIT");
+
+        assert!(parse_derivative_ticker_msg_as_funding_rate(&msg, instrument_id).is_none());
+    }
+
+    #[rstest]
+    fn test_parse_tardis_ws_message_derivative_ticker() {
+        let json_data = load_test_json("derivative_ticker.json");
+        let msg: DerivativeTickerMsg = serde_json::from_str(&json_data).unwrap();
+        let info = Arc::new(InstrumentMiniInfo::new(
+            InstrumentId::from("BTC-PERPETUAL.DERIBIT"),
+            None,
+            msg.exchange.clone(),
+            2,
+            0,
+        ));
+
+        let data = parse_tardis_ws_message(WsMessage::DerivativeTicker(msg), info);
+
+        assert_eq!(data.len(), 3);
+        assert!(matches!(data[0], Data::MarkPrice(_)));
+        assert!(matches!(data[1], Data::IndexPrice(_)));
+        assert!(matches!(data[2], Data::FundingRate(_)));
+    }
 }
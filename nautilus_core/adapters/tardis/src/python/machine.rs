@@ -155,10 +155,12 @@ impl TardisMachineClient {
             while let Some(result) = stream.next().await {
                 match result {
                     Ok(msg) => {
-                        if let Some(Data::Bar(bar)) = determine_instrument_info(&msg, &map)
-                            .and_then(|info| parse_tardis_ws_message(msg, info))
-                        {
-                            bars.push(bar);
+                        if let Some(info) = determine_instrument_info(&msg, &map) {
+                            for data in parse_tardis_ws_message(msg, info) {
+                                if let Data::Bar(bar) = data {
+                                    bars.push(bar);
+                                }
+                            }
                         }
                     }
                     Err(e) => {
@@ -247,7 +249,7 @@ async fn handle_python_stream<S>(
                 });
 
                 if let Some(info) = info {
-                    if let Some(data) = parse_tardis_ws_message(msg, info) {
+                    for data in parse_tardis_ws_message(msg, info) {
                         Python::with_gil(|py| {
                             let py_obj = data_to_pycapsule(py, data);
                             call_python(py, &callback, py_obj);
@@ -26,14 +26,16 @@ use heck::ToSnakeCase;
 use nautilus_core::{parsing::precision_from_str, UnixNanos};
 use nautilus_model::{
     data::{
-        Bar, BarType, Data, OrderBookDelta, OrderBookDeltas_API, OrderBookDepth10, QuoteTick,
-        TradeTick,
+        Bar, BarType, Data, FundingRateUpdate, IndexPriceUpdate, MarkPriceUpdate, OrderBookDelta,
+        OrderBookDeltas_API, OrderBookDepth10, QuoteTick, TradeTick,
     },
     identifiers::InstrumentId,
 };
 use nautilus_serialization::{
     arrow::{
-        bars_to_arrow_record_batch_bytes, order_book_deltas_to_arrow_record_batch_bytes,
+        bars_to_arrow_record_batch_bytes, funding_rates_to_arrow_record_batch_bytes,
+        index_prices_to_arrow_record_batch_bytes, mark_prices_to_arrow_record_batch_bytes,
+        order_book_deltas_to_arrow_record_batch_bytes,
         order_book_depth10_to_arrow_record_batch_bytes, quote_ticks_to_arrow_record_batch_bytes,
         trade_ticks_to_arrow_record_batch_bytes,
     },
@@ -166,6 +168,9 @@ pub async fn run_tardis_machine_replay_from_config(config_filepath: &Path) -> an
     let mut quotes_cursors: HashMap<InstrumentId, DateCursor> = HashMap::new();
     let mut trades_cursors: HashMap<InstrumentId, DateCursor> = HashMap::new();
     let mut bars_cursors: HashMap<BarType, DateCursor> = HashMap::new();
+    let mut mark_prices_cursors: HashMap<InstrumentId, DateCursor> = HashMap::new();
+    let mut index_prices_cursors: HashMap<InstrumentId, DateCursor> = HashMap::new();
+    let mut funding_rates_cursors: HashMap<InstrumentId, DateCursor> = HashMap::new();
 
     // Initialize date collection maps
     let mut deltas_map: HashMap<InstrumentId, Vec<OrderBookDelta>> = HashMap::new();
@@ -173,6 +178,9 @@ pub async fn run_tardis_machine_replay_from_config(config_filepath: &Path) -> an
     let mut quotes_map: HashMap<InstrumentId, Vec<QuoteTick>> = HashMap::new();
     let mut trades_map: HashMap<InstrumentId, Vec<TradeTick>> = HashMap::new();
     let mut bars_map: HashMap<BarType, Vec<Bar>> = HashMap::new();
+    let mut mark_prices_map: HashMap<InstrumentId, Vec<MarkPriceUpdate>> = HashMap::new();
+    let mut index_prices_map: HashMap<InstrumentId, Vec<IndexPriceUpdate>> = HashMap::new();
+    let mut funding_rates_map: HashMap<InstrumentId, Vec<FundingRateUpdate>> = HashMap::new();
 
     let mut msg_count = 0;
 
@@ -188,6 +196,25 @@ pub async fn run_tardis_machine_replay_from_config(config_filepath: &Path) -> an
             Data::Trade(msg) => handle_trade_msg(msg, &mut trades_map, &mut trades_cursors, &path),
             Data::Bar(msg) => handle_bar_msg(msg, &mut bars_map, &mut bars_cursors, &path),
             Data::Delta(_) => panic!("Individual delta message not implemented (or required)"),
+            Data::MarkPrice(msg) => {
+                handle_mark_price_msg(msg, &mut mark_prices_map, &mut mark_prices_cursors, &path);
+            }
+            Data::IndexPrice(msg) => {
+                handle_index_price_msg(
+                    msg,
+                    &mut index_prices_map,
+                    &mut index_prices_cursors,
+                    &path,
+                );
+            }
+            Data::FundingRate(msg) => {
+                handle_funding_rate_msg(
+                    msg,
+                    &mut funding_rates_map,
+                    &mut funding_rates_cursors,
+                    &path,
+                );
+            }
         }
 
         msg_count += 1;
@@ -223,6 +250,27 @@ pub async fn run_tardis_machine_replay_from_config(config_filepath: &Path) -> an
         batch_and_write_bars(bars, &bar_type, cursor.date_utc, &path);
     }
 
+    for (instrument_id, mark_prices) in mark_prices_map {
+        let cursor = mark_prices_cursors
+            .get(&instrument_id)
+            .expect("Expected cursor");
+        batch_and_write_mark_prices(mark_prices, &instrument_id, cursor.date_utc, &path);
+    }
+
+    for (instrument_id, index_prices) in index_prices_map {
+        let cursor = index_prices_cursors
+            .get(&instrument_id)
+            .expect("Expected cursor");
+        batch_and_write_index_prices(index_prices, &instrument_id, cursor.date_utc, &path);
+    }
+
+    for (instrument_id, funding_rates) in funding_rates_map {
+        let cursor = funding_rates_cursors
+            .get(&instrument_id)
+            .expect("Expected cursor");
+        batch_and_write_funding_rates(funding_rates, &instrument_id, cursor.date_utc, &path);
+    }
+
     tracing::info!(
         "Replay completed after {} messages",
         msg_count.separate_with_commas()
@@ -345,6 +393,90 @@ fn handle_bar_msg(
         .push(bar);
 }
 
+fn handle_mark_price_msg(
+    mark_price: MarkPriceUpdate,
+    map: &mut HashMap<InstrumentId, Vec<MarkPriceUpdate>>,
+    cursors: &mut HashMap<InstrumentId, DateCursor>,
+    path: &Path,
+) {
+    let cursor = cursors
+        .entry(mark_price.instrument_id)
+        .or_insert_with(|| DateCursor::new(mark_price.ts_init));
+
+    if mark_price.ts_init > cursor.end_ns {
+        if let Some(mark_prices_vec) = map.remove(&mark_price.instrument_id) {
+            batch_and_write_mark_prices(
+                mark_prices_vec,
+                &mark_price.instrument_id,
+                cursor.date_utc,
+                path,
+            );
+        }
+        // Update cursor
+        *cursor = DateCursor::new(mark_price.ts_init);
+    }
+
+    map.entry(mark_price.instrument_id)
+        .or_insert_with(|| Vec::with_capacity(1_000_000))
+        .push(mark_price);
+}
+
+fn handle_index_price_msg(
+    index_price: IndexPriceUpdate,
+    map: &mut HashMap<InstrumentId, Vec<IndexPriceUpdate>>,
+    cursors: &mut HashMap<InstrumentId, DateCursor>,
+    path: &Path,
+) {
+    let cursor = cursors
+        .entry(index_price.instrument_id)
+        .or_insert_with(|| DateCursor::new(index_price.ts_init));
+
+    if index_price.ts_init > cursor.end_ns {
+        if let Some(index_prices_vec) = map.remove(&index_price.instrument_id) {
+            batch_and_write_index_prices(
+                index_prices_vec,
+                &index_price.instrument_id,
+                cursor.date_utc,
+                path,
+            );
+        }
+        // Update cursor
+        *cursor = DateCursor::new(index_price.ts_init);
+    }
+
+    map.entry(index_price.instrument_id)
+        .or_insert_with(|| Vec::with_capacity(1_000_000))
+        .push(index_price);
+}
+
+fn handle_funding_rate_msg(
+    funding_rate: FundingRateUpdate,
+    map: &mut HashMap<InstrumentId, Vec<FundingRateUpdate>>,
+    cursors: &mut HashMap<InstrumentId, DateCursor>,
+    path: &Path,
+) {
+    let cursor = cursors
+        .entry(funding_rate.instrument_id)
+        .or_insert_with(|| DateCursor::new(funding_rate.ts_init));
+
+    if funding_rate.ts_init > cursor.end_ns {
+        if let Some(funding_rates_vec) = map.remove(&funding_rate.instrument_id) {
+            batch_and_write_funding_rates(
+                funding_rates_vec,
+                &funding_rate.instrument_id,
+                cursor.date_utc,
+                path,
+            );
+        }
+        // Update cursor
+        *cursor = DateCursor::new(funding_rate.ts_init);
+    }
+
+    map.entry(funding_rate.instrument_id)
+        .or_insert_with(|| Vec::with_capacity(1_000_000))
+        .push(funding_rate);
+}
+
 fn batch_and_write_deltas(
     deltas: Vec<OrderBookDelta>,
     instrument_id: &InstrumentId,
@@ -422,6 +554,51 @@ fn batch_and_write_bars(bars: Vec<Bar>, bar_type: &BarType, date: NaiveDate, pat
     }
 }
 
+fn batch_and_write_mark_prices(
+    mark_prices: Vec<MarkPriceUpdate>,
+    instrument_id: &InstrumentId,
+    date: NaiveDate,
+    path: &Path,
+) {
+    let typename = stringify!(MarkPriceUpdate);
+    match mark_prices_to_arrow_record_batch_bytes(mark_prices) {
+        Ok(batch) => write_batch(batch, typename, instrument_id, date, path),
+        Err(e) => {
+            tracing::error!("Error converting `{typename}` to Arrow: {e:?}",);
+        }
+    }
+}
+
+fn batch_and_write_index_prices(
+    index_prices: Vec<IndexPriceUpdate>,
+    instrument_id: &InstrumentId,
+    date: NaiveDate,
+    path: &Path,
+) {
+    let typename = stringify!(IndexPriceUpdate);
+    match index_prices_to_arrow_record_batch_bytes(index_prices) {
+        Ok(batch) => write_batch(batch, typename, instrument_id, date, path),
+        Err(e) => {
+            tracing::error!("Error converting `{typename}` to Arrow: {e:?}",);
+        }
+    }
+}
+
+fn batch_and_write_funding_rates(
+    funding_rates: Vec<FundingRateUpdate>,
+    instrument_id: &InstrumentId,
+    date: NaiveDate,
+    path: &Path,
+) {
+    let typename = stringify!(FundingRateUpdate);
+    match funding_rates_to_arrow_record_batch_bytes(funding_rates) {
+        Ok(batch) => write_batch(batch, typename, instrument_id, date, path),
+        Err(e) => {
+            tracing::error!("Error converting `{typename}` to Arrow: {e:?}",);
+        }
+    }
+}
+
 fn parquet_filepath(typename: &str, instrument_id: &InstrumentId, date: NaiveDate) -> PathBuf {
     let typename = typename.to_snake_case();
     let instrument_id_str = instrument_id.to_string().replace('/', "");
@@ -447,12 +447,18 @@ mod tests {
             duration_ns: 2,
             avg_px_open: 0.0,
             avg_px_close: None,
+            avg_px_open_exact: Decimal::ZERO,
+            avg_px_close_exact: None,
             realized_return,
             realized_pnl: Some(Money::new(realized_pnl, currency)),
             trade_ids: Vec::new(),
             buy_qty: Quantity::default(),
             sell_qty: Quantity::default(),
             commissions: HashMap::new(),
+            lot_matching: None,
+            lots: Vec::new(),
+            closed_lots: Vec::new(),
+            lot_selections: HashMap::new(),
         }
     }
 
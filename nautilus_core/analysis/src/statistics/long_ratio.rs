@@ -74,6 +74,7 @@ mod tests {
         },
         types::{Currency, Quantity},
     };
+    use rust_decimal::Decimal;
 
     use super::*;
 
@@ -106,12 +107,18 @@ mod tests {
             duration_ns: 2,
             avg_px_open: 0.0,
             avg_px_close: None,
+            avg_px_open_exact: Decimal::ZERO,
+            avg_px_close_exact: None,
             realized_return: 0.0,
             realized_pnl: None,
             trade_ids: Vec::new(),
             buy_qty: Quantity::default(),
             sell_qty: Quantity::default(),
             commissions: HashMap::new(),
+            lot_matching: None,
+            lots: Vec::new(),
+            closed_lots: Vec::new(),
+            lot_selections: HashMap::new(),
         }
     }
 
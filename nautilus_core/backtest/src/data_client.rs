@@ -124,6 +124,30 @@ impl DataClient for BacktestDataClient {
         Ok(())
     }
 
+    fn subscribe_mark_prices(
+        &mut self,
+        instrument_id: &InstrumentId,
+        params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn subscribe_index_prices(
+        &mut self,
+        instrument_id: &InstrumentId,
+        params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn subscribe_funding_rates(
+        &mut self,
+        instrument_id: &InstrumentId,
+        params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
     fn subscribe_bars(
         &mut self,
         bar_type: &BarType,
@@ -204,6 +228,30 @@ impl DataClient for BacktestDataClient {
         Ok(())
     }
 
+    fn unsubscribe_mark_prices(
+        &mut self,
+        instrument_id: &InstrumentId,
+        params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn unsubscribe_index_prices(
+        &mut self,
+        instrument_id: &InstrumentId,
+        params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn unsubscribe_funding_rates(
+        &mut self,
+        instrument_id: &InstrumentId,
+        params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
     fn unsubscribe_bars(
         &mut self,
         bar_type: &BarType,
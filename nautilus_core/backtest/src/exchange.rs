@@ -24,20 +24,22 @@ use std::{cell::RefCell, collections::HashMap, rc::Rc};
 use nautilus_common::{cache::Cache, msgbus::MessageBus};
 use nautilus_core::{
     correctness::{check_equal, FAILED},
-    AtomicTime, UnixNanos,
+    AtomicTime, UnixNanos, UUID4,
 };
 use nautilus_execution::{client::ExecutionClient, messages::TradingCommand};
 use nautilus_model::{
     accounts::AccountAny,
     data::{
-        Bar, Data, InstrumentStatus, OrderBookDelta, OrderBookDeltas, OrderBookDeltas_API,
-        QuoteTick, TradeTick,
+        Bar, CorporateAction, Data, InstrumentStatus, OrderBookDelta, OrderBookDeltas,
+        OrderBookDeltas_API, QuoteTick, TradeTick,
     },
     enums::{AccountType, BookType, OmsType},
+    events::PositionChanged,
     identifiers::{InstrumentId, Venue},
     instruments::InstrumentAny,
     orderbook::OrderBook,
     orders::PassiveOrderAny,
+    position::Position,
     types::{AccountBalance, Currency, Money, Price},
 };
 use rust_decimal::{prelude::ToPrimitive, Decimal};
@@ -63,6 +65,7 @@ pub struct SimulatedExchange {
     matching_engines: HashMap<InstrumentId, OrderMatchingEngine>,
     leverages: HashMap<InstrumentId, Decimal>,
     modules: Vec<Box<dyn SimulationModule>>,
+    corporate_actions: Vec<CorporateAction>,
     clock: &'static AtomicTime,
     msgbus: Rc<RefCell<MessageBus>>,
     cache: Rc<RefCell<Cache>>,
@@ -128,6 +131,7 @@ impl SimulatedExchange {
             matching_engines: HashMap::new(),
             leverages,
             modules,
+            corporate_actions: Vec::new(),
             clock,
             msgbus,
             cache,
@@ -365,6 +369,8 @@ impl SimulatedExchange {
     }
 
     pub fn process_order_book_delta(&mut self, delta: OrderBookDelta) {
+        self.apply_corporate_actions(delta.ts_init);
+
         for module in &self.modules {
             module.pre_process(Data::Delta(delta));
         }
@@ -393,6 +399,8 @@ impl SimulatedExchange {
     }
 
     pub fn process_order_book_deltas(&mut self, deltas: OrderBookDeltas) {
+        self.apply_corporate_actions(deltas.ts_init);
+
         for module in &self.modules {
             module.pre_process(Data::Deltas(OrderBookDeltas_API::new(deltas.clone())));
         }
@@ -421,6 +429,8 @@ impl SimulatedExchange {
     }
 
     pub fn process_quote_tick(&mut self, quote: &QuoteTick) {
+        self.apply_corporate_actions(quote.ts_init);
+
         for module in &self.modules {
             module.pre_process(Data::Quote(quote.to_owned()));
         }
@@ -449,6 +459,8 @@ impl SimulatedExchange {
     }
 
     pub fn process_trade_tick(&mut self, trade: &TradeTick) {
+        self.apply_corporate_actions(trade.ts_init);
+
         for module in &self.modules {
             module.pre_process(Data::Trade(trade.to_owned()));
         }
@@ -477,6 +489,8 @@ impl SimulatedExchange {
     }
 
     pub fn process_bar(&mut self, bar: Bar) {
+        self.apply_corporate_actions(bar.ts_init);
+
         for module in &self.modules {
             module.pre_process(Data::Bar(bar));
         }
@@ -504,6 +518,26 @@ impl SimulatedExchange {
         }
     }
 
+    /// Processes the given `instrument` definition update from the data stream.
+    ///
+    /// The instrument is added if not already known, otherwise its matching engine applies the
+    /// new version (e.g. a change of tick size or fees) from this point in time. The definition
+    /// is also recorded as a version in the cache instrument history.
+    pub fn process_instrument(&mut self, instrument: InstrumentAny) -> anyhow::Result<()> {
+        let instrument_id = instrument.id();
+        self.cache.borrow_mut().add_instrument(instrument.clone())?;
+
+        match self.matching_engines.get_mut(&instrument_id) {
+            Some(matching_engine) => {
+                matching_engine.update_instrument(instrument.clone());
+                self.instruments.insert(instrument_id, instrument);
+                log::info!("Updated instrument {instrument_id}");
+                Ok(())
+            }
+            None => self.add_instrument(instrument),
+        }
+    }
+
     pub fn process_instrument_status(&mut self, status: InstrumentStatus) {
         // TODO add module preprocessing
 
@@ -530,6 +564,79 @@ impl SimulatedExchange {
         }
     }
 
+    /// Processes the given corporate `action` from the data stream.
+    ///
+    /// The action is held until market data is processed at or after its `ts_effective`
+    /// (the ex-date), when it is applied to all open positions in the instrument. Split adjusted
+    /// positions are replaced in the cache and published as `PositionChanged` events, and cash
+    /// dividends are credited to (or debited from,
+    /// for short positions) the account.
+    pub fn process_corporate_action(&mut self, action: CorporateAction) {
+        log::info!("Scheduled corporate action {action}");
+        self.corporate_actions.push(action);
+        self.corporate_actions
+            .sort_by_key(|action| action.ts_effective);
+    }
+
+    fn apply_corporate_actions(&mut self, ts_now: UnixNanos) {
+        let count = self
+            .corporate_actions
+            .partition_point(|action| action.ts_effective <= ts_now);
+        let actions: Vec<CorporateAction> = self.corporate_actions.drain(..count).collect();
+
+        for action in actions {
+            self.apply_corporate_action(&action);
+        }
+    }
+
+    fn apply_corporate_action(&mut self, action: &CorporateAction) {
+        let positions: Vec<Position> = {
+            let cache = self.cache.as_ref().borrow();
+            cache
+                .positions_open(Some(&self.id), Some(&action.instrument_id), None, None)
+                .into_iter()
+                .cloned()
+                .collect()
+        };
+
+        for mut position in positions {
+            if let Some(dividend) = position.calculate_dividend(action) {
+                self.adjust_account(dividend);
+            }
+
+            if action.is_split() {
+                if let Err(e) = position.apply_corporate_action(action) {
+                    log::error!("Cannot apply {action} to position {}: {e}", position.id);
+                    continue;
+                }
+                if let Err(e) = self.cache.borrow_mut().replace_position(&position) {
+                    log::error!("Cannot update position {}: {e}", position.id);
+                    continue;
+                }
+                self.publish_position_changed(&position, action.ts_effective);
+            }
+        }
+
+        log::info!("Applied corporate action {action}");
+    }
+
+    fn publish_position_changed(&self, position: &Position, ts_event: UnixNanos) {
+        let event = PositionChanged {
+            ts_event,
+            ..PositionChanged::create(
+                position,
+                &position.last_event(),
+                UUID4::new(),
+                self.clock.get_time_ns(),
+            )
+        };
+        let mut msgbus = self.msgbus.borrow_mut();
+        let topic = msgbus
+            .switchboard
+            .get_event_positions_topic(position.strategy_id);
+        msgbus.publish(&topic, &event);
+    }
+
     pub fn process(&mut self, _ts_now: UnixNanos) {
         todo!("process")
     }
@@ -545,6 +652,8 @@ impl SimulatedExchange {
             matching_engine.reset();
         }
 
+        self.corporate_actions.clear();
+
         // TODO Clear the inflight and message queues
         log::info!("Resetting exchange state");
     }
@@ -630,19 +739,25 @@ mod tests {
     use nautilus_model::{
         accounts::{AccountAny, MarginAccount},
         data::{
-            Bar, BarType, BookOrder, InstrumentStatus, OrderBookDelta, OrderBookDeltas, QuoteTick,
-            TradeTick,
+            Bar, BarType, BookOrder, CorporateAction, InstrumentStatus, OrderBookDelta,
+            OrderBookDeltas, QuoteTick, TradeTick,
         },
         enums::{
-            AccountType, AggressorSide, BookAction, BookType, MarketStatus, MarketStatusAction,
-            OmsType, OrderSide,
+            AccountType, AggressorSide, BookAction, BookType, CorporateActionType, MarketStatus,
+            MarketStatusAction, OmsType, OrderSide,
         },
-        events::AccountState,
+        events::{AccountState, PositionChanged},
         identifiers::{AccountId, ClientId, TradeId, TraderId, Venue},
-        instruments::{stubs::crypto_perpetual_ethusdt, CryptoPerpetual, InstrumentAny},
+        instruments::{
+            stubs::{audusd_sim, crypto_perpetual_ethusdt},
+            CryptoPerpetual, CurrencyPair, InstrumentAny,
+        },
+        position::Position,
+        stubs::stub_position_long,
         types::{AccountBalance, Currency, Money, Price, Quantity},
     };
     use rstest::rstest;
+    use rust_decimal::Decimal;
     use ustr::Ustr;
 
     use crate::{
@@ -776,6 +891,59 @@ mod tests {
         assert_eq!(best_ask_price, Some(Price::from("1001")));
     }
 
+    #[rstest]
+    fn test_exchange_process_instrument_updates_matching_engine(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+    ) {
+        let cache = Rc::new(RefCell::new(Cache::default()));
+        let mut exchange: SimulatedExchange = get_exchange(
+            Venue::new("BINANCE"),
+            AccountType::Margin,
+            BookType::L1_MBP,
+            None,
+            Some(cache.clone()),
+        );
+        exchange
+            .process_instrument(InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt))
+            .unwrap();
+
+        let updated = CryptoPerpetual {
+            price_increment: Price::from("0.05"),
+            taker_fee: Decimal::new(5, 4),
+            ts_event: UnixNanos::from(1),
+            ..crypto_perpetual_ethusdt
+        };
+        exchange
+            .process_instrument(InstrumentAny::CryptoPerpetual(updated))
+            .unwrap();
+
+        let matching_engine = exchange
+            .get_matching_engine(crypto_perpetual_ethusdt.id)
+            .unwrap();
+        assert_eq!(
+            matching_engine.instrument.price_increment(),
+            Price::from("0.05")
+        );
+        assert_eq!(matching_engine.instrument.taker_fee(), Decimal::new(5, 4));
+        assert_eq!(exchange.get_matching_engines().len(), 1);
+
+        let cache = cache.borrow();
+        let history = cache.instrument_history(&crypto_perpetual_ethusdt.id);
+        assert_eq!(history.len(), 2);
+        assert_eq!(
+            history[0].price_increment(),
+            crypto_perpetual_ethusdt.price_increment
+        );
+        assert_eq!(history[1].price_increment(), Price::from("0.05"));
+        assert_eq!(
+            cache
+                .instrument(&crypto_perpetual_ethusdt.id)
+                .unwrap()
+                .taker_fee(),
+            Decimal::new(5, 4)
+        );
+    }
+
     #[rstest]
     fn test_exchange_process_trade_tick(crypto_perpetual_ethusdt: CryptoPerpetual) {
         let mut exchange: SimulatedExchange = get_exchange(
@@ -1101,4 +1269,126 @@ mod tests {
         assert_eq!(current_balance.locked, Money::new(0.0, Currency::USD()));
         assert_eq!(current_balance.total, Money::new(1500.0, Currency::USD()));
     }
+
+    #[rstest]
+    fn test_process_corporate_action_applies_at_ts_effective(
+        stub_position_long: Position,
+        audusd_sim: CurrencyPair,
+    ) {
+        let account_type = AccountType::Margin;
+        let mut msgbus = MessageBus::default();
+        let mut cache = Cache::default();
+        let handler = get_message_saving_handler::<AccountState>(None);
+        msgbus.register(Ustr::from("Portfolio.update_account"), handler.clone());
+        let position_handler = get_message_saving_handler::<PositionChanged>(None);
+        msgbus.subscribe(
+            format!("events.position.{}", stub_position_long.strategy_id),
+            position_handler.clone(),
+            None,
+        );
+        let margin_account = MarginAccount::new(
+            AccountState::new(
+                AccountId::from("SIM-001"),
+                account_type,
+                vec![AccountBalance::new(
+                    Money::from("1000 USD"),
+                    Money::from("0 USD"),
+                    Money::from("1000 USD"),
+                )],
+                vec![],
+                false,
+                UUID4::default(),
+                UnixNanos::default(),
+                UnixNanos::default(),
+                None,
+            ),
+            false,
+        );
+        cache
+            .add_account(AccountAny::Margin(margin_account))
+            .unwrap();
+        let position_id = stub_position_long.id;
+        let instrument_id = stub_position_long.instrument_id;
+        cache
+            .add_position(stub_position_long, OmsType::Netting)
+            .unwrap();
+        cache.build_index();
+        let cache = Rc::new(RefCell::new(cache));
+
+        let mut exchange = get_exchange(
+            Venue::new("SIM"),
+            account_type,
+            BookType::L2_MBP,
+            Some(Rc::new(RefCell::new(msgbus))),
+            Some(cache.clone()),
+        );
+        exchange.initialize_account();
+
+        exchange.process_corporate_action(CorporateAction::new(
+            instrument_id,
+            CorporateActionType::CashDividend,
+            1,
+            1,
+            Some(Money::from("5.00 USD")),
+            UnixNanos::from(10),
+            UnixNanos::from(1),
+            UnixNanos::from(1),
+        ));
+        exchange.process_corporate_action(CorporateAction::new(
+            instrument_id,
+            CorporateActionType::Split,
+            1,
+            2,
+            None,
+            UnixNanos::from(20),
+            UnixNanos::from(1),
+            UnixNanos::from(1),
+        ));
+
+        exchange
+            .add_instrument(InstrumentAny::CurrencyPair(audusd_sim))
+            .unwrap();
+        let quote = |ts: u64| {
+            QuoteTick::new(
+                instrument_id,
+                Price::from("0.50000"),
+                Price::from("0.50010"),
+                Quantity::from(1),
+                Quantity::from(1),
+                UnixNanos::from(ts),
+                UnixNanos::from(ts),
+            )
+        };
+
+        // Neither action is effective yet
+        exchange.process_quote_tick(&quote(5));
+        assert_eq!(get_saved_messages::<AccountState>(handler.clone()).len(), 1);
+
+        // Dividend is effective, split is not
+        exchange.process_quote_tick(&quote(10));
+        let messages = get_saved_messages::<AccountState>(handler.clone());
+        assert_eq!(messages.len(), 2);
+        let current_balance = messages.last().unwrap().balances[0];
+        assert_eq!(current_balance.free, Money::from("1005 USD"));
+        assert_eq!(current_balance.total, Money::from("1005 USD"));
+        assert_eq!(
+            cache.borrow().position(&position_id).unwrap().quantity,
+            Quantity::from(1)
+        );
+        assert!(get_saved_messages::<PositionChanged>(position_handler.clone()).is_empty());
+
+        // Split is effective
+        exchange.process_quote_tick(&quote(20));
+        assert_eq!(get_saved_messages::<AccountState>(handler).len(), 2);
+        let cache = cache.borrow();
+        let position = cache.position(&position_id).unwrap();
+        assert_eq!(position.quantity, Quantity::from(2));
+        assert_eq!(position.avg_px_open, 0.5001);
+        let events = get_saved_messages::<PositionChanged>(position_handler);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].position_id, position_id);
+        assert_eq!(events[0].quantity, Quantity::from(2));
+        assert_eq!(events[0].avg_px_open, 0.5001);
+        assert_eq!(events[0].ts_event, UnixNanos::from(20));
+    }
 }
@@ -461,6 +461,24 @@ impl OrderMatchingEngine {
         self.iterate(trade.ts_event);
     }
 
+    /// Updates the instrument definition used by the matching engine.
+    ///
+    /// # Panics
+    ///
+    /// This function panics:
+    /// - If the `instrument` ID is not equal to the matching engine instrument ID.
+    pub fn update_instrument(&mut self, instrument: InstrumentAny) {
+        assert_eq!(
+            instrument.id(),
+            self.instrument.id(),
+            "`instrument.id` was not equal to the matching engine instrument ID"
+        );
+        log::debug!("Updating instrument {}", instrument.id());
+
+        self.core.price_increment = instrument.price_increment();
+        self.instrument = instrument;
+    }
+
     pub fn process_status(&mut self, action: MarketStatusAction) {
         log::debug!("Processing {action}");
 
@@ -36,18 +36,20 @@ use nautilus_core::{
         check_key_not_in_map, check_predicate_false, check_slice_not_empty, check_valid_string,
         FAILED,
     },
-    UUID4,
+    UnixNanos, UUID4,
 };
 use nautilus_model::{
     accounts::AccountAny,
-    data::{Bar, BarType, QuoteTick, TradeTick},
+    data::{
+        Bar, BarType, FundingRateUpdate, IndexPriceUpdate, MarkPriceUpdate, QuoteTick, TradeTick,
+    },
     enums::{AggregationSource, OmsType, OrderSide, PositionSide, PriceType, TriggerType},
     identifiers::{
         AccountId, ClientId, ClientOrderId, ComponentId, ExecAlgorithmId, InstrumentId,
         OrderListId, PositionId, StrategyId, Symbol, Venue, VenueOrderId,
     },
     instruments::{InstrumentAny, SyntheticInstrument},
-    orderbook::OrderBook,
+    orderbook::{OrderBook, OwnBookOrder, OwnOrderBook},
     orders::{OrderAny, OrderList},
     position::Position,
     types::{Currency, Money, Price, Quantity},
@@ -215,10 +217,15 @@ pub struct Cache {
     general: HashMap<String, Bytes>,
     quotes: HashMap<InstrumentId, VecDeque<QuoteTick>>,
     trades: HashMap<InstrumentId, VecDeque<TradeTick>>,
+    mark_prices: HashMap<InstrumentId, MarkPriceUpdate>,
+    index_prices: HashMap<InstrumentId, IndexPriceUpdate>,
+    funding_rates: HashMap<InstrumentId, FundingRateUpdate>,
     books: HashMap<InstrumentId, OrderBook>,
+    own_books: HashMap<InstrumentId, OwnOrderBook>,
     bars: HashMap<BarType, VecDeque<Bar>>,
     currencies: HashMap<Ustr, Currency>,
     instruments: HashMap<InstrumentId, InstrumentAny>,
+    instrument_history: HashMap<InstrumentId, Vec<InstrumentAny>>,
     synthetics: HashMap<InstrumentId, SyntheticInstrument>,
     accounts: HashMap<AccountId, AccountAny>,
     orders: HashMap<ClientOrderId, OrderAny>,
@@ -283,10 +290,15 @@ impl Cache {
             general: HashMap::new(),
             quotes: HashMap::new(),
             trades: HashMap::new(),
+            mark_prices: HashMap::new(),
+            index_prices: HashMap::new(),
+            funding_rates: HashMap::new(),
             books: HashMap::new(),
+            own_books: HashMap::new(),
             bars: HashMap::new(),
             currencies: HashMap::new(),
             instruments: HashMap::new(),
+            instrument_history: HashMap::new(),
             synthetics: HashMap::new(),
             accounts: HashMap::new(),
             orders: HashMap::new(),
@@ -335,6 +347,11 @@ impl Cache {
             Some(db) => db.load_instruments()?,
             None => HashMap::new(),
         };
+        self.instrument_history = self
+            .instruments
+            .iter()
+            .map(|(instrument_id, instrument)| (*instrument_id, vec![instrument.clone()]))
+            .collect();
 
         log::info!("Cached {} instruments from database", self.general.len());
         Ok(())
@@ -964,10 +981,15 @@ impl Cache {
         self.general.clear();
         self.quotes.clear();
         self.trades.clear();
+        self.mark_prices.clear();
+        self.index_prices.clear();
+        self.funding_rates.clear();
         self.books.clear();
+        self.own_books.clear();
         self.bars.clear();
         self.currencies.clear();
         self.instruments.clear();
+        self.instrument_history.clear();
         self.synthetics.clear();
         self.accounts.clear();
         self.orders.clear();
@@ -1113,6 +1135,39 @@ impl Cache {
         Ok(())
     }
 
+    /// Adds the given `mark_price` update to the cache.
+    ///
+    /// Only the latest mark price is held for each instrument.
+    pub fn add_mark_price(&mut self, mark_price: MarkPriceUpdate) -> anyhow::Result<()> {
+        log::debug!("Adding `MarkPriceUpdate` {}", mark_price.instrument_id);
+
+        self.mark_prices
+            .insert(mark_price.instrument_id, mark_price);
+        Ok(())
+    }
+
+    /// Adds the given `index_price` update to the cache.
+    ///
+    /// Only the latest index price is held for each instrument.
+    pub fn add_index_price(&mut self, index_price: IndexPriceUpdate) -> anyhow::Result<()> {
+        log::debug!("Adding `IndexPriceUpdate` {}", index_price.instrument_id);
+
+        self.index_prices
+            .insert(index_price.instrument_id, index_price);
+        Ok(())
+    }
+
+    /// Adds the given `funding_rate` update to the cache.
+    ///
+    /// Only the latest funding rate is held for each instrument.
+    pub fn add_funding_rate(&mut self, funding_rate: FundingRateUpdate) -> anyhow::Result<()> {
+        log::debug!("Adding `FundingRateUpdate` {}", funding_rate.instrument_id);
+
+        self.funding_rates
+            .insert(funding_rate.instrument_id, funding_rate);
+        Ok(())
+    }
+
     /// Adds the given `bar` to the cache.
     pub fn add_bar(&mut self, bar: Bar) -> anyhow::Result<()> {
         log::debug!("Adding `Bar` {}", bar.bar_type);
@@ -1170,14 +1225,38 @@ impl Cache {
     }
 
     /// Adds the given `instrument` to the cache.
+    ///
+    /// Each definition is kept as a version in the instrument history, keyed by its `ts_event`
+    /// (replacing any version with the same `ts_event`). The current instrument is the version
+    /// with the latest `ts_event`, so adding a historical version does not replace it.
+    ///
+    /// Only a version which becomes the current instrument is persisted to the database, as
+    /// the database holds a single definition per instrument ID.
     pub fn add_instrument(&mut self, instrument: InstrumentAny) -> anyhow::Result<()> {
         log::debug!("Adding `Instrument` {}", instrument.id());
 
-        if let Some(database) = &mut self.database {
-            database.add_instrument(&instrument)?;
+        let instrument_id = instrument.id();
+        let is_latest = self
+            .instrument_history
+            .get(&instrument_id)
+            .and_then(|history| history.last())
+            .is_none_or(|latest| instrument.ts_event() >= latest.ts_event());
+
+        if is_latest {
+            if let Some(database) = &mut self.database {
+                database.add_instrument(&instrument)?;
+            }
+        }
+
+        let history = self.instrument_history.entry(instrument_id).or_default();
+        match history.binary_search_by_key(&instrument.ts_event(), InstrumentAny::ts_event) {
+            Ok(index) => history[index] = instrument,
+            Err(index) => history.insert(index, instrument),
         }
 
-        self.instruments.insert(instrument.id(), instrument);
+        if let Some(latest) = history.last() {
+            self.instruments.insert(instrument_id, latest.clone());
+        }
         Ok(())
     }
 
@@ -1376,6 +1455,7 @@ impl Cache {
             // }
         }
 
+        self.update_own_order_book(&order);
         self.orders.insert(client_order_id, order);
 
         Ok(())
@@ -1518,12 +1598,29 @@ impl Cache {
             // }
         }
 
+        self.update_own_order_book(order);
+
         // update the order in the cache
         self.orders.insert(client_order_id, order.clone());
 
         Ok(())
     }
 
+    /// Updates the own order book for the given `order`, removing the order from the book
+    /// once it is no longer resting at a price.
+    fn update_own_order_book(&mut self, order: &OrderAny) {
+        let instrument_id = order.instrument_id();
+        if OwnBookOrder::from_order(order).is_none() && !self.own_books.contains_key(&instrument_id)
+        {
+            return;
+        }
+
+        self.own_books
+            .entry(instrument_id)
+            .or_insert_with(|| OwnOrderBook::new(instrument_id))
+            .update_from_order(order);
+    }
+
     /// Updates the given `order` as pending cancel locally.
     pub fn update_order_pending_cancel_local(&mut self, order: &OrderAny) {
         self.index
@@ -1552,6 +1649,22 @@ impl Cache {
         Ok(())
     }
 
+    /// Replaces the cached position with the given `position`, then updates its state.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the `position` is not already in the cache (use [`Cache::add_position`] instead).
+    /// - If updating the position in the database fails.
+    pub fn replace_position(&mut self, position: &Position) -> anyhow::Result<()> {
+        match self.positions.get_mut(&position.id) {
+            Some(cached) => *cached = position.clone(),
+            None => anyhow::bail!("Position {} not found in cache", position.id),
+        }
+
+        self.update_position(position)
+    }
+
     /// Creates a snapshot of the given position by cloning it, assigning a new ID,
     /// serializing it, and storing it in the position snapshots.
     pub fn snapshot_position(&mut self, position: &Position) -> anyhow::Result<()> {
@@ -2510,6 +2623,12 @@ impl Cache {
         self.books.get(instrument_id)
     }
 
+    /// Gets a reference to the own order book for the given `instrument_id`.
+    #[must_use]
+    pub fn own_order_book(&self, instrument_id: &InstrumentId) -> Option<&OwnOrderBook> {
+        self.own_books.get(instrument_id)
+    }
+
     /// Gets a reference to the order book for the given `instrument_id`.
     #[must_use]
     pub fn order_book_mut(&mut self, instrument_id: &InstrumentId) -> Option<&mut OrderBook> {
@@ -2532,6 +2651,24 @@ impl Cache {
             .and_then(|trades| trades.front())
     }
 
+    /// Gets a reference to the latest mark price update for the given `instrument_id`.
+    #[must_use]
+    pub fn mark_price(&self, instrument_id: &InstrumentId) -> Option<&MarkPriceUpdate> {
+        self.mark_prices.get(instrument_id)
+    }
+
+    /// Gets a reference to the latest index price update for the given `instrument_id`.
+    #[must_use]
+    pub fn index_price(&self, instrument_id: &InstrumentId) -> Option<&IndexPriceUpdate> {
+        self.index_prices.get(instrument_id)
+    }
+
+    /// Gets a reference to the latest funding rate update for the given `instrument_id`.
+    #[must_use]
+    pub fn funding_rate(&self, instrument_id: &InstrumentId) -> Option<&FundingRateUpdate> {
+        self.funding_rates.get(instrument_id)
+    }
+
     /// Gets a reference to the latest bar for the given `bar_type`.
     #[must_use]
     pub fn bar(&self, bar_type: &BarType) -> Option<&Bar> {
@@ -2672,6 +2809,29 @@ impl Cache {
         self.instruments.get(instrument_id)
     }
 
+    /// Returns a reference to the version of the instrument for the given `instrument_id` which
+    /// was in effect at `ts` (if found).
+    ///
+    /// This is the version with the latest `ts_event` at or before `ts`.
+    #[must_use]
+    pub fn instrument_at(
+        &self,
+        instrument_id: &InstrumentId,
+        ts: UnixNanos,
+    ) -> Option<&InstrumentAny> {
+        let history = self.instrument_history.get(instrument_id)?;
+        let index = history.partition_point(|instrument| instrument.ts_event() <= ts);
+        index.checked_sub(1).map(|index| &history[index])
+    }
+
+    /// Returns all versions of the instrument for the given `instrument_id`, ordered by `ts_event`.
+    #[must_use]
+    pub fn instrument_history(&self, instrument_id: &InstrumentId) -> &[InstrumentAny] {
+        self.instrument_history
+            .get(instrument_id)
+            .map_or(&[], Vec::as_slice)
+    }
+
     /// Returns references to all instrument IDs for the given `venue`.
     #[must_use]
     pub fn instrument_ids(&self, venue: Option<&Venue>) -> Vec<&InstrumentId> {
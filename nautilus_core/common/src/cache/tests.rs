@@ -18,9 +18,13 @@
 #[cfg(test)]
 mod tests {
     use bytes::Bytes;
+    use nautilus_core::UnixNanos;
     use nautilus_model::{
         accounts::AccountAny,
-        data::{Bar, QuoteTick, TradeTick},
+        data::{
+            stubs::{stub_funding_rate_update, stub_index_price_update, stub_mark_price_update},
+            Bar, FundingRateUpdate, IndexPriceUpdate, MarkPriceUpdate, QuoteTick, TradeTick,
+        },
         enums::{BookType, OmsType, OrderSide, OrderStatus, OrderType},
         events::{OrderAccepted, OrderEventAny, OrderRejected, OrderSubmitted},
         identifiers::{AccountId, ClientOrderId, PositionId, Venue},
@@ -28,9 +32,11 @@ mod tests {
         orderbook::OrderBook,
         orders::{builder::OrderTestBuilder, stubs::TestOrderEventStubs},
         position::Position,
+        stubs::stub_position_long,
         types::{Price, Quantity},
     };
     use rstest::{fixture, rstest};
+    use rust_decimal_macros::dec;
 
     use crate::cache::Cache;
 
@@ -371,6 +377,35 @@ mod tests {
         assert!(!cache.position_exists(&position_id));
     }
 
+    #[rstest]
+    fn test_replace_position_when_not_cached(mut cache: Cache, stub_position_long: Position) {
+        assert!(cache.replace_position(&stub_position_long).is_err());
+        assert!(cache.position(&stub_position_long.id).is_none());
+    }
+
+    #[rstest]
+    fn test_update_position_does_not_add_position(mut cache: Cache, stub_position_long: Position) {
+        cache.update_position(&stub_position_long).unwrap();
+
+        assert!(cache.position(&stub_position_long.id).is_none());
+    }
+
+    #[rstest]
+    fn test_replace_position(mut cache: Cache, stub_position_long: Position) {
+        cache
+            .add_position(stub_position_long.clone(), OmsType::Netting)
+            .unwrap();
+        let mut position = stub_position_long;
+        position.quantity = Quantity::from(2);
+
+        cache.replace_position(&position).unwrap();
+
+        assert_eq!(
+            cache.position(&position.id).unwrap().quantity,
+            Quantity::from(2)
+        );
+    }
+
     #[rstest]
     fn test_position_when_some(mut cache: Cache, audusd_sim: CurrencyPair) {
         let audusd_sim = InstrumentAny::CurrencyPair(audusd_sim);
@@ -443,6 +478,91 @@ mod tests {
         assert_eq!(result, Some(&InstrumentAny::CurrencyPair(audusd_sim)));
     }
 
+    #[rstest]
+    fn test_instrument_at_returns_version_in_effect(mut cache: Cache, audusd_sim: CurrencyPair) {
+        let v1 = CurrencyPair {
+            ts_event: UnixNanos::from(10),
+            ..audusd_sim
+        };
+        let v2 = CurrencyPair {
+            price_increment: Price::from("0.00005"),
+            ts_event: UnixNanos::from(20),
+            ..audusd_sim
+        };
+        // Add out of order to check versions are ordered by `ts_event`
+        cache
+            .add_instrument(InstrumentAny::CurrencyPair(v2))
+            .unwrap();
+        cache
+            .add_instrument(InstrumentAny::CurrencyPair(v1))
+            .unwrap();
+
+        // Instruments compare equal by ID, so distinguish versions by `ts_event`
+        let id = audusd_sim.id;
+        let ts_event_at = |ts: u64| {
+            cache
+                .instrument_at(&id, UnixNanos::from(ts))
+                .map(InstrumentAny::ts_event)
+        };
+        assert_eq!(ts_event_at(5), None);
+        assert_eq!(ts_event_at(10), Some(v1.ts_event));
+        assert_eq!(ts_event_at(19), Some(v1.ts_event));
+        assert_eq!(ts_event_at(25), Some(v2.ts_event));
+        assert_eq!(
+            cache.instrument(&id).map(InstrumentAny::price_increment),
+            Some(v2.price_increment)
+        );
+        assert_eq!(
+            cache
+                .instrument_history(&id)
+                .iter()
+                .map(InstrumentAny::ts_event)
+                .collect::<Vec<_>>(),
+            vec![v1.ts_event, v2.ts_event]
+        );
+    }
+
+    #[rstest]
+    fn test_add_instrument_with_same_ts_event_replaces_version(
+        mut cache: Cache,
+        audusd_sim: CurrencyPair,
+    ) {
+        let corrected = CurrencyPair {
+            price_increment: Price::from("0.00005"),
+            ..audusd_sim
+        };
+        cache
+            .add_instrument(InstrumentAny::CurrencyPair(audusd_sim))
+            .unwrap();
+        cache
+            .add_instrument(InstrumentAny::CurrencyPair(corrected))
+            .unwrap();
+
+        let history = cache.instrument_history(&audusd_sim.id);
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].price_increment(), corrected.price_increment);
+        assert_eq!(
+            cache
+                .instrument(&audusd_sim.id)
+                .map(InstrumentAny::price_increment),
+            Some(corrected.price_increment)
+        );
+    }
+
+    #[rstest]
+    fn test_reset_clears_instrument_history(mut cache: Cache, audusd_sim: CurrencyPair) {
+        cache
+            .add_instrument(InstrumentAny::CurrencyPair(audusd_sim))
+            .unwrap();
+
+        cache.reset();
+
+        assert!(cache.instrument_history(&audusd_sim.id).is_empty());
+        assert!(cache
+            .instrument_at(&audusd_sim.id, UnixNanos::default())
+            .is_none());
+    }
+
     #[rstest]
     fn test_instruments_when_empty(cache: Cache) {
         let esz1 = futures_contract_es(None, None);
@@ -512,6 +632,84 @@ mod tests {
         assert_eq!(result, Some(&mut book));
     }
 
+    #[rstest]
+    fn test_own_order_book_when_empty(cache: Cache, audusd_sim: CurrencyPair) {
+        assert!(cache.own_order_book(&audusd_sim.id).is_none());
+    }
+
+    #[rstest]
+    fn test_own_order_book_tracks_resting_limit_order(mut cache: Cache, audusd_sim: CurrencyPair) {
+        let audusd_sim = InstrumentAny::CurrencyPair(audusd_sim);
+        let mut order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(audusd_sim.id())
+            .side(OrderSide::Buy)
+            .price(Price::from("1.00000"))
+            .quantity(Quantity::from(100_000))
+            .build();
+        cache.add_order(order.clone(), None, None, false).unwrap();
+        assert!(cache.own_order_book(&audusd_sim.id()).is_none());
+
+        order
+            .apply(OrderEventAny::Submitted(OrderSubmitted::default()))
+            .unwrap();
+        cache.update_order(&order).unwrap();
+        order
+            .apply(OrderEventAny::Accepted(OrderAccepted::default()))
+            .unwrap();
+        cache.update_order(&order).unwrap();
+
+        let own_book = cache.own_order_book(&audusd_sim.id()).unwrap();
+        assert_eq!(own_book.count, 1);
+        assert_eq!(
+            own_book.size_at(OrderSide::Buy, Price::from("1.00000")),
+            dec!(100_000)
+        );
+
+        let filled = TestOrderEventStubs::order_filled(
+            &order,
+            &audusd_sim,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        order.apply(filled).unwrap();
+        cache.update_order(&order).unwrap();
+
+        let own_book = cache.own_order_book(&audusd_sim.id()).unwrap();
+        assert!(own_book.get_order(&order.client_order_id()).is_none());
+        assert_eq!(
+            own_book.size_at(OrderSide::Buy, Price::from("1.00000")),
+            dec!(0)
+        );
+    }
+
+    #[rstest]
+    fn test_reset_clears_own_order_books(mut cache: Cache, audusd_sim: CurrencyPair) {
+        let mut order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(audusd_sim.id)
+            .side(OrderSide::Sell)
+            .price(Price::from("1.00010"))
+            .quantity(Quantity::from(100_000))
+            .build();
+        order
+            .apply(OrderEventAny::Submitted(OrderSubmitted::default()))
+            .unwrap();
+        order
+            .apply(OrderEventAny::Accepted(OrderAccepted::default()))
+            .unwrap();
+        cache.add_order(order, None, None, false).unwrap();
+        assert!(cache.own_order_book(&audusd_sim.id).is_some());
+
+        cache.reset();
+
+        assert!(cache.own_order_book(&audusd_sim.id).is_none());
+    }
+
     #[rstest]
     fn test_quote_tick_when_empty(cache: Cache, audusd_sim: CurrencyPair) {
         let result = cache.quote(&audusd_sim.id);
@@ -576,6 +774,59 @@ mod tests {
         assert_eq!(result, Some(trades));
     }
 
+    #[rstest]
+    fn test_mark_price_when_empty(cache: Cache, stub_mark_price_update: MarkPriceUpdate) {
+        let result = cache.mark_price(&stub_mark_price_update.instrument_id);
+        assert!(result.is_none());
+    }
+
+    #[rstest]
+    fn test_mark_price_holds_latest(mut cache: Cache, stub_mark_price_update: MarkPriceUpdate) {
+        let mut latest = stub_mark_price_update;
+        latest.value = Price::from("100010.00");
+        latest.ts_event = UnixNanos::from(3);
+        latest.ts_init = UnixNanos::from(4);
+
+        cache.add_mark_price(stub_mark_price_update).unwrap();
+        cache.add_mark_price(latest).unwrap();
+
+        let result = cache.mark_price(&latest.instrument_id);
+        assert_eq!(result, Some(&latest));
+    }
+
+    #[rstest]
+    fn test_index_price_when_some(mut cache: Cache, stub_index_price_update: IndexPriceUpdate) {
+        cache.add_index_price(stub_index_price_update).unwrap();
+        let result = cache.index_price(&stub_index_price_update.instrument_id);
+        assert_eq!(result, Some(&stub_index_price_update));
+    }
+
+    #[rstest]
+    fn test_funding_rate_when_some(mut cache: Cache, stub_funding_rate_update: FundingRateUpdate) {
+        cache.add_funding_rate(stub_funding_rate_update).unwrap();
+        let result = cache.funding_rate(&stub_funding_rate_update.instrument_id);
+        assert_eq!(result, Some(&stub_funding_rate_update));
+    }
+
+    #[rstest]
+    fn test_reset_clears_derivatives_prices(
+        mut cache: Cache,
+        stub_mark_price_update: MarkPriceUpdate,
+        stub_funding_rate_update: FundingRateUpdate,
+    ) {
+        cache.add_mark_price(stub_mark_price_update).unwrap();
+        cache.add_funding_rate(stub_funding_rate_update).unwrap();
+
+        cache.reset();
+
+        assert!(cache
+            .mark_price(&stub_mark_price_update.instrument_id)
+            .is_none());
+        assert!(cache
+            .funding_rate(&stub_funding_rate_update.instrument_id)
+            .is_none());
+    }
+
     #[rstest]
     fn test_bar_when_empty(cache: Cache) {
         let bar = Bar::default();
@@ -16,21 +16,150 @@
 //! Factories for constructing domain objects such as orders.
 
 use indexmap::IndexMap;
-use nautilus_core::{AtomicTime, UUID4};
+use nautilus_core::{correctness::FAILED, AtomicTime, UnixNanos, UUID4};
 use nautilus_model::{
-    enums::{ContingencyType, OrderSide, TimeInForce},
+    enums::{ContingencyType, OrderSide, OrderType, TimeInForce, TrailingOffsetType, TriggerType},
+    events::OrderEventAny,
     identifiers::{
         ClientOrderId, ExecAlgorithmId, InstrumentId, OrderListId, StrategyId, TraderId,
     },
-    orders::{MarketOrder, OrderAny},
-    types::Quantity,
+    orders::{
+        LimitIfTouchedOrder, LimitOrder, MarketIfTouchedOrder, MarketOrder, MarketToLimitOrder,
+        OrderAny, OrderList, StopLimitOrder, StopMarketOrder, TrailingStopLimitOrder,
+        TrailingStopMarketOrder,
+    },
+    types::{quantity::check_quantity_positive, Price, Quantity},
 };
+use rust_decimal::Decimal;
 use ustr::Ustr;
 
 use crate::generators::{
     client_order_id::ClientOrderIdGenerator, order_list_id::OrderListIdGenerator,
 };
 
+/// The parameters of a single order to be created by an [`OrderFactory`].
+///
+/// Parameters which do not apply to the `order_type` are ignored.
+#[derive(Clone, Debug)]
+struct OrderSpec {
+    instrument_id: InstrumentId,
+    order_side: OrderSide,
+    quantity: Quantity,
+    order_type: OrderType,
+    price: Option<Price>,
+    trigger_price: Option<Price>,
+    trigger_type: Option<TriggerType>,
+    limit_offset: Option<Decimal>,
+    trailing_offset: Option<Decimal>,
+    trailing_offset_type: Option<TrailingOffsetType>,
+    time_in_force: Option<TimeInForce>,
+    expire_time: Option<UnixNanos>,
+    post_only: Option<bool>,
+    reduce_only: Option<bool>,
+    quote_quantity: Option<bool>,
+    display_qty: Option<Quantity>,
+    emulation_trigger: Option<TriggerType>,
+    trigger_instrument_id: Option<InstrumentId>,
+    exec_algorithm_id: Option<ExecAlgorithmId>,
+    exec_algorithm_params: Option<IndexMap<Ustr, Ustr>>,
+    tags: Option<Vec<Ustr>>,
+    client_order_id: Option<ClientOrderId>,
+}
+
+impl OrderSpec {
+    const fn new(
+        instrument_id: InstrumentId,
+        order_side: OrderSide,
+        quantity: Quantity,
+        order_type: OrderType,
+    ) -> Self {
+        Self {
+            instrument_id,
+            order_side,
+            quantity,
+            order_type,
+            price: None,
+            trigger_price: None,
+            trigger_type: None,
+            limit_offset: None,
+            trailing_offset: None,
+            trailing_offset_type: None,
+            time_in_force: None,
+            expire_time: None,
+            post_only: None,
+            reduce_only: None,
+            quote_quantity: None,
+            display_qty: None,
+            emulation_trigger: None,
+            trigger_instrument_id: None,
+            exec_algorithm_id: None,
+            exec_algorithm_params: None,
+            tags: None,
+            client_order_id: None,
+        }
+    }
+
+    /// Checks the spec has everything its `order_type` requires, so that creating the order
+    /// will not fail.
+    fn validate(&self) -> anyhow::Result<()> {
+        let order_type = self.order_type;
+        check_quantity_positive(self.quantity)?;
+        if matches!(
+            order_type,
+            OrderType::Limit
+                | OrderType::StopLimit
+                | OrderType::LimitIfTouched
+                | OrderType::TrailingStopLimit
+        ) {
+            required(self.price, "price", order_type)?;
+        }
+        if matches!(
+            order_type,
+            OrderType::StopMarket
+                | OrderType::StopLimit
+                | OrderType::MarketIfTouched
+                | OrderType::LimitIfTouched
+                | OrderType::TrailingStopMarket
+                | OrderType::TrailingStopLimit
+        ) {
+            required(self.trigger_price, "trigger_price", order_type)?;
+        }
+        if order_type == OrderType::TrailingStopLimit {
+            required(self.limit_offset, "limit_offset", order_type)?;
+        }
+        if matches!(
+            order_type,
+            OrderType::TrailingStopMarket | OrderType::TrailingStopLimit
+        ) {
+            required(self.trailing_offset, "trailing_offset", order_type)?;
+        }
+        if self.time_in_force == Some(TimeInForce::Gtd) {
+            anyhow::ensure!(
+                order_type != OrderType::Market,
+                "GTD not supported for Market orders"
+            );
+            anyhow::ensure!(
+                self.expire_time.is_some_and(|time| time != 0),
+                "`expire_time` is required for a GTD {order_type} order"
+            );
+        }
+        Ok(())
+    }
+}
+
+/// The contingency links of an order within an order list.
+#[derive(Clone, Debug, Default)]
+struct OrderLinkage {
+    contingency_type: Option<ContingencyType>,
+    order_list_id: Option<OrderListId>,
+    linked_order_ids: Option<Vec<ClientOrderId>>,
+    parent_order_id: Option<ClientOrderId>,
+}
+
+fn required<T>(value: Option<T>, param: &str, order_type: OrderType) -> anyhow::Result<T> {
+    value.ok_or_else(|| anyhow::anyhow!("`{param}` is required for a {order_type} order"))
+}
+
 #[repr(C)]
 #[derive(Debug)]
 pub struct OrderFactory {
@@ -106,126 +235,1098 @@ impl OrderFactory {
         tags: Option<Vec<Ustr>>,
         client_order_id: Option<ClientOrderId>,
     ) -> OrderAny {
-        let client_order_id = client_order_id.unwrap_or_else(|| self.generate_client_order_id());
-        let exec_spawn_id: Option<ClientOrderId> = if exec_algorithm_id.is_none() {
-            None
-        } else {
-            Some(client_order_id)
-        };
-        let order = MarketOrder::new(
-            self.trader_id,
-            self.strategy_id,
-            instrument_id,
-            client_order_id,
-            order_side,
-            quantity,
-            time_in_force.unwrap_or(TimeInForce::Gtc),
-            UUID4::new(),
-            self.clock.get_time_ns(),
-            reduce_only.unwrap_or(false),
-            quote_quantity.unwrap_or(false),
-            Some(ContingencyType::NoContingency),
-            None,
-            None,
-            None,
+        let spec = OrderSpec {
+            time_in_force,
+            reduce_only,
+            quote_quantity,
             exec_algorithm_id,
             exec_algorithm_params,
-            exec_spawn_id,
             tags,
-        );
-        OrderAny::Market(order)
+            client_order_id,
+            ..OrderSpec::new(instrument_id, order_side, quantity, OrderType::Market)
+        };
+        self.create_order(spec, OrderLinkage::default())
+            .expect(FAILED)
     }
-}
 
-////////////////////////////////////////////////////////////////////////////////
-// Tests
-////////////////////////////////////////////////////////////////////////////////
-#[cfg(test)]
-pub mod tests {
-    use nautilus_core::time::get_atomic_clock_static;
-    use nautilus_model::{
-        enums::{OrderSide, TimeInForce},
-        identifiers::{
-            stubs::{strategy_id_ema_cross, trader_id},
-            ClientOrderId, InstrumentId, OrderListId,
-        },
-    };
-    use rstest::{fixture, rstest};
+    #[allow(clippy::too_many_arguments)]
+    pub fn limit(
+        &mut self,
+        instrument_id: InstrumentId,
+        order_side: OrderSide,
+        quantity: Quantity,
+        price: Price,
+        time_in_force: Option<TimeInForce>,
+        expire_time: Option<UnixNanos>,
+        post_only: Option<bool>,
+        reduce_only: Option<bool>,
+        quote_quantity: Option<bool>,
+        display_qty: Option<Quantity>,
+        emulation_trigger: Option<TriggerType>,
+        trigger_instrument_id: Option<InstrumentId>,
+        exec_algorithm_id: Option<ExecAlgorithmId>,
+        exec_algorithm_params: Option<IndexMap<Ustr, Ustr>>,
+        tags: Option<Vec<Ustr>>,
+        client_order_id: Option<ClientOrderId>,
+    ) -> OrderAny {
+        let spec = OrderSpec {
+            price: Some(price),
+            time_in_force,
+            expire_time,
+            post_only,
+            reduce_only,
+            quote_quantity,
+            display_qty,
+            emulation_trigger,
+            trigger_instrument_id,
+            exec_algorithm_id,
+            exec_algorithm_params,
+            tags,
+            client_order_id,
+            ..OrderSpec::new(instrument_id, order_side, quantity, OrderType::Limit)
+        };
+        self.create_order(spec, OrderLinkage::default())
+            .expect(FAILED)
+    }
 
-    use crate::factories::OrderFactory;
+    #[allow(clippy::too_many_arguments)]
+    pub fn stop_market(
+        &mut self,
+        instrument_id: InstrumentId,
+        order_side: OrderSide,
+        quantity: Quantity,
+        trigger_price: Price,
+        trigger_type: Option<TriggerType>,
+        time_in_force: Option<TimeInForce>,
+        expire_time: Option<UnixNanos>,
+        reduce_only: Option<bool>,
+        quote_quantity: Option<bool>,
+        display_qty: Option<Quantity>,
+        emulation_trigger: Option<TriggerType>,
+        trigger_instrument_id: Option<InstrumentId>,
+        exec_algorithm_id: Option<ExecAlgorithmId>,
+        exec_algorithm_params: Option<IndexMap<Ustr, Ustr>>,
+        tags: Option<Vec<Ustr>>,
+        client_order_id: Option<ClientOrderId>,
+    ) -> OrderAny {
+        let spec = OrderSpec {
+            trigger_price: Some(trigger_price),
+            trigger_type,
+            time_in_force,
+            expire_time,
+            reduce_only,
+            quote_quantity,
+            display_qty,
+            emulation_trigger,
+            trigger_instrument_id,
+            exec_algorithm_id,
+            exec_algorithm_params,
+            tags,
+            client_order_id,
+            ..OrderSpec::new(instrument_id, order_side, quantity, OrderType::StopMarket)
+        };
+        self.create_order(spec, OrderLinkage::default())
+            .expect(FAILED)
+    }
 
-    #[fixture]
-    pub fn order_factory() -> OrderFactory {
-        let trader_id = trader_id();
-        let strategy_id = strategy_id_ema_cross();
-        OrderFactory::new(
-            trader_id,
-            strategy_id,
-            None,
-            None,
-            get_atomic_clock_static(),
-        )
+    #[allow(clippy::too_many_arguments)]
+    pub fn stop_limit(
+        &mut self,
+        instrument_id: InstrumentId,
+        order_side: OrderSide,
+        quantity: Quantity,
+        price: Price,
+        trigger_price: Price,
+        trigger_type: Option<TriggerType>,
+        time_in_force: Option<TimeInForce>,
+        expire_time: Option<UnixNanos>,
+        post_only: Option<bool>,
+        reduce_only: Option<bool>,
+        quote_quantity: Option<bool>,
+        display_qty: Option<Quantity>,
+        emulation_trigger: Option<TriggerType>,
+        trigger_instrument_id: Option<InstrumentId>,
+        exec_algorithm_id: Option<ExecAlgorithmId>,
+        exec_algorithm_params: Option<IndexMap<Ustr, Ustr>>,
+        tags: Option<Vec<Ustr>>,
+        client_order_id: Option<ClientOrderId>,
+    ) -> OrderAny {
+        let spec = OrderSpec {
+            price: Some(price),
+            trigger_price: Some(trigger_price),
+            trigger_type,
+            time_in_force,
+            expire_time,
+            post_only,
+            reduce_only,
+            quote_quantity,
+            display_qty,
+            emulation_trigger,
+            trigger_instrument_id,
+            exec_algorithm_id,
+            exec_algorithm_params,
+            tags,
+            client_order_id,
+            ..OrderSpec::new(instrument_id, order_side, quantity, OrderType::StopLimit)
+        };
+        self.create_order(spec, OrderLinkage::default())
+            .expect(FAILED)
     }
 
-    #[rstest]
-    fn test_generate_client_order_id(mut order_factory: OrderFactory) {
-        let client_order_id = order_factory.generate_client_order_id();
-        assert_eq!(
+    #[allow(clippy::too_many_arguments)]
+    pub fn market_to_limit(
+        &mut self,
+        instrument_id: InstrumentId,
+        order_side: OrderSide,
+        quantity: Quantity,
+        time_in_force: Option<TimeInForce>,
+        expire_time: Option<UnixNanos>,
+        reduce_only: Option<bool>,
+        quote_quantity: Option<bool>,
+        display_qty: Option<Quantity>,
+        exec_algorithm_id: Option<ExecAlgorithmId>,
+        exec_algorithm_params: Option<IndexMap<Ustr, Ustr>>,
+        tags: Option<Vec<Ustr>>,
+        client_order_id: Option<ClientOrderId>,
+    ) -> OrderAny {
+        let spec = OrderSpec {
+            time_in_force,
+            expire_time,
+            reduce_only,
+            quote_quantity,
+            display_qty,
+            exec_algorithm_id,
+            exec_algorithm_params,
+            tags,
             client_order_id,
-            ClientOrderId::new("O-19700101-000000-001-001-1")
-        );
+            ..OrderSpec::new(
+                instrument_id,
+                order_side,
+                quantity,
+                OrderType::MarketToLimit,
+            )
+        };
+        self.create_order(spec, OrderLinkage::default())
+            .expect(FAILED)
     }
 
-    #[rstest]
-    fn test_generate_order_list_id(mut order_factory: OrderFactory) {
-        let order_list_id = order_factory.generate_order_list_id();
-        assert_eq!(
-            order_list_id,
-            OrderListId::new("OL-19700101-000000-001-001-1")
-        );
+    #[allow(clippy::too_many_arguments)]
+    pub fn market_if_touched(
+        &mut self,
+        instrument_id: InstrumentId,
+        order_side: OrderSide,
+        quantity: Quantity,
+        trigger_price: Price,
+        trigger_type: Option<TriggerType>,
+        time_in_force: Option<TimeInForce>,
+        expire_time: Option<UnixNanos>,
+        reduce_only: Option<bool>,
+        quote_quantity: Option<bool>,
+        display_qty: Option<Quantity>,
+        emulation_trigger: Option<TriggerType>,
+        trigger_instrument_id: Option<InstrumentId>,
+        exec_algorithm_id: Option<ExecAlgorithmId>,
+        exec_algorithm_params: Option<IndexMap<Ustr, Ustr>>,
+        tags: Option<Vec<Ustr>>,
+        client_order_id: Option<ClientOrderId>,
+    ) -> OrderAny {
+        let spec = OrderSpec {
+            trigger_price: Some(trigger_price),
+            trigger_type,
+            time_in_force,
+            expire_time,
+            reduce_only,
+            quote_quantity,
+            display_qty,
+            emulation_trigger,
+            trigger_instrument_id,
+            exec_algorithm_id,
+            exec_algorithm_params,
+            tags,
+            client_order_id,
+            ..OrderSpec::new(
+                instrument_id,
+                order_side,
+                quantity,
+                OrderType::MarketIfTouched,
+            )
+        };
+        self.create_order(spec, OrderLinkage::default())
+            .expect(FAILED)
     }
 
-    #[rstest]
-    fn test_set_client_order_id_count(mut order_factory: OrderFactory) {
-        order_factory.set_client_order_id_count(10);
-        let client_order_id = order_factory.generate_client_order_id();
-        assert_eq!(
+    #[allow(clippy::too_many_arguments)]
+    pub fn limit_if_touched(
+        &mut self,
+        instrument_id: InstrumentId,
+        order_side: OrderSide,
+        quantity: Quantity,
+        price: Price,
+        trigger_price: Price,
+        trigger_type: Option<TriggerType>,
+        time_in_force: Option<TimeInForce>,
+        expire_time: Option<UnixNanos>,
+        post_only: Option<bool>,
+        reduce_only: Option<bool>,
+        quote_quantity: Option<bool>,
+        display_qty: Option<Quantity>,
+        emulation_trigger: Option<TriggerType>,
+        trigger_instrument_id: Option<InstrumentId>,
+        exec_algorithm_id: Option<ExecAlgorithmId>,
+        exec_algorithm_params: Option<IndexMap<Ustr, Ustr>>,
+        tags: Option<Vec<Ustr>>,
+        client_order_id: Option<ClientOrderId>,
+    ) -> OrderAny {
+        let spec = OrderSpec {
+            price: Some(price),
+            trigger_price: Some(trigger_price),
+            trigger_type,
+            time_in_force,
+            expire_time,
+            post_only,
+            reduce_only,
+            quote_quantity,
+            display_qty,
+            emulation_trigger,
+            trigger_instrument_id,
+            exec_algorithm_id,
+            exec_algorithm_params,
+            tags,
             client_order_id,
-            ClientOrderId::new("O-19700101-000000-001-001-11")
-        );
+            ..OrderSpec::new(
+                instrument_id,
+                order_side,
+                quantity,
+                OrderType::LimitIfTouched,
+            )
+        };
+        self.create_order(spec, OrderLinkage::default())
+            .expect(FAILED)
     }
 
-    #[rstest]
-    fn test_set_order_list_id_count(mut order_factory: OrderFactory) {
-        order_factory.set_order_list_id_count(10);
-        let order_list_id = order_factory.generate_order_list_id();
-        assert_eq!(
-            order_list_id,
-            OrderListId::new("OL-19700101-000000-001-001-11")
-        );
+    #[allow(clippy::too_many_arguments)]
+    pub fn trailing_stop_market(
+        &mut self,
+        instrument_id: InstrumentId,
+        order_side: OrderSide,
+        quantity: Quantity,
+        trigger_price: Price,
+        trailing_offset: Decimal,
+        trailing_offset_type: Option<TrailingOffsetType>,
+        trigger_type: Option<TriggerType>,
+        time_in_force: Option<TimeInForce>,
+        expire_time: Option<UnixNanos>,
+        reduce_only: Option<bool>,
+        quote_quantity: Option<bool>,
+        display_qty: Option<Quantity>,
+        emulation_trigger: Option<TriggerType>,
+        trigger_instrument_id: Option<InstrumentId>,
+        exec_algorithm_id: Option<ExecAlgorithmId>,
+        exec_algorithm_params: Option<IndexMap<Ustr, Ustr>>,
+        tags: Option<Vec<Ustr>>,
+        client_order_id: Option<ClientOrderId>,
+    ) -> OrderAny {
+        let spec = OrderSpec {
+            trigger_price: Some(trigger_price),
+            trailing_offset: Some(trailing_offset),
+            trailing_offset_type,
+            trigger_type,
+            time_in_force,
+            expire_time,
+            reduce_only,
+            quote_quantity,
+            display_qty,
+            emulation_trigger,
+            trigger_instrument_id,
+            exec_algorithm_id,
+            exec_algorithm_params,
+            tags,
+            client_order_id,
+            ..OrderSpec::new(
+                instrument_id,
+                order_side,
+                quantity,
+                OrderType::TrailingStopMarket,
+            )
+        };
+        self.create_order(spec, OrderLinkage::default())
+            .expect(FAILED)
     }
 
-    #[rstest]
-    fn test_reset_factory(mut order_factory: OrderFactory) {
-        order_factory.generate_order_list_id();
-        order_factory.generate_client_order_id();
-        order_factory.reset_factory();
-        let client_order_id = order_factory.generate_client_order_id();
-        let order_list_id = order_factory.generate_order_list_id();
-        assert_eq!(
+    #[allow(clippy::too_many_arguments)]
+    pub fn trailing_stop_limit(
+        &mut self,
+        instrument_id: InstrumentId,
+        order_side: OrderSide,
+        quantity: Quantity,
+        price: Price,
+        trigger_price: Price,
+        limit_offset: Decimal,
+        trailing_offset: Decimal,
+        trailing_offset_type: Option<TrailingOffsetType>,
+        trigger_type: Option<TriggerType>,
+        time_in_force: Option<TimeInForce>,
+        expire_time: Option<UnixNanos>,
+        post_only: Option<bool>,
+        reduce_only: Option<bool>,
+        quote_quantity: Option<bool>,
+        display_qty: Option<Quantity>,
+        emulation_trigger: Option<TriggerType>,
+        trigger_instrument_id: Option<InstrumentId>,
+        exec_algorithm_id: Option<ExecAlgorithmId>,
+        exec_algorithm_params: Option<IndexMap<Ustr, Ustr>>,
+        tags: Option<Vec<Ustr>>,
+        client_order_id: Option<ClientOrderId>,
+    ) -> OrderAny {
+        let spec = OrderSpec {
+            price: Some(price),
+            trigger_price: Some(trigger_price),
+            limit_offset: Some(limit_offset),
+            trailing_offset: Some(trailing_offset),
+            trailing_offset_type,
+            trigger_type,
+            time_in_force,
+            expire_time,
+            post_only,
+            reduce_only,
+            quote_quantity,
+            display_qty,
+            emulation_trigger,
+            trigger_instrument_id,
+            exec_algorithm_id,
+            exec_algorithm_params,
+            tags,
             client_order_id,
-            ClientOrderId::new("O-19700101-000000-001-001-1")
-        );
-        assert_eq!(
-            order_list_id,
-            OrderListId::new("OL-19700101-000000-001-001-1")
-        );
+            ..OrderSpec::new(
+                instrument_id,
+                order_side,
+                quantity,
+                OrderType::TrailingStopLimit,
+            )
+        };
+        self.create_order(spec, OrderLinkage::default())
+            .expect(FAILED)
     }
 
-    #[rstest]
-    fn test_market_order(mut order_factory: OrderFactory) {
-        let market_order = order_factory.market(
-            InstrumentId::from("BTCUSDT.BINANCE"),
+    /// Creates a bracket order list: an entry order with attached take-profit and stop-loss orders.
+    ///
+    /// The entry order is the OTO (One-Triggers-Other) parent of the take-profit and stop-loss
+    /// orders, which are reduce-only and linked to each other by `contingency_type` (OCO by
+    /// default, or OUO). All three orders share a generated order list ID and are emulated with
+    /// `emulation_trigger` where the order type allows it.
+    ///
+    /// - The entry is a `MARKET` order by default, or a `LIMIT`, `MARKET_IF_TOUCHED`,
+    ///   `LIMIT_IF_TOUCHED`, `STOP_MARKET` or `STOP_LIMIT` order using `entry_price` and/or
+    ///   `entry_trigger_price`, with `time_in_force` and `expire_time`.
+    /// - The take-profit is a `LIMIT` order by default, or a `LIMIT_IF_TOUCHED` or
+    ///   `MARKET_IF_TOUCHED` order using `tp_price` and/or `tp_trigger_price`.
+    /// - The stop-loss is a `STOP_MARKET` order by default, or a `TRAILING_STOP_MARKET` order
+    ///   trailing by `sl_trailing_offset` from the initial `sl_trigger_price`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if:
+    /// - `contingency_type` is not OCO or OUO.
+    /// - An order type is not supported for its leg of the bracket.
+    /// - A price or offset required by a leg's order type is not provided.
+    /// - Any of the orders fails its validation.
+    #[allow(clippy::too_many_arguments)]
+    pub fn bracket(
+        &mut self,
+        instrument_id: InstrumentId,
+        order_side: OrderSide,
+        quantity: Quantity,
+        entry_order_type: Option<OrderType>,
+        entry_price: Option<Price>,
+        entry_trigger_price: Option<Price>,
+        tp_order_type: Option<OrderType>,
+        tp_price: Option<Price>,
+        tp_trigger_price: Option<Price>,
+        sl_order_type: Option<OrderType>,
+        sl_trigger_price: Price,
+        sl_trailing_offset: Option<Decimal>,
+        sl_trailing_offset_type: Option<TrailingOffsetType>,
+        time_in_force: Option<TimeInForce>,
+        expire_time: Option<UnixNanos>,
+        tp_post_only: Option<bool>,
+        quote_quantity: Option<bool>,
+        emulation_trigger: Option<TriggerType>,
+        trigger_instrument_id: Option<InstrumentId>,
+        contingency_type: Option<ContingencyType>,
+    ) -> anyhow::Result<OrderList> {
+        let entry_order_type = entry_order_type.unwrap_or(OrderType::Market);
+        let tp_order_type = tp_order_type.unwrap_or(OrderType::Limit);
+        let sl_order_type = sl_order_type.unwrap_or(OrderType::StopMarket);
+        let contingency_type = contingency_type.unwrap_or(ContingencyType::Oco);
+
+        anyhow::ensure!(
+            matches!(contingency_type, ContingencyType::Oco | ContingencyType::Ouo),
+            "Invalid `contingency_type` for bracket take-profit and stop-loss, was {contingency_type}"
+        );
+        anyhow::ensure!(
+            matches!(
+                entry_order_type,
+                OrderType::Market
+                    | OrderType::Limit
+                    | OrderType::MarketIfTouched
+                    | OrderType::LimitIfTouched
+                    | OrderType::StopMarket
+                    | OrderType::StopLimit
+            ),
+            "Invalid `entry_order_type` for bracket, was {entry_order_type}"
+        );
+        anyhow::ensure!(
+            matches!(
+                tp_order_type,
+                OrderType::Limit | OrderType::LimitIfTouched | OrderType::MarketIfTouched
+            ),
+            "Invalid `tp_order_type` for bracket, was {tp_order_type}"
+        );
+        anyhow::ensure!(
+            matches!(
+                sl_order_type,
+                OrderType::StopMarket | OrderType::TrailingStopMarket
+            ),
+            "Invalid `sl_order_type` for bracket, was {sl_order_type}"
+        );
+
+        let exit_side = match order_side {
+            OrderSide::Buy => OrderSide::Sell,
+            OrderSide::Sell => OrderSide::Buy,
+            OrderSide::NoOrderSide => {
+                anyhow::bail!("Invalid `order_side` for bracket, was {order_side}")
+            }
+        };
+
+        let entry_spec = OrderSpec {
+            price: entry_price,
+            trigger_price: entry_trigger_price,
+            time_in_force,
+            expire_time,
+            quote_quantity,
+            emulation_trigger,
+            trigger_instrument_id,
+            tags: Some(vec![Ustr::from("ENTRY")]),
+            ..OrderSpec::new(instrument_id, order_side, quantity, entry_order_type)
+        };
+
+        let tp_spec = OrderSpec {
+            price: tp_price,
+            trigger_price: tp_trigger_price,
+            post_only: tp_post_only,
+            reduce_only: Some(true),
+            quote_quantity,
+            emulation_trigger,
+            trigger_instrument_id,
+            tags: Some(vec![Ustr::from("TAKE_PROFIT")]),
+            ..OrderSpec::new(instrument_id, exit_side, quantity, tp_order_type)
+        };
+
+        let sl_spec = OrderSpec {
+            trigger_price: Some(sl_trigger_price),
+            trailing_offset: sl_trailing_offset,
+            trailing_offset_type: sl_trailing_offset_type,
+            reduce_only: Some(true),
+            quote_quantity,
+            emulation_trigger,
+            trigger_instrument_id,
+            tags: Some(vec![Ustr::from("STOP_LOSS")]),
+            ..OrderSpec::new(instrument_id, exit_side, quantity, sl_order_type)
+        };
+
+        entry_spec.validate()?;
+        tp_spec.validate()?;
+        sl_spec.validate()?;
+
+        // Only generate IDs once all orders are known to be valid
+        let order_list_id = self.generate_order_list_id();
+        let entry_client_order_id = self.generate_client_order_id();
+        let tp_client_order_id = self.generate_client_order_id();
+        let sl_client_order_id = self.generate_client_order_id();
+
+        let entry_linkage = OrderLinkage {
+            contingency_type: Some(ContingencyType::Oto),
+            order_list_id: Some(order_list_id),
+            linked_order_ids: Some(vec![tp_client_order_id, sl_client_order_id]),
+            parent_order_id: None,
+        };
+        let tp_linkage = OrderLinkage {
+            contingency_type: Some(contingency_type),
+            order_list_id: Some(order_list_id),
+            linked_order_ids: Some(vec![sl_client_order_id]),
+            parent_order_id: Some(entry_client_order_id),
+        };
+        let sl_linkage = OrderLinkage {
+            contingency_type: Some(contingency_type),
+            order_list_id: Some(order_list_id),
+            linked_order_ids: Some(vec![tp_client_order_id]),
+            parent_order_id: Some(entry_client_order_id),
+        };
+
+        let orders = vec![
+            self.create_order(
+                OrderSpec {
+                    client_order_id: Some(entry_client_order_id),
+                    ..entry_spec
+                },
+                entry_linkage,
+            )?,
+            self.create_order(
+                OrderSpec {
+                    client_order_id: Some(tp_client_order_id),
+                    ..tp_spec
+                },
+                tp_linkage,
+            )?,
+            self.create_order(
+                OrderSpec {
+                    client_order_id: Some(sl_client_order_id),
+                    ..sl_spec
+                },
+                sl_linkage,
+            )?,
+        ];
+
+        Ok(OrderList::new(
+            order_list_id,
+            instrument_id,
+            self.strategy_id,
+            orders,
+            self.clock.get_time_ns(),
+        ))
+    }
+
+    /// Creates an OCO (One-Cancels-Other) order list from `orders`.
+    ///
+    /// Each order is linked to all of the others with an OCO contingency, and all orders share a
+    /// generated order list ID.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if:
+    /// - Fewer than two orders are provided.
+    /// - The orders are not all for this factory's strategy and the same instrument.
+    /// - Any order has already been submitted or already belongs to an order list.
+    /// - The orders do not have unique client order IDs.
+    pub fn oco(&mut self, orders: Vec<OrderAny>) -> anyhow::Result<OrderList> {
+        anyhow::ensure!(
+            orders.len() >= 2,
+            "OCO order list requires at least two orders, was {}",
+            orders.len()
+        );
+        self.check_listable(&orders)?;
+
+        let order_list_id = self.generate_order_list_id();
+        let client_order_ids: Vec<ClientOrderId> =
+            orders.iter().map(OrderAny::client_order_id).collect();
+        let orders = orders
+            .into_iter()
+            .map(|order| {
+                let client_order_id = order.client_order_id();
+                let linked_order_ids = client_order_ids
+                    .iter()
+                    .copied()
+                    .filter(|id| *id != client_order_id)
+                    .collect();
+                let linkage = OrderLinkage {
+                    contingency_type: Some(ContingencyType::Oco),
+                    order_list_id: Some(order_list_id),
+                    linked_order_ids: Some(linked_order_ids),
+                    parent_order_id: None,
+                };
+                Self::link_order(order, linkage)
+            })
+            .collect();
+
+        Ok(self.create_order_list(order_list_id, orders))
+    }
+
+    /// Creates an OTO (One-Triggers-Other) order list from a `parent` order and its `children`.
+    ///
+    /// The parent order is linked to the children with an OTO contingency, each child has the
+    /// parent as its parent order, and all orders share a generated order list ID.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if:
+    /// - No children are provided.
+    /// - The orders are not all for this factory's strategy and the same instrument.
+    /// - Any order has already been submitted or already belongs to an order list.
+    /// - The orders do not have unique client order IDs.
+    pub fn oto(&mut self, parent: OrderAny, children: Vec<OrderAny>) -> anyhow::Result<OrderList> {
+        anyhow::ensure!(
+            !children.is_empty(),
+            "OTO order list requires at least one child order"
+        );
+        let mut orders = Vec::with_capacity(children.len() + 1);
+        orders.push(parent);
+        orders.extend(children);
+        self.check_listable(&orders)?;
+
+        let order_list_id = self.generate_order_list_id();
+        let parent_order_id = orders[0].client_order_id();
+        let child_order_ids: Vec<ClientOrderId> =
+            orders[1..].iter().map(OrderAny::client_order_id).collect();
+        let orders = orders
+            .into_iter()
+            .enumerate()
+            .map(|(i, order)| {
+                let linkage = if i == 0 {
+                    OrderLinkage {
+                        contingency_type: Some(ContingencyType::Oto),
+                        order_list_id: Some(order_list_id),
+                        linked_order_ids: Some(child_order_ids.clone()),
+                        parent_order_id: None,
+                    }
+                } else {
+                    OrderLinkage {
+                        order_list_id: Some(order_list_id),
+                        parent_order_id: Some(parent_order_id),
+                        ..OrderLinkage::default()
+                    }
+                };
+                Self::link_order(order, linkage)
+            })
+            .collect();
+
+        Ok(self.create_order_list(order_list_id, orders))
+    }
+
+    fn check_listable(&self, orders: &[OrderAny]) -> anyhow::Result<()> {
+        let instrument_id = orders[0].instrument_id();
+        let mut client_order_ids = Vec::with_capacity(orders.len());
+        for order in orders {
+            let client_order_id = order.client_order_id();
+            anyhow::ensure!(
+                order.strategy_id() == self.strategy_id,
+                "Order {client_order_id} strategy ID {} does not match {}",
+                order.strategy_id(),
+                self.strategy_id
+            );
+            anyhow::ensure!(
+                order.instrument_id() == instrument_id,
+                "Order {client_order_id} instrument ID {} does not match {instrument_id}",
+                order.instrument_id()
+            );
+            anyhow::ensure!(
+                order.events().len() == 1,
+                "Order {client_order_id} has already been submitted"
+            );
+            anyhow::ensure!(
+                order.order_list_id().is_none(),
+                "Order {client_order_id} already belongs to an order list"
+            );
+            anyhow::ensure!(
+                !client_order_ids.contains(&client_order_id),
+                "Duplicate client order ID {client_order_id}"
+            );
+            client_order_ids.push(client_order_id);
+        }
+        Ok(())
+    }
+
+    fn link_order(order: OrderAny, linkage: OrderLinkage) -> OrderAny {
+        let mut init = match order.events()[0] {
+            OrderEventAny::Initialized(init) => init.clone(),
+            event => panic!("First event must be `OrderInitialized`, was {event:?}"),
+        };
+        init.contingency_type = Some(
+            linkage
+                .contingency_type
+                .unwrap_or(ContingencyType::NoContingency),
+        );
+        init.order_list_id = linkage.order_list_id;
+        init.linked_order_ids = linkage.linked_order_ids;
+        init.parent_order_id = linkage.parent_order_id;
+        OrderAny::from(init)
+    }
+
+    fn create_order_list(&self, order_list_id: OrderListId, orders: Vec<OrderAny>) -> OrderList {
+        OrderList::new(
+            order_list_id,
+            orders[0].instrument_id(),
+            self.strategy_id,
+            orders,
+            self.clock.get_time_ns(),
+        )
+    }
+
+    fn create_order(&mut self, spec: OrderSpec, linkage: OrderLinkage) -> anyhow::Result<OrderAny> {
+        let order_type = spec.order_type;
+        let client_order_id = spec
+            .client_order_id
+            .unwrap_or_else(|| self.generate_client_order_id());
+        let exec_spawn_id: Option<ClientOrderId> = if spec.exec_algorithm_id.is_none() {
+            None
+        } else {
+            Some(client_order_id)
+        };
+        let time_in_force = spec.time_in_force.unwrap_or(TimeInForce::Gtc);
+        let trigger_type = spec.trigger_type.unwrap_or(TriggerType::Default);
+        let trailing_offset_type = spec
+            .trailing_offset_type
+            .unwrap_or(TrailingOffsetType::Price);
+        let post_only = spec.post_only.unwrap_or(false);
+        let reduce_only = spec.reduce_only.unwrap_or(false);
+        let quote_quantity = spec.quote_quantity.unwrap_or(false);
+        let contingency_type = Some(
+            linkage
+                .contingency_type
+                .unwrap_or(ContingencyType::NoContingency),
+        );
+        let init_id = UUID4::new();
+        let ts_init = self.clock.get_time_ns();
+
+        let order = match order_type {
+            OrderType::Market => OrderAny::Market(MarketOrder::new(
+                self.trader_id,
+                self.strategy_id,
+                spec.instrument_id,
+                client_order_id,
+                spec.order_side,
+                spec.quantity,
+                time_in_force,
+                init_id,
+                ts_init,
+                reduce_only,
+                quote_quantity,
+                contingency_type,
+                linkage.order_list_id,
+                linkage.linked_order_ids,
+                linkage.parent_order_id,
+                spec.exec_algorithm_id,
+                spec.exec_algorithm_params,
+                exec_spawn_id,
+                spec.tags,
+            )),
+            OrderType::Limit => OrderAny::Limit(LimitOrder::new(
+                self.trader_id,
+                self.strategy_id,
+                spec.instrument_id,
+                client_order_id,
+                spec.order_side,
+                spec.quantity,
+                required(spec.price, "price", order_type)?,
+                time_in_force,
+                spec.expire_time,
+                post_only,
+                reduce_only,
+                quote_quantity,
+                spec.display_qty,
+                spec.emulation_trigger,
+                spec.trigger_instrument_id,
+                contingency_type,
+                linkage.order_list_id,
+                linkage.linked_order_ids,
+                linkage.parent_order_id,
+                spec.exec_algorithm_id,
+                spec.exec_algorithm_params,
+                exec_spawn_id,
+                spec.tags,
+                init_id,
+                ts_init,
+            )?),
+            OrderType::StopMarket => OrderAny::StopMarket(StopMarketOrder::new(
+                self.trader_id,
+                self.strategy_id,
+                spec.instrument_id,
+                client_order_id,
+                spec.order_side,
+                spec.quantity,
+                required(spec.trigger_price, "trigger_price", order_type)?,
+                trigger_type,
+                time_in_force,
+                spec.expire_time,
+                reduce_only,
+                quote_quantity,
+                spec.display_qty,
+                spec.emulation_trigger,
+                spec.trigger_instrument_id,
+                contingency_type,
+                linkage.order_list_id,
+                linkage.linked_order_ids,
+                linkage.parent_order_id,
+                spec.exec_algorithm_id,
+                spec.exec_algorithm_params,
+                exec_spawn_id,
+                spec.tags,
+                init_id,
+                ts_init,
+            )),
+            OrderType::StopLimit => OrderAny::StopLimit(StopLimitOrder::new(
+                self.trader_id,
+                self.strategy_id,
+                spec.instrument_id,
+                client_order_id,
+                spec.order_side,
+                spec.quantity,
+                required(spec.price, "price", order_type)?,
+                required(spec.trigger_price, "trigger_price", order_type)?,
+                trigger_type,
+                time_in_force,
+                spec.expire_time,
+                post_only,
+                reduce_only,
+                quote_quantity,
+                spec.display_qty,
+                spec.emulation_trigger,
+                spec.trigger_instrument_id,
+                contingency_type,
+                linkage.order_list_id,
+                linkage.linked_order_ids,
+                linkage.parent_order_id,
+                spec.exec_algorithm_id,
+                spec.exec_algorithm_params,
+                exec_spawn_id,
+                spec.tags,
+                init_id,
+                ts_init,
+            )),
+            OrderType::MarketToLimit => OrderAny::MarketToLimit(MarketToLimitOrder::new(
+                self.trader_id,
+                self.strategy_id,
+                spec.instrument_id,
+                client_order_id,
+                spec.order_side,
+                spec.quantity,
+                time_in_force,
+                spec.expire_time,
+                post_only,
+                reduce_only,
+                quote_quantity,
+                spec.display_qty,
+                contingency_type,
+                linkage.order_list_id,
+                linkage.linked_order_ids,
+                linkage.parent_order_id,
+                spec.exec_algorithm_id,
+                spec.exec_algorithm_params,
+                exec_spawn_id,
+                spec.tags,
+                init_id,
+                ts_init,
+            )),
+            OrderType::MarketIfTouched => OrderAny::MarketIfTouched(MarketIfTouchedOrder::new(
+                self.trader_id,
+                self.strategy_id,
+                spec.instrument_id,
+                client_order_id,
+                spec.order_side,
+                spec.quantity,
+                required(spec.trigger_price, "trigger_price", order_type)?,
+                trigger_type,
+                time_in_force,
+                spec.expire_time,
+                reduce_only,
+                quote_quantity,
+                spec.display_qty,
+                spec.emulation_trigger,
+                spec.trigger_instrument_id,
+                contingency_type,
+                linkage.order_list_id,
+                linkage.linked_order_ids,
+                linkage.parent_order_id,
+                spec.exec_algorithm_id,
+                spec.exec_algorithm_params,
+                exec_spawn_id,
+                spec.tags,
+                init_id,
+                ts_init,
+            )),
+            OrderType::LimitIfTouched => OrderAny::LimitIfTouched(LimitIfTouchedOrder::new(
+                self.trader_id,
+                self.strategy_id,
+                spec.instrument_id,
+                client_order_id,
+                spec.order_side,
+                spec.quantity,
+                required(spec.price, "price", order_type)?,
+                required(spec.trigger_price, "trigger_price", order_type)?,
+                trigger_type,
+                time_in_force,
+                spec.expire_time,
+                post_only,
+                reduce_only,
+                quote_quantity,
+                spec.display_qty,
+                spec.emulation_trigger,
+                spec.trigger_instrument_id,
+                contingency_type,
+                linkage.order_list_id,
+                linkage.linked_order_ids,
+                linkage.parent_order_id,
+                spec.exec_algorithm_id,
+                spec.exec_algorithm_params,
+                exec_spawn_id,
+                spec.tags,
+                init_id,
+                ts_init,
+            )),
+            OrderType::TrailingStopMarket => {
+                OrderAny::TrailingStopMarket(TrailingStopMarketOrder::new(
+                    self.trader_id,
+                    self.strategy_id,
+                    spec.instrument_id,
+                    client_order_id,
+                    spec.order_side,
+                    spec.quantity,
+                    required(spec.trigger_price, "trigger_price", order_type)?,
+                    trigger_type,
+                    required(spec.trailing_offset, "trailing_offset", order_type)?,
+                    trailing_offset_type,
+                    time_in_force,
+                    spec.expire_time,
+                    reduce_only,
+                    quote_quantity,
+                    spec.display_qty,
+                    spec.emulation_trigger,
+                    spec.trigger_instrument_id,
+                    contingency_type,
+                    linkage.order_list_id,
+                    linkage.linked_order_ids,
+                    linkage.parent_order_id,
+                    spec.exec_algorithm_id,
+                    spec.exec_algorithm_params,
+                    exec_spawn_id,
+                    spec.tags,
+                    init_id,
+                    ts_init,
+                ))
+            }
+            OrderType::TrailingStopLimit => {
+                OrderAny::TrailingStopLimit(TrailingStopLimitOrder::new(
+                    self.trader_id,
+                    self.strategy_id,
+                    spec.instrument_id,
+                    client_order_id,
+                    spec.order_side,
+                    spec.quantity,
+                    required(spec.price, "price", order_type)?,
+                    required(spec.trigger_price, "trigger_price", order_type)?,
+                    trigger_type,
+                    required(spec.limit_offset, "limit_offset", order_type)?,
+                    required(spec.trailing_offset, "trailing_offset", order_type)?,
+                    trailing_offset_type,
+                    time_in_force,
+                    spec.expire_time,
+                    post_only,
+                    reduce_only,
+                    quote_quantity,
+                    spec.display_qty,
+                    spec.emulation_trigger,
+                    spec.trigger_instrument_id,
+                    contingency_type,
+                    linkage.order_list_id,
+                    linkage.linked_order_ids,
+                    linkage.parent_order_id,
+                    spec.exec_algorithm_id,
+                    spec.exec_algorithm_params,
+                    exec_spawn_id,
+                    spec.tags,
+                    init_id,
+                    ts_init,
+                ))
+            }
+        };
+        Ok(order)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+pub mod tests {
+    use nautilus_core::{time::get_atomic_clock_static, UnixNanos};
+    use nautilus_model::{
+        enums::{
+            ContingencyType, OrderSide, OrderType, TimeInForce, TrailingOffsetType, TriggerType,
+        },
+        identifiers::{
+            stubs::{strategy_id_ema_cross, trader_id},
+            ClientOrderId, InstrumentId, OrderListId,
+        },
+        orders::OrderAny,
+        types::{Price, Quantity},
+    };
+    use rstest::{fixture, rstest};
+    use rust_decimal_macros::dec;
+    use ustr::Ustr;
+
+    use crate::factories::OrderFactory;
+
+    #[fixture]
+    pub fn order_factory() -> OrderFactory {
+        let trader_id = trader_id();
+        let strategy_id = strategy_id_ema_cross();
+        OrderFactory::new(
+            trader_id,
+            strategy_id,
+            None,
+            None,
+            get_atomic_clock_static(),
+        )
+    }
+
+    #[rstest]
+    fn test_generate_client_order_id(mut order_factory: OrderFactory) {
+        let client_order_id = order_factory.generate_client_order_id();
+        assert_eq!(
+            client_order_id,
+            ClientOrderId::new("O-19700101-000000-001-001-1")
+        );
+    }
+
+    #[rstest]
+    fn test_generate_order_list_id(mut order_factory: OrderFactory) {
+        let order_list_id = order_factory.generate_order_list_id();
+        assert_eq!(
+            order_list_id,
+            OrderListId::new("OL-19700101-000000-001-001-1")
+        );
+    }
+
+    #[rstest]
+    fn test_set_client_order_id_count(mut order_factory: OrderFactory) {
+        order_factory.set_client_order_id_count(10);
+        let client_order_id = order_factory.generate_client_order_id();
+        assert_eq!(
+            client_order_id,
+            ClientOrderId::new("O-19700101-000000-001-001-11")
+        );
+    }
+
+    #[rstest]
+    fn test_set_order_list_id_count(mut order_factory: OrderFactory) {
+        order_factory.set_order_list_id_count(10);
+        let order_list_id = order_factory.generate_order_list_id();
+        assert_eq!(
+            order_list_id,
+            OrderListId::new("OL-19700101-000000-001-001-11")
+        );
+    }
+
+    #[rstest]
+    fn test_reset_factory(mut order_factory: OrderFactory) {
+        order_factory.generate_order_list_id();
+        order_factory.generate_client_order_id();
+        order_factory.reset_factory();
+        let client_order_id = order_factory.generate_client_order_id();
+        let order_list_id = order_factory.generate_order_list_id();
+        assert_eq!(
+            client_order_id,
+            ClientOrderId::new("O-19700101-000000-001-001-1")
+        );
+        assert_eq!(
+            order_list_id,
+            OrderListId::new("OL-19700101-000000-001-001-1")
+        );
+    }
+
+    #[rstest]
+    fn test_market_order(mut order_factory: OrderFactory) {
+        let market_order = order_factory.market(
+            InstrumentId::from("BTCUSDT.BINANCE"),
             OrderSide::Buy,
             100.into(),
             Some(TimeInForce::Gtc),
@@ -253,4 +1354,608 @@ pub mod tests {
         );
         // assert_eq!(market_order.order_list_id(), None);
     }
+
+    #[rstest]
+    fn test_limit_order(mut order_factory: OrderFactory) {
+        let order = order_factory.limit(
+            InstrumentId::from("BTCUSDT.BINANCE"),
+            OrderSide::Sell,
+            Quantity::from(1),
+            Price::from("50000.00"),
+            Some(TimeInForce::Gtd),
+            Some(UnixNanos::from(1_000)),
+            Some(true),
+            None,
+            None,
+            Some(Quantity::from(1)),
+            Some(TriggerType::BidAsk),
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        assert_eq!(order.order_type(), OrderType::Limit);
+        assert_eq!(order.order_side(), OrderSide::Sell);
+        assert_eq!(order.price(), Some(Price::from("50000.00")));
+        assert_eq!(order.time_in_force(), TimeInForce::Gtd);
+        assert_eq!(order.expire_time(), Some(UnixNanos::from(1_000)));
+        assert!(order.is_post_only());
+        assert!(!order.is_reduce_only());
+        assert_eq!(order.display_qty(), Some(Quantity::from(1)));
+        assert_eq!(order.emulation_trigger(), Some(TriggerType::BidAsk));
+        assert_eq!(
+            order.contingency_type(),
+            Some(ContingencyType::NoContingency)
+        );
+        assert_eq!(
+            order.client_order_id(),
+            ClientOrderId::new("O-19700101-000000-001-001-1")
+        );
+    }
+
+    #[rstest]
+    #[should_panic]
+    fn test_limit_order_gtd_without_expire_time_panics(mut order_factory: OrderFactory) {
+        order_factory.limit(
+            InstrumentId::from("BTCUSDT.BINANCE"),
+            OrderSide::Buy,
+            Quantity::from(1),
+            Price::from("50000.00"),
+            Some(TimeInForce::Gtd),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+    }
+
+    #[rstest]
+    fn test_stop_market_order(mut order_factory: OrderFactory) {
+        let order = order_factory.stop_market(
+            InstrumentId::from("BTCUSDT.BINANCE"),
+            OrderSide::Sell,
+            Quantity::from(1),
+            Price::from("49000.00"),
+            Some(TriggerType::LastPrice),
+            None,
+            None,
+            Some(true),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        assert_eq!(order.order_type(), OrderType::StopMarket);
+        assert_eq!(order.trigger_price(), Some(Price::from("49000.00")));
+        assert_eq!(order.trigger_type(), Some(TriggerType::LastPrice));
+        assert_eq!(order.time_in_force(), TimeInForce::Gtc);
+        assert!(order.is_reduce_only());
+    }
+
+    #[rstest]
+    fn test_stop_limit_order(mut order_factory: OrderFactory) {
+        let order = order_factory.stop_limit(
+            InstrumentId::from("BTCUSDT.BINANCE"),
+            OrderSide::Buy,
+            Quantity::from(1),
+            Price::from("51010.00"),
+            Price::from("51000.00"),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        assert_eq!(order.order_type(), OrderType::StopLimit);
+        assert_eq!(order.price(), Some(Price::from("51010.00")));
+        assert_eq!(order.trigger_price(), Some(Price::from("51000.00")));
+        assert_eq!(order.trigger_type(), Some(TriggerType::Default));
+    }
+
+    #[rstest]
+    fn test_market_to_limit_order(mut order_factory: OrderFactory) {
+        let order = order_factory.market_to_limit(
+            InstrumentId::from("BTCUSDT.BINANCE"),
+            OrderSide::Buy,
+            Quantity::from(1),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        assert_eq!(order.order_type(), OrderType::MarketToLimit);
+        assert_eq!(order.price(), None);
+    }
+
+    #[rstest]
+    fn test_market_if_touched_order(mut order_factory: OrderFactory) {
+        let order = order_factory.market_if_touched(
+            InstrumentId::from("BTCUSDT.BINANCE"),
+            OrderSide::Buy,
+            Quantity::from(1),
+            Price::from("48000.00"),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        assert_eq!(order.order_type(), OrderType::MarketIfTouched);
+        assert_eq!(order.trigger_price(), Some(Price::from("48000.00")));
+    }
+
+    #[rstest]
+    fn test_limit_if_touched_order(mut order_factory: OrderFactory) {
+        let order = order_factory.limit_if_touched(
+            InstrumentId::from("BTCUSDT.BINANCE"),
+            OrderSide::Buy,
+            Quantity::from(1),
+            Price::from("48010.00"),
+            Price::from("48000.00"),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        assert_eq!(order.order_type(), OrderType::LimitIfTouched);
+        assert_eq!(order.price(), Some(Price::from("48010.00")));
+        assert_eq!(order.trigger_price(), Some(Price::from("48000.00")));
+    }
+
+    #[rstest]
+    fn test_trailing_stop_market_order(mut order_factory: OrderFactory) {
+        let order = order_factory.trailing_stop_market(
+            InstrumentId::from("BTCUSDT.BINANCE"),
+            OrderSide::Sell,
+            Quantity::from(1),
+            Price::from("49000.00"),
+            dec!(100),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        assert_eq!(order.order_type(), OrderType::TrailingStopMarket);
+        assert_eq!(order.trailing_offset(), Some(dec!(100)));
+        assert_eq!(
+            order.trailing_offset_type(),
+            Some(TrailingOffsetType::Price)
+        );
+    }
+
+    #[rstest]
+    fn test_trailing_stop_limit_order(mut order_factory: OrderFactory) {
+        let order = order_factory.trailing_stop_limit(
+            InstrumentId::from("BTCUSDT.BINANCE"),
+            OrderSide::Sell,
+            Quantity::from(1),
+            Price::from("48990.00"),
+            Price::from("49000.00"),
+            dec!(10),
+            dec!(50),
+            Some(TrailingOffsetType::BasisPoints),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        assert_eq!(order.order_type(), OrderType::TrailingStopLimit);
+        assert_eq!(order.limit_offset(), Some(dec!(10)));
+        assert_eq!(order.trailing_offset(), Some(dec!(50)));
+        assert_eq!(
+            order.trailing_offset_type(),
+            Some(TrailingOffsetType::BasisPoints)
+        );
+    }
+
+    #[rstest]
+    fn test_bracket_market_entry(mut order_factory: OrderFactory) {
+        let order_list = order_factory
+            .bracket(
+                InstrumentId::from("BTCUSDT.BINANCE"),
+                OrderSide::Buy,
+                Quantity::from(1),
+                None,
+                None,
+                None,
+                None,
+                Some(Price::from("51000.00")),
+                None,
+                None,
+                Price::from("49000.00"),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+
+        let entry_id = ClientOrderId::new("O-19700101-000000-001-001-1");
+        let tp_id = ClientOrderId::new("O-19700101-000000-001-001-2");
+        let sl_id = ClientOrderId::new("O-19700101-000000-001-001-3");
+        let order_list_id = OrderListId::new("OL-19700101-000000-001-001-1");
+        assert_eq!(order_list.id, order_list_id);
+        assert_eq!(order_list.orders.len(), 3);
+
+        let entry = &order_list.orders[0];
+        assert_eq!(entry.client_order_id(), entry_id);
+        assert_eq!(entry.order_type(), OrderType::Market);
+        assert_eq!(entry.order_side(), OrderSide::Buy);
+        assert_eq!(entry.contingency_type(), Some(ContingencyType::Oto));
+        assert_eq!(entry.linked_order_ids(), Some(vec![tp_id, sl_id]));
+        assert_eq!(entry.parent_order_id(), None);
+        assert_eq!(entry.order_list_id(), Some(order_list_id));
+        assert_eq!(entry.tags(), Some(vec![Ustr::from("ENTRY")]));
+
+        let tp = &order_list.orders[1];
+        assert_eq!(tp.client_order_id(), tp_id);
+        assert_eq!(tp.order_type(), OrderType::Limit);
+        assert_eq!(tp.order_side(), OrderSide::Sell);
+        assert_eq!(tp.price(), Some(Price::from("51000.00")));
+        assert!(tp.is_reduce_only());
+        assert_eq!(tp.contingency_type(), Some(ContingencyType::Oco));
+        assert_eq!(tp.linked_order_ids(), Some(vec![sl_id]));
+        assert_eq!(tp.parent_order_id(), Some(entry_id));
+        assert_eq!(tp.order_list_id(), Some(order_list_id));
+        assert_eq!(tp.tags(), Some(vec![Ustr::from("TAKE_PROFIT")]));
+
+        let sl = &order_list.orders[2];
+        assert_eq!(sl.client_order_id(), sl_id);
+        assert_eq!(sl.order_type(), OrderType::StopMarket);
+        assert_eq!(sl.order_side(), OrderSide::Sell);
+        assert_eq!(sl.trigger_price(), Some(Price::from("49000.00")));
+        assert!(sl.is_reduce_only());
+        assert_eq!(sl.contingency_type(), Some(ContingencyType::Oco));
+        assert_eq!(sl.linked_order_ids(), Some(vec![tp_id]));
+        assert_eq!(sl.parent_order_id(), Some(entry_id));
+        assert_eq!(sl.order_list_id(), Some(order_list_id));
+        assert_eq!(sl.tags(), Some(vec![Ustr::from("STOP_LOSS")]));
+    }
+
+    #[rstest]
+    fn test_bracket_limit_entry_with_emulation_and_trailing_stop(mut order_factory: OrderFactory) {
+        let order_list = order_factory
+            .bracket(
+                InstrumentId::from("BTCUSDT.BINANCE"),
+                OrderSide::Sell,
+                Quantity::from(2),
+                Some(OrderType::Limit),
+                Some(Price::from("50000.00")),
+                None,
+                Some(OrderType::LimitIfTouched),
+                Some(Price::from("48000.00")),
+                Some(Price::from("48010.00")),
+                Some(OrderType::TrailingStopMarket),
+                Price::from("51000.00"),
+                Some(dec!(100)),
+                Some(TrailingOffsetType::Price),
+                Some(TimeInForce::Day),
+                None,
+                Some(true),
+                None,
+                Some(TriggerType::BidAsk),
+                None,
+                Some(ContingencyType::Ouo),
+            )
+            .unwrap();
+
+        let entry = &order_list.orders[0];
+        assert_eq!(entry.order_type(), OrderType::Limit);
+        assert_eq!(entry.price(), Some(Price::from("50000.00")));
+        assert_eq!(entry.time_in_force(), TimeInForce::Day);
+        assert_eq!(entry.emulation_trigger(), Some(TriggerType::BidAsk));
+
+        let tp = &order_list.orders[1];
+        assert_eq!(tp.order_type(), OrderType::LimitIfTouched);
+        assert_eq!(tp.order_side(), OrderSide::Buy);
+        assert_eq!(tp.trigger_price(), Some(Price::from("48010.00")));
+        assert_eq!(tp.time_in_force(), TimeInForce::Gtc);
+        assert!(tp.is_post_only());
+        assert_eq!(tp.contingency_type(), Some(ContingencyType::Ouo));
+        assert_eq!(tp.emulation_trigger(), Some(TriggerType::BidAsk));
+
+        let sl = &order_list.orders[2];
+        assert_eq!(sl.order_type(), OrderType::TrailingStopMarket);
+        assert_eq!(sl.order_side(), OrderSide::Buy);
+        assert_eq!(sl.trigger_price(), Some(Price::from("51000.00")));
+        assert_eq!(sl.trailing_offset(), Some(dec!(100)));
+        assert_eq!(sl.contingency_type(), Some(ContingencyType::Ouo));
+        assert_eq!(sl.emulation_trigger(), Some(TriggerType::BidAsk));
+    }
+
+    #[rstest]
+    #[case(None, None, Some(ContingencyType::Oto))]
+    #[case(Some(OrderType::Limit), None, None)]
+    #[case(
+        Some(OrderType::TrailingStopLimit),
+        Some(Price::from("50000.00")),
+        None
+    )]
+    fn test_bracket_invalid_parameters(
+        mut order_factory: OrderFactory,
+        #[case] entry_order_type: Option<OrderType>,
+        #[case] entry_price: Option<Price>,
+        #[case] contingency_type: Option<ContingencyType>,
+    ) {
+        let result = order_factory.bracket(
+            InstrumentId::from("BTCUSDT.BINANCE"),
+            OrderSide::Buy,
+            Quantity::from(1),
+            entry_order_type,
+            entry_price,
+            None,
+            None,
+            Some(Price::from("51000.00")),
+            None,
+            None,
+            Price::from("49000.00"),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            contingency_type,
+        );
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_bracket_trailing_stop_without_offset(mut order_factory: OrderFactory) {
+        let result = order_factory.bracket(
+            InstrumentId::from("BTCUSDT.BINANCE"),
+            OrderSide::Buy,
+            Quantity::from(1),
+            None,
+            None,
+            None,
+            None,
+            Some(Price::from("51000.00")),
+            None,
+            Some(OrderType::TrailingStopMarket),
+            Price::from("49000.00"),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "`trailing_offset` is required for a TRAILING_STOP_MARKET order"
+        );
+    }
+
+    #[rstest]
+    fn test_bracket_invalid_parameters_do_not_use_ids(mut order_factory: OrderFactory) {
+        let result = order_factory.bracket(
+            InstrumentId::from("BTCUSDT.BINANCE"),
+            OrderSide::Buy,
+            Quantity::from(1),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Price::from("49000.00"),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "`price` is required for a LIMIT order"
+        );
+        assert_eq!(
+            order_factory.generate_client_order_id(),
+            ClientOrderId::new("O-19700101-000000-001-001-1")
+        );
+        assert_eq!(
+            order_factory.generate_order_list_id(),
+            OrderListId::new("OL-19700101-000000-001-001-1")
+        );
+    }
+
+    fn limit_order(
+        order_factory: &mut OrderFactory,
+        order_side: OrderSide,
+        price: &str,
+    ) -> OrderAny {
+        order_factory.limit(
+            InstrumentId::from("BTCUSDT.BINANCE"),
+            order_side,
+            Quantity::from(1),
+            Price::from(price),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+    }
+
+    #[rstest]
+    fn test_oco(mut order_factory: OrderFactory) {
+        let order1 = limit_order(&mut order_factory, OrderSide::Sell, "51000.00");
+        let order2 = limit_order(&mut order_factory, OrderSide::Sell, "52000.00");
+        let order3 = limit_order(&mut order_factory, OrderSide::Sell, "53000.00");
+
+        let order_list = order_factory.oco(vec![order1, order2, order3]).unwrap();
+
+        let id1 = ClientOrderId::new("O-19700101-000000-001-001-1");
+        let id2 = ClientOrderId::new("O-19700101-000000-001-001-2");
+        let id3 = ClientOrderId::new("O-19700101-000000-001-001-3");
+        let order_list_id = OrderListId::new("OL-19700101-000000-001-001-1");
+        assert_eq!(order_list.id, order_list_id);
+        assert_eq!(order_list.orders.len(), 3);
+        for (order, linked_order_ids) in
+            order_list
+                .orders
+                .iter()
+                .zip([vec![id2, id3], vec![id1, id3], vec![id1, id2]])
+        {
+            assert_eq!(order.contingency_type(), Some(ContingencyType::Oco));
+            assert_eq!(order.order_list_id(), Some(order_list_id));
+            assert_eq!(order.linked_order_ids(), Some(linked_order_ids));
+            assert_eq!(order.parent_order_id(), None);
+        }
+        assert_eq!(order_list.orders[1].price(), Some(Price::from("52000.00")));
+    }
+
+    #[rstest]
+    fn test_oto(mut order_factory: OrderFactory) {
+        let parent = limit_order(&mut order_factory, OrderSide::Buy, "50000.00");
+        let child1 = limit_order(&mut order_factory, OrderSide::Sell, "51000.00");
+        let child2 = limit_order(&mut order_factory, OrderSide::Sell, "52000.00");
+
+        let order_list = order_factory.oto(parent, vec![child1, child2]).unwrap();
+
+        let parent_id = ClientOrderId::new("O-19700101-000000-001-001-1");
+        let child1_id = ClientOrderId::new("O-19700101-000000-001-001-2");
+        let child2_id = ClientOrderId::new("O-19700101-000000-001-001-3");
+        let order_list_id = OrderListId::new("OL-19700101-000000-001-001-1");
+        assert_eq!(order_list.id, order_list_id);
+
+        let parent = &order_list.orders[0];
+        assert_eq!(parent.client_order_id(), parent_id);
+        assert_eq!(parent.contingency_type(), Some(ContingencyType::Oto));
+        assert_eq!(parent.linked_order_ids(), Some(vec![child1_id, child2_id]));
+        assert_eq!(parent.parent_order_id(), None);
+        assert_eq!(parent.order_list_id(), Some(order_list_id));
+
+        for child in &order_list.orders[1..] {
+            assert_eq!(
+                child.contingency_type(),
+                Some(ContingencyType::NoContingency)
+            );
+            assert_eq!(child.linked_order_ids(), None);
+            assert_eq!(child.parent_order_id(), Some(parent_id));
+            assert_eq!(child.order_list_id(), Some(order_list_id));
+        }
+    }
+
+    #[rstest]
+    fn test_oco_with_single_order(mut order_factory: OrderFactory) {
+        let order = limit_order(&mut order_factory, OrderSide::Sell, "51000.00");
+
+        let result = order_factory.oco(vec![order]);
+
+        assert!(result.is_err());
+        assert_eq!(
+            order_factory.generate_order_list_id(),
+            OrderListId::new("OL-19700101-000000-001-001-1")
+        );
+    }
+
+    #[rstest]
+    fn test_oto_with_order_already_in_list(mut order_factory: OrderFactory) {
+        let order1 = limit_order(&mut order_factory, OrderSide::Sell, "51000.00");
+        let order2 = limit_order(&mut order_factory, OrderSide::Sell, "52000.00");
+        let order_list = order_factory.oco(vec![order1, order2]).unwrap();
+        let parent = limit_order(&mut order_factory, OrderSide::Buy, "50000.00");
+
+        let result = order_factory.oto(parent, vec![order_list.orders[0].clone()]);
+
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("already belongs to an order list"));
+    }
+
+    #[rstest]
+    fn test_oco_with_duplicate_orders(mut order_factory: OrderFactory) {
+        let order = limit_order(&mut order_factory, OrderSide::Sell, "51000.00");
+
+        let result = order_factory.oco(vec![order.clone(), order]);
+
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .starts_with("Duplicate client order ID"));
+    }
 }
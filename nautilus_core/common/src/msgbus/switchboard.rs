@@ -13,7 +13,7 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::collections::HashMap;
+use std::{collections::HashMap, num::NonZeroU64};
 
 use nautilus_model::{
     data::{BarType, DataType},
@@ -37,7 +37,14 @@ pub struct MessagingSwitchboard {
     depth_topics: HashMap<InstrumentId, Ustr>,
     quote_topics: HashMap<InstrumentId, Ustr>,
     trade_topics: HashMap<InstrumentId, Ustr>,
+    mark_price_topics: HashMap<InstrumentId, Ustr>,
+    index_price_topics: HashMap<InstrumentId, Ustr>,
+    funding_rate_topics: HashMap<InstrumentId, Ustr>,
     bar_topics: HashMap<BarType, Ustr>,
+    book_bar_topics: HashMap<BarType, Ustr>,
+    roll_topics: HashMap<InstrumentId, Ustr>,
+    corporate_action_topics: HashMap<InstrumentId, Ustr>,
+    conflated_topics: HashMap<(Ustr, NonZeroU64), Ustr>,
     order_snapshots_topics: HashMap<ClientOrderId, Ustr>,
     positions_snapshots_topics: HashMap<PositionId, Ustr>,
 }
@@ -57,7 +64,14 @@ impl Default for MessagingSwitchboard {
             depth_topics: HashMap::new(),
             quote_topics: HashMap::new(),
             trade_topics: HashMap::new(),
+            mark_price_topics: HashMap::new(),
+            index_price_topics: HashMap::new(),
+            funding_rate_topics: HashMap::new(),
             bar_topics: HashMap::new(),
+            book_bar_topics: HashMap::new(),
+            roll_topics: HashMap::new(),
+            corporate_action_topics: HashMap::new(),
+            conflated_topics: HashMap::new(),
             order_snapshots_topics: HashMap::new(),
             event_orders_topics: HashMap::new(),
             event_positions_topics: HashMap::new(),
@@ -141,6 +155,45 @@ impl MessagingSwitchboard {
         })
     }
 
+    #[must_use]
+    pub fn get_mark_prices_topic(&mut self, instrument_id: InstrumentId) -> Ustr {
+        *self
+            .mark_price_topics
+            .entry(instrument_id)
+            .or_insert_with(|| {
+                Ustr::from(&format!(
+                    "data.mark_prices.{}.{}",
+                    instrument_id.venue, instrument_id.symbol
+                ))
+            })
+    }
+
+    #[must_use]
+    pub fn get_index_prices_topic(&mut self, instrument_id: InstrumentId) -> Ustr {
+        *self
+            .index_price_topics
+            .entry(instrument_id)
+            .or_insert_with(|| {
+                Ustr::from(&format!(
+                    "data.index_prices.{}.{}",
+                    instrument_id.venue, instrument_id.symbol
+                ))
+            })
+    }
+
+    #[must_use]
+    pub fn get_funding_rates_topic(&mut self, instrument_id: InstrumentId) -> Ustr {
+        *self
+            .funding_rate_topics
+            .entry(instrument_id)
+            .or_insert_with(|| {
+                Ustr::from(&format!(
+                    "data.funding_rates.{}.{}",
+                    instrument_id.venue, instrument_id.symbol
+                ))
+            })
+    }
+
     #[must_use]
     pub fn get_bars_topic(&mut self, bar_type: BarType) -> Ustr {
         *self
@@ -149,6 +202,49 @@ impl MessagingSwitchboard {
             .or_insert_with(|| Ustr::from(&format!("data.bars.{bar_type}")))
     }
 
+    /// Returns the topic for bars of the `bar_type` aggregated from order book prices.
+    #[must_use]
+    pub fn get_book_bars_topic(&mut self, bar_type: BarType) -> Ustr {
+        *self
+            .book_bar_topics
+            .entry(bar_type)
+            .or_insert_with(|| Ustr::from(&format!("data.book_bars.{bar_type}")))
+    }
+
+    /// Returns the topic for roll events of the continuous futures `instrument_id`.
+    #[must_use]
+    pub fn get_rolls_topic(&mut self, instrument_id: InstrumentId) -> Ustr {
+        *self.roll_topics.entry(instrument_id).or_insert_with(|| {
+            Ustr::from(&format!(
+                "data.rolls.{}.{}",
+                instrument_id.venue, instrument_id.symbol
+            ))
+        })
+    }
+
+    /// Returns the topic for corporate actions (splits and dividends) of the `instrument_id`.
+    #[must_use]
+    pub fn get_corporate_actions_topic(&mut self, instrument_id: InstrumentId) -> Ustr {
+        *self
+            .corporate_action_topics
+            .entry(instrument_id)
+            .or_insert_with(|| {
+                Ustr::from(&format!(
+                    "data.corporate_actions.{}.{}",
+                    instrument_id.venue, instrument_id.symbol
+                ))
+            })
+    }
+
+    /// Returns the topic for data from `topic` conflated to at most one update per `interval_ms`.
+    #[must_use]
+    pub fn get_conflated_topic(&mut self, topic: Ustr, interval_ms: NonZeroU64) -> Ustr {
+        *self
+            .conflated_topics
+            .entry((topic, interval_ms))
+            .or_insert_with(|| Ustr::from(&format!("conflated.{interval_ms}ms.{topic}")))
+    }
+
     #[must_use]
     pub fn get_order_snapshots_topic(&mut self, client_order_id: ClientOrderId) -> Ustr {
         *self
@@ -270,6 +366,39 @@ mod tests {
         assert!(switchboard.trade_topics.contains_key(&instrument_id));
     }
 
+    #[rstest]
+    fn test_get_mark_prices_topic(
+        mut switchboard: MessagingSwitchboard,
+        instrument_id: InstrumentId,
+    ) {
+        let expected_topic = Ustr::from("data.mark_prices.XCME.ESZ24");
+        let result = switchboard.get_mark_prices_topic(instrument_id);
+        assert_eq!(result, expected_topic);
+        assert!(switchboard.mark_price_topics.contains_key(&instrument_id));
+    }
+
+    #[rstest]
+    fn test_get_index_prices_topic(
+        mut switchboard: MessagingSwitchboard,
+        instrument_id: InstrumentId,
+    ) {
+        let expected_topic = Ustr::from("data.index_prices.XCME.ESZ24");
+        let result = switchboard.get_index_prices_topic(instrument_id);
+        assert_eq!(result, expected_topic);
+        assert!(switchboard.index_price_topics.contains_key(&instrument_id));
+    }
+
+    #[rstest]
+    fn test_get_funding_rates_topic(
+        mut switchboard: MessagingSwitchboard,
+        instrument_id: InstrumentId,
+    ) {
+        let expected_topic = Ustr::from("data.funding_rates.XCME.ESZ24");
+        let result = switchboard.get_funding_rates_topic(instrument_id);
+        assert_eq!(result, expected_topic);
+        assert!(switchboard.funding_rate_topics.contains_key(&instrument_id));
+    }
+
     #[rstest]
     fn test_get_bars_topic(mut switchboard: MessagingSwitchboard) {
         let bar_type = BarType::from("ESZ24.XCME-1-MINUTE-LAST-INTERNAL");
@@ -279,6 +408,48 @@ mod tests {
         assert!(switchboard.bar_topics.contains_key(&bar_type));
     }
 
+    #[rstest]
+    fn test_get_book_bars_topic(mut switchboard: MessagingSwitchboard) {
+        let bar_type = BarType::from("ESZ24.XCME-1-MINUTE-MID-INTERNAL");
+        let expected_topic = Ustr::from(&format!("data.book_bars.{bar_type}"));
+        let result = switchboard.get_book_bars_topic(bar_type);
+        assert_eq!(result, expected_topic);
+        assert!(switchboard.book_bar_topics.contains_key(&bar_type));
+    }
+
+    #[rstest]
+    fn test_get_rolls_topic(mut switchboard: MessagingSwitchboard, instrument_id: InstrumentId) {
+        let expected_topic = Ustr::from("data.rolls.XCME.ESZ24");
+        let result = switchboard.get_rolls_topic(instrument_id);
+        assert_eq!(result, expected_topic);
+        assert!(switchboard.roll_topics.contains_key(&instrument_id));
+    }
+
+    #[rstest]
+    fn test_get_corporate_actions_topic(
+        mut switchboard: MessagingSwitchboard,
+        instrument_id: InstrumentId,
+    ) {
+        let expected_topic = Ustr::from("data.corporate_actions.XCME.ESZ24");
+        let result = switchboard.get_corporate_actions_topic(instrument_id);
+        assert_eq!(result, expected_topic);
+        assert!(switchboard
+            .corporate_action_topics
+            .contains_key(&instrument_id));
+    }
+
+    #[rstest]
+    fn test_get_conflated_topic(mut switchboard: MessagingSwitchboard) {
+        let topic = Ustr::from("data.quotes.XCME.ESZ24");
+        let interval_ms = NonZeroU64::new(100).unwrap();
+        let expected_topic = Ustr::from("conflated.100ms.data.quotes.XCME.ESZ24");
+        let result = switchboard.get_conflated_topic(topic, interval_ms);
+        assert_eq!(result, expected_topic);
+        assert!(switchboard
+            .conflated_topics
+            .contains_key(&(topic, interval_ms)));
+    }
+
     #[rstest]
     fn test_get_order_snapshots_topic(mut switchboard: MessagingSwitchboard) {
         let client_order_id = ClientOrderId::from("O-123456789");
@@ -20,7 +20,7 @@
 #![allow(unused_variables)]
 #![allow(unused_assignments)]
 
-use std::{cell::RefCell, ops::Add, rc::Rc};
+use std::{cell::RefCell, cmp, collections::HashMap, ops::Add, rc::Rc, str::FromStr};
 
 use chrono::{DateTime, TimeDelta, TimeZone, Utc};
 use nautilus_common::{
@@ -34,13 +34,138 @@ use nautilus_core::{
 };
 use nautilus_model::{
     data::{
-        bar::{get_bar_interval, get_bar_interval_ns, get_time_bar_start, Bar, BarType},
+        bar::{get_bar_interval, get_time_bar_start, Bar, BarType},
         QuoteTick, TradeTick,
     },
-    enums::{AggregationSource, BarAggregation, BarIntervalType},
-    types::{fixed::FIXED_SCALAR, quantity::QuantityRaw, Price, Quantity},
+    enums::{AggregationSource, BarAggregation, BarIntervalType, OrderSide, OrderSideSpecified},
+    orderbook::{BookLevel, OrderBook},
+    types::{
+        fixed::{FIXED_PRECISION, FIXED_SCALAR},
+        quantity::QuantityRaw,
+        Money, Price, Quantity,
+    },
 };
 
+use crate::session::TradingSession;
+
+/// The subscription parameter for aggregating bars from order book prices rather than
+/// quotes or trades, one of `MID`, `MICROPRICE` or `NOTIONAL_VWAP`.
+pub const BOOK_PRICE_TYPE: &str = "book_price_type";
+
+/// The subscription parameter for the notional (e.g. `100000.00 USD`) of `NOTIONAL_VWAP` book bars.
+pub const BOOK_NOTIONAL: &str = "book_notional";
+
+/// The subscription parameter for the order side (`BUY` or `SELL`) of `NOTIONAL_VWAP` book bars.
+pub const BOOK_SIDE: &str = "book_side";
+
+/// The price sampled from an [`OrderBook`] to aggregate book-derived bars.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum BookPriceType {
+    /// The midpoint between the best bid and best ask prices.
+    Mid,
+    /// The best bid and ask prices weighted by the size on the opposite side of the book.
+    Microprice,
+    /// The volume-weighted average price to fill the given notional exposure on the given
+    /// side of the book, where a buy fills against the asks and a sell against the bids.
+    NotionalVwap { side: OrderSide, notional: Money },
+}
+
+impl BookPriceType {
+    /// Returns the book price type from the given subscription `params`, or `None` if the
+    /// params do not contain a [`BOOK_PRICE_TYPE`].
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the book price type is not recognized.
+    /// - If a `NOTIONAL_VWAP` book price type does not have a valid side and positive notional.
+    pub fn from_params(params: &HashMap<String, String>) -> anyhow::Result<Option<Self>> {
+        let Some(price_type) = params.get(BOOK_PRICE_TYPE) else {
+            return Ok(None);
+        };
+
+        let price_type = match price_type.to_ascii_uppercase().as_str() {
+            "MID" => Self::Mid,
+            "MICROPRICE" => Self::Microprice,
+            "NOTIONAL_VWAP" => {
+                let side = params
+                    .get(BOOK_SIDE)
+                    .ok_or_else(|| anyhow::anyhow!("Missing '{BOOK_SIDE}' parameter"))?;
+                let side = OrderSide::from_str(side).map_err(|e| {
+                    anyhow::anyhow!("Invalid '{BOOK_SIDE}' parameter '{side}': {e}")
+                })?;
+                if side == OrderSide::NoOrderSide {
+                    anyhow::bail!("Invalid '{BOOK_SIDE}' parameter: must be 'BUY' or 'SELL'");
+                }
+
+                let notional = params
+                    .get(BOOK_NOTIONAL)
+                    .ok_or_else(|| anyhow::anyhow!("Missing '{BOOK_NOTIONAL}' parameter"))?;
+                let notional = Money::from_str(notional).map_err(|e| {
+                    anyhow::anyhow!("Invalid '{BOOK_NOTIONAL}' parameter '{notional}': {e}")
+                })?;
+                if notional.raw <= 0 {
+                    anyhow::bail!("Invalid '{BOOK_NOTIONAL}' parameter: must be positive");
+                }
+
+                Self::NotionalVwap { side, notional }
+            }
+            other => anyhow::bail!("Invalid '{BOOK_PRICE_TYPE}' parameter '{other}'"),
+        };
+
+        Ok(Some(price_type))
+    }
+
+    /// Returns the price and size sampled from the given `book`, or `None` if the book
+    /// does not have the liquidity to derive a price.
+    ///
+    /// For `NotionalVwap` the size is the quantity consumed to fill the notional, otherwise
+    /// it is the average of the best bid and ask sizes. A `NotionalVwap` price is only derived
+    /// when the book has the liquidity to fill the full notional, as a partial fill would make
+    /// the price depend on the book depth.
+    #[must_use]
+    pub fn extract(&self, book: &OrderBook) -> Option<(Price, Quantity)> {
+        let price_precision = book.best_bid_price().or(book.best_ask_price())?.precision;
+        let size_precision = book.best_bid_size().or(book.best_ask_size())?.precision;
+        let mid_precision = cmp::min(price_precision + 1, FIXED_PRECISION);
+
+        if let Self::NotionalVwap { side, notional } = self {
+            let available_exposure: f64 = match side.as_specified() {
+                OrderSideSpecified::Buy => book.asks(None).map(BookLevel::exposure).sum(),
+                OrderSideSpecified::Sell => book.bids(None).map(BookLevel::exposure).sum(),
+            };
+            if available_exposure < notional.as_f64() {
+                return None; // Insufficient liquidity to fill notional
+            }
+            let target_exposure =
+                Quantity::from_decimal(notional.as_decimal(), notional.currency.precision).ok()?;
+            let (avg_px, qty, _) = book.get_avg_px_qty_for_exposure(target_exposure, *side);
+            if qty <= 0.0 {
+                return None; // No liquidity on side
+            }
+            return Some((
+                Price::new(avg_px, mid_precision),
+                Quantity::new(qty, size_precision),
+            ));
+        }
+
+        let (bid_price, ask_price) = (book.best_bid_price()?, book.best_ask_price()?);
+        let (bid_size, ask_size) = (book.best_bid_size()?, book.best_ask_size()?);
+        let mid_size = Quantity::new(
+            (bid_size.as_f64() + ask_size.as_f64()) / 2.0,
+            size_precision,
+        );
+
+        let price = if *self == Self::Microprice {
+            Price::new(book.microprice()?, mid_precision)
+        } else {
+            Price::from_raw((bid_price.raw + ask_price.raw) / 2, mid_precision)
+        };
+
+        Some((price, mid_size))
+    }
+}
+
 pub trait BarAggregator {
     /// The [`BarType`] to be aggregated.
     fn bar_type(&self) -> BarType;
@@ -59,6 +184,12 @@ pub trait BarAggregator {
             quote.ts_event,
         );
     }
+    /// Updates the aggregator with the price sampled from the given order book.
+    fn handle_book(&mut self, book: &OrderBook, price_type: BookPriceType, ts_event: UnixNanos) {
+        if let Some((price, size)) = price_type.extract(book) {
+            self.update(price, size, ts_event);
+        }
+    }
     /// Updates the aggregator with the given trade.
     fn handle_trade(&mut self, trade: TradeTick) {
         self.update(trade.price, trade.size, trade.ts_event);
@@ -737,6 +868,8 @@ where
     batch_open_ns: UnixNanos,
     batch_next_close_ns: UnixNanos,
     time_bars_origin: Option<DateTime<Utc>>,
+    session: Option<TradingSession>,
+    session_callback: Option<TimeEventCallback>,
 }
 
 #[derive(Clone)]
@@ -798,6 +931,16 @@ where
             false
         };
 
+        // Week and month bars are always aligned to session closes (UTC midnight by default)
+        let aggregation = bar_type.spec().aggregation;
+        let is_calendar_aligned =
+            matches!(aggregation, BarAggregation::Week | BarAggregation::Month);
+        let interval = if is_calendar_aligned {
+            TimeDelta::zero()
+        } else {
+            get_bar_interval(&bar_type)
+        };
+
         let core = BarAggregatorCore::new(
             bar_type,
             price_precision,
@@ -817,19 +960,36 @@ where
             stored_close_ns: UnixNanos::default(),
             cached_update: None,
             timer_name: bar_type.to_string(),
-            interval: get_bar_interval(&bar_type),
-            interval_ns: get_bar_interval_ns(&bar_type),
+            interval,
+            interval_ns: UnixNanos::from(
+                interval.num_nanoseconds().expect("Invalid bar interval") as u64
+            ),
             next_close_ns: UnixNanos::default(),
             composite_bar_build_delay,
             add_delay,
             batch_open_ns: UnixNanos::default(),
             batch_next_close_ns: UnixNanos::default(),
             time_bars_origin,
+            session: is_calendar_aligned.then(TradingSession::utc),
+            session_callback: None,
         }
     }
 
+    /// Sets the trading `session` for the aggregator.
+    ///
+    /// Day, week and month bars will close at the session closes, and bars will not be built
+    /// for periods with no trading session unless there were updates. Must be set before the
+    /// aggregator is started.
+    pub fn set_session(&mut self, session: TradingSession) {
+        self.session = Some(session);
+    }
+
     /// Starts the time bar aggregator.
     pub fn start(&mut self, callback: NewBarCallback<H>) -> anyhow::Result<()> {
+        if self.is_session_aligned() {
+            return self.start_session_aligned(callback.into());
+        }
+
         let now = self.clock.borrow().utc_now();
         let start_time = get_time_bar_start(now, &self.bar_type());
         let start_time_ns = UnixNanos::from(start_time.timestamp_nanos_opt().unwrap() as u64);
@@ -849,9 +1009,77 @@ where
         Ok(())
     }
 
+    fn start_session_aligned(&mut self, callback: TimeEventCallback) -> anyhow::Result<()> {
+        let now_ns = self.clock.borrow().timestamp_ns();
+        self.stored_open_ns = self
+            .previous_session_close_ns(now_ns)
+            .ok_or_else(|| anyhow::anyhow!("No session close found before {now_ns}"))?;
+        self.session_callback = Some(callback);
+        self.set_session_alert(now_ns)?;
+        self.next_close_ns = self.clock.borrow().next_time_ns(&self.timer_name);
+
+        log::debug!(
+            "Started session aligned timer {}, next close at {}",
+            self.timer_name,
+            self.next_close_ns
+        );
+        Ok(())
+    }
+
     /// Stops the time bar aggregator.
     pub fn stop(&mut self) {
         self.clock.borrow_mut().cancel_timer(&self.timer_name);
+        self.session_callback = None;
+    }
+
+    fn is_session_aligned(&self) -> bool {
+        self.session.is_some()
+            && matches!(
+                self.bar_type().spec().aggregation,
+                BarAggregation::Day | BarAggregation::Week | BarAggregation::Month
+            )
+    }
+
+    fn is_trading_time(&self, time_ns: UnixNanos) -> bool {
+        self.session.as_ref().is_none_or(|session| {
+            session.is_trading_time(Utc.timestamp_nanos(time_ns.as_u64() as i64))
+        })
+    }
+
+    /// Returns the next session aligned bar close after `time_ns`, `step` sessions ahead.
+    ///
+    /// Returns `None` if there is no trading session or no session close was found.
+    fn next_session_close_ns(&self, time_ns: UnixNanos) -> Option<UnixNanos> {
+        let session = self.session.as_ref()?;
+        let spec = self.bar_type().spec();
+
+        let mut close = Utc.timestamp_nanos(time_ns.as_u64() as i64);
+        for _ in 0..spec.step.get() {
+            close = session.next_bar_close(close, spec.aggregation)?;
+        }
+        Some(UnixNanos::from(close))
+    }
+
+    /// Returns the last session aligned bar close at or before `time_ns`.
+    ///
+    /// Returns `None` if there is no trading session or no session close was found.
+    fn previous_session_close_ns(&self, time_ns: UnixNanos) -> Option<UnixNanos> {
+        let session = self.session.as_ref()?;
+        let aggregation = self.bar_type().spec().aggregation;
+        let close = session
+            .previous_bar_close(Utc.timestamp_nanos(time_ns.as_u64() as i64), aggregation)?;
+        Some(UnixNanos::from(close))
+    }
+
+    fn set_session_alert(&mut self, time_ns: UnixNanos) -> anyhow::Result<()> {
+        let alert_time_ns = self
+            .next_session_close_ns(time_ns)
+            .ok_or_else(|| anyhow::anyhow!("No session close found after {time_ns}"))?;
+        self.clock.borrow_mut().set_time_alert_ns(
+            &self.timer_name,
+            alert_time_ns,
+            self.session_callback.clone(),
+        )
     }
 
     fn batch_pre_update(&mut self, time_ns: UnixNanos) {
@@ -885,7 +1113,17 @@ where
 
         if time_ns > self.batch_next_close_ns {
             // Ensure batch times are coherent with last builder update
-            if self.bar_type().spec().aggregation == BarAggregation::Month {
+            if self.is_session_aligned() {
+                while self.batch_next_close_ns < time_ns {
+                    let Some(next_close_ns) = self.next_session_close_ns(self.batch_next_close_ns)
+                    else {
+                        log::error!("No session close found after {}", self.batch_next_close_ns);
+                        return;
+                    };
+                    self.batch_open_ns = self.batch_next_close_ns;
+                    self.batch_next_close_ns = next_close_ns;
+                }
+            } else if self.bar_type().spec().aggregation == BarAggregation::Month {
                 // TODO: Handle monthly bars which need special date arithmetic
                 // This will need chrono/datetime handling
             } else {
@@ -911,7 +1149,14 @@ where
             self.core.build_and_send(ts_event, time_ns);
             self.batch_open_ns = self.batch_next_close_ns;
 
-            if self.bar_type().spec().aggregation == BarAggregation::Month {
+            if self.is_session_aligned() {
+                match self.next_session_close_ns(self.batch_next_close_ns) {
+                    Some(next_close_ns) => self.batch_next_close_ns = next_close_ns,
+                    None => {
+                        log::error!("No session close found after {}", self.batch_next_close_ns);
+                    }
+                }
+            } else if self.bar_type().spec().aggregation == BarAggregation::Month {
                 // TODO: Handle monthly bars increment
             } else {
                 self.batch_next_close_ns += self.interval_ns;
@@ -928,6 +1173,25 @@ where
     pub fn start_batch_time(&mut self, time_ns: UnixNanos) {
         self.core.batch_mode = true;
 
+        if self.is_session_aligned() {
+            // A time at a session close belongs to the bar closing at that time
+            let prior_ns = UnixNanos::from(time_ns.as_u64().saturating_sub(1));
+            let closes = self
+                .previous_session_close_ns(prior_ns)
+                .and_then(|open_ns| {
+                    self.next_session_close_ns(open_ns)
+                        .map(|next_close_ns| (open_ns, next_close_ns))
+                });
+            match closes {
+                Some((open_ns, next_close_ns)) => {
+                    self.batch_open_ns = open_ns;
+                    self.batch_next_close_ns = next_close_ns;
+                }
+                None => log::error!("No session close found around {time_ns}"),
+            }
+            return;
+        }
+
         let dt = Utc.timestamp_nanos(time_ns.as_u64() as i64);
         let mut start_dt = get_time_bar_start(dt, &self.bar_type());
         self.batch_open_ns = UnixNanos::from(
@@ -970,6 +1234,12 @@ where
     }
 
     fn build_bar(&mut self, event: TimeEvent) {
+        if self.is_session_aligned() {
+            if let Err(e) = self.set_session_alert(event.ts_event) {
+                log::error!("Failed to set next session close alert: {e}");
+            }
+        }
+
         if !self.core.builder.initialized {
             self.build_on_next_tick = true;
             self.stored_close_ns = self.next_close_ns;
@@ -980,6 +1250,13 @@ where
             return;
         }
 
+        if self.core.builder.count == 0 && !self.is_trading_time(event.ts_event) {
+            // No empty bars for periods outside of trading sessions
+            self.stored_open_ns = event.ts_event;
+            self.next_close_ns = self.clock.borrow().next_time_ns(&self.timer_name);
+            return;
+        }
+
         let ts_init = event.ts_event;
         let ts_event = if self.is_left_open {
             if self.timestamp_on_close {
@@ -1086,13 +1363,16 @@ where
 mod tests {
     use std::sync::{Arc, Mutex};
 
+    use chrono::{NaiveDate, NaiveTime};
+    use chrono_tz::Tz;
     use nautilus_common::clock::TestClock;
     use nautilus_core::UUID4;
     use nautilus_model::{
-        data::{BarSpecification, BarType},
-        enums::{AggregationSource, BarAggregation, PriceType},
+        data::{BarSpecification, BarType, BookOrder},
+        enums::{AggregationSource, BarAggregation, BookType, PriceType},
+        identifiers::InstrumentId,
         instruments::{stubs::*, CurrencyPair, Equity, InstrumentAny},
-        types::{Price, Quantity},
+        types::{Money, Price, Quantity},
     };
     use rstest::rstest;
     use ustr::Ustr;
@@ -2077,4 +2357,255 @@ mod tests {
         let handler_guard = handler.lock().unwrap();
         assert_eq!(handler_guard.len(), 0);
     }
+
+    #[rstest]
+    fn test_time_bar_aggregator_aligns_day_bars_to_session_close(equity_aapl: Equity) {
+        let instrument = InstrumentAny::Equity(equity_aapl);
+        let bar_spec = BarSpecification::new(1, BarAggregation::Day, PriceType::Last);
+        let bar_type = BarType::new(instrument.id(), bar_spec, AggregationSource::Internal);
+        let handler = Arc::new(Mutex::new(Vec::new()));
+        let handler_clone = Arc::clone(&handler);
+        let clock = Rc::new(RefCell::new(TestClock::new()));
+
+        // Friday 2024-03-08 09:00 America/Chicago
+        let friday = UnixNanos::from(Utc.with_ymd_and_hms(2024, 3, 8, 15, 0, 0).unwrap());
+        clock.borrow_mut().set_time(friday);
+
+        let aggregator = Rc::new(RefCell::new(TimeBarAggregator::new(
+            bar_type,
+            instrument.price_precision(),
+            instrument.size_precision(),
+            clock.clone(),
+            move |bar: Bar| {
+                let mut handler_guard = handler_clone.lock().unwrap();
+                handler_guard.push(bar);
+            },
+            false,
+            true,  // build_with_no_updates
+            false, // timestamp_on_close
+            BarIntervalType::LeftOpen,
+            None,
+            15,
+        )));
+        aggregator.borrow_mut().set_session(TradingSession::new(
+            Tz::America__Chicago,
+            NaiveTime::from_hms_opt(16, 0, 0).unwrap(),
+        ));
+        aggregator
+            .borrow_mut()
+            .start(NewBarCallback::new(aggregator.clone()))
+            .unwrap();
+
+        let thursday_close = UnixNanos::from(Utc.with_ymd_and_hms(2024, 3, 7, 22, 0, 0).unwrap());
+        let friday_close = UnixNanos::from(Utc.with_ymd_and_hms(2024, 3, 8, 22, 0, 0).unwrap());
+        // Monday close is an hour earlier in UTC after the DST transition
+        let monday_close = UnixNanos::from(Utc.with_ymd_and_hms(2024, 3, 11, 21, 0, 0).unwrap());
+        let timer_name = bar_type.to_string();
+        assert_eq!(clock.borrow().next_time_ns(&timer_name), friday_close);
+
+        aggregator
+            .borrow_mut()
+            .update(Price::from("100.00"), Quantity::from(1), friday);
+        clock.borrow_mut().set_time(friday_close);
+        let event = TimeEvent::new(
+            Ustr::from(&timer_name),
+            UUID4::new(),
+            friday_close,
+            friday_close,
+        );
+        aggregator.borrow_mut().build_bar(event);
+
+        let handler_guard = handler.lock().unwrap();
+        assert_eq!(handler_guard.len(), 1);
+        let bar = handler_guard.first().unwrap();
+        assert_eq!(bar.ts_event, thursday_close);
+        assert_eq!(bar.ts_init, friday_close);
+        assert_eq!(clock.borrow().next_time_ns(&timer_name), monday_close);
+    }
+
+    #[rstest]
+    fn test_time_bar_aggregator_skips_empty_bars_outside_session(equity_aapl: Equity) {
+        let instrument = InstrumentAny::Equity(equity_aapl);
+        let bar_spec = BarSpecification::new(1, BarAggregation::Hour, PriceType::Last);
+        let bar_type = BarType::new(instrument.id(), bar_spec, AggregationSource::Internal);
+        let handler = Arc::new(Mutex::new(Vec::new()));
+        let handler_clone = Arc::clone(&handler);
+        let clock = Rc::new(RefCell::new(TestClock::new()));
+
+        let mut aggregator = TimeBarAggregator::new(
+            bar_type,
+            instrument.price_precision(),
+            instrument.size_precision(),
+            clock.clone(),
+            move |bar: Bar| {
+                let mut handler_guard = handler_clone.lock().unwrap();
+                handler_guard.push(bar);
+            },
+            false,
+            true, // build_with_no_updates
+            false,
+            BarIntervalType::LeftOpen,
+            None,
+            15,
+        );
+        aggregator.set_session(
+            TradingSession::utc().with_holidays([NaiveDate::from_ymd_opt(2024, 3, 9).unwrap()]),
+        );
+
+        let friday = UnixNanos::from(Utc.with_ymd_and_hms(2024, 3, 8, 22, 0, 0).unwrap());
+        aggregator.update(Price::from("100.00"), Quantity::from(1), friday);
+
+        for ts in [
+            Utc.with_ymd_and_hms(2024, 3, 8, 23, 0, 0).unwrap(), // Friday session
+            Utc.with_ymd_and_hms(2024, 3, 9, 1, 0, 0).unwrap(),  // Holiday session
+            Utc.with_ymd_and_hms(2024, 3, 10, 1, 0, 0).unwrap(), // Sunday session
+        ] {
+            let ts = UnixNanos::from(ts);
+            let event = TimeEvent::new(Ustr::from("1-HOUR-LAST"), UUID4::new(), ts, ts);
+            aggregator.build_bar(event);
+        }
+
+        let handler_guard = handler.lock().unwrap();
+        assert_eq!(handler_guard.len(), 2);
+        assert_eq!(
+            handler_guard[1].ts_init,
+            UnixNanos::from(Utc.with_ymd_and_hms(2024, 3, 10, 1, 0, 0).unwrap())
+        );
+    }
+
+    fn stub_book() -> OrderBook {
+        let mut book = OrderBook::new(InstrumentId::from("ETHUSDT-PERP.BINANCE"), BookType::L2_MBP);
+        let orders = [
+            (OrderSide::Buy, "100.00", 10),
+            (OrderSide::Sell, "101.00", 30),
+            (OrderSide::Sell, "102.00", 10),
+        ];
+        for (side, price, size) in orders {
+            let order = BookOrder::new(side, Price::from(price), Quantity::from(size), 0);
+            book.add(order, 0, 0, UnixNanos::default());
+        }
+        book
+    }
+
+    #[rstest]
+    #[case(BookPriceType::Mid, Price::from("100.500"), Quantity::from(20))]
+    #[case(BookPriceType::Microprice, Price::from("100.250"), Quantity::from(20))]
+    #[case(
+        BookPriceType::NotionalVwap {
+            side: OrderSide::Buy,
+            notional: Money::from("4050.00 USD"),
+        },
+        Price::from("101.250"),
+        Quantity::from(40),
+    )]
+    fn test_book_price_type_extract(
+        #[case] price_type: BookPriceType,
+        #[case] expected_price: Price,
+        #[case] expected_size: Quantity,
+    ) {
+        let book = stub_book();
+
+        let (price, size) = price_type.extract(&book).unwrap();
+
+        assert_eq!(price, expected_price);
+        assert_eq!(size, expected_size);
+    }
+
+    #[rstest]
+    fn test_book_price_type_extract_when_side_empty_returns_none() {
+        let mut book = OrderBook::new(InstrumentId::from("ETHUSDT-PERP.BINANCE"), BookType::L2_MBP);
+        let order = BookOrder::new(OrderSide::Buy, Price::from("100.00"), Quantity::from(10), 0);
+        book.add(order, 0, 0, UnixNanos::default());
+
+        assert!(BookPriceType::Mid.extract(&book).is_none());
+        assert!(BookPriceType::Microprice.extract(&book).is_none());
+        let vwap = BookPriceType::NotionalVwap {
+            side: OrderSide::Buy,
+            notional: Money::from("1000.00 USD"),
+        };
+        assert!(vwap.extract(&book).is_none());
+    }
+
+    #[rstest]
+    fn test_book_price_type_extract_notional_vwap_when_insufficient_liquidity_returns_none() {
+        let book = stub_book();
+        let vwap = BookPriceType::NotionalVwap {
+            side: OrderSide::Buy,
+            notional: Money::from("4050.01 USD"),
+        };
+
+        assert!(vwap.extract(&book).is_none());
+    }
+
+    #[rstest]
+    #[case::none(&[], None)]
+    #[case::mid(&[(BOOK_PRICE_TYPE, "MID")], Some(BookPriceType::Mid))]
+    #[case::microprice(&[(BOOK_PRICE_TYPE, "microprice")], Some(BookPriceType::Microprice))]
+    #[case::notional_vwap(
+        &[(BOOK_PRICE_TYPE, "NOTIONAL_VWAP"), (BOOK_SIDE, "SELL"), (BOOK_NOTIONAL, "1000.00 USD")],
+        Some(BookPriceType::NotionalVwap {
+            side: OrderSide::Sell,
+            notional: Money::from("1000.00 USD"),
+        }),
+    )]
+    fn test_book_price_type_from_params(
+        #[case] params: &[(&str, &str)],
+        #[case] expected: Option<BookPriceType>,
+    ) {
+        let params = params
+            .iter()
+            .map(|(key, value)| ((*key).to_string(), (*value).to_string()))
+            .collect();
+
+        assert_eq!(BookPriceType::from_params(&params).unwrap(), expected);
+    }
+
+    #[rstest]
+    #[case::unknown(&[(BOOK_PRICE_TYPE, "LAST")])]
+    #[case::missing_side(&[(BOOK_PRICE_TYPE, "NOTIONAL_VWAP"), (BOOK_NOTIONAL, "1000.00 USD")])]
+    #[case::missing_notional(&[(BOOK_PRICE_TYPE, "NOTIONAL_VWAP"), (BOOK_SIDE, "BUY")])]
+    #[case::zero_notional(
+        &[(BOOK_PRICE_TYPE, "NOTIONAL_VWAP"), (BOOK_SIDE, "BUY"), (BOOK_NOTIONAL, "0.00 USD")],
+    )]
+    fn test_book_price_type_from_params_when_invalid_returns_error(
+        #[case] params: &[(&str, &str)],
+    ) {
+        let params = params
+            .iter()
+            .map(|(key, value)| ((*key).to_string(), (*value).to_string()))
+            .collect();
+
+        assert!(BookPriceType::from_params(&params).is_err());
+    }
+
+    #[rstest]
+    fn test_tick_bar_aggregator_handle_book(audusd_sim: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let bar_spec = BarSpecification::new(2, BarAggregation::Tick, PriceType::Mid);
+        let bar_type = BarType::new(instrument.id(), bar_spec, AggregationSource::Internal);
+        let handler = Arc::new(Mutex::new(Vec::new()));
+        let handler_clone = Arc::clone(&handler);
+
+        let mut aggregator = TickBarAggregator::new(
+            bar_type,
+            instrument.price_precision(),
+            instrument.size_precision(),
+            move |bar: Bar| {
+                let mut handler_guard = handler_clone.lock().unwrap();
+                handler_guard.push(bar);
+            },
+            false,
+        );
+
+        let book = stub_book();
+        aggregator.handle_book(&book, BookPriceType::Mid, UnixNanos::default());
+        aggregator.handle_book(&book, BookPriceType::Microprice, UnixNanos::default());
+
+        let handler_guard = handler.lock().unwrap();
+        assert_eq!(handler_guard.len(), 1);
+        let bar = handler_guard.first().unwrap();
+        assert_eq!(bar.open, Price::from("100.50000"));
+        assert_eq!(bar.low, Price::from("100.25000"));
+        assert_eq!(bar.close, Price::from("100.25000"));
+    }
 }
@@ -25,22 +25,23 @@ use std::{
     fmt::Debug,
     ops::{Deref, DerefMut},
     rc::Rc,
-    sync::Arc,
 };
 
 use indexmap::IndexMap;
 use nautilus_common::{
     clock::{Clock, TestClock},
-    messages::data::{Action, DataRequest, DataResponse, Payload, SubscriptionCommand},
+    messages::data::{Action, DataEvent, DataRequest, DataResponse, Payload, SubscriptionCommand},
 };
 use nautilus_core::{UnixNanos, UUID4};
 use nautilus_model::{
-    data::{Bar, BarType, DataType, QuoteTick, TradeTick},
+    data::{Bar, BarType, Data, DataType, QuoteTick, TradeTick},
     enums::BookType,
     identifiers::{ClientId, InstrumentId, Venue},
     instruments::InstrumentAny,
 };
 
+use crate::{engine::runner::get_data_queue, latency::LatencyTracker};
+
 pub trait DataClient {
     fn client_id(&self) -> ClientId;
     fn venue(&self) -> Option<Venue>;
@@ -98,6 +99,21 @@ pub trait DataClient {
         instrument_id: &InstrumentId,
         params: &Option<HashMap<String, String>>,
     ) -> anyhow::Result<()>;
+    fn subscribe_mark_prices(
+        &mut self,
+        instrument_id: &InstrumentId,
+        params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()>;
+    fn subscribe_index_prices(
+        &mut self,
+        instrument_id: &InstrumentId,
+        params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()>;
+    fn subscribe_funding_rates(
+        &mut self,
+        instrument_id: &InstrumentId,
+        params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()>;
     fn subscribe_bars(
         &mut self,
         bar_type: &BarType,
@@ -148,6 +164,21 @@ pub trait DataClient {
         instrument_id: &InstrumentId,
         params: &Option<HashMap<String, String>>,
     ) -> anyhow::Result<()>;
+    fn unsubscribe_mark_prices(
+        &mut self,
+        instrument_id: &InstrumentId,
+        params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()>;
+    fn unsubscribe_index_prices(
+        &mut self,
+        instrument_id: &InstrumentId,
+        params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()>;
+    fn unsubscribe_funding_rates(
+        &mut self,
+        instrument_id: &InstrumentId,
+        params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()>;
     fn unsubscribe_bars(
         &mut self,
         bar_type: &BarType,
@@ -232,11 +263,15 @@ pub struct DataClientAdapter {
     pub subscriptions_order_book_snapshot: HashSet<InstrumentId>,
     pub subscriptions_quote_tick: HashSet<InstrumentId>,
     pub subscriptions_trade_tick: HashSet<InstrumentId>,
+    pub subscriptions_mark_price: HashSet<InstrumentId>,
+    pub subscriptions_index_price: HashSet<InstrumentId>,
+    pub subscriptions_funding_rate: HashSet<InstrumentId>,
     pub subscriptions_bar: HashSet<BarType>,
     pub subscriptions_instrument_status: HashSet<InstrumentId>,
     pub subscriptions_instrument_close: HashSet<InstrumentId>,
     pub subscriptions_instrument: HashSet<InstrumentId>,
     pub subscriptions_instrument_venue: HashSet<Venue>,
+    latency: Option<Rc<RefCell<LatencyTracker>>>,
 }
 
 impl Deref for DataClientAdapter {
@@ -274,6 +309,12 @@ impl Debug for DataClientAdapter {
             )
             .field("subscriptions_quote_tick", &self.subscriptions_quote_tick)
             .field("subscriptions_trade_tick", &self.subscriptions_trade_tick)
+            .field("subscriptions_mark_price", &self.subscriptions_mark_price)
+            .field("subscriptions_index_price", &self.subscriptions_index_price)
+            .field(
+                "subscriptions_funding_rate",
+                &self.subscriptions_funding_rate,
+            )
             .field("subscriptions_bar", &self.subscriptions_bar)
             .field(
                 "subscriptions_instrument_status",
@@ -315,12 +356,32 @@ impl DataClientAdapter {
             subscriptions_order_book_snapshot: HashSet::new(),
             subscriptions_quote_tick: HashSet::new(),
             subscriptions_trade_tick: HashSet::new(),
+            subscriptions_mark_price: HashSet::new(),
+            subscriptions_index_price: HashSet::new(),
+            subscriptions_funding_rate: HashSet::new(),
             subscriptions_bar: HashSet::new(),
             subscriptions_instrument_status: HashSet::new(),
             subscriptions_instrument_close: HashSet::new(),
             subscriptions_instrument: HashSet::new(),
             subscriptions_instrument_venue: HashSet::new(),
+            latency: None,
+        }
+    }
+
+    /// Sets the `tracker` to record client latencies for the data sent by the client.
+    pub fn set_latency_tracker(&mut self, tracker: Rc<RefCell<LatencyTracker>>) {
+        self.latency = Some(tracker);
+    }
+
+    /// Sends the given `data` received by the client to the data engine through the data
+    /// queue, recording its client latency if a latency tracker is set.
+    pub fn send_data(&self, data: Data) {
+        if let Some(latency) = &self.latency {
+            let ts_now = self.clock.borrow().timestamp_ns();
+            latency.borrow_mut().record_client(&data, ts_now);
         }
+
+        get_data_queue().borrow_mut().push(DataEvent::Data(data));
     }
 
     /// TODO: Decide whether to use mut references for subscription commands
@@ -343,6 +404,9 @@ impl DataClientAdapter {
             }
             stringify!(QuoteTick) => Self::subscribe_quote_ticks(self, command),
             stringify!(TradeTick) => Self::subscribe_trade_ticks(self, command),
+            stringify!(MarkPriceUpdate) => Self::subscribe_mark_prices(self, command),
+            stringify!(IndexPriceUpdate) => Self::subscribe_index_prices(self, command),
+            stringify!(FundingRateUpdate) => Self::subscribe_funding_rates(self, command),
             stringify!(Bar) => Self::subscribe_bars(self, command),
             _ => Self::subscribe(self, command),
         }
@@ -358,6 +422,9 @@ impl DataClientAdapter {
             }
             stringify!(QuoteTick) => Self::unsubscribe_quote_ticks(self, command),
             stringify!(TradeTick) => Self::unsubscribe_trade_ticks(self, command),
+            stringify!(MarkPriceUpdate) => Self::unsubscribe_mark_prices(self, command),
+            stringify!(IndexPriceUpdate) => Self::unsubscribe_index_prices(self, command),
+            stringify!(FundingRateUpdate) => Self::unsubscribe_funding_rates(self, command),
             stringify!(Bar) => Self::unsubscribe_bars(self, command),
             _ => Self::unsubscribe(self, command),
         }
@@ -544,6 +611,90 @@ impl DataClientAdapter {
         self.subscriptions_trade_tick.insert(instrument_id);
     }
 
+    fn subscribe_mark_prices(&mut self, command: SubscriptionCommand) {
+        let instrument_id = command
+            .data_type
+            .instrument_id()
+            .expect("Error on subscribe: no 'instrument_id' in metadata");
+
+        if !self.subscriptions_mark_price.contains(&instrument_id) {
+            self.client
+                .subscribe_mark_prices(&instrument_id, &command.params)
+                .expect("Error on subscribe");
+        }
+        self.subscriptions_mark_price.insert(instrument_id);
+    }
+
+    fn unsubscribe_mark_prices(&mut self, command: SubscriptionCommand) {
+        let instrument_id = command
+            .data_type
+            .instrument_id()
+            .expect("Error on subscribe: no 'instrument_id' in metadata");
+
+        if self.subscriptions_mark_price.contains(&instrument_id) {
+            self.client
+                .unsubscribe_mark_prices(&instrument_id, &command.params)
+                .expect("Error on subscribe");
+        }
+        self.subscriptions_mark_price.remove(&instrument_id);
+    }
+
+    fn subscribe_index_prices(&mut self, command: SubscriptionCommand) {
+        let instrument_id = command
+            .data_type
+            .instrument_id()
+            .expect("Error on subscribe: no 'instrument_id' in metadata");
+
+        if !self.subscriptions_index_price.contains(&instrument_id) {
+            self.client
+                .subscribe_index_prices(&instrument_id, &command.params)
+                .expect("Error on subscribe");
+        }
+        self.subscriptions_index_price.insert(instrument_id);
+    }
+
+    fn unsubscribe_index_prices(&mut self, command: SubscriptionCommand) {
+        let instrument_id = command
+            .data_type
+            .instrument_id()
+            .expect("Error on subscribe: no 'instrument_id' in metadata");
+
+        if self.subscriptions_index_price.contains(&instrument_id) {
+            self.client
+                .unsubscribe_index_prices(&instrument_id, &command.params)
+                .expect("Error on subscribe");
+        }
+        self.subscriptions_index_price.remove(&instrument_id);
+    }
+
+    fn subscribe_funding_rates(&mut self, command: SubscriptionCommand) {
+        let instrument_id = command
+            .data_type
+            .instrument_id()
+            .expect("Error on subscribe: no 'instrument_id' in metadata");
+
+        if !self.subscriptions_funding_rate.contains(&instrument_id) {
+            self.client
+                .subscribe_funding_rates(&instrument_id, &command.params)
+                .expect("Error on subscribe");
+        }
+        self.subscriptions_funding_rate.insert(instrument_id);
+    }
+
+    fn unsubscribe_funding_rates(&mut self, command: SubscriptionCommand) {
+        let instrument_id = command
+            .data_type
+            .instrument_id()
+            .expect("Error on subscribe: no 'instrument_id' in metadata");
+
+        if self.subscriptions_funding_rate.contains(&instrument_id) {
+            self.client
+                .unsubscribe_funding_rates(&instrument_id, &command.params)
+                .expect("Error on subscribe");
+        }
+        self.subscriptions_funding_rate.remove(&instrument_id);
+    }
+
     fn subscribe_bars(&mut self, command: SubscriptionCommand) {
         let bar_type = command.data_type.bar_type();
 
@@ -683,14 +834,13 @@ impl DataClientAdapter {
         let instrument_id = instrument.id();
         let metadata = IndexMap::from([("instrument_id".to_string(), instrument_id.to_string())]);
         let data_type = DataType::new(stringify!(InstrumentAny), Some(metadata));
-        let data = Arc::new(instrument);
 
         DataResponse::new(
             correlation_id,
             self.client_id,
             instrument_id.venue,
             data_type,
-            data,
+            instrument,
             self.clock.borrow().timestamp_ns(),
             None,
         )
@@ -705,14 +855,13 @@ impl DataClientAdapter {
     ) -> DataResponse {
         let metadata = IndexMap::from([("venue".to_string(), venue.to_string())]);
         let data_type = DataType::new(stringify!(InstrumentAny), Some(metadata));
-        let data = Arc::new(instruments);
 
         DataResponse::new(
             correlation_id,
             self.client_id,
             venue,
             data_type,
-            data,
+            instruments,
             self.clock.borrow().timestamp_ns(),
             None,
         )
@@ -727,14 +876,13 @@ impl DataClientAdapter {
     ) -> DataResponse {
         let metadata = IndexMap::from([("instrument_id".to_string(), instrument_id.to_string())]);
         let data_type = DataType::new(stringify!(QuoteTick), Some(metadata));
-        let data = Arc::new(quotes);
 
         DataResponse::new(
             correlation_id,
             self.client_id,
             instrument_id.venue,
             data_type,
-            data,
+            quotes,
             self.clock.borrow().timestamp_ns(),
             None,
         )
@@ -749,14 +897,13 @@ impl DataClientAdapter {
     ) -> DataResponse {
         let metadata = IndexMap::from([("instrument_id".to_string(), instrument_id.to_string())]);
         let data_type = DataType::new(stringify!(TradeTick), Some(metadata));
-        let data = Arc::new(trades);
 
         DataResponse::new(
             correlation_id,
             self.client_id,
             instrument_id.venue,
             data_type,
-            data,
+            trades,
             self.clock.borrow().timestamp_ns(),
             None,
         )
@@ -771,14 +918,13 @@ impl DataClientAdapter {
     ) -> DataResponse {
         let metadata = IndexMap::from([("bar_type".to_string(), bar_type.to_string())]);
         let data_type = DataType::new(stringify!(Bar), Some(metadata));
-        let data = Arc::new(bars);
 
         DataResponse::new(
             correlation_id,
             self.client_id,
             bar_type.instrument_id().venue,
             data_type,
-            data,
+            bars,
             self.clock.borrow().timestamp_ns(),
             None,
         )
@@ -0,0 +1,571 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Continuous futures series built from a chain of futures contracts.
+
+use std::collections::HashMap;
+
+use nautilus_core::{datetime::NANOSECONDS_IN_SECOND, UnixNanos};
+use nautilus_model::{
+    data::{Bar, BarType, QuoteTick, TradeTick},
+    identifiers::InstrumentId,
+    instruments::FuturesContract,
+    types::Price,
+};
+
+const NANOSECONDS_IN_DAY: u64 = 86_400 * NANOSECONDS_IN_SECOND;
+
+/// The rule for when a continuous futures series rolls from the front to the next contract.
+///
+/// Regardless of the rule, the series always rolls once the front contract has expired.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RollRule {
+    /// Rolls the given number of calendar days before the front contract expires.
+    CalendarOffset { days: u32 },
+    /// Rolls once the open interest of the next contract exceeds the front contract.
+    ///
+    /// Open interest is not carried by the data processed for the contracts, so it must be
+    /// supplied manually through [`ContinuousFutures::update_open_interest`].
+    OpenInterest,
+    /// Rolls once the latest bar volume of the next contract exceeds the front contract.
+    Volume,
+}
+
+/// The method for back-adjusting historical prices at each roll.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AdjustmentMethod {
+    /// Prices are not adjusted.
+    None,
+    /// Prices before a roll are shifted by the difference between the next and front prices.
+    Difference,
+    /// Prices before a roll are scaled by the ratio of the next to front prices.
+    Ratio,
+}
+
+/// Represents a roll of a continuous futures series from one contract to the next.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RollEvent {
+    /// The continuous futures instrument ID.
+    pub instrument_id: InstrumentId,
+    /// The contract rolled from.
+    pub from_instrument_id: InstrumentId,
+    /// The contract rolled to.
+    pub to_instrument_id: InstrumentId,
+    /// The price difference or ratio for back-adjusting prices before the roll, if adjusted.
+    pub adjustment: Option<f64>,
+    /// UNIX timestamp (nanoseconds) when the roll occurred.
+    pub ts_event: UnixNanos,
+}
+
+/// The result of updating a continuous futures series with contract data.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ContinuousUpdate<T> {
+    /// The rolls triggered by the update.
+    pub rolls: Vec<RollEvent>,
+    /// The data for the continuous instrument, if the update was for the front contract.
+    pub data: Option<T>,
+}
+
+/// Builds a continuous futures series from a chain of [`FuturesContract`] instruments.
+///
+/// Data for the front contract is published under the stable continuous `instrument_id`, with
+/// each roll recorded as a [`RollEvent`] carrying the adjustment to apply to prices before the
+/// roll. Live quotes, trades and bars are all published at unadjusted (tradable) prices, so
+/// every data type for the continuous instrument ID is priced consistently. Historical bars are
+/// back-adjusted for the rolls after them with [`Self::back_adjust_bar`], so the most recent
+/// prices remain tradable while the history is continuous across rolls.
+#[derive(Clone, Debug)]
+pub struct ContinuousFutures {
+    pub instrument_id: InstrumentId,
+    pub roll_rule: RollRule,
+    pub adjustment_method: AdjustmentMethod,
+    contracts: Vec<FuturesContract>,
+    front: usize,
+    initialized: bool,
+    last_prices: HashMap<InstrumentId, f64>,
+    volumes: HashMap<InstrumentId, f64>,
+    open_interest: HashMap<InstrumentId, f64>,
+    rolls: Vec<RollEvent>,
+}
+
+impl ContinuousFutures {
+    /// Creates a new [`ContinuousFutures`] instance, with the chain ordered by expiration.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `contracts` is empty.
+    /// - If `contracts` contains a duplicate instrument ID.
+    pub fn new(
+        instrument_id: InstrumentId,
+        mut contracts: Vec<FuturesContract>,
+        roll_rule: RollRule,
+        adjustment_method: AdjustmentMethod,
+    ) -> anyhow::Result<Self> {
+        anyhow::ensure!(
+            !contracts.is_empty(),
+            "Cannot create continuous futures {instrument_id}: no contracts"
+        );
+
+        contracts.sort_by_key(|contract| contract.expiration_ns);
+        for pair in contracts.windows(2) {
+            anyhow::ensure!(
+                pair[0].id != pair[1].id,
+                "Cannot create continuous futures {instrument_id}: duplicate contract {}",
+                pair[0].id
+            );
+        }
+
+        Ok(Self {
+            instrument_id,
+            roll_rule,
+            adjustment_method,
+            contracts,
+            front: 0,
+            initialized: false,
+            last_prices: HashMap::new(),
+            volumes: HashMap::new(),
+            open_interest: HashMap::new(),
+            rolls: Vec::new(),
+        })
+    }
+
+    /// Returns the contracts in the chain ordered by expiration.
+    #[must_use]
+    pub fn contracts(&self) -> &[FuturesContract] {
+        &self.contracts
+    }
+
+    /// Returns the current front contract.
+    #[must_use]
+    pub fn front_contract(&self) -> &FuturesContract {
+        &self.contracts[self.front]
+    }
+
+    /// Returns whether the given `instrument_id` is a contract in the chain.
+    #[must_use]
+    pub fn contains(&self, instrument_id: &InstrumentId) -> bool {
+        self.contracts
+            .iter()
+            .any(|contract| contract.id == *instrument_id)
+    }
+
+    /// Returns all rolls recorded for the series.
+    #[must_use]
+    pub fn rolls(&self) -> &[RollEvent] {
+        &self.rolls
+    }
+
+    /// Updates the series with the given `quote`.
+    pub fn handle_quote(&mut self, quote: &QuoteTick) -> ContinuousUpdate<QuoteTick> {
+        let mid = (quote.bid_price.as_f64() + quote.ask_price.as_f64()) / 2.0;
+        self.update(quote.instrument_id, mid, quote.ts_event, |instrument_id| {
+            QuoteTick {
+                instrument_id,
+                ..*quote
+            }
+        })
+    }
+
+    /// Updates the series with the given `trade`.
+    pub fn handle_trade(&mut self, trade: &TradeTick) -> ContinuousUpdate<TradeTick> {
+        self.update(
+            trade.instrument_id,
+            trade.price.as_f64(),
+            trade.ts_event,
+            |instrument_id| TradeTick {
+                instrument_id,
+                ..*trade
+            },
+        )
+    }
+
+    /// Updates the series with the given `bar`, which also updates the volume for the contract.
+    pub fn handle_bar(&mut self, bar: &Bar) -> ContinuousUpdate<Bar> {
+        let contract_id = bar.bar_type.instrument_id();
+        if self.contains(&contract_id) {
+            self.volumes.insert(contract_id, bar.volume.as_f64());
+        }
+
+        self.update(
+            contract_id,
+            bar.close.as_f64(),
+            bar.ts_event,
+            |instrument_id| Bar {
+                bar_type: BarType::new(
+                    instrument_id,
+                    bar.bar_type.spec(),
+                    bar.bar_type.aggregation_source(),
+                ),
+                ..*bar
+            },
+        )
+    }
+
+    /// Updates the open interest for the given contract `instrument_id`, returning any rolls.
+    ///
+    /// This is the only source of open interest for the [`RollRule::OpenInterest`] rule.
+    pub fn update_open_interest(
+        &mut self,
+        instrument_id: InstrumentId,
+        open_interest: f64,
+        ts_event: UnixNanos,
+    ) -> Vec<RollEvent> {
+        if !self.contains(&instrument_id) {
+            return Vec::new();
+        }
+
+        self.open_interest.insert(instrument_id, open_interest);
+        self.check_rolls(ts_event)
+    }
+
+    /// Returns the given unadjusted contract `bar` back-adjusted for all rolls after it.
+    #[must_use]
+    pub fn back_adjust_bar(&self, bar: &Bar) -> Bar {
+        let adjust = |price: Price| {
+            Price::new(
+                self.back_adjust(price.as_f64(), bar.ts_event),
+                price.precision,
+            )
+        };
+
+        Bar {
+            open: adjust(bar.open),
+            high: adjust(bar.high),
+            low: adjust(bar.low),
+            close: adjust(bar.close),
+            ..*bar
+        }
+    }
+
+    /// Returns the given `price` at `ts_event` back-adjusted for all rolls after it.
+    #[must_use]
+    pub fn back_adjust(&self, price: f64, ts_event: UnixNanos) -> f64 {
+        self.rolls
+            .iter()
+            .filter(|roll| roll.ts_event > ts_event)
+            .filter_map(|roll| roll.adjustment)
+            .fold(price, |price, adjustment| match self.adjustment_method {
+                AdjustmentMethod::None => price,
+                AdjustmentMethod::Difference => price + adjustment,
+                AdjustmentMethod::Ratio => price * adjustment,
+            })
+    }
+
+    fn update<T>(
+        &mut self,
+        contract_id: InstrumentId,
+        price: f64,
+        ts_event: UnixNanos,
+        relabel: impl FnOnce(InstrumentId) -> T,
+    ) -> ContinuousUpdate<T> {
+        if !self.contains(&contract_id) {
+            return ContinuousUpdate {
+                rolls: Vec::new(),
+                data: None,
+            };
+        }
+
+        self.last_prices.insert(contract_id, price);
+        let rolls = self.check_rolls(ts_event);
+        self.initialized = true;
+
+        let data = (contract_id == self.front_contract().id).then(|| relabel(self.instrument_id));
+        ContinuousUpdate { rolls, data }
+    }
+
+    fn check_rolls(&mut self, ts_event: UnixNanos) -> Vec<RollEvent> {
+        let mut rolls = Vec::new();
+
+        while self.front + 1 < self.contracts.len() && self.should_roll(ts_event) {
+            let from = self.contracts[self.front].id;
+            let to = self.contracts[self.front + 1].id;
+            self.front += 1;
+
+            if !self.initialized {
+                continue; // Skip contracts expired before the series started
+            }
+
+            let roll = RollEvent {
+                instrument_id: self.instrument_id,
+                from_instrument_id: from,
+                to_instrument_id: to,
+                adjustment: self.roll_adjustment(&from, &to),
+                ts_event,
+            };
+            log::info!("Rolled {} from {from} to {to}", self.instrument_id);
+
+            self.rolls.push(roll);
+            rolls.push(roll);
+        }
+
+        rolls
+    }
+
+    fn should_roll(&self, ts_event: UnixNanos) -> bool {
+        let front = &self.contracts[self.front];
+        if ts_event >= front.expiration_ns {
+            return true;
+        }
+
+        let next = &self.contracts[self.front + 1];
+        let exceeds = |values: &HashMap<InstrumentId, f64>| {
+            matches!(
+                (values.get(&front.id), values.get(&next.id)),
+                (Some(front), Some(next)) if next > front
+            )
+        };
+
+        match self.roll_rule {
+            RollRule::CalendarOffset { days } => {
+                ts_event.as_u64() + u64::from(days) * NANOSECONDS_IN_DAY
+                    >= front.expiration_ns.as_u64()
+            }
+            RollRule::OpenInterest => exceeds(&self.open_interest),
+            RollRule::Volume => exceeds(&self.volumes),
+        }
+    }
+
+    fn roll_adjustment(&self, from: &InstrumentId, to: &InstrumentId) -> Option<f64> {
+        let (Some(from_price), Some(to_price)) =
+            (self.last_prices.get(from), self.last_prices.get(to))
+        else {
+            if self.adjustment_method != AdjustmentMethod::None {
+                log::warn!(
+                    "Cannot adjust {} roll from {from} to {to}: no prices for both contracts",
+                    self.instrument_id
+                );
+            }
+            return None;
+        };
+
+        match self.adjustment_method {
+            AdjustmentMethod::None => None,
+            AdjustmentMethod::Difference => Some(to_price - from_price),
+            AdjustmentMethod::Ratio if *from_price != 0.0 && *to_price != 0.0 => {
+                Some(to_price / from_price)
+            }
+            AdjustmentMethod::Ratio => None,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use nautilus_model::{
+        data::{BarSpecification, BarType},
+        enums::{AggregationSource, BarAggregation, PriceType},
+        identifiers::Symbol,
+        instruments::stubs::futures_contract_es,
+        types::Quantity,
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn ts(year: i32, month: u32, day: u32) -> UnixNanos {
+        UnixNanos::from(Utc.with_ymd_and_hms(year, month, day, 0, 0, 0).unwrap())
+    }
+
+    fn contract(symbol: &str, expiration: UnixNanos) -> FuturesContract {
+        let mut contract = futures_contract_es(None, Some(expiration));
+        contract.id = InstrumentId::from(format!("{symbol}.GLBX").as_str());
+        contract.raw_symbol = Symbol::from(symbol);
+        contract
+    }
+
+    fn series(roll_rule: RollRule, adjustment_method: AdjustmentMethod) -> ContinuousFutures {
+        let contracts = vec![
+            contract("ESH22", ts(2022, 3, 18)),
+            contract("ESZ21", ts(2021, 12, 17)),
+        ];
+        ContinuousFutures::new(
+            InstrumentId::from("ES.CONT"),
+            contracts,
+            roll_rule,
+            adjustment_method,
+        )
+        .unwrap()
+    }
+
+    fn bar(symbol: &str, close: &str, volume: u64, ts_event: UnixNanos) -> Bar {
+        let bar_type = BarType::new(
+            InstrumentId::from(format!("{symbol}.GLBX").as_str()),
+            BarSpecification::new(1, BarAggregation::Day, PriceType::Last),
+            AggregationSource::External,
+        );
+        let price = Price::from(close);
+        Bar::new(
+            bar_type,
+            price,
+            price,
+            price,
+            price,
+            Quantity::from(volume),
+            ts_event,
+            ts_event,
+        )
+    }
+
+    #[rstest]
+    fn test_new_when_no_contracts_returns_error() {
+        let result = ContinuousFutures::new(
+            InstrumentId::from("ES.CONT"),
+            Vec::new(),
+            RollRule::Volume,
+            AdjustmentMethod::None,
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_new_orders_contracts_by_expiration() {
+        let series = series(RollRule::Volume, AdjustmentMethod::None);
+
+        assert_eq!(series.front_contract().id, InstrumentId::from("ESZ21.GLBX"));
+        assert_eq!(series.contracts()[1].id, InstrumentId::from("ESH22.GLBX"));
+    }
+
+    #[rstest]
+    fn test_handle_bar_publishes_front_contract_only() {
+        let mut series = series(RollRule::Volume, AdjustmentMethod::None);
+
+        let front = series.handle_bar(&bar("ESZ21", "4700.00", 100, ts(2021, 12, 1)));
+        let next = series.handle_bar(&bar("ESH22", "4690.00", 10, ts(2021, 12, 1)));
+
+        let front_bar = front.data.unwrap();
+        assert_eq!(
+            front_bar.bar_type.instrument_id(),
+            InstrumentId::from("ES.CONT")
+        );
+        assert_eq!(front_bar.close, Price::from("4700.00"));
+        assert!(next.data.is_none());
+        assert!(series.rolls().is_empty());
+    }
+
+    #[rstest]
+    fn test_calendar_offset_roll_with_difference_adjustment() {
+        let mut series = series(
+            RollRule::CalendarOffset { days: 5 },
+            AdjustmentMethod::Difference,
+        );
+        series.handle_bar(&bar("ESZ21", "4700.00", 100, ts(2021, 12, 10)));
+        series.handle_bar(&bar("ESH22", "4690.00", 10, ts(2021, 12, 10)));
+
+        let update = series.handle_bar(&bar("ESH22", "4680.00", 20, ts(2021, 12, 13)));
+
+        assert_eq!(update.rolls.len(), 1);
+        let roll = update.rolls[0];
+        assert_eq!(roll.from_instrument_id, InstrumentId::from("ESZ21.GLBX"));
+        assert_eq!(roll.to_instrument_id, InstrumentId::from("ESH22.GLBX"));
+        assert_eq!(roll.adjustment, Some(-20.0));
+        // The published bar is at the tradable price of the new front contract
+        assert_eq!(update.data.unwrap().close, Price::from("4680.00"));
+        assert_eq!(series.front_contract().id, InstrumentId::from("ESH22.GLBX"));
+
+        let history = bar("ES", "4700.00", 100, ts(2021, 12, 10));
+        let adjusted = series.back_adjust_bar(&history);
+        assert_eq!(adjusted.close, Price::from("4680.00"));
+    }
+
+    #[rstest]
+    fn test_volume_crossover_roll_with_ratio_adjustment() {
+        let mut series = series(RollRule::Volume, AdjustmentMethod::Ratio);
+        series.handle_bar(&bar("ESZ21", "4000.00", 100, ts(2021, 12, 1)));
+
+        let update = series.handle_bar(&bar("ESH22", "4400.00", 150, ts(2021, 12, 1)));
+
+        assert_eq!(update.rolls.len(), 1);
+        assert_eq!(update.rolls[0].adjustment, Some(1.1));
+        assert_eq!(update.data.unwrap().close, Price::from("4400.00"));
+        assert_eq!(series.back_adjust(4000.0, ts(2021, 11, 30)), 4400.0);
+        assert_eq!(series.back_adjust(4400.0, ts(2021, 12, 1)), 4400.0);
+    }
+
+    #[rstest]
+    fn test_handle_bar_after_rolls_publishes_unadjusted_and_back_adjusts_history() {
+        let contracts = vec![
+            contract("ESZ21", ts(2021, 12, 17)),
+            contract("ESH22", ts(2022, 3, 18)),
+            contract("ESM22", ts(2022, 6, 17)),
+        ];
+        let mut series = ContinuousFutures::new(
+            InstrumentId::from("ES.CONT"),
+            contracts,
+            RollRule::CalendarOffset { days: 5 },
+            AdjustmentMethod::Difference,
+        )
+        .unwrap();
+
+        series.handle_bar(&bar("ESZ21", "4700.00", 100, ts(2021, 12, 10)));
+        series.handle_bar(&bar("ESH22", "4690.00", 100, ts(2021, 12, 13))); // Rolls at -10
+        series.handle_bar(&bar("ESH22", "4600.00", 100, ts(2022, 3, 10)));
+
+        let update = series.handle_bar(&bar("ESM22", "4550.00", 10, ts(2022, 3, 14))); // Rolls at -50
+
+        assert_eq!(series.rolls().len(), 2);
+        let published = update.data.unwrap();
+        assert_eq!(published.close, Price::from("4550.00"));
+        assert_eq!(
+            published.bar_type.instrument_id(),
+            InstrumentId::from("ES.CONT")
+        );
+
+        // History is adjusted for every roll after it
+        let history = bar("ES", "4700.00", 100, ts(2021, 12, 10));
+        assert_eq!(
+            series.back_adjust_bar(&history).close,
+            Price::from("4640.00")
+        );
+        let history = bar("ES", "4600.00", 100, ts(2022, 3, 10));
+        assert_eq!(
+            series.back_adjust_bar(&history).close,
+            Price::from("4550.00")
+        );
+    }
+
+    #[rstest]
+    fn test_open_interest_crossover_roll() {
+        let mut series = series(RollRule::OpenInterest, AdjustmentMethod::None);
+        let front_id = InstrumentId::from("ESZ21.GLBX");
+        let next_id = InstrumentId::from("ESH22.GLBX");
+        series.handle_bar(&bar("ESZ21", "4700.00", 100, ts(2021, 12, 1)));
+
+        assert!(series
+            .update_open_interest(front_id, 2_000_000.0, ts(2021, 12, 1))
+            .is_empty());
+        let rolls = series.update_open_interest(next_id, 2_100_000.0, ts(2021, 12, 2));
+
+        assert_eq!(rolls.len(), 1);
+        assert_eq!(rolls[0].adjustment, None);
+        assert_eq!(series.rolls(), rolls.as_slice());
+    }
+
+    #[rstest]
+    fn test_series_starting_after_expiry_skips_expired_contracts() {
+        let mut series = series(RollRule::Volume, AdjustmentMethod::Difference);
+
+        let update = series.handle_bar(&bar("ESH22", "4600.00", 10, ts(2022, 1, 3)));
+
+        assert!(update.rolls.is_empty());
+        assert!(update.data.is_some());
+        assert_eq!(series.front_contract().id, InstrumentId::from("ESH22.GLBX"));
+    }
+}
@@ -16,6 +16,8 @@
 use std::{
     any::Any,
     cell::{Ref, RefCell},
+    cmp::Ordering,
+    collections::HashSet,
     num::NonZeroU64,
     rc::Rc,
 };
@@ -26,10 +28,13 @@ use nautilus_common::{
     msgbus::{handler::MessageHandler, MessageBus},
     timer::TimeEvent,
 };
+use nautilus_core::UnixNanos;
 use nautilus_model::{
-    data::Data,
+    data::{Data, OrderBookDelta, OrderBookDeltas, OrderBookDepth10},
+    enums::RecordFlag,
     identifiers::{InstrumentId, Venue},
 };
+use rust_decimal::Decimal;
 use ustr::Ustr;
 
 /// Contains information for creating snapshots of specific order books.
@@ -47,17 +52,30 @@ pub struct BookUpdater {
     pub id: Ustr,
     pub instrument_id: InstrumentId,
     pub cache: Rc<RefCell<Cache>>,
+    pub derived_depth_books: Rc<RefCell<HashSet<InstrumentId>>>,
 }
 
 impl BookUpdater {
     /// Creates a new [`BookUpdater`] instance.
-    pub fn new(instrument_id: &InstrumentId, cache: Rc<RefCell<Cache>>) -> Self {
+    ///
+    /// Depth data is not applied to books in `derived_depth_books`, as it was derived from the
+    /// book itself.
+    pub fn new(
+        instrument_id: &InstrumentId,
+        cache: Rc<RefCell<Cache>>,
+        derived_depth_books: Rc<RefCell<HashSet<InstrumentId>>>,
+    ) -> Self {
         Self {
             id: Ustr::from(&format!("{}-{}", stringify!(BookUpdater), instrument_id)),
             instrument_id: *instrument_id,
             cache,
+            derived_depth_books,
         }
     }
+
+    fn is_depth_derived(&self, instrument_id: &InstrumentId) -> bool {
+        self.derived_depth_books.borrow().contains(instrument_id)
+    }
 }
 
 impl MessageHandler for BookUpdater {
@@ -65,9 +83,28 @@ impl MessageHandler for BookUpdater {
         self.id
     }
 
-    fn handle(&self, message: &dyn Any) {}
+    fn handle(&self, message: &dyn Any) {
+        let mut cache = self.cache.borrow_mut();
+        let Some(book) = cache.order_book_mut(&self.instrument_id) else {
+            return; // Book not managed
+        };
+
+        if let Some(deltas) = message.downcast_ref::<OrderBookDeltas>() {
+            book.apply_deltas(deltas);
+        } else if let Some(depth) = message.downcast_ref::<OrderBookDepth10>() {
+            if !self.is_depth_derived(&depth.instrument_id) {
+                book.apply_depth(depth);
+            }
+        } else {
+            log::error!("Invalid message type for book update");
+        }
+    }
     fn handle_response(&self, _resp: DataResponse) {}
     fn handle_data(&self, data: Data) {
+        if matches!(data, Data::Depth10(_)) && self.is_depth_derived(&data.instrument_id()) {
+            return;
+        }
+
         if let Some(book) = self
             .cache
             .borrow_mut()
@@ -92,6 +129,7 @@ pub struct BookSnapshotter {
     pub snap_info: BookSnapshotInfo,
     pub cache: Rc<RefCell<Cache>>,
     pub msgbus: Rc<RefCell<MessageBus>>,
+    pub invalid_books: Rc<RefCell<HashSet<InstrumentId>>>,
 }
 
 impl BookSnapshotter {
@@ -100,6 +138,7 @@ impl BookSnapshotter {
         snap_info: BookSnapshotInfo,
         cache: Rc<RefCell<Cache>>,
         msgbus: Rc<RefCell<MessageBus>>,
+        invalid_books: Rc<RefCell<HashSet<InstrumentId>>>,
     ) -> Self {
         let id_str = format!(
             "{}-{}",
@@ -117,6 +156,7 @@ impl BookSnapshotter {
             snap_info,
             cache,
             msgbus,
+            invalid_books,
         }
     }
 
@@ -156,6 +196,324 @@ impl BookSnapshotter {
             return;
         }
 
+        if self.invalid_books.borrow().contains(instrument_id) {
+            log::debug!("OrderBook for {instrument_id} is resynchronizing, skipping snapshot");
+            return;
+        }
+
         msgbus.publish(topic, book as &dyn Any);
     }
 }
+
+/// Publishes [`OrderBookDepth10`] snapshots derived from a maintained order book on the depth
+/// topic for its instrument, either on each book update or at an interval.
+pub struct BookDepthPublisher {
+    pub id: Ustr,
+    pub timer_name: Option<Ustr>,
+    pub instrument_id: InstrumentId,
+    pub topic: Ustr,
+    pub interval_ms: Option<NonZeroU64>,
+    pub group_size: Option<Decimal>,
+    pub cache: Rc<RefCell<Cache>>,
+    pub msgbus: Rc<RefCell<MessageBus>>,
+    pub invalid_books: Rc<RefCell<HashSet<InstrumentId>>>,
+}
+
+impl BookDepthPublisher {
+    /// Creates a new [`BookDepthPublisher`] instance.
+    ///
+    /// Depth is published on each book update if no `interval_ms` is given, with levels grouped
+    /// into price buckets of `group_size` if given.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        instrument_id: InstrumentId,
+        topic: Ustr,
+        interval_ms: Option<NonZeroU64>,
+        group_size: Option<Decimal>,
+        cache: Rc<RefCell<Cache>>,
+        msgbus: Rc<RefCell<MessageBus>>,
+        invalid_books: Rc<RefCell<HashSet<InstrumentId>>>,
+    ) -> Self {
+        let id_str = format!("{}-{}", stringify!(BookDepthPublisher), instrument_id);
+        let timer_name = interval_ms.map(|interval_ms| {
+            Ustr::from(&format!("OrderBookDepth|{instrument_id}|{interval_ms}"))
+        });
+
+        Self {
+            id: Ustr::from(&id_str),
+            timer_name,
+            instrument_id,
+            topic,
+            interval_ms,
+            group_size,
+            cache,
+            msgbus,
+            invalid_books,
+        }
+    }
+
+    /// Publishes depth derived from the current state of the order book, unless the book has
+    /// not yet been updated or is resynchronizing.
+    pub fn publish(&self, ts_init: UnixNanos) {
+        let depth = {
+            let cache = self.cache.borrow();
+            let Some(book) = cache.order_book(&self.instrument_id) else {
+                log::debug!(
+                    "OrderBook for {} not in cache for depth",
+                    self.instrument_id
+                );
+                return;
+            };
+
+            if book.count == 0 {
+                log::debug!(
+                    "OrderBook for {} not yet updated for depth",
+                    self.instrument_id
+                );
+                return;
+            }
+
+            if self.invalid_books.borrow().contains(&self.instrument_id) {
+                log::debug!(
+                    "OrderBook for {} is resynchronizing, skipping depth",
+                    self.instrument_id
+                );
+                return;
+            }
+
+            book.to_depth10(self.group_size, ts_init)
+        };
+
+        // Cache borrow released, as subscribers may require mutable access
+        self.msgbus
+            .borrow_mut()
+            .publish(&self.topic, &depth as &dyn Any);
+    }
+}
+
+/// The outcome of checking an [`OrderBookDelta`] sequence against the last applied sequence.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SequenceCheck {
+    /// The delta follows on from the last applied sequence.
+    Valid,
+    /// The delta repeats a sequence from an already completed event.
+    Duplicate,
+    /// The delta is prior to the last applied sequence.
+    OutOfOrder,
+    /// One or more sequences were missed before the delta (contains the expected sequence).
+    Gap(u64),
+}
+
+/// The action to take for an [`OrderBookDelta`] after validating its sequence.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SequenceAction {
+    /// Publish the delta.
+    Publish,
+    /// Drop (or buffer) the delta.
+    Drop,
+    /// Drop (or buffer) the delta and attempt to resynchronize the book.
+    Resync,
+}
+
+/// Tracks the delta sequence of an order book, buffering deltas while the book
+/// is resynchronized from a fresh snapshot after a gap.
+#[derive(Clone, Debug, Default)]
+pub struct BookSequenceState {
+    /// The last applied sequence (if any).
+    pub last_sequence: Option<u64>,
+    /// If the last applied delta completed its event (had the `F_LAST` flag).
+    pub is_event_complete: bool,
+    /// If the book is invalid and awaiting a snapshot.
+    pub is_resyncing: bool,
+    /// When a snapshot was last requested from the data client (UNIX nanoseconds).
+    pub last_snapshot_request: Option<UnixNanos>,
+    /// The deltas received while resyncing, to be applied after the snapshot.
+    pub buffered: Vec<OrderBookDelta>,
+    /// The snapshot deltas streamed from the venue while resyncing.
+    pub snapshot: Vec<OrderBookDelta>,
+}
+
+impl BookSequenceState {
+    /// Checks the sequence of the given `delta` against the last applied sequence.
+    ///
+    /// Deltas with a zero sequence are considered unsequenced and always valid, as are
+    /// snapshot deltas which reset the book whatever their sequence.
+    #[must_use]
+    pub fn check(&self, delta: &OrderBookDelta) -> SequenceCheck {
+        let Some(last_sequence) = self.last_sequence else {
+            return SequenceCheck::Valid;
+        };
+
+        if delta.sequence == 0 || RecordFlag::F_SNAPSHOT.matches(delta.flags) {
+            return SequenceCheck::Valid;
+        }
+
+        match delta.sequence.cmp(&last_sequence) {
+            Ordering::Equal if self.is_event_complete => SequenceCheck::Duplicate,
+            Ordering::Equal => SequenceCheck::Valid, // Continuation of the same event
+            Ordering::Less => SequenceCheck::OutOfOrder,
+            Ordering::Greater if delta.sequence == last_sequence + 1 => SequenceCheck::Valid,
+            Ordering::Greater => SequenceCheck::Gap(last_sequence + 1),
+        }
+    }
+
+    /// Records the given `delta` as applied.
+    pub fn update(&mut self, delta: &OrderBookDelta) {
+        if delta.sequence != 0 {
+            self.last_sequence = Some(delta.sequence);
+        }
+        self.is_event_complete = RecordFlag::F_LAST.matches(delta.flags);
+    }
+
+    /// Buffers the given `delta` received while resyncing.
+    pub fn buffer(&mut self, delta: OrderBookDelta) {
+        if RecordFlag::F_SNAPSHOT.matches(delta.flags) {
+            self.snapshot.push(delta);
+        } else {
+            self.buffered.push(delta);
+        }
+    }
+
+    /// Returns whether a snapshot request is due at `ts_now`, given the minimum
+    /// `interval_ns` between requests.
+    #[must_use]
+    pub fn is_snapshot_request_due(&self, ts_now: UnixNanos, interval_ns: u64) -> bool {
+        self.last_snapshot_request
+            .is_none_or(|ts_last| ts_now.as_u64() >= ts_last.as_u64().saturating_add(interval_ns))
+    }
+
+    /// Returns whether a complete snapshot has been streamed while resyncing.
+    #[must_use]
+    pub fn has_complete_snapshot(&self) -> bool {
+        self.snapshot
+            .last()
+            .is_some_and(|delta| RecordFlag::F_LAST.matches(delta.flags))
+    }
+
+    /// Marks the book as resynchronized from a snapshot at `sequence`, returning
+    /// the buffered deltas which follow the snapshot.
+    pub fn complete_resync(&mut self, sequence: u64) -> Vec<OrderBookDelta> {
+        self.last_sequence = Some(sequence);
+        self.is_event_complete = true;
+        self.is_resyncing = false;
+        self.snapshot.clear();
+
+        std::mem::take(&mut self.buffered)
+            .into_iter()
+            .filter(|delta| delta.sequence > sequence)
+            .collect()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::UnixNanos;
+    use nautilus_model::{
+        data::{stubs::stub_delta, OrderBookDelta},
+        enums::RecordFlag,
+    };
+    use rstest::rstest;
+
+    use super::{BookSequenceState, SequenceCheck};
+
+    fn delta(sequence: u64, flags: u8) -> OrderBookDelta {
+        OrderBookDelta {
+            sequence,
+            flags,
+            ..stub_delta()
+        }
+    }
+
+    #[rstest]
+    #[case::first_delta(None, true, 5, SequenceCheck::Valid)]
+    #[case::next_sequence(Some(1), true, 2, SequenceCheck::Valid)]
+    #[case::same_event(Some(1), false, 1, SequenceCheck::Valid)]
+    #[case::unsequenced(Some(1), true, 0, SequenceCheck::Valid)]
+    #[case::duplicate(Some(2), true, 2, SequenceCheck::Duplicate)]
+    #[case::out_of_order(Some(3), true, 2, SequenceCheck::OutOfOrder)]
+    #[case::gap(Some(3), true, 5, SequenceCheck::Gap(4))]
+    fn test_sequence_check(
+        #[case] last_sequence: Option<u64>,
+        #[case] is_event_complete: bool,
+        #[case] sequence: u64,
+        #[case] expected: SequenceCheck,
+    ) {
+        let state = BookSequenceState {
+            last_sequence,
+            is_event_complete,
+            ..Default::default()
+        };
+
+        assert_eq!(state.check(&delta(sequence, 0)), expected);
+    }
+
+    #[rstest]
+    #[case::prior_sequence(2)]
+    #[case::same_sequence(3)]
+    #[case::later_sequence(5)]
+    fn test_sequence_check_snapshot_is_always_valid(#[case] sequence: u64) {
+        let mut state = BookSequenceState {
+            last_sequence: Some(3),
+            is_event_complete: true,
+            ..Default::default()
+        };
+        let snapshot = delta(sequence, RecordFlag::F_SNAPSHOT as u8);
+
+        assert_eq!(state.check(&snapshot), SequenceCheck::Valid);
+
+        state.update(&snapshot);
+
+        assert_eq!(state.last_sequence, Some(sequence));
+    }
+
+    #[rstest]
+    fn test_complete_resync_drops_deltas_covered_by_snapshot() {
+        let mut state = BookSequenceState {
+            is_resyncing: true,
+            ..Default::default()
+        };
+        state.buffer(delta(3, 0));
+        state.buffer(delta(4, 0));
+        state.buffer(delta(5, RecordFlag::F_LAST as u8));
+        state.buffer(OrderBookDelta::clear(
+            stub_delta().instrument_id,
+            4,
+            UnixNanos::default(),
+            UnixNanos::default(),
+        ));
+
+        assert_eq!(state.snapshot.len(), 1);
+        assert!(!state.has_complete_snapshot());
+
+        let replay = state.complete_resync(4);
+
+        assert_eq!(replay.len(), 1);
+        assert_eq!(replay[0].sequence, 5);
+        assert_eq!(state.last_sequence, Some(4));
+        assert!(!state.is_resyncing);
+        assert!(state.snapshot.is_empty());
+    }
+
+    #[rstest]
+    #[case::never_requested(None, 1_500, true)]
+    #[case::within_interval(Some(1_000), 1_500, false)]
+    #[case::interval_elapsed(Some(1_000), 2_000, true)]
+    fn test_is_snapshot_request_due(
+        #[case] last_snapshot_request: Option<u64>,
+        #[case] ts_now: u64,
+        #[case] expected: bool,
+    ) {
+        let state = BookSequenceState {
+            last_snapshot_request: last_snapshot_request.map(UnixNanos::from),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            state.is_snapshot_request_due(UnixNanos::from(ts_now), 1_000),
+            expected
+        );
+    }
+}
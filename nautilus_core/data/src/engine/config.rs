@@ -13,7 +13,14 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use nautilus_model::{enums::BarIntervalType, identifiers::ClientId};
+use std::{collections::HashMap, num::NonZeroU64};
+
+use nautilus_model::{
+    enums::BarIntervalType,
+    identifiers::{ClientId, Venue},
+};
+
+use crate::session::TradingSession;
 
 /// Configuration for `DataEngine` instances.
 #[derive(Clone, Debug)]
@@ -21,8 +28,16 @@ pub struct DataEngineConfig {
     pub time_bars_build_with_no_updates: bool,
     pub time_bars_timestamp_on_close: bool,
     pub time_bars_interval_type: BarIntervalType,
+    /// The trading sessions per venue for aligning time bars to session closes.
+    pub time_bars_sessions: HashMap<Venue, TradingSession>,
     pub validate_data_sequence: bool,
+    /// The minimum interval (milliseconds) between order book snapshot requests when resyncing.
+    pub book_resync_interval_ms: u64,
     pub buffer_deltas: bool,
+    /// If latency histograms are recorded for all data processed by the engine.
+    pub record_latency: bool,
+    /// The interval (milliseconds) between writing latency histograms to the log.
+    pub latency_log_interval_ms: Option<NonZeroU64>,
     pub external_clients: Option<Vec<ClientId>>,
     pub debug: bool,
 }
@@ -34,8 +49,12 @@ impl Default for DataEngineConfig {
             time_bars_build_with_no_updates: true,
             time_bars_timestamp_on_close: true,
             time_bars_interval_type: BarIntervalType::LeftOpen,
+            time_bars_sessions: HashMap::new(),
             validate_data_sequence: false,
+            book_resync_interval_ms: 1_000,
             buffer_deltas: false,
+            record_latency: false,
+            latency_log_interval_ms: None,
             external_clients: None,
             debug: false,
         }
@@ -0,0 +1,298 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    any::Any,
+    cell::{Cell, RefCell},
+    num::NonZeroU64,
+    rc::Rc,
+};
+
+use indexmap::IndexMap;
+use nautilus_common::{msgbus::MessageBus, timer::TimeEvent};
+use nautilus_model::{
+    data::{OrderBookDelta, OrderBookDeltas, QuoteTick, TradeTick},
+    enums::{BookAction, OrderSide, RecordFlag},
+    identifiers::InstrumentId,
+    types::price::PriceRaw,
+};
+use ustr::Ustr;
+
+/// The subscription parameter for conflating data to at most one update per interval (milliseconds).
+pub const CONFLATE_INTERVAL_MS: &str = "conflate_interval_ms";
+
+/// The data pending publication for a conflated subscription.
+#[derive(Clone, Debug)]
+enum ConflatedData {
+    Quote(QuoteTick),
+    Trade(TradeTick),
+    Deltas(InstrumentId, Vec<OrderBookDelta>),
+}
+
+/// Conflates the data published on a source topic, publishing at most one update per
+/// interval on a conflated topic.
+///
+/// Quotes and trades are conflated to the last value received in the interval, and
+/// order book deltas are coalesced into a single batch with one delta per order or level.
+///
+/// A conflator is shared by all subscriptions to the same source topic and interval, which
+/// all receive the same conflated stream from the conflated topic. As the conflated data only
+/// depends on the source topic and interval, per subscriber state would only duplicate the
+/// work. The conflator is reference counted by `subscriptions` and stopped once the last
+/// subscription is removed.
+pub struct Conflator {
+    pub topic: Ustr,
+    pub timer_name: Ustr,
+    pub interval_ms: NonZeroU64,
+    pub subscriptions: Cell<usize>,
+    pending: RefCell<Option<ConflatedData>>,
+    msgbus: Rc<RefCell<MessageBus>>,
+}
+
+impl Conflator {
+    /// Creates a new [`Conflator`] instance.
+    pub fn new(
+        source_topic: Ustr,
+        topic: Ustr,
+        interval_ms: NonZeroU64,
+        msgbus: Rc<RefCell<MessageBus>>,
+    ) -> Self {
+        let timer_name = format!("Conflation|{source_topic}|{interval_ms}");
+
+        Self {
+            topic,
+            timer_name: Ustr::from(&timer_name),
+            interval_ms,
+            subscriptions: Cell::new(0),
+            pending: RefCell::new(None),
+            msgbus,
+        }
+    }
+
+    pub fn update_quote(&self, quote: QuoteTick) {
+        self.pending.replace(Some(ConflatedData::Quote(quote)));
+    }
+
+    pub fn update_trade(&self, trade: TradeTick) {
+        self.pending.replace(Some(ConflatedData::Trade(trade)));
+    }
+
+    pub fn update_deltas(&self, instrument_id: InstrumentId, deltas: &[OrderBookDelta]) {
+        let mut pending = self.pending.borrow_mut();
+        match pending.as_mut() {
+            Some(ConflatedData::Deltas(_, buffered)) => buffered.extend_from_slice(deltas),
+            _ => *pending = Some(ConflatedData::Deltas(instrument_id, deltas.to_vec())),
+        }
+    }
+
+    /// Publishes any data received since the last interval on the conflated topic.
+    pub fn flush(&self, _event: TimeEvent) {
+        let Some(data) = self.pending.take() else {
+            return; // No updates in interval
+        };
+
+        let msgbus = self.msgbus.borrow_mut();
+        match data {
+            ConflatedData::Quote(quote) => msgbus.publish(&self.topic, &quote as &dyn Any),
+            ConflatedData::Trade(trade) => msgbus.publish(&self.topic, &trade as &dyn Any),
+            ConflatedData::Deltas(instrument_id, deltas) => {
+                let deltas = coalesce_deltas(deltas);
+                if deltas.is_empty() {
+                    return; // All updates cancelled out
+                }
+                let deltas = OrderBookDeltas::new(instrument_id, deltas);
+                msgbus.publish(&self.topic, &deltas as &dyn Any);
+            }
+        }
+    }
+}
+
+/// Coalesces the given `deltas` into one delta per order, or per price level for deltas
+/// without order IDs, preserving the order in which each was first updated.
+///
+/// A clear discards all prior deltas, an update to an order added in the same batch is
+/// folded into the add, and an order both added and deleted in the batch is dropped. An order
+/// deleted and then added again in the batch becomes an update, so the resting order is
+/// replaced.
+#[must_use]
+pub fn coalesce_deltas(deltas: Vec<OrderBookDelta>) -> Vec<OrderBookDelta> {
+    let mut clear: Option<OrderBookDelta> = None;
+    let mut coalesced: IndexMap<(OrderSide, u64, PriceRaw), OrderBookDelta> = IndexMap::new();
+
+    for delta in deltas {
+        if delta.action == BookAction::Clear {
+            clear = Some(delta);
+            coalesced.clear();
+            continue;
+        }
+
+        let order = delta.order;
+        let level = if order.order_id == 0 {
+            order.price.raw
+        } else {
+            0
+        };
+        let key = (order.side, order.order_id, level);
+
+        match coalesced.get_mut(&key) {
+            Some(prev) => match (prev.action, delta.action) {
+                (BookAction::Add, BookAction::Delete) => {
+                    coalesced.shift_remove(&key);
+                }
+                (BookAction::Add, _) => {
+                    *prev = OrderBookDelta {
+                        action: BookAction::Add,
+                        ..delta
+                    };
+                }
+                (BookAction::Delete, BookAction::Add) => {
+                    // The order still rests in the book, so replace it rather than add again
+                    *prev = OrderBookDelta {
+                        action: BookAction::Update,
+                        ..delta
+                    };
+                }
+                _ => *prev = delta,
+            },
+            None => {
+                coalesced.insert(key, delta);
+            }
+        }
+    }
+
+    let mut deltas: Vec<OrderBookDelta> =
+        clear.into_iter().chain(coalesced.into_values()).collect();
+
+    // Only the final delta completes the coalesced event
+    let last_index = deltas.len().saturating_sub(1);
+    for (i, delta) in deltas.iter_mut().enumerate() {
+        if i == last_index {
+            delta.flags |= RecordFlag::F_LAST as u8;
+        } else {
+            delta.flags &= !(RecordFlag::F_LAST as u8);
+        }
+    }
+
+    deltas
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::UnixNanos;
+    use nautilus_model::{
+        data::{stubs::stub_delta, BookOrder, OrderBookDelta},
+        enums::{BookAction, OrderSide, RecordFlag},
+        types::{Price, Quantity},
+    };
+    use rstest::rstest;
+
+    use super::coalesce_deltas;
+
+    fn delta(action: BookAction, order_id: u64, price: &str, size: u64) -> OrderBookDelta {
+        OrderBookDelta {
+            action,
+            order: BookOrder::new(
+                OrderSide::Buy,
+                Price::from(price),
+                Quantity::from(size),
+                order_id,
+            ),
+            flags: 0,
+            ..stub_delta()
+        }
+    }
+
+    #[rstest]
+    fn test_coalesce_deltas_keeps_latest_update_per_order() {
+        let deltas = vec![
+            delta(BookAction::Update, 1, "100.00", 10),
+            delta(BookAction::Update, 2, "99.00", 5),
+            delta(BookAction::Update, 1, "100.00", 20),
+        ];
+
+        let coalesced = coalesce_deltas(deltas);
+
+        assert_eq!(coalesced.len(), 2);
+        assert_eq!(coalesced[0].order.order_id, 1);
+        assert_eq!(coalesced[0].order.size, Quantity::from(20));
+        assert_eq!(coalesced[0].flags, 0);
+        assert_eq!(coalesced[1].order.order_id, 2);
+        assert_eq!(coalesced[1].flags, RecordFlag::F_LAST as u8);
+    }
+
+    #[rstest]
+    fn test_coalesce_deltas_folds_add_with_update_and_delete() {
+        let deltas = vec![
+            delta(BookAction::Add, 1, "100.00", 10),
+            delta(BookAction::Update, 1, "100.00", 15),
+            delta(BookAction::Add, 2, "99.00", 5),
+            delta(BookAction::Delete, 2, "99.00", 0),
+        ];
+
+        let coalesced = coalesce_deltas(deltas);
+
+        assert_eq!(coalesced.len(), 1);
+        assert_eq!(coalesced[0].action, BookAction::Add);
+        assert_eq!(coalesced[0].order.size, Quantity::from(15));
+    }
+
+    #[rstest]
+    fn test_coalesce_deltas_folds_delete_with_add_into_update() {
+        let deltas = vec![
+            delta(BookAction::Delete, 1, "100.00", 0),
+            delta(BookAction::Add, 1, "101.00", 10),
+        ];
+
+        let coalesced = coalesce_deltas(deltas);
+
+        assert_eq!(coalesced.len(), 1);
+        assert_eq!(coalesced[0].action, BookAction::Update);
+        assert_eq!(coalesced[0].order.price, Price::from("101.00"));
+        assert_eq!(coalesced[0].order.size, Quantity::from(10));
+    }
+
+    #[rstest]
+    fn test_coalesce_deltas_keys_levels_by_price_without_order_ids() {
+        let deltas = vec![
+            delta(BookAction::Update, 0, "100.00", 10),
+            delta(BookAction::Update, 0, "101.00", 10),
+            delta(BookAction::Update, 0, "100.00", 30),
+        ];
+
+        let coalesced = coalesce_deltas(deltas);
+
+        assert_eq!(coalesced.len(), 2);
+        assert_eq!(coalesced[0].order.size, Quantity::from(30));
+    }
+
+    #[rstest]
+    fn test_coalesce_deltas_clear_discards_prior_deltas() {
+        let instrument_id = stub_delta().instrument_id;
+        let deltas = vec![
+            delta(BookAction::Update, 1, "100.00", 10),
+            OrderBookDelta::clear(instrument_id, 0, UnixNanos::default(), UnixNanos::default()),
+            delta(BookAction::Add, 2, "99.00", 5),
+        ];
+
+        let coalesced = coalesce_deltas(deltas);
+
+        assert_eq!(coalesced.len(), 2);
+        assert_eq!(coalesced[0].action, BookAction::Clear);
+        assert_eq!(coalesced[1].order.order_id, 2);
+    }
+}
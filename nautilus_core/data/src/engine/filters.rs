@@ -0,0 +1,643 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Market data validation filters run by the `DataEngine` before data is published.
+
+use std::{
+    cell::RefCell,
+    collections::{HashMap, VecDeque},
+    fmt::Debug,
+    rc::Rc,
+};
+
+use nautilus_common::cache::Cache;
+use nautilus_core::{
+    correctness::{
+        check_in_range_inclusive_usize, check_positive_u64, check_predicate_true, FAILED,
+    },
+    UnixNanos,
+};
+use nautilus_model::{
+    data::{BarType, Data, TradeTick},
+    identifiers::InstrumentId,
+};
+
+/// The action taken for data which fails a filter check.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FilterAction {
+    /// Publish the data, only counting the failure.
+    Pass,
+    /// Drop the data so it is not published.
+    Drop,
+    /// Publish the data, logging a warning for the failure.
+    Flag,
+}
+
+/// Counts of the data checked by a filter, and the actions taken on failures.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FilterStats {
+    pub checked: u64,
+    pub failed: u64,
+    pub dropped: u64,
+    pub flagged: u64,
+}
+
+/// A check applied to market data before it is published.
+pub trait DataFilter {
+    /// The name of the filter, for logging and statistics.
+    fn name(&self) -> &str;
+
+    /// Checks the given `data`, returning an error describing why it is invalid.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the data fails the filter check.
+    fn check(&mut self, data: &Data) -> anyhow::Result<()>;
+}
+
+/// A chain of [`DataFilter`]s, each with the [`FilterAction`] to take on failure.
+#[derive(Default)]
+pub struct DataFilterPipeline {
+    filters: Vec<(Box<dyn DataFilter>, FilterAction, FilterStats)>,
+}
+
+impl DataFilterPipeline {
+    /// Adds the given `filter` to the end of the pipeline.
+    pub fn add(&mut self, filter: Box<dyn DataFilter>, action: FilterAction) {
+        log::info!("Added data filter {} with action {action:?}", filter.name());
+        self.filters.push((filter, action, FilterStats::default()));
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.filters.is_empty()
+    }
+
+    /// Returns the statistics for each filter in the pipeline by name.
+    #[must_use]
+    pub fn stats(&self) -> Vec<(String, FilterStats)> {
+        self.filters
+            .iter()
+            .map(|(filter, _, stats)| (filter.name().to_string(), *stats))
+            .collect()
+    }
+
+    /// Runs the given `data` through the pipeline, returning whether it should be published.
+    ///
+    /// Data dropped by a filter is not checked by any later filters.
+    pub fn process(&mut self, data: &Data) -> bool {
+        for (filter, action, stats) in &mut self.filters {
+            stats.checked += 1;
+
+            let Err(e) = filter.check(data) else {
+                continue;
+            };
+            stats.failed += 1;
+
+            match action {
+                FilterAction::Pass => {}
+                FilterAction::Drop => {
+                    stats.dropped += 1;
+                    log::debug!(
+                        "Dropped data for {} by {}: {e}",
+                        data.instrument_id(),
+                        filter.name()
+                    );
+                    return false;
+                }
+                FilterAction::Flag => {
+                    stats.flagged += 1;
+                    log::warn!(
+                        "Flagged data for {} by {}: {e}",
+                        data.instrument_id(),
+                        filter.name()
+                    );
+                }
+            }
+        }
+
+        true
+    }
+}
+
+/// Rejects crossed quotes (bid above ask), and optionally locked quotes (bid equal to ask).
+#[derive(Clone, Debug)]
+pub struct CrossedQuoteFilter {
+    pub reject_locked: bool,
+}
+
+impl CrossedQuoteFilter {
+    /// Creates a new [`CrossedQuoteFilter`] instance.
+    #[must_use]
+    pub const fn new(reject_locked: bool) -> Self {
+        Self { reject_locked }
+    }
+}
+
+impl DataFilter for CrossedQuoteFilter {
+    fn name(&self) -> &str {
+        stringify!(CrossedQuoteFilter)
+    }
+
+    fn check(&mut self, data: &Data) -> anyhow::Result<()> {
+        let Data::Quote(quote) = data else {
+            return Ok(());
+        };
+
+        if quote.bid_price > quote.ask_price {
+            anyhow::bail!("Crossed quote {quote}");
+        }
+        if self.reject_locked && quote.bid_price == quote.ask_price {
+            anyhow::bail!("Locked quote {quote}");
+        }
+
+        Ok(())
+    }
+}
+
+/// The recent trade prices of an instrument checked by an [`OutlierTradeFilter`].
+#[derive(Clone, Debug, Default)]
+struct TradePrices {
+    /// The rolling window of accepted trade prices.
+    accepted: VecDeque<f64>,
+    /// The trade prices rejected since the last accepted trade.
+    rejected: VecDeque<f64>,
+}
+
+/// Rejects trades priced too far from a reference, either by a number of standard
+/// deviations from the mean of a rolling window of recent trade prices, or by a number of
+/// ticks from the last trade price.
+///
+/// Ticks are measured in the price increment of the instrument in the cache, or at the
+/// precision of the trade price if the instrument is not cached. The standard deviation is
+/// floored at one tick, so a window of identical prices still accepts small moves.
+///
+/// Only accepted trade prices are added to the window, so a single outlier does not move the
+/// reference. A sustained move in price is accepted once `window` consecutive trades fail the
+/// check, which re-anchors the window to those trade prices.
+#[derive(Clone)]
+pub struct OutlierTradeFilter {
+    pub window: usize,
+    pub max_std_devs: Option<f64>,
+    pub max_ticks: Option<u64>,
+    cache: Rc<RefCell<Cache>>,
+    prices: HashMap<InstrumentId, TradePrices>,
+}
+
+impl OutlierTradeFilter {
+    /// Creates a new [`OutlierTradeFilter`] instance with correctness checking.
+    ///
+    /// The standard deviation check only applies once `window` trade prices are available.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `window` is less than 2.
+    /// - If `max_std_devs` is not positive.
+    /// - If `max_ticks` is zero.
+    pub fn new_checked(
+        window: usize,
+        max_std_devs: Option<f64>,
+        max_ticks: Option<u64>,
+        cache: Rc<RefCell<Cache>>,
+    ) -> anyhow::Result<Self> {
+        check_in_range_inclusive_usize(window, 2, usize::MAX, "window")?;
+        if let Some(max_std_devs) = max_std_devs {
+            check_predicate_true(
+                max_std_devs > 0.0,
+                &format!("`max_std_devs` must be positive, was {max_std_devs}"),
+            )?;
+        }
+        if let Some(max_ticks) = max_ticks {
+            check_positive_u64(max_ticks, "max_ticks")?;
+        }
+
+        Ok(Self {
+            window,
+            max_std_devs,
+            max_ticks,
+            cache,
+            prices: HashMap::new(),
+        })
+    }
+
+    /// Creates a new [`OutlierTradeFilter`] instance.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if a correctness check fails. See [`OutlierTradeFilter::new_checked`]
+    /// for more details.
+    #[must_use]
+    pub fn new(
+        window: usize,
+        max_std_devs: Option<f64>,
+        max_ticks: Option<u64>,
+        cache: Rc<RefCell<Cache>>,
+    ) -> Self {
+        Self::new_checked(window, max_std_devs, max_ticks, cache).expect(FAILED)
+    }
+
+    fn tick(&self, trade: &TradeTick) -> f64 {
+        self.cache
+            .borrow()
+            .instrument(&trade.instrument_id)
+            .map_or_else(
+                || 10f64.powi(-i32::from(trade.price.precision)),
+                |instrument| instrument.price_increment().as_f64(),
+            )
+    }
+
+    fn check_price(&self, prices: &VecDeque<f64>, price: f64, tick: f64) -> anyhow::Result<()> {
+        if let (Some(max_ticks), Some(last)) = (self.max_ticks, prices.back()) {
+            let ticks = ((price - last).abs() / tick).round();
+            if ticks > max_ticks as f64 {
+                anyhow::bail!("Trade price {price} is {ticks} ticks from last trade price {last}");
+            }
+        }
+
+        if let Some(max_std_devs) = self.max_std_devs {
+            if prices.len() < self.window {
+                return Ok(());
+            }
+
+            let count = prices.len() as f64;
+            let mean = prices.iter().sum::<f64>() / count;
+            let variance = prices.iter().map(|p| (p - mean).powi(2)).sum::<f64>() / (count - 1.0);
+            let std_dev = variance.sqrt().max(tick);
+            if (price - mean).abs() > max_std_devs * std_dev {
+                anyhow::bail!(
+                    "Trade price {price} is more than {max_std_devs} std devs from mean {mean}"
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Debug for OutlierTradeFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct(stringify!(OutlierTradeFilter))
+            .field("window", &self.window)
+            .field("max_std_devs", &self.max_std_devs)
+            .field("max_ticks", &self.max_ticks)
+            .field("prices", &self.prices)
+            .finish_non_exhaustive()
+    }
+}
+
+impl DataFilter for OutlierTradeFilter {
+    fn name(&self) -> &str {
+        stringify!(OutlierTradeFilter)
+    }
+
+    fn check(&mut self, data: &Data) -> anyhow::Result<()> {
+        let Data::Trade(trade) = data else {
+            return Ok(());
+        };
+
+        let price = trade.price.as_f64();
+        let tick = self.tick(trade);
+        let mut prices = self.prices.remove(&trade.instrument_id).unwrap_or_default();
+        let mut result = self.check_price(&prices.accepted, price, tick);
+
+        if result.is_ok() {
+            prices.rejected.clear();
+            if prices.accepted.len() == self.window {
+                prices.accepted.pop_front();
+            }
+            prices.accepted.push_back(price);
+        } else {
+            prices.rejected.push_back(price);
+            if prices.rejected.len() == self.window {
+                log::info!(
+                    "Re-anchored {} trade price reference after {} consecutive outliers",
+                    trade.instrument_id,
+                    self.window
+                );
+                prices.accepted = std::mem::take(&mut prices.rejected);
+                result = Ok(());
+            }
+        }
+        self.prices.insert(trade.instrument_id, prices);
+
+        result
+    }
+}
+
+/// Rejects quotes received more than `max_age_ns` after their `ts_event`.
+#[derive(Clone, Debug)]
+pub struct StaleQuoteFilter {
+    pub max_age_ns: u64,
+}
+
+impl StaleQuoteFilter {
+    /// Creates a new [`StaleQuoteFilter`] instance.
+    #[must_use]
+    pub const fn new(max_age_ns: u64) -> Self {
+        Self { max_age_ns }
+    }
+}
+
+impl DataFilter for StaleQuoteFilter {
+    fn name(&self) -> &str {
+        stringify!(StaleQuoteFilter)
+    }
+
+    fn check(&mut self, data: &Data) -> anyhow::Result<()> {
+        let Data::Quote(quote) = data else {
+            return Ok(());
+        };
+
+        let age_ns = quote
+            .ts_init
+            .as_u64()
+            .saturating_sub(quote.ts_event.as_u64());
+        if age_ns > self.max_age_ns {
+            anyhow::bail!("Stale quote {quote}, received {age_ns}ns after `ts_event`");
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum TimestampKey {
+    Data(InstrumentId, &'static str),
+    Bar(BarType),
+}
+
+/// Rejects data with a `ts_event` prior to the last data of the same type and instrument
+/// (or bar type).
+#[derive(Clone, Debug, Default)]
+pub struct MonotonicTimestampFilter {
+    last_ts_event: HashMap<TimestampKey, UnixNanos>,
+}
+
+impl MonotonicTimestampFilter {
+    /// Creates a new [`MonotonicTimestampFilter`] instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl DataFilter for MonotonicTimestampFilter {
+    fn name(&self) -> &str {
+        stringify!(MonotonicTimestampFilter)
+    }
+
+    fn check(&mut self, data: &Data) -> anyhow::Result<()> {
+        let (key, ts_event) = match data {
+            Data::Delta(delta) => (
+                TimestampKey::Data(delta.instrument_id, "OrderBookDelta"),
+                delta.ts_event,
+            ),
+            Data::Deltas(deltas) => (
+                TimestampKey::Data(deltas.instrument_id, "OrderBookDelta"),
+                deltas.ts_event,
+            ),
+            Data::Depth10(depth) => (
+                TimestampKey::Data(depth.instrument_id, "OrderBookDepth10"),
+                depth.ts_event,
+            ),
+            Data::Quote(quote) => (
+                TimestampKey::Data(quote.instrument_id, "QuoteTick"),
+                quote.ts_event,
+            ),
+            Data::Trade(trade) => (
+                TimestampKey::Data(trade.instrument_id, "TradeTick"),
+                trade.ts_event,
+            ),
+            Data::Bar(bar) => (TimestampKey::Bar(bar.bar_type), bar.ts_event),
+            Data::MarkPrice(mark_price) => (
+                TimestampKey::Data(mark_price.instrument_id, "MarkPriceUpdate"),
+                mark_price.ts_event,
+            ),
+            Data::IndexPrice(index_price) => (
+                TimestampKey::Data(index_price.instrument_id, "IndexPriceUpdate"),
+                index_price.ts_event,
+            ),
+            Data::FundingRate(funding_rate) => (
+                TimestampKey::Data(funding_rate.instrument_id, "FundingRateUpdate"),
+                funding_rate.ts_event,
+            ),
+        };
+
+        let last_ts_event = self.last_ts_event.entry(key).or_default();
+        if ts_event < *last_ts_event {
+            anyhow::bail!("`ts_event` {ts_event} was prior to last `ts_event` {last_ts_event}");
+        }
+        *last_ts_event = ts_event;
+
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::UnixNanos;
+    use nautilus_model::{
+        data::{Data, QuoteTick, TradeTick},
+        instruments::{stubs::equity_aapl, Equity, InstrumentAny},
+        types::Price,
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn quote(bid: &str, ask: &str, ts_event: u64, ts_init: u64) -> Data {
+        Data::Quote(QuoteTick {
+            bid_price: Price::from(bid),
+            ask_price: Price::from(ask),
+            ts_event: UnixNanos::from(ts_event),
+            ts_init: UnixNanos::from(ts_init),
+            ..QuoteTick::default()
+        })
+    }
+
+    fn trade(price: &str, ts_event: u64) -> Data {
+        Data::Trade(TradeTick {
+            price: Price::from(price),
+            ts_event: UnixNanos::from(ts_event),
+            ts_init: UnixNanos::from(ts_event),
+            ..TradeTick::default()
+        })
+    }
+
+    fn cache() -> Rc<RefCell<Cache>> {
+        Rc::new(RefCell::new(Cache::default()))
+    }
+
+    #[rstest]
+    #[case::valid("1.00000", "1.00001", false, true)]
+    #[case::crossed("1.00001", "1.00000", false, false)]
+    #[case::locked_allowed("1.00000", "1.00000", false, true)]
+    #[case::locked_rejected("1.00000", "1.00000", true, false)]
+    fn test_crossed_quote_filter(
+        #[case] bid: &str,
+        #[case] ask: &str,
+        #[case] reject_locked: bool,
+        #[case] expected: bool,
+    ) {
+        let mut filter = CrossedQuoteFilter::new(reject_locked);
+        assert_eq!(filter.check(&quote(bid, ask, 0, 0)).is_ok(), expected);
+    }
+
+    #[rstest]
+    fn test_outlier_trade_filter_by_ticks() {
+        let mut filter = OutlierTradeFilter::new(10, None, Some(5), cache());
+
+        assert!(filter.check(&trade("100.00", 1)).is_ok());
+        assert!(filter.check(&trade("100.05", 2)).is_ok());
+        assert!(filter.check(&trade("100.11", 3)).is_err());
+
+        // The rejected trade is not added to the reference window
+        assert!(filter.check(&trade("100.00", 4)).is_ok());
+    }
+
+    #[rstest]
+    #[case::window_too_small(1, None, None)]
+    #[case::zero_std_devs(10, Some(0.0), None)]
+    #[case::negative_std_devs(10, Some(-1.0), None)]
+    #[case::zero_ticks(10, None, Some(0))]
+    fn test_outlier_trade_filter_new_checked_with_invalid_params(
+        #[case] window: usize,
+        #[case] max_std_devs: Option<f64>,
+        #[case] max_ticks: Option<u64>,
+    ) {
+        assert!(OutlierTradeFilter::new_checked(window, max_std_devs, max_ticks, cache()).is_err());
+    }
+
+    #[rstest]
+    fn test_outlier_trade_filter_by_std_devs() {
+        let mut filter = OutlierTradeFilter::new(4, Some(3.0), None, cache());
+
+        for (i, price) in ["100.00", "100.10", "99.90", "100.00"].iter().enumerate() {
+            assert!(filter.check(&trade(price, i as u64)).is_ok());
+        }
+
+        assert!(filter.check(&trade("100.05", 5)).is_ok());
+        assert!(filter.check(&trade("110.00", 6)).is_err());
+    }
+
+    #[rstest]
+    fn test_outlier_trade_filter_with_flat_window() {
+        let mut filter = OutlierTradeFilter::new(4, Some(3.0), None, cache());
+
+        for i in 0..4 {
+            assert!(filter.check(&trade("100.00", i)).is_ok());
+        }
+
+        // The standard deviation is floored at one tick of 0.01
+        assert!(filter.check(&trade("100.02", 5)).is_ok());
+        assert!(filter.check(&trade("110.00", 6)).is_err());
+    }
+
+    #[rstest]
+    fn test_outlier_trade_filter_accepts_sustained_move() {
+        let mut filter = OutlierTradeFilter::new(3, None, Some(5), cache());
+
+        assert!(filter.check(&trade("100.00", 1)).is_ok());
+        assert!(filter.check(&trade("101.00", 2)).is_err());
+        assert!(filter.check(&trade("101.01", 3)).is_err());
+
+        // The third consecutive outlier re-anchors the reference to the new prices
+        assert!(filter.check(&trade("101.02", 4)).is_ok());
+        assert!(filter.check(&trade("101.03", 5)).is_ok());
+        assert!(filter.check(&trade("100.00", 6)).is_err());
+    }
+
+    #[rstest]
+    fn test_outlier_trade_filter_ticks_use_instrument_price_increment() {
+        let instrument = Equity {
+            price_increment: Price::from("0.25"),
+            ..equity_aapl()
+        };
+        let cache = cache();
+        cache
+            .borrow_mut()
+            .add_instrument(InstrumentAny::Equity(instrument))
+            .unwrap();
+        let mut filter = OutlierTradeFilter::new(10, None, Some(4), cache);
+        let trade = |price: &str, ts_event: u64| {
+            Data::Trade(TradeTick {
+                instrument_id: instrument.id,
+                price: Price::from(price),
+                ts_event: UnixNanos::from(ts_event),
+                ts_init: UnixNanos::from(ts_event),
+                ..TradeTick::default()
+            })
+        };
+
+        assert!(filter.check(&trade("100.00", 1)).is_ok());
+        // 4 ticks of 0.25 (rather than 100 ticks of 0.01)
+        assert!(filter.check(&trade("101.00", 2)).is_ok());
+        assert!(filter.check(&trade("102.25", 3)).is_err());
+    }
+
+    #[rstest]
+    fn test_stale_quote_filter() {
+        let mut filter = StaleQuoteFilter::new(1_000);
+
+        assert!(filter.check(&quote("1.0", "1.1", 0, 1_000)).is_ok());
+        assert!(filter.check(&quote("1.0", "1.1", 0, 1_001)).is_err());
+    }
+
+    #[rstest]
+    fn test_monotonic_timestamp_filter_per_data_type() {
+        let mut filter = MonotonicTimestampFilter::new();
+
+        assert!(filter.check(&quote("1.0", "1.1", 2, 2)).is_ok());
+        assert!(filter.check(&trade("1.0", 1)).is_ok()); // Separate stream for trades
+        assert!(filter.check(&quote("1.0", "1.1", 2, 2)).is_ok());
+        assert!(filter.check(&quote("1.0", "1.1", 1, 1)).is_err());
+    }
+
+    #[rstest]
+    fn test_pipeline_actions_and_stats() {
+        let mut pipeline = DataFilterPipeline::default();
+        pipeline.add(Box::new(StaleQuoteFilter::new(10)), FilterAction::Flag);
+        pipeline.add(Box::new(CrossedQuoteFilter::new(false)), FilterAction::Drop);
+
+        assert!(pipeline.process(&quote("1.0", "1.1", 0, 0)));
+        assert!(pipeline.process(&quote("1.0", "1.1", 0, 100))); // Stale but only flagged
+        assert!(!pipeline.process(&quote("1.2", "1.1", 0, 0)));
+
+        let stats = pipeline.stats();
+        assert_eq!(stats[0].0, "StaleQuoteFilter");
+        assert_eq!(
+            stats[0].1,
+            FilterStats {
+                checked: 3,
+                failed: 1,
+                dropped: 0,
+                flagged: 1
+            }
+        );
+        assert_eq!(
+            stats[1].1,
+            FilterStats {
+                checked: 3,
+                failed: 1,
+                dropped: 1,
+                flagged: 0
+            }
+        );
+    }
+}
@@ -35,6 +35,8 @@
 
 pub mod book;
 pub mod config;
+pub mod conflation;
+pub mod filters;
 pub mod runner;
 
 #[cfg(test)]
@@ -49,8 +51,13 @@ use std::{
     sync::Arc,
 };
 
-use book::{BookSnapshotInfo, BookSnapshotter, BookUpdater};
+use book::{
+    BookDepthPublisher, BookSequenceState, BookSnapshotInfo, BookSnapshotter, BookUpdater,
+    SequenceAction, SequenceCheck,
+};
 use config::DataEngineConfig;
+use conflation::{Conflator, CONFLATE_INTERVAL_MS};
+use filters::{DataFilter, DataFilterPipeline, FilterAction, FilterStats};
 use indexmap::IndexMap;
 use nautilus_common::{
     cache::Cache,
@@ -66,25 +73,33 @@ use nautilus_common::{
 use nautilus_core::{
     correctness::{check_key_in_index_map, check_key_not_in_index_map, FAILED},
     datetime::{millis_to_nanos, NANOSECONDS_IN_MILLISECOND, NANOSECONDS_IN_SECOND},
+    UnixNanos, UUID4,
 };
 use nautilus_model::{
     data::{
-        Bar, BarType, Data, DataType, OrderBookDelta, OrderBookDeltas, OrderBookDepth10, QuoteTick,
-        TradeTick,
+        Bar, BarType, CorporateAction, Data, DataType, FundingRateUpdate, GetTsInit,
+        IndexPriceUpdate, MarkPriceUpdate, OrderBookDelta, OrderBookDeltas, OrderBookDepth10,
+        QuoteTick, TradeTick,
     },
-    enums::{AggregationSource, BarAggregation, BookType, PriceType, RecordFlag},
-    identifiers::{ClientId, InstrumentId, Venue},
+    enums::{AggregationSource, BarAggregation, BookAction, BookType, PriceType, RecordFlag},
+    identifiers::{ClientId, InstrumentId, TradeId, Venue},
     instruments::{InstrumentAny, SyntheticInstrument},
     orderbook::OrderBook,
+    types::{Price, Quantity},
 };
+use nautilus_persistence::backend::catalog::{CatalogPathPrefix, ParquetDataCatalog};
+use nautilus_serialization::arrow::DecodeDataFromRecordBatch;
+use rust_decimal::{Decimal, RoundingStrategy};
 use ustr::Ustr;
 
 use crate::{
     aggregation::{
-        BarAggregator, TickBarAggregator, TimeBarAggregator, ValueBarAggregator,
-        VolumeBarAggregator,
+        BarAggregator, BookPriceType, TickBarAggregator, TimeBarAggregator, ValueBarAggregator,
+        VolumeBarAggregator, BOOK_PRICE_TYPE,
     },
     client::DataClientAdapter,
+    continuous::{ContinuousFutures, RollEvent},
+    latency::LatencyTracker,
 };
 
 /// Provides a high-performance `DataEngine` for all environments.
@@ -94,15 +109,27 @@ pub struct DataEngine {
     msgbus: Rc<RefCell<MessageBus>>,
     clients: IndexMap<ClientId, DataClientAdapter>,
     default_client: Option<DataClientAdapter>,
+    catalogs: RefCell<IndexMap<Ustr, ParquetDataCatalog>>,
     external_clients: HashSet<ClientId>,
     routing_map: IndexMap<Venue, ClientId>,
     book_intervals: HashMap<NonZeroU64, HashSet<InstrumentId>>,
     book_updaters: HashMap<InstrumentId, Rc<BookUpdater>>,
     book_snapshotters: HashMap<InstrumentId, Rc<BookSnapshotter>>,
+    book_depth_publishers: HashMap<InstrumentId, Rc<BookDepthPublisher>>,
     bar_aggregators: HashMap<BarType, Box<dyn BarAggregator>>,
+    book_bar_aggregators: HashMap<BarType, (BookPriceType, Box<dyn BarAggregator>)>,
+    conflators: HashMap<Ustr, Vec<Rc<Conflator>>>,
+    filters: DataFilterPipeline,
+    continuous_futures: IndexMap<InstrumentId, ContinuousFutures>,
     synthetic_quote_feeds: HashMap<InstrumentId, Vec<SyntheticInstrument>>,
     synthetic_trade_feeds: HashMap<InstrumentId, Vec<SyntheticInstrument>>,
+    synthetic_quote_subscribers: HashMap<InstrumentId, usize>,
+    synthetic_trade_subscribers: HashMap<InstrumentId, usize>,
     buffered_deltas_map: HashMap<InstrumentId, Vec<OrderBookDelta>>, // TODO: Use OrderBookDeltas?
+    book_sequences: HashMap<InstrumentId, BookSequenceState>,
+    invalid_books: Rc<RefCell<HashSet<InstrumentId>>>,
+    derived_depth_books: Rc<RefCell<HashSet<InstrumentId>>>,
+    latency: Rc<RefCell<LatencyTracker>>,
     msgbus_priority: u8,
     command_queue: VecDeque<SubscriptionCommand>,
     config: DataEngineConfig,
@@ -117,25 +144,45 @@ impl DataEngine {
         msgbus: Rc<RefCell<MessageBus>>,
         config: Option<DataEngineConfig>,
     ) -> Self {
-        Self {
+        let mut engine = Self {
             clock,
             cache,
             msgbus,
             clients: IndexMap::new(),
             default_client: None,
+            catalogs: RefCell::new(IndexMap::new()),
             external_clients: HashSet::new(),
             routing_map: IndexMap::new(),
             book_intervals: HashMap::new(),
             book_updaters: HashMap::new(),
             book_snapshotters: HashMap::new(),
+            book_depth_publishers: HashMap::new(),
             bar_aggregators: HashMap::new(),
+            book_bar_aggregators: HashMap::new(),
+            conflators: HashMap::new(),
+            filters: DataFilterPipeline::default(),
+            continuous_futures: IndexMap::new(),
             synthetic_quote_feeds: HashMap::new(),
             synthetic_trade_feeds: HashMap::new(),
+            synthetic_quote_subscribers: HashMap::new(),
+            synthetic_trade_subscribers: HashMap::new(),
             buffered_deltas_map: HashMap::new(),
+            book_sequences: HashMap::new(),
+            invalid_books: Rc::new(RefCell::new(HashSet::new())),
+            derived_depth_books: Rc::new(RefCell::new(HashSet::new())),
+            latency: Rc::new(RefCell::new(LatencyTracker::default())),
             msgbus_priority: 10, // High-priority for built-in component
             command_queue: VecDeque::new(),
             config: config.unwrap_or_default(),
+        };
+
+        if let Some(interval_ms) = engine.config.latency_log_interval_ms {
+            if let Err(e) = engine.start_latency_logging(interval_ms) {
+                log::error!("Error starting latency logging: {e}");
+            }
         }
+
+        engine
     }
 
     /// Provides read-only access to the cache.
@@ -144,7 +191,39 @@ impl DataEngine {
         self.cache.borrow()
     }
 
-    // pub fn register_catalog(&mut self, catalog: ParquetDataCatalog) {}  TODO: Implement catalog
+    /// Adds the given data `filter` to the end of the filter pipeline, which all data is run
+    /// through before being published, taking the given `action` for data failing the filter.
+    pub fn add_filter(&mut self, filter: Box<dyn DataFilter>, action: FilterAction) {
+        self.filters.add(filter, action);
+    }
+
+    /// Returns the statistics for each filter in the filter pipeline by name.
+    #[must_use]
+    pub fn filter_stats(&self) -> Vec<(String, FilterStats)> {
+        self.filters.stats()
+    }
+
+    /// Registers the given data `catalog` with the engine for serving historical data requests.
+    ///
+    /// If no `name` is given then a name is generated from the number of registered catalogs.
+    ///
+    /// # Panics
+    ///
+    /// This function panics:
+    /// - If a catalog with the same name has already been registered.
+    pub fn register_catalog(&mut self, catalog: ParquetDataCatalog, name: Option<String>) {
+        let catalogs = self.catalogs.get_mut();
+        let name = Ustr::from(&name.unwrap_or_else(|| format!("catalog_{}", catalogs.len())));
+        check_key_not_in_index_map(&name, catalogs, "name", "catalogs").expect(FAILED);
+
+        catalogs.insert(name, catalog);
+        log::info!("Registered catalog {name}");
+    }
+
+    #[must_use]
+    pub fn registered_catalogs(&self) -> Vec<Ustr> {
+        self.catalogs.borrow().keys().copied().collect()
+    }
 
     /// Registers the given data `client` with the engine as the default routing client.
     ///
@@ -271,6 +350,21 @@ impl DataEngine {
         self.collect_subscriptions(|client| &client.subscriptions_trade_tick)
     }
 
+    #[must_use]
+    pub fn subscribed_mark_prices(&self) -> Vec<InstrumentId> {
+        self.collect_subscriptions(|client| &client.subscriptions_mark_price)
+    }
+
+    #[must_use]
+    pub fn subscribed_index_prices(&self) -> Vec<InstrumentId> {
+        self.collect_subscriptions(|client| &client.subscriptions_index_price)
+    }
+
+    #[must_use]
+    pub fn subscribed_funding_rates(&self) -> Vec<InstrumentId> {
+        self.collect_subscriptions(|client| &client.subscriptions_funding_rate)
+    }
+
     #[must_use]
     pub fn subscribed_bars(&self) -> Vec<BarType> {
         self.collect_subscriptions(|client| &client.subscriptions_bar)
@@ -286,6 +380,33 @@ impl DataEngine {
         self.collect_subscriptions(|client| &client.subscriptions_instrument_close)
     }
 
+    /// Returns the bar types currently aggregated from order book prices.
+    #[must_use]
+    pub fn subscribed_book_bars(&self) -> Vec<BarType> {
+        self.book_bar_aggregators.keys().copied().collect()
+    }
+
+    /// Returns the instrument IDs for which depth is currently derived from order books.
+    #[must_use]
+    pub fn subscribed_book_depth(&self) -> Vec<InstrumentId> {
+        self.book_depth_publishers.keys().copied().collect()
+    }
+
+    /// Returns the latency histograms recorded for data processed by the engine.
+    ///
+    /// The tracker is shared with the registered data clients, which record the client latency
+    /// of the data they send to the engine.
+    #[must_use]
+    pub fn latency_tracker(&self) -> Rc<RefCell<LatencyTracker>> {
+        self.latency.clone()
+    }
+
+    /// Returns the instrument IDs of order books currently invalid and awaiting resync.
+    #[must_use]
+    pub fn invalid_order_books(&self) -> Vec<InstrumentId> {
+        self.invalid_books.borrow().iter().copied().collect()
+    }
+
     pub fn on_start(self) {
         todo!()
     }
@@ -300,10 +421,14 @@ impl DataEngine {
     ///
     /// This function panics:
     /// - If a client with the same client ID has already been registered.
-    pub fn register_client(&mut self, client: DataClientAdapter, routing: Option<Venue>) {
+    pub fn register_client(&mut self, mut client: DataClientAdapter, routing: Option<Venue>) {
         check_key_not_in_index_map(&client.client_id, &self.clients, "client_id", "clients")
             .expect(FAILED);
 
+        if self.config.record_latency {
+            client.set_latency_tracker(self.latency.clone());
+        }
+
         if let Some(routing) = routing {
             self.routing_map.insert(routing, client.client_id());
             log::info!("Set client {} routing for {routing}", client.client_id());
@@ -347,21 +472,32 @@ impl DataEngine {
                 stringify!(OrderBookDelta) => self.handle_subscribe_book_deltas(&cmd),
                 stringify!(OrderBook) => self.handle_subscribe_book_snapshots(&cmd),
                 stringify!(Bar) => self.handle_subscribe_bars(&cmd),
+                stringify!(QuoteTick) | stringify!(TradeTick) if is_synthetic_command(&cmd) => {
+                    self.handle_subscribe_synthetic(&cmd)
+                }
                 _ => Ok(()), // No other actions for engine
             },
             Action::Unsubscribe => match cmd.data_type.type_name() {
                 stringify!(OrderBookDelta) => self.handle_unsubscribe_book_deltas(&cmd),
                 stringify!(OrderBook) => self.handle_unsubscribe_book_snapshots(&cmd),
                 stringify!(Bar) => self.handle_unsubscribe_bars(&cmd),
+                stringify!(QuoteTick) | stringify!(TradeTick) if is_synthetic_command(&cmd) => {
+                    self.handle_unsubscribe_synthetic(&cmd)
+                }
                 _ => Ok(()), // No other actions for engine
             },
         };
+        let result = result.and_then(|()| self.handle_conflation(&cmd));
 
         if let Err(e) = result {
             log::error!("{e}");
             return;
         }
 
+        if is_synthetic_command(&cmd) {
+            return; // Synthetic data is built by the engine from the component subscriptions
+        }
+
         if let Some(client) = self.get_client_mut(&cmd.client_id, &cmd.venue) {
             client.execute(cmd);
         } else {
@@ -373,7 +509,21 @@ impl DataEngine {
     }
 
     /// Sends a [`DataRequest`] to an endpoint that must be a data client implementation.
+    ///
+    /// When catalogs are registered any data they hold for the request is served first,
+    /// with the client requested for the remainder of the range and the merged results
+    /// returned through [`DataEngine::response`].
+    ///
+    /// Catalog queries need mutable access to the catalog query session, which is kept
+    /// behind a `RefCell` so requests can still be made through a shared reference.
     pub fn request(&self, req: DataRequest) {
+        if !self.catalogs.borrow().is_empty() {
+            if let Some(resp) = self.request_from_catalogs(&req) {
+                self.response(resp);
+                return;
+            }
+        }
+
         if let Some(client) = self.get_client(&req.client_id, &req.venue) {
             client.through_request(req);
         } else {
@@ -387,12 +537,29 @@ impl DataEngine {
     pub fn process(&mut self, data: &dyn Any) {
         if let Some(instrument) = data.downcast_ref::<InstrumentAny>() {
             self.handle_instrument(instrument.clone());
+        } else if let Some(action) = data.downcast_ref::<CorporateAction>() {
+            self.handle_corporate_action(*action);
         } else {
             log::error!("Cannot process data {data:?}, type is unrecognized");
         }
     }
 
     pub fn process_data(&mut self, data: Data) {
+        if !self.filters.is_empty() && !self.filters.process(&data) {
+            return; // Dropped by filter
+        }
+
+        if self.config.record_latency {
+            self.latency.borrow_mut().record_feed(&data);
+            self.handle_data(data.clone());
+            let ts_now = self.clock.borrow().timestamp_ns();
+            self.latency.borrow_mut().record_processing(&data, ts_now);
+        } else {
+            self.handle_data(data);
+        }
+    }
+
+    fn handle_data(&mut self, data: Data) {
         match data {
             Data::Delta(delta) => self.handle_delta(delta),
             Data::Deltas(deltas) => self.handle_deltas(deltas.into_inner()),
@@ -400,10 +567,13 @@ impl DataEngine {
             Data::Quote(quote) => self.handle_quote(quote),
             Data::Trade(trade) => self.handle_trade(trade),
             Data::Bar(bar) => self.handle_bar(bar),
+            Data::MarkPrice(mark_price) => self.handle_mark_price(mark_price),
+            Data::IndexPrice(index_price) => self.handle_index_price(index_price),
+            Data::FundingRate(funding_rate) => self.handle_funding_rate(funding_rate),
         }
     }
 
-    pub fn response(&self, resp: DataResponse) {
+    pub fn response(&self, mut resp: DataResponse) {
         log::debug!("{}", format!("{RECV}{RES} {resp:?}"));
 
         match resp.data_type.type_name() {
@@ -425,8 +595,11 @@ impl DataEngine {
             stringify!(Bar) => {
                 let bars =
                     Arc::downcast::<Vec<Bar>>(resp.data.clone()).expect("Invalid response data");
+                let bars = self.back_adjust_continuous_bars(bars);
+                resp.data = bars.clone();
                 self.handle_bars(bars);
             }
+            stringify!(OrderBookDelta) => {} // Deltas are not cached
             type_name => log::error!("Cannot handle request, type {type_name} is unrecognized"),
         }
 
@@ -451,6 +624,25 @@ impl DataEngine {
     }
 
     fn handle_delta(&mut self, delta: OrderBookDelta) {
+        if self.config.validate_data_sequence {
+            match self.validate_delta_sequence(&delta) {
+                SequenceAction::Publish => {}
+                SequenceAction::Drop => return,
+                SequenceAction::Resync => {
+                    self.resync_order_book(&delta.instrument_id);
+                    return;
+                }
+            }
+        }
+
+        self.publish_delta(delta);
+
+        if self.config.validate_data_sequence && RecordFlag::F_LAST.matches(delta.flags) {
+            self.check_book_integrity(&delta.instrument_id);
+        }
+    }
+
+    fn publish_delta(&mut self, delta: OrderBookDelta) {
         let deltas = if self.config.buffer_deltas {
             let buffer_deltas = self
                 .buffered_deltas_map
@@ -472,12 +664,40 @@ impl DataEngine {
             OrderBookDeltas::new(delta.instrument_id, vec![delta])
         };
 
-        let mut msgbus = self.msgbus.borrow_mut();
-        let topic = msgbus.switchboard.get_deltas_topic(deltas.instrument_id);
-        msgbus.publish(&topic, &deltas as &dyn Any);
+        {
+            let mut msgbus = self.msgbus.borrow_mut();
+            let topic = msgbus.switchboard.get_deltas_topic(deltas.instrument_id);
+            msgbus.publish(&topic, &deltas as &dyn Any);
+
+            for conflator in self.conflators.get(&topic).into_iter().flatten() {
+                conflator.update_deltas(deltas.instrument_id, &deltas.deltas);
+            }
+        }
+
+        self.update_book_bars(&deltas.instrument_id, deltas.ts_event);
+        self.update_book_depth(&deltas);
     }
 
     fn handle_deltas(&mut self, deltas: OrderBookDeltas) {
+        if !self.config.validate_data_sequence {
+            self.publish_deltas(deltas);
+            return;
+        }
+
+        let instrument_id = deltas.instrument_id;
+        let (deltas, needs_resync) = self.validate_deltas_sequence(deltas.deltas);
+
+        if !deltas.is_empty() {
+            self.publish_deltas(OrderBookDeltas::new(instrument_id, deltas));
+            self.check_book_integrity(&instrument_id);
+        }
+
+        if needs_resync {
+            self.resync_order_book(&instrument_id);
+        }
+    }
+
+    fn publish_deltas(&mut self, deltas: OrderBookDeltas) {
         let deltas = if self.config.buffer_deltas {
             let buffer_deltas = self
                 .buffered_deltas_map
@@ -506,15 +726,28 @@ impl DataEngine {
             deltas
         };
 
-        let mut msgbus = self.msgbus.borrow_mut();
-        let topic = msgbus.switchboard.get_deltas_topic(deltas.instrument_id);
-        msgbus.publish(&topic, &deltas as &dyn Any); // TODO: Optimize
+        {
+            let mut msgbus = self.msgbus.borrow_mut();
+            let topic = msgbus.switchboard.get_deltas_topic(deltas.instrument_id);
+            msgbus.publish(&topic, &deltas as &dyn Any); // TODO: Optimize
+
+            for conflator in self.conflators.get(&topic).into_iter().flatten() {
+                conflator.update_deltas(deltas.instrument_id, &deltas.deltas);
+            }
+        }
+
+        self.update_book_bars(&deltas.instrument_id, deltas.ts_event);
+        self.update_book_depth(&deltas);
     }
 
     fn handle_depth10(&mut self, depth: OrderBookDepth10) {
-        let mut msgbus = self.msgbus.borrow_mut();
-        let topic = msgbus.switchboard.get_depth_topic(depth.instrument_id);
-        msgbus.publish(&topic, &depth as &dyn Any); // TODO: Optimize
+        {
+            let mut msgbus = self.msgbus.borrow_mut();
+            let topic = msgbus.switchboard.get_depth_topic(depth.instrument_id);
+            msgbus.publish(&topic, &depth as &dyn Any); // TODO: Optimize
+        }
+
+        self.update_book_bars(&depth.instrument_id, depth.ts_event);
     }
 
     fn handle_quote(&mut self, quote: QuoteTick) {
@@ -522,11 +755,24 @@ impl DataEngine {
             log::error!("Error on cache insert: {e}");
         }
 
-        // TODO: Handle synthetics
+        {
+            let mut msgbus = self.msgbus.borrow_mut();
+            let topic = msgbus.switchboard.get_quotes_topic(quote.instrument_id);
+            msgbus.publish(&topic, &quote as &dyn Any); // TODO: Optimize
 
-        let mut msgbus = self.msgbus.borrow_mut();
-        let topic = msgbus.switchboard.get_quotes_topic(quote.instrument_id);
-        msgbus.publish(&topic, &quote as &dyn Any); // TODO: Optimize
+            for conflator in self.conflators.get(&topic).into_iter().flatten() {
+                conflator.update_quote(quote);
+            }
+        }
+
+        // Derived quotes are published after the component quote they were derived from
+        for synthetic_quote in self.update_synthetics_with_quote(&quote) {
+            self.handle_quote(synthetic_quote);
+        }
+
+        for continuous_quote in self.update_continuous_with_quote(&quote) {
+            self.handle_quote(continuous_quote);
+        }
     }
 
     fn handle_trade(&mut self, trade: TradeTick) {
@@ -534,11 +780,78 @@ impl DataEngine {
             log::error!("Error on cache insert: {e}");
         }
 
-        // TODO: Handle synthetics
+        {
+            let mut msgbus = self.msgbus.borrow_mut();
+            let topic = msgbus.switchboard.get_trades_topic(trade.instrument_id);
+            msgbus.publish(&topic, &trade as &dyn Any); // TODO: Optimize
+
+            for conflator in self.conflators.get(&topic).into_iter().flatten() {
+                conflator.update_trade(trade);
+            }
+        }
+
+        // Derived trades are published after the component trade they were derived from
+        for synthetic_trade in self.update_synthetics_with_trade(&trade) {
+            self.handle_trade(synthetic_trade);
+        }
+
+        for continuous_trade in self.update_continuous_with_trade(&trade) {
+            self.handle_trade(continuous_trade);
+        }
+    }
+
+    fn handle_mark_price(&mut self, mark_price: MarkPriceUpdate) {
+        if let Err(e) = self.cache.as_ref().borrow_mut().add_mark_price(mark_price) {
+            log::error!("Error on cache insert: {e}");
+        }
+
+        let mut msgbus = self.msgbus.borrow_mut();
+        let topic = msgbus
+            .switchboard
+            .get_mark_prices_topic(mark_price.instrument_id);
+        msgbus.publish(&topic, &mark_price as &dyn Any);
+    }
+
+    fn handle_index_price(&mut self, index_price: IndexPriceUpdate) {
+        if let Err(e) = self
+            .cache
+            .as_ref()
+            .borrow_mut()
+            .add_index_price(index_price)
+        {
+            log::error!("Error on cache insert: {e}");
+        }
+
+        let mut msgbus = self.msgbus.borrow_mut();
+        let topic = msgbus
+            .switchboard
+            .get_index_prices_topic(index_price.instrument_id);
+        msgbus.publish(&topic, &index_price as &dyn Any);
+    }
+
+    fn handle_funding_rate(&mut self, funding_rate: FundingRateUpdate) {
+        if let Err(e) = self
+            .cache
+            .as_ref()
+            .borrow_mut()
+            .add_funding_rate(funding_rate)
+        {
+            log::error!("Error on cache insert: {e}");
+        }
+
+        let mut msgbus = self.msgbus.borrow_mut();
+        let topic = msgbus
+            .switchboard
+            .get_funding_rates_topic(funding_rate.instrument_id);
+        msgbus.publish(&topic, &funding_rate as &dyn Any);
+    }
 
+    fn handle_corporate_action(&mut self, action: CorporateAction) {
         let mut msgbus = self.msgbus.borrow_mut();
-        let topic = msgbus.switchboard.get_trades_topic(trade.instrument_id);
-        msgbus.publish(&topic, &trade as &dyn Any); // TODO: Optimize
+        let topic = msgbus
+            .switchboard
+            .get_corporate_actions_topic(action.instrument_id);
+        msgbus.publish(&topic, &action as &dyn Any);
     }
 
     fn handle_bar(&mut self, bar: Bar) {
@@ -567,9 +880,230 @@ impl DataEngine {
             log::error!("Error on cache insert: {e}");
         }
 
-        let mut msgbus = self.msgbus.borrow_mut();
-        let topic = msgbus.switchboard.get_bars_topic(bar.bar_type);
-        msgbus.publish(&topic, &bar as &dyn Any); // TODO: Optimize
+        {
+            let mut msgbus = self.msgbus.borrow_mut();
+            let topic = msgbus.switchboard.get_bars_topic(bar.bar_type);
+            msgbus.publish(&topic, &bar as &dyn Any); // TODO: Optimize
+        }
+
+        for continuous_bar in self.update_continuous_with_bar(&bar) {
+            self.handle_bar(continuous_bar);
+        }
+    }
+
+    // -- SEQUENCE VALIDATION ---------------------------------------------------------------------
+
+    /// Validates the sequence of the given `delta` for its order book.
+    ///
+    /// Duplicate and out of order deltas are dropped. On a gap the book is marked invalid
+    /// with all further deltas buffered until the book is resynchronized from a snapshot,
+    /// which is attempted again once a snapshot is streamed or a request is due.
+    fn validate_delta_sequence(&mut self, delta: &OrderBookDelta) -> SequenceAction {
+        let instrument_id = delta.instrument_id;
+        let ts_now = self.clock.borrow().timestamp_ns();
+        let interval_ns = self.config.book_resync_interval_ms * NANOSECONDS_IN_MILLISECOND;
+        let state = self.book_sequences.entry(instrument_id).or_default();
+
+        if state.is_resyncing {
+            state.buffer(*delta);
+            if state.has_complete_snapshot() || state.is_snapshot_request_due(ts_now, interval_ns) {
+                return SequenceAction::Resync;
+            }
+            return SequenceAction::Drop;
+        }
+
+        match state.check(delta) {
+            SequenceCheck::Valid => {
+                state.update(delta);
+                SequenceAction::Publish
+            }
+            SequenceCheck::Duplicate => {
+                log::warn!(
+                    "Dropping duplicate delta for {instrument_id}: sequence={}",
+                    delta.sequence
+                );
+                SequenceAction::Drop
+            }
+            SequenceCheck::OutOfOrder => {
+                log::warn!(
+                    "Dropping out of order delta for {instrument_id}: sequence={}, last_sequence={:?}",
+                    delta.sequence,
+                    state.last_sequence,
+                );
+                SequenceAction::Drop
+            }
+            SequenceCheck::Gap(expected) => {
+                log::error!(
+                    "Sequence gap for {instrument_id}: expected={expected}, received={}, resyncing book",
+                    delta.sequence
+                );
+                state.is_resyncing = true;
+                state.buffer(*delta);
+                self.invalid_books.borrow_mut().insert(instrument_id);
+                SequenceAction::Resync
+            }
+        }
+    }
+
+    /// Validates the sequence of the given `deltas` for a single order book, returning
+    /// the deltas to publish and whether the book should be resynchronized.
+    fn validate_deltas_sequence(
+        &mut self,
+        deltas: Vec<OrderBookDelta>,
+    ) -> (Vec<OrderBookDelta>, bool) {
+        let mut needs_resync = false;
+        let deltas = deltas
+            .into_iter()
+            .filter(|delta| match self.validate_delta_sequence(delta) {
+                SequenceAction::Publish => true,
+                SequenceAction::Drop => false,
+                SequenceAction::Resync => {
+                    needs_resync = true;
+                    false
+                }
+            })
+            .collect();
+
+        (deltas, needs_resync)
+    }
+
+    /// Checks the integrity of the cached order book for the given `instrument_id`,
+    /// marking the book invalid and resynchronizing it if the book is crossed.
+    fn check_book_integrity(&mut self, instrument_id: &InstrumentId) {
+        let crossed = {
+            let cache = self.cache.borrow();
+            let Some(book) = cache.order_book(instrument_id) else {
+                return;
+            };
+            match (book.best_bid_price(), book.best_ask_price()) {
+                (Some(bid), Some(ask)) if bid >= ask => Some((bid, ask)),
+                _ => None,
+            }
+        };
+
+        let Some((bid, ask)) = crossed else {
+            return;
+        };
+
+        log::error!(
+            "OrderBook for {instrument_id} is crossed: bid={bid}, ask={ask}, resyncing book"
+        );
+        let state = self.book_sequences.entry(*instrument_id).or_default();
+        state.is_resyncing = true;
+        self.invalid_books.borrow_mut().insert(*instrument_id);
+        self.resync_order_book(instrument_id);
+    }
+
+    /// Resynchronizes the invalid order book for the given `instrument_id` if a snapshot
+    /// is available.
+    ///
+    /// A snapshot streamed from the venue is preferred, otherwise a snapshot is requested
+    /// from the data client at most once per `book_resync_interval_ms`.
+    fn resync_order_book(&mut self, instrument_id: &InstrumentId) {
+        if !self
+            .book_sequences
+            .get(instrument_id)
+            .is_some_and(|state| state.is_resyncing)
+        {
+            return;
+        }
+
+        let snapshot = self
+            .take_streamed_snapshot(instrument_id)
+            .or_else(|| self.request_book_snapshot(instrument_id));
+
+        if let Some(snapshot) = snapshot {
+            self.apply_book_snapshot(snapshot);
+        }
+    }
+
+    fn take_streamed_snapshot(&mut self, instrument_id: &InstrumentId) -> Option<OrderBookDeltas> {
+        let state = self.book_sequences.get_mut(instrument_id)?;
+        if !state.has_complete_snapshot() {
+            return None;
+        }
+
+        let deltas = std::mem::take(&mut state.snapshot);
+        Some(OrderBookDeltas::new(*instrument_id, deltas))
+    }
+
+    fn request_book_snapshot(&mut self, instrument_id: &InstrumentId) -> Option<OrderBookDeltas> {
+        let ts_now = self.clock.borrow().timestamp_ns();
+        let interval_ns = self.config.book_resync_interval_ms * NANOSECONDS_IN_MILLISECOND;
+        let state = self.book_sequences.get_mut(instrument_id)?;
+        if !state.is_snapshot_request_due(ts_now, interval_ns) {
+            return None; // Rate limited, awaiting a streamed snapshot or the next request
+        }
+        state.last_snapshot_request = Some(ts_now);
+
+        let Some(client) = self.get_client_for_venue(&instrument_id.venue) else {
+            log::error!("Cannot request snapshot for {instrument_id}: no client found");
+            return None;
+        };
+
+        log::info!("Requesting order book snapshot for {instrument_id}");
+        let payload = client.request_order_book_snapshot(UUID4::new(), *instrument_id, None, &None);
+
+        if let Some(deltas) = payload.downcast_ref::<OrderBookDeltas>() {
+            Some(deltas.clone())
+        } else if let Some(depth) = payload.downcast_ref::<OrderBookDepth10>() {
+            Some(depth_to_snapshot_deltas(depth))
+        } else {
+            log::warn!("No snapshot received for {instrument_id}, awaiting venue snapshot");
+            None
+        }
+    }
+
+    /// Applies the given `snapshot` to the order book followed by any buffered deltas,
+    /// then marks the book as valid again.
+    fn apply_book_snapshot(&mut self, snapshot: OrderBookDeltas) {
+        let instrument_id = snapshot.instrument_id;
+        let sequence = snapshot.sequence;
+
+        // Publish the snapshot (which starts with a clear) so the book is rebuilt
+        self.publish_deltas(snapshot);
+
+        let buffered = self
+            .book_sequences
+            .get_mut(&instrument_id)
+            .map(|state| state.complete_resync(sequence))
+            .unwrap_or_default();
+        self.invalid_books.borrow_mut().remove(&instrument_id);
+        log::info!("Resynchronized order book for {instrument_id} at sequence {sequence}");
+
+        let (deltas, needs_resync) = self.validate_deltas_sequence(buffered);
+
+        if !deltas.is_empty() {
+            self.publish_deltas(OrderBookDeltas::new(instrument_id, deltas));
+        }
+
+        if needs_resync {
+            self.resync_order_book(&instrument_id);
+        }
+    }
+
+    fn get_client_for_venue_mut(&mut self, venue: &Venue) -> Option<&mut DataClientAdapter> {
+        if let Some(client_id) = self.routing_map.get(venue) {
+            return self.clients.get_mut(client_id);
+        }
+
+        if let Some(index) = self
+            .clients
+            .values()
+            .position(|client| client.venue == *venue)
+        {
+            return self.clients.get_index_mut(index).map(|(_, client)| client);
+        }
+
+        self.default_client.as_mut()
+    }
+
+    fn get_client_for_venue(&self, venue: &Venue) -> Option<&DataClientAdapter> {
+        self.routing_map
+            .get(venue)
+            .and_then(|client_id| self.clients.get(client_id))
+            .or_else(|| self.clients.values().find(|client| client.venue == *venue))
+            .or(self.default_client.as_ref())
     }
 
     // -- SUBSCRIPTION HANDLERS -------------------------------------------------------------------
@@ -654,6 +1188,7 @@ impl DataEngine {
                     snap_info,
                     self.cache.clone(),
                     self.msgbus.clone(),
+                    self.invalid_books.clone(),
                 ));
                 self.book_snapshotters
                     .insert(instrument_id, snapshotter.clone());
@@ -685,6 +1220,16 @@ impl DataEngine {
 
         match bar_type.aggregation_source() {
             AggregationSource::Internal => {
+                let book_price_type = command
+                    .params
+                    .as_ref()
+                    .map(BookPriceType::from_params)
+                    .transpose()?
+                    .flatten();
+                if let Some(price_type) = book_price_type {
+                    return self.subscribe_book_bars(bar_type, price_type);
+                }
+
                 if !self.bar_aggregators.contains_key(&bar_type.standard()) {
                     self.start_bar_aggregator(bar_type)?;
                 }
@@ -763,36 +1308,319 @@ impl DataEngine {
         Ok(())
     }
 
-    const fn handle_unsubscribe_bars(
-        &mut self,
-        command: &SubscriptionCommand,
-    ) -> anyhow::Result<()> {
+    fn handle_unsubscribe_bars(&mut self, command: &SubscriptionCommand) -> anyhow::Result<()> {
+        let is_book_bars = command
+            .params
+            .as_ref()
+            .is_some_and(|params| params.contains_key(BOOK_PRICE_TYPE));
+        if is_book_bars {
+            self.unsubscribe_book_bars(&command.data_type.bar_type());
+        }
+
         // TODO: Handle aggregators
         Ok(())
     }
 
-    fn maintain_book_updater(&mut self, instrument_id: &InstrumentId, topics: &[Ustr]) {
-        if let Some(updater) = self.book_updaters.get(instrument_id) {
-            let handler = ShareableMessageHandler(updater.clone());
-            let mut msgbus = self.msgbus.borrow_mut();
+    fn handle_conflation(&mut self, command: &SubscriptionCommand) -> anyhow::Result<()> {
+        let Some(interval_ms) = command
+            .params
+            .as_ref()
+            .and_then(|params| params.get(CONFLATE_INTERVAL_MS))
+        else {
+            return Ok(()); // Not a conflated subscription
+        };
 
-            // Unsubscribe handler if it is the last subscriber
-            for topic in topics {
-                if msgbus.subscriptions_count(*topic) == 1
-                    && msgbus.is_subscribed(*topic, handler.clone())
-                {
-                    log::debug!("Unsubscribing BookUpdater from {topic}");
-                    msgbus.unsubscribe(*topic, handler.clone());
-                }
+        let interval_ms = interval_ms.parse::<NonZeroU64>().map_err(|e| {
+            anyhow::anyhow!("Invalid '{CONFLATE_INTERVAL_MS}' parameter '{interval_ms}': {e}")
+        })?;
+        let instrument_id = command.data_type.instrument_id().ok_or_else(|| {
+            anyhow::anyhow!(
+                "Invalid conflated subscription: did not contain an 'instrument_id', {}",
+                command.data_type
+            )
+        })?;
+
+        let source_topic = {
+            let mut msgbus = self.msgbus.borrow_mut();
+            match command.data_type.type_name() {
+                stringify!(QuoteTick) => msgbus.switchboard.get_quotes_topic(instrument_id),
+                stringify!(TradeTick) => msgbus.switchboard.get_trades_topic(instrument_id),
+                stringify!(OrderBookDelta) => msgbus.switchboard.get_deltas_topic(instrument_id),
+                type_name => anyhow::bail!("Cannot conflate {type_name} data"),
             }
+        };
 
-            // Check remaining subscriptions, if none then remove updater
-            let still_subscribed = topics
-                .iter()
-                .any(|topic| msgbus.is_subscribed(*topic, handler.clone()));
-            if !still_subscribed {
-                self.book_updaters.remove(instrument_id);
-                log::debug!("Removed BookUpdater for instrument ID {instrument_id}");
+        match command.action {
+            Action::Subscribe => self.subscribe_conflated(source_topic, interval_ms),
+            Action::Unsubscribe => {
+                self.unsubscribe_conflated(source_topic, interval_ms);
+                Ok(())
+            }
+        }
+    }
+
+    fn subscribe_conflated(
+        &mut self,
+        source_topic: Ustr,
+        interval_ms: NonZeroU64,
+    ) -> anyhow::Result<()> {
+        if let Some(conflator) = self
+            .conflators
+            .get(&source_topic)
+            .and_then(|conflators| conflators.iter().find(|c| c.interval_ms == interval_ms))
+        {
+            conflator
+                .subscriptions
+                .set(conflator.subscriptions.get() + 1);
+            return Ok(());
+        }
+
+        let topic = self
+            .msgbus
+            .borrow_mut()
+            .switchboard
+            .get_conflated_topic(source_topic, interval_ms);
+        let conflator = Rc::new(Conflator::new(
+            source_topic,
+            topic,
+            interval_ms,
+            self.msgbus.clone(),
+        ));
+        conflator.subscriptions.set(1);
+
+        let interval_ns = millis_to_nanos(interval_ms.get() as f64);
+        let now_ns = self.clock.borrow().timestamp_ns().as_u64();
+        let start_time_ns = now_ns - (now_ns % interval_ns); // First flush at next interval
+
+        let timer_name = conflator.timer_name;
+        let flusher = conflator.clone();
+        let callback = TimeEventCallback::Rust(Rc::new(move |event| flusher.flush(event)));
+
+        self.clock.borrow_mut().set_timer_ns(
+            &timer_name,
+            interval_ns,
+            start_time_ns.into(),
+            None,
+            Some(callback),
+        )?;
+
+        log::debug!("Conflating {source_topic} to {topic} every {interval_ms}ms");
+        self.conflators
+            .entry(source_topic)
+            .or_default()
+            .push(conflator);
+
+        Ok(())
+    }
+
+    fn unsubscribe_conflated(&mut self, source_topic: Ustr, interval_ms: NonZeroU64) {
+        let Some(conflators) = self.conflators.get_mut(&source_topic) else {
+            return;
+        };
+        let Some(index) = conflators.iter().position(|c| c.interval_ms == interval_ms) else {
+            return;
+        };
+
+        let subscriptions = conflators[index].subscriptions.get().saturating_sub(1);
+        conflators[index].subscriptions.set(subscriptions);
+
+        if subscriptions == 0 {
+            let conflator = conflators.remove(index);
+            self.clock.borrow_mut().cancel_timer(&conflator.timer_name);
+            log::debug!("Stopped conflating {source_topic} to {}", conflator.topic);
+        }
+
+        if conflators.is_empty() {
+            self.conflators.remove(&source_topic);
+        }
+    }
+
+    fn handle_subscribe_synthetic(&mut self, command: &SubscriptionCommand) -> anyhow::Result<()> {
+        let type_name = command.data_type.type_name();
+        let synthetic = self.get_synthetic(command)?;
+
+        // Check every component can be fed before registering anything
+        if let Some(component) = synthetic
+            .components
+            .iter()
+            .find(|component| self.get_client_for_venue(&component.venue).is_none())
+        {
+            anyhow::bail!(
+                "Cannot subscribe to {type_name} for synthetic {}: no client found for component {component}",
+                synthetic.id
+            );
+        }
+
+        let subscribers = match type_name {
+            stringify!(QuoteTick) => &mut self.synthetic_quote_subscribers,
+            _ => &mut self.synthetic_trade_subscribers,
+        };
+        let count = subscribers.entry(synthetic.id).or_default();
+        *count += 1;
+        if *count > 1 {
+            return Ok(()); // Already feeding the synthetic from its components
+        }
+
+        for component in &synthetic.components {
+            let feeds = match type_name {
+                stringify!(QuoteTick) => &mut self.synthetic_quote_feeds,
+                _ => &mut self.synthetic_trade_feeds,
+            };
+            let synthetics = feeds.entry(*component).or_default();
+            if synthetics.iter().any(|s| s.id == synthetic.id) {
+                continue; // Component repeated in the formula
+            }
+            synthetics.push(synthetic.clone());
+
+            // Subscribe to the component data from its venue client on the first reference
+            if synthetics.len() == 1 {
+                self.execute_synthetic_component_command(
+                    &synthetic,
+                    component,
+                    type_name,
+                    Action::Subscribe,
+                    command.params.clone(),
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn handle_unsubscribe_synthetic(
+        &mut self,
+        command: &SubscriptionCommand,
+    ) -> anyhow::Result<()> {
+        let type_name = command.data_type.type_name();
+        let synthetic = self.get_synthetic(command)?;
+
+        let subscribers = match type_name {
+            stringify!(QuoteTick) => &mut self.synthetic_quote_subscribers,
+            _ => &mut self.synthetic_trade_subscribers,
+        };
+        let Some(count) = subscribers.get_mut(&synthetic.id) else {
+            log::warn!(
+                "Cannot unsubscribe from {type_name} for synthetic {}: not subscribed",
+                synthetic.id
+            );
+            return Ok(());
+        };
+        *count -= 1;
+        if *count > 0 {
+            return Ok(()); // Synthetic still has other subscribers
+        }
+        subscribers.remove(&synthetic.id);
+
+        for component in &synthetic.components {
+            let feeds = match type_name {
+                stringify!(QuoteTick) => &mut self.synthetic_quote_feeds,
+                _ => &mut self.synthetic_trade_feeds,
+            };
+            let Some(synthetics) = feeds.get_mut(component) else {
+                continue; // Component repeated in the formula (already released)
+            };
+            synthetics.retain(|s| s.id != synthetic.id);
+            if !synthetics.is_empty() {
+                continue; // Component still referenced by other synthetics
+            }
+            feeds.remove(component);
+
+            // Keep the component feed while there are direct subscribers for its data
+            let has_subscribers = {
+                let mut msgbus = self.msgbus.borrow_mut();
+                let topic = match type_name {
+                    stringify!(QuoteTick) => msgbus.switchboard.get_quotes_topic(*component),
+                    _ => msgbus.switchboard.get_trades_topic(*component),
+                };
+                msgbus.has_subscribers(topic)
+            };
+            if has_subscribers {
+                continue;
+            }
+
+            self.execute_synthetic_component_command(
+                &synthetic,
+                component,
+                type_name,
+                Action::Unsubscribe,
+                command.params.clone(),
+            )?;
+        }
+
+        Ok(())
+    }
+
+    fn execute_synthetic_component_command(
+        &mut self,
+        synthetic: &SyntheticInstrument,
+        component: &InstrumentId,
+        type_name: &str,
+        action: Action,
+        params: Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        let ts_init = self.clock.borrow().timestamp_ns();
+        let Some(client) = self.get_client_for_venue_mut(&component.venue) else {
+            anyhow::bail!(
+                "Cannot {action:?} {type_name} for synthetic {}: no client found for component {component}",
+                synthetic.id
+            );
+        };
+
+        let metadata = IndexMap::from([("instrument_id".to_string(), component.to_string())]);
+        client.execute(SubscriptionCommand::new(
+            client.client_id,
+            component.venue,
+            DataType::new(type_name, Some(metadata)),
+            action,
+            UUID4::new(),
+            ts_init,
+            params,
+        ));
+
+        Ok(())
+    }
+
+    fn get_synthetic(&self, command: &SubscriptionCommand) -> anyhow::Result<SyntheticInstrument> {
+        let instrument_id = command.data_type.instrument_id().ok_or_else(|| {
+            anyhow::anyhow!(
+                "Invalid synthetic subscription: did not contain an 'instrument_id', {}",
+                command.data_type
+            )
+        })?;
+
+        self.cache
+            .borrow()
+            .synthetic(&instrument_id)
+            .cloned()
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Cannot subscribe to synthetic data: no synthetic instrument found for {instrument_id}"
+                )
+            })
+    }
+
+    fn maintain_book_updater(&mut self, instrument_id: &InstrumentId, topics: &[Ustr]) {
+        if let Some(updater) = self.book_updaters.get(instrument_id) {
+            let handler = ShareableMessageHandler(updater.clone());
+            let mut msgbus = self.msgbus.borrow_mut();
+
+            // Unsubscribe handler if it is the last subscriber
+            for topic in topics {
+                if msgbus.subscriptions_count(*topic) == 1
+                    && msgbus.is_subscribed(*topic, handler.clone())
+                {
+                    log::debug!("Unsubscribing BookUpdater from {topic}");
+                    msgbus.unsubscribe(*topic, handler.clone());
+                }
+            }
+
+            // Check remaining subscriptions, if none then remove updater
+            let still_subscribed = topics
+                .iter()
+                .any(|topic| msgbus.is_subscribed(*topic, handler.clone()));
+            if !still_subscribed {
+                self.book_updaters.remove(instrument_id);
+                log::debug!("Removed BookUpdater for instrument ID {instrument_id}");
             }
         }
     }
@@ -846,6 +1674,738 @@ impl DataEngine {
         }
     }
 
+    // -- LATENCY ---------------------------------------------------------------------------------
+
+    fn start_latency_logging(&mut self, interval_ms: NonZeroU64) -> anyhow::Result<()> {
+        let latency = self.latency.clone();
+        let callback = TimeEventCallback::Rust(Rc::new(move |_| latency.borrow().log_summary()));
+
+        let mut clock = self.clock.borrow_mut();
+        let start_time_ns = clock.timestamp_ns();
+        clock.set_timer_ns(
+            "DataEngine|LatencyLog",
+            millis_to_nanos(interval_ms.get() as f64),
+            start_time_ns,
+            None,
+            Some(callback),
+        )
+    }
+
+    // -- BOOK BARS -------------------------------------------------------------------------------
+
+    /// Starts aggregating bars of the given `bar_type` from the given `price_type` sampled
+    /// from the order book maintained for the bar type instrument on every book update.
+    ///
+    /// Subscribed to through a bar subscription with a [`BOOK_PRICE_TYPE`] parameter. An order
+    /// book is created in the cache if one is not already maintained, and is updated from the
+    /// order book deltas and depth data for the instrument processed by the engine.
+    ///
+    /// Bars are published on the book bars topic for the `bar_type` and are not cached, so they
+    /// are kept apart from bars of the same type aggregated from quotes or trades.
+    fn subscribe_book_bars(
+        &mut self,
+        bar_type: BarType,
+        price_type: BookPriceType,
+    ) -> anyhow::Result<()> {
+        if self.book_bar_aggregators.contains_key(&bar_type) {
+            return Ok(()); // Already aggregating
+        }
+
+        let instrument_id = bar_type.instrument_id();
+        let instrument = self
+            .cache
+            .borrow()
+            .instrument(&instrument_id)
+            .cloned()
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Cannot subscribe to book bars: no instrument found for {instrument_id}"
+                )
+            })?;
+
+        self.setup_order_book(&instrument_id, BookType::L2_MBP, None, false, true)?;
+
+        let topic = self
+            .msgbus
+            .borrow_mut()
+            .switchboard
+            .get_book_bars_topic(bar_type);
+        let mut aggregator = self.create_bar_aggregator(&instrument, bar_type, Some(topic));
+        aggregator.set_is_running(true);
+        self.book_bar_aggregators
+            .insert(bar_type, (price_type, aggregator));
+
+        log::info!("Subscribed to book bars {bar_type} from {price_type:?} prices");
+        Ok(())
+    }
+
+    /// Stops aggregating bars of the given `bar_type` from order book prices.
+    fn unsubscribe_book_bars(&mut self, bar_type: &BarType) {
+        if self.book_bar_aggregators.remove(bar_type).is_none() {
+            log::warn!("Cannot unsubscribe from book bars {bar_type}: not subscribed");
+            return;
+        }
+
+        let instrument_id = bar_type.instrument_id();
+        let still_sampled = self
+            .book_bar_aggregators
+            .keys()
+            .any(|bar_type| bar_type.instrument_id() == instrument_id);
+        if !still_sampled {
+            let topics = {
+                let mut msgbus = self.msgbus.borrow_mut();
+                [
+                    msgbus.switchboard.get_deltas_topic(instrument_id),
+                    msgbus.switchboard.get_depth_topic(instrument_id),
+                ]
+            };
+            self.maintain_book_updater(&instrument_id, &topics);
+        }
+
+        log::info!("Unsubscribed from book bars {bar_type}");
+    }
+
+    /// Updates all book bar aggregators for the given `instrument_id` with prices sampled
+    /// from the current state of its order book.
+    fn update_book_bars(&mut self, instrument_id: &InstrumentId, ts_event: UnixNanos) {
+        if self.book_bar_aggregators.is_empty() {
+            return;
+        }
+
+        // Sample prices first, as bar handlers require mutable access to the cache
+        let samples: Vec<(BarType, Price, Quantity)> = {
+            let cache = self.cache.borrow();
+            let Some(book) = cache.order_book(instrument_id) else {
+                return;
+            };
+            self.book_bar_aggregators
+                .iter()
+                .filter(|(bar_type, _)| bar_type.instrument_id() == *instrument_id)
+                .filter_map(|(bar_type, (price_type, _))| {
+                    price_type
+                        .extract(book)
+                        .map(|(price, size)| (*bar_type, price, size))
+                })
+                .collect()
+        };
+
+        for (bar_type, price, size) in samples {
+            if let Some((_, aggregator)) = self.book_bar_aggregators.get_mut(&bar_type) {
+                aggregator.update(price, size, ts_event);
+            }
+        }
+    }
+
+    // -- BOOK DEPTH ------------------------------------------------------------------------------
+
+    /// Starts publishing `OrderBookDepth10` derived from the order book maintained for the given
+    /// `instrument_id` on its depth topic, either on each book update or at `interval_ms`.
+    ///
+    /// When a `group_size` is given, levels are grouped into price buckets of that size. An order
+    /// book of the given `book_type` is created in the cache if one is not already maintained,
+    /// and is updated from the order book deltas for the instrument processed by the engine.
+    /// Depth data for the instrument is no longer applied to the book, as it is derived from it.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If depth is already being derived for the `instrument_id`.
+    /// - If `group_size` is not positive.
+    pub fn subscribe_book_depth(
+        &mut self,
+        instrument_id: InstrumentId,
+        book_type: BookType,
+        interval_ms: Option<NonZeroU64>,
+        group_size: Option<Decimal>,
+    ) -> anyhow::Result<()> {
+        if self.book_depth_publishers.contains_key(&instrument_id) {
+            anyhow::bail!("Cannot subscribe to book depth: already deriving for {instrument_id}");
+        }
+
+        if let Some(group_size) = group_size {
+            if group_size <= Decimal::ZERO {
+                anyhow::bail!(
+                    "Cannot subscribe to book depth: `group_size` was not positive, was {group_size}"
+                );
+            }
+        }
+
+        self.setup_order_book(&instrument_id, book_type, None, true, true)?;
+
+        let topic = self
+            .msgbus
+            .borrow_mut()
+            .switchboard
+            .get_depth_topic(instrument_id);
+        let publisher = Rc::new(BookDepthPublisher::new(
+            instrument_id,
+            topic,
+            interval_ms,
+            group_size,
+            self.cache.clone(),
+            self.msgbus.clone(),
+            self.invalid_books.clone(),
+        ));
+
+        if let (Some(interval_ms), Some(timer_name)) = (interval_ms, publisher.timer_name) {
+            let timer_publisher = publisher.clone();
+            let callback = TimeEventCallback::Rust(Rc::new(move |event| {
+                timer_publisher.publish(event.ts_event);
+            }));
+
+            let mut clock = self.clock.borrow_mut();
+            let start_time_ns = clock.timestamp_ns();
+            clock
+                .set_timer_ns(
+                    &timer_name,
+                    millis_to_nanos(interval_ms.get() as f64),
+                    start_time_ns,
+                    None,
+                    Some(callback),
+                )
+                .expect(FAILED);
+        }
+
+        self.derived_depth_books.borrow_mut().insert(instrument_id);
+        self.book_depth_publishers.insert(instrument_id, publisher);
+
+        log::info!("Subscribed to book depth for {instrument_id}");
+        Ok(())
+    }
+
+    /// Stops publishing `OrderBookDepth10` derived from the order book for the `instrument_id`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If depth is not being derived for the `instrument_id`.
+    pub fn unsubscribe_book_depth(&mut self, instrument_id: &InstrumentId) -> anyhow::Result<()> {
+        let Some(publisher) = self.book_depth_publishers.remove(instrument_id) else {
+            anyhow::bail!("Cannot unsubscribe from book depth: not deriving for {instrument_id}");
+        };
+
+        if let Some(timer_name) = publisher.timer_name {
+            let mut clock = self.clock.borrow_mut();
+            if clock.timer_names().contains(&timer_name.as_str()) {
+                clock.cancel_timer(&timer_name);
+            }
+        }
+        self.derived_depth_books.borrow_mut().remove(instrument_id);
+
+        let topics = {
+            let mut msgbus = self.msgbus.borrow_mut();
+            [
+                msgbus.switchboard.get_deltas_topic(*instrument_id),
+                msgbus.switchboard.get_depth_topic(*instrument_id),
+            ]
+        };
+        self.maintain_book_updater(instrument_id, &topics);
+
+        log::info!("Unsubscribed from book depth for {instrument_id}");
+        Ok(())
+    }
+
+    /// Publishes depth derived from the order book for the instrument of the given `deltas`, if
+    /// published on each update and the deltas complete a book event.
+    fn update_book_depth(&self, deltas: &OrderBookDeltas) {
+        if !RecordFlag::F_LAST.matches(deltas.flags) {
+            return;
+        }
+
+        if let Some(publisher) = self.book_depth_publishers.get(&deltas.instrument_id) {
+            if publisher.interval_ms.is_none() {
+                let ts_init = self.clock.borrow().timestamp_ns();
+                publisher.publish(ts_init);
+            }
+        }
+    }
+
+    // -- CONTINUOUS FUTURES ----------------------------------------------------------------------
+
+    /// Adds the given continuous futures `series`, which is updated from the data processed
+    /// for its contracts and publishes data and rolls for its continuous instrument ID.
+    ///
+    /// Live data is published at unadjusted prices, while historical bars received in responses
+    /// for the continuous instrument ID are back-adjusted for the rolls after them.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If a series with the same instrument ID has already been added.
+    pub fn add_continuous_futures(&mut self, series: ContinuousFutures) -> anyhow::Result<()> {
+        let instrument_id = series.instrument_id;
+        if self.continuous_futures.contains_key(&instrument_id) {
+            anyhow::bail!("Cannot add continuous futures: {instrument_id} already added");
+        }
+
+        log::info!(
+            "Added continuous futures {instrument_id} with {} contracts",
+            series.contracts().len()
+        );
+        self.continuous_futures.insert(instrument_id, series);
+        Ok(())
+    }
+
+    /// Returns the continuous futures series for the given `instrument_id` (if found).
+    #[must_use]
+    pub fn continuous_futures(&self, instrument_id: &InstrumentId) -> Option<&ContinuousFutures> {
+        self.continuous_futures.get(instrument_id)
+    }
+
+    /// Updates the open interest for the given contract `instrument_id`, rolling any
+    /// continuous futures series with an open interest roll rule.
+    ///
+    /// Open interest is not carried by any data processed by the engine, so it must be
+    /// supplied through this method for open interest rolls to occur.
+    pub fn update_open_interest(
+        &mut self,
+        instrument_id: InstrumentId,
+        open_interest: f64,
+        ts_event: UnixNanos,
+    ) {
+        let rolls: Vec<RollEvent> = self
+            .continuous_futures
+            .values_mut()
+            .flat_map(|series| series.update_open_interest(instrument_id, open_interest, ts_event))
+            .collect();
+        self.publish_rolls(&rolls);
+    }
+
+    fn update_continuous_with_quote(&mut self, quote: &QuoteTick) -> Vec<QuoteTick> {
+        let mut rolls = Vec::new();
+        let mut quotes = Vec::new();
+        for series in self.continuous_futures.values_mut() {
+            let update = series.handle_quote(quote);
+            rolls.extend(update.rolls);
+            quotes.extend(update.data);
+        }
+
+        self.publish_rolls(&rolls);
+        quotes
+    }
+
+    fn update_continuous_with_trade(&mut self, trade: &TradeTick) -> Vec<TradeTick> {
+        let mut rolls = Vec::new();
+        let mut trades = Vec::new();
+        for series in self.continuous_futures.values_mut() {
+            let update = series.handle_trade(trade);
+            rolls.extend(update.rolls);
+            trades.extend(update.data);
+        }
+
+        self.publish_rolls(&rolls);
+        trades
+    }
+
+    fn update_continuous_with_bar(&mut self, bar: &Bar) -> Vec<Bar> {
+        let mut rolls = Vec::new();
+        let mut bars = Vec::new();
+        for series in self.continuous_futures.values_mut() {
+            let update = series.handle_bar(bar);
+            rolls.extend(update.rolls);
+            bars.extend(update.data);
+        }
+
+        self.publish_rolls(&rolls);
+        bars
+    }
+
+    /// Returns the given historical `bars` back-adjusted for all later rolls when they are for
+    /// a continuous futures instrument, otherwise returns the bars unchanged.
+    fn back_adjust_continuous_bars(&self, bars: Arc<Vec<Bar>>) -> Arc<Vec<Bar>> {
+        let Some(series) = bars
+            .first()
+            .and_then(|bar| self.continuous_futures.get(&bar.bar_type.instrument_id()))
+        else {
+            return bars;
+        };
+
+        Arc::new(bars.iter().map(|bar| series.back_adjust_bar(bar)).collect())
+    }
+
+    fn publish_rolls(&self, rolls: &[RollEvent]) {
+        if rolls.is_empty() {
+            return;
+        }
+
+        let mut msgbus = self.msgbus.borrow_mut();
+        for roll in rolls {
+            let topic = msgbus.switchboard.get_rolls_topic(roll.instrument_id);
+            msgbus.publish(&topic, roll as &dyn Any);
+        }
+    }
+
+    // -- SYNTHETICS ------------------------------------------------------------------------------
+
+    /// Returns quotes for all synthetic instruments with the given `quote` instrument as a
+    /// component, where quotes are available for every other component.
+    ///
+    /// The bid and ask sizes are the smallest of the component sizes, as the synthetic can
+    /// be no more liquid than its least liquid component. As a synthetic has no size precision
+    /// of its own, both sizes are rounded down to the coarsest component size precision.
+    fn update_synthetics_with_quote(&mut self, quote: &QuoteTick) -> Vec<QuoteTick> {
+        let Some(synthetics) = self.synthetic_quote_feeds.get_mut(&quote.instrument_id) else {
+            return Vec::new();
+        };
+
+        let cache = self.cache.borrow();
+        let mut synthetic_quotes = Vec::with_capacity(synthetics.len());
+
+        for synthetic in synthetics.iter_mut() {
+            let mut bids = Vec::with_capacity(synthetic.components.len());
+            let mut asks = Vec::with_capacity(synthetic.components.len());
+            let mut bid_sizes = Vec::with_capacity(synthetic.components.len());
+            let mut ask_sizes = Vec::with_capacity(synthetic.components.len());
+
+            for component in &synthetic.components {
+                let component_quote = if *component == quote.instrument_id {
+                    Some(quote)
+                } else {
+                    cache.quote(component)
+                };
+
+                if let Some(component_quote) = component_quote {
+                    bids.push(component_quote.bid_price.as_f64());
+                    asks.push(component_quote.ask_price.as_f64());
+                    bid_sizes.push(component_quote.bid_size);
+                    ask_sizes.push(component_quote.ask_size);
+                } else {
+                    log::debug!(
+                        "Cannot calculate synthetic {} quote: no quotes yet for {component}",
+                        synthetic.id
+                    );
+                    break;
+                }
+            }
+
+            if bids.len() != synthetic.components.len() {
+                continue;
+            }
+            let (Some(bid_size), Some(ask_size), Some(size_precision)) = (
+                bid_sizes.iter().min(),
+                ask_sizes.iter().min(),
+                bid_sizes
+                    .iter()
+                    .chain(&ask_sizes)
+                    .map(|size| size.precision)
+                    .min(),
+            ) else {
+                continue;
+            };
+
+            let result = synthetic.calculate(&bids).and_then(|bid_price| {
+                let ask_price = synthetic.calculate(&asks)?;
+                QuoteTick::new_checked(
+                    synthetic.id,
+                    bid_price,
+                    ask_price,
+                    round_down_size(*bid_size, size_precision)?,
+                    round_down_size(*ask_size, size_precision)?,
+                    quote.ts_event,
+                    quote.ts_init,
+                )
+            });
+
+            match result {
+                Ok(synthetic_quote) => synthetic_quotes.push(synthetic_quote),
+                Err(e) => log::error!("Error calculating synthetic {} quote: {e}", synthetic.id),
+            }
+        }
+
+        synthetic_quotes
+    }
+
+    /// Returns trades for all synthetic instruments with the given `trade` instrument as a
+    /// component, where trades are available for every other component.
+    ///
+    /// The size is the smallest of the last component trade sizes, and each synthetic
+    /// trade is assigned a new trade ID.
+    fn update_synthetics_with_trade(&mut self, trade: &TradeTick) -> Vec<TradeTick> {
+        let Some(synthetics) = self.synthetic_trade_feeds.get_mut(&trade.instrument_id) else {
+            return Vec::new();
+        };
+
+        let cache = self.cache.borrow();
+        let mut synthetic_trades = Vec::with_capacity(synthetics.len());
+
+        for synthetic in synthetics.iter_mut() {
+            let mut prices = Vec::with_capacity(synthetic.components.len());
+            let mut sizes = Vec::with_capacity(synthetic.components.len());
+
+            for component in &synthetic.components {
+                let component_trade = if *component == trade.instrument_id {
+                    Some(trade)
+                } else {
+                    cache.trade(component)
+                };
+
+                if let Some(component_trade) = component_trade {
+                    prices.push(component_trade.price.as_f64());
+                    sizes.push(component_trade.size);
+                } else {
+                    log::debug!(
+                        "Cannot calculate synthetic {} trade: no trades yet for {component}",
+                        synthetic.id
+                    );
+                    break;
+                }
+            }
+
+            if prices.len() != synthetic.components.len() {
+                continue;
+            }
+            let Some(size) = sizes.iter().min() else {
+                continue;
+            };
+
+            let result = synthetic.calculate(&prices).and_then(|price| {
+                TradeTick::new_checked(
+                    synthetic.id,
+                    price,
+                    *size,
+                    trade.aggressor_side,
+                    TradeId::new(UUID4::new().to_string()),
+                    trade.ts_event,
+                    trade.ts_init,
+                )
+            });
+
+            match result {
+                Ok(synthetic_trade) => synthetic_trades.push(synthetic_trade),
+                Err(e) => log::error!("Error calculating synthetic {} trade: {e}", synthetic.id),
+            }
+        }
+
+        synthetic_trades
+    }
+
+    // -- CATALOG REQUESTS ------------------------------------------------------------------------
+
+    /// Returns a response for the request from the registered catalogs, or `None` if
+    /// the request cannot be served from them and should be sent to a client as is.
+    fn request_from_catalogs(&self, req: &DataRequest) -> Option<DataResponse> {
+        let start = req.data_type.start();
+        let end = req.data_type.end();
+
+        let data: Arc<dyn Any + Send + Sync> = match req.data_type.type_name() {
+            stringify!(InstrumentAny) => {
+                let instruments = self.query_catalog_instruments(req);
+                if instruments.is_empty() {
+                    return None;
+                }
+                Arc::new(Self::apply_limit(req, instruments))
+            }
+            stringify!(QuoteTick) => {
+                let instrument_id = req.data_type.instrument_id()?;
+                let quotes =
+                    self.query_catalogs::<QuoteTick>(vec![instrument_id.to_string()], start, end);
+                Arc::new(self.merge_client_data(req, quotes)?)
+            }
+            stringify!(TradeTick) => {
+                let instrument_id = req.data_type.instrument_id()?;
+                let trades =
+                    self.query_catalogs::<TradeTick>(vec![instrument_id.to_string()], start, end);
+                Arc::new(self.merge_client_data(req, trades)?)
+            }
+            stringify!(Bar) => {
+                // Bars are partitioned by instrument ID, so only that partition is queried
+                let bar_type = req.data_type.bar_type();
+                let instrument_ids = vec![bar_type.instrument_id().to_string()];
+                let mut bars = self.query_catalogs::<Bar>(instrument_ids, start, end);
+                bars.retain(|bar| bar.bar_type == bar_type);
+                Arc::new(self.merge_client_data(req, bars)?)
+            }
+            stringify!(OrderBookDelta) => {
+                // Clients do not serve historical deltas, so only the catalogs are used
+                let instrument_id = req.data_type.instrument_id()?;
+                let deltas = self.query_catalogs::<OrderBookDelta>(
+                    vec![instrument_id.to_string()],
+                    start,
+                    end,
+                );
+                if deltas.is_empty() {
+                    return None;
+                }
+                Arc::new(Self::apply_limit(req, deltas))
+            }
+            _ => return None, // Not stored in catalogs
+        };
+
+        Some(DataResponse {
+            correlation_id: req.correlation_id,
+            client_id: req.client_id,
+            venue: req.venue,
+            data_type: req.data_type.clone(),
+            data,
+            ts_init: self.clock.borrow().timestamp_ns(),
+            params: req.params.clone(),
+        })
+    }
+
+    /// Queries all registered catalogs, returning the combined data in `ts_init` order.
+    fn query_catalogs<T>(
+        &self,
+        instrument_ids: Vec<String>,
+        start: Option<UnixNanos>,
+        end: Option<UnixNanos>,
+    ) -> Vec<T>
+    where
+        T: DecodeDataFromRecordBatch + CatalogPathPrefix + TryFrom<Data> + GetTsInit,
+    {
+        let mut data = Vec::new();
+        for (name, catalog) in self.catalogs.borrow_mut().iter_mut() {
+            match catalog.query_typed_data::<T>(instrument_ids.clone(), start, end, None) {
+                Ok(result) => data.extend(result),
+                Err(e) => log::error!("Error querying catalog {name}: {e}"),
+            }
+        }
+
+        // Stable sort preserves the order of data with equal timestamps within each catalog
+        data.sort_by_key(GetTsInit::ts_init);
+        data
+    }
+
+    /// Queries all registered catalogs for the instrument definitions of the request,
+    /// either for its `instrument_id` or for all instruments of its `venue`.
+    fn query_catalog_instruments(&self, req: &DataRequest) -> Vec<InstrumentAny> {
+        let instrument_id = req.data_type.instrument_id();
+        let venue = req.data_type.venue();
+        let instrument_ids = instrument_id
+            .map(|instrument_id| vec![instrument_id.to_string()])
+            .unwrap_or_default();
+
+        let mut instruments = Vec::new();
+        for (name, catalog) in self.catalogs.borrow().iter() {
+            match catalog.query_instruments(
+                instrument_ids.clone(),
+                req.data_type.start(),
+                req.data_type.end(),
+            ) {
+                Ok(result) => instruments.extend(result.into_iter().filter(|instrument| {
+                    instrument_id.is_some()
+                        || venue.is_some_and(|venue| instrument.id().venue == venue)
+                })),
+                Err(e) => log::error!("Error querying catalog {name} instruments: {e}"),
+            }
+        }
+
+        instruments.sort_by_key(InstrumentAny::ts_init);
+        instruments
+    }
+
+    /// Completes the `catalog_data` with any data from the client for the request
+    /// before and after the range held by the catalogs.
+    ///
+    /// The client is requested for the range before the catalog data only when the
+    /// request has a `start`, and for the range after it unless the catalog data
+    /// reaches the request `end`.
+    ///
+    /// Returns `None` if the catalogs held no data, in which case the whole request
+    /// should be sent to the client.
+    fn merge_client_data<T>(&self, req: &DataRequest, catalog_data: Vec<T>) -> Option<Vec<T>>
+    where
+        T: GetTsInit + Clone + Send + Sync + 'static,
+    {
+        let first_ts_init = catalog_data.first()?.ts_init();
+        let last_ts_init = catalog_data.last()?.ts_init();
+
+        let mut data = Vec::with_capacity(catalog_data.len());
+
+        if let Some(start) = req.data_type.start() {
+            if start < first_ts_init {
+                let client_data =
+                    self.request_client_range::<T>(req, start, Some(first_ts_init - 1));
+                data.extend(
+                    client_data
+                        .into_iter()
+                        .filter(|data| data.ts_init() < first_ts_init),
+                );
+            }
+        }
+
+        data.extend(catalog_data);
+
+        let is_complete = req.data_type.end().is_some_and(|end| last_ts_init >= end);
+        if !is_complete {
+            let client_data = self.request_client_range::<T>(req, last_ts_init + 1, None);
+            data.extend(
+                client_data
+                    .into_iter()
+                    .filter(|data| data.ts_init() > last_ts_init),
+            );
+        }
+
+        Some(Self::apply_limit(req, data))
+    }
+
+    /// Requests the data for the request from its client for the range from `start`
+    /// to `end` (or the request `end` if not given).
+    ///
+    /// The request `limit` is not passed to the client, as it applies to the data merged
+    /// with the catalog data.
+    ///
+    /// Returns no data if the client is not registered, the data type cannot be requested
+    /// from clients, or the client response is invalid.
+    fn request_client_range<T>(
+        &self,
+        req: &DataRequest,
+        start: UnixNanos,
+        end: Option<UnixNanos>,
+    ) -> Vec<T>
+    where
+        T: Clone + Send + Sync + 'static,
+    {
+        let type_name = req.data_type.type_name();
+        if !matches!(
+            type_name,
+            stringify!(QuoteTick) | stringify!(TradeTick) | stringify!(Bar)
+        ) {
+            log::error!("Cannot request {type_name} range from client, using catalog data only");
+            return Vec::new();
+        }
+
+        let Some(client) = self.get_client(&req.client_id, &req.venue) else {
+            return Vec::new();
+        };
+
+        let mut metadata = req.data_type.metadata().cloned().unwrap_or_default();
+        metadata.shift_remove("limit");
+        metadata.insert("start".to_string(), start.to_string());
+        if let Some(end) = end {
+            metadata.insert("end".to_string(), end.to_string());
+        }
+
+        let range_req = DataRequest {
+            correlation_id: req.correlation_id,
+            client_id: req.client_id,
+            venue: req.venue,
+            data_type: DataType::new(req.data_type.type_name(), Some(metadata)),
+            ts_init: req.ts_init,
+            params: req.params.clone(),
+        };
+
+        match Arc::downcast::<Vec<T>>(client.request(range_req).data) {
+            Ok(client_data) => client_data.as_ref().clone(),
+            Err(_) => {
+                log::error!(
+                    "Invalid response data from client {}, using catalog data only",
+                    client.client_id
+                );
+                Vec::new()
+            }
+        }
+    }
+
+    /// Truncates the `data` to the most recent `limit` values for the request (if specified).
+    fn apply_limit<T>(req: &DataRequest, mut data: Vec<T>) -> Vec<T> {
+        if let Some(limit) = req.data_type.limit() {
+            if data.len() > limit {
+                data.drain(..data.len() - limit);
+            }
+        }
+        data
+    }
+
     // -- INTERNAL --------------------------------------------------------------------------------
 
     #[allow(clippy::too_many_arguments)]
@@ -867,7 +2427,11 @@ impl DataEngine {
         let mut msgbus = self.msgbus.borrow_mut();
 
         // Set up subscriptions
-        let updater = Rc::new(BookUpdater::new(instrument_id, self.cache.clone()));
+        let updater = Rc::new(BookUpdater::new(
+            instrument_id,
+            self.cache.clone(),
+            self.derived_depth_books.clone(),
+        ));
         self.book_updaters.insert(*instrument_id, updater.clone());
 
         let handler = ShareableMessageHandler(updater);
@@ -885,15 +2449,24 @@ impl DataEngine {
         Ok(())
     }
 
+    /// Creates a bar aggregator for the `bar_type` whose bars are cached and published on the
+    /// bars topic, or only published on the given `topic` (to keep them apart from bars of the
+    /// same type aggregated from other data).
     fn create_bar_aggregator(
         &mut self,
         instrument: &InstrumentAny,
         bar_type: BarType,
+        topic: Option<Ustr>,
     ) -> Box<dyn BarAggregator> {
         let cache = self.cache.clone();
         let msgbus = self.msgbus.clone();
 
         let handler = move |bar: Bar| {
+            if let Some(topic) = topic {
+                msgbus.borrow().publish(&topic, &bar as &dyn Any);
+                return;
+            }
+
             if let Err(e) = cache.as_ref().borrow_mut().add_bar(bar) {
                 log::error!("Error on cache insert: {e}");
             }
@@ -910,7 +2483,7 @@ impl DataEngine {
         let size_precision = instrument.size_precision();
 
         if bar_type.spec().is_time_aggregated() {
-            Box::new(TimeBarAggregator::new(
+            let mut aggregator = TimeBarAggregator::new(
                 bar_type,
                 price_precision,
                 size_precision,
@@ -922,7 +2495,16 @@ impl DataEngine {
                 config.time_bars_interval_type,
                 None, // TODO: Implement
                 20,   // TODO: TBD, composite bar build delay
-            ))
+            );
+
+            if let Some(session) = config
+                .time_bars_sessions
+                .get(&bar_type.instrument_id().venue)
+            {
+                aggregator.set_session(session.clone());
+            }
+
+            Box::new(aggregator)
         } else {
             match bar_type.spec().aggregation {
                 BarAggregation::Tick => Box::new(TickBarAggregator::new(
@@ -971,7 +2553,7 @@ impl DataEngine {
         let aggregator = if let Some(aggregator) = self.bar_aggregators.get_mut(&bar_type) {
             aggregator
         } else {
-            let aggregator = self.create_bar_aggregator(&instrument, bar_type);
+            let aggregator = self.create_bar_aggregator(&instrument, bar_type, None);
             self.bar_aggregators.insert(bar_type, aggregator);
             self.bar_aggregators.get_mut(&bar_type).unwrap()
         };
@@ -1014,6 +2596,56 @@ impl DataEngine {
     }
 }
 
+/// Returns whether the command is for synthetic instrument quotes or trades.
+fn is_synthetic_command(command: &SubscriptionCommand) -> bool {
+    matches!(
+        command.data_type.type_name(),
+        stringify!(QuoteTick) | stringify!(TradeTick)
+    ) && command
+        .data_type
+        .instrument_id()
+        .is_some_and(|instrument_id| instrument_id.is_synthetic())
+}
+
+/// Rebuilds the given `size` at the given `precision`, rounding down so the result
+/// never exceeds the original size.
+fn round_down_size(size: Quantity, precision: u8) -> anyhow::Result<Quantity> {
+    let value = size
+        .as_decimal()
+        .round_dp_with_strategy(u32::from(precision), RoundingStrategy::ToZero);
+    Quantity::from_decimal(value, precision)
+}
+
+/// Converts the given `depth` into snapshot deltas which clear and rebuild a book.
+fn depth_to_snapshot_deltas(depth: &OrderBookDepth10) -> OrderBookDeltas {
+    let mut deltas = vec![OrderBookDelta::clear(
+        depth.instrument_id,
+        depth.sequence,
+        depth.ts_event,
+        depth.ts_init,
+    )];
+
+    for order in depth.bids.iter().chain(depth.asks.iter()) {
+        if order.size.is_positive() {
+            deltas.push(OrderBookDelta::new(
+                depth.instrument_id,
+                BookAction::Add,
+                *order,
+                RecordFlag::F_SNAPSHOT as u8,
+                depth.sequence,
+                depth.ts_event,
+                depth.ts_init,
+            ));
+        }
+    }
+
+    // SAFETY: There is always at least the clear delta
+    let last = deltas.last_mut().unwrap();
+    last.flags |= RecordFlag::F_LAST as u8;
+
+    OrderBookDeltas::new(depth.instrument_id, deltas)
+}
+
 pub struct SubscriptionCommandHandler {
     pub id: Ustr,
     pub engine_ref: Rc<RefCell<DataEngine>>,
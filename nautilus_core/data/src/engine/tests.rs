@@ -18,14 +18,18 @@
 use std::{
     any::Any,
     cell::{OnceCell, RefCell},
+    collections::HashMap,
+    num::NonZeroU64,
     rc::Rc,
 };
 
+use chrono::{TimeZone, Utc};
 use indexmap::indexmap;
+use nautilus_common::messages::data::DataRequest;
 use nautilus_common::{
     cache::Cache,
     clock::{Clock, TestClock},
-    messages::data::{Action, SubscriptionCommand},
+    messages::data::{Action, DataEvent, DataResponse, SubscriptionCommand},
     msgbus::{
         handler::ShareableMessageHandler,
         stubs::{get_message_saving_handler, get_saved_messages},
@@ -37,19 +41,39 @@ use nautilus_common::{
 use nautilus_core::{UnixNanos, UUID4};
 use nautilus_model::{
     data::{
-        stubs::{stub_delta, stub_deltas, stub_depth10},
-        Bar, BarType, Data, DataType, OrderBookDeltas, OrderBookDeltas_API, OrderBookDepth10,
+        stubs::{
+            stub_corporate_action_split, stub_delta, stub_deltas, stub_depth10,
+            stub_funding_rate_update, stub_mark_price_update,
+        },
+        Bar, BarType, BookOrder, CorporateAction, Data, DataType, FundingRateUpdate,
+        MarkPriceUpdate, OrderBookDelta, OrderBookDeltas, OrderBookDeltas_API, OrderBookDepth10,
         QuoteTick, TradeTick,
     },
-    enums::BookType,
-    identifiers::{ClientId, TraderId, Venue},
-    instruments::{stubs::audusd_sim, CurrencyPair, InstrumentAny},
+    enums::{AggressorSide, BookAction, BookType, OrderSide, RecordFlag},
+    identifiers::{ClientId, InstrumentId, TradeId, TraderId, Venue},
+    instruments::{
+        stubs::{audusd_sim, futures_contract_es},
+        CurrencyPair, InstrumentAny, SyntheticInstrument,
+    },
+    types::{Price, Quantity},
 };
+use nautilus_persistence::backend::catalog::ParquetDataCatalog;
 use rstest::*;
+use rust_decimal_macros::dec;
+use ustr::Ustr;
 
 use crate::{
+    aggregation::{BOOK_NOTIONAL, BOOK_PRICE_TYPE, BOOK_SIDE},
     client::DataClientAdapter,
-    engine::{DataEngine, SubscriptionCommandHandler},
+    continuous::{AdjustmentMethod, ContinuousFutures, RollEvent, RollRule},
+    engine::{
+        config::DataEngineConfig,
+        conflation::CONFLATE_INTERVAL_MS,
+        filters::{CrossedQuoteFilter, FilterAction},
+        runner::{set_data_queue, DataQueue},
+        DataEngine, SubscriptionCommandHandler,
+    },
+    latency::LatencyStage,
     mocks::MockDataClient,
 };
 
@@ -477,6 +501,65 @@ fn test_execute_subscribe_trade_ticks(
         .contains(&audusd_sim.id));
 }
 
+#[rstest]
+fn test_execute_subscribe_mark_prices(
+    msgbus: Rc<RefCell<MessageBus>>,
+    switchboard: MessagingSwitchboard,
+    data_engine: Rc<RefCell<DataEngine>>,
+    data_client: DataClientAdapter,
+) {
+    let client_id = data_client.client_id;
+    let venue = data_client.venue;
+    data_engine.borrow_mut().register_client(data_client, None);
+
+    let endpoint = switchboard.data_engine_execute;
+    let handler = ShareableMessageHandler(Rc::new(SubscriptionCommandHandler {
+        id: endpoint,
+        engine_ref: data_engine.clone(),
+    }));
+    msgbus.borrow_mut().register(endpoint, handler);
+
+    let instrument_id = InstrumentId::from("BTCUSDT-PERP.BINANCE");
+    let metadata = indexmap! {
+        "instrument_id".to_string() => instrument_id.to_string(),
+    };
+    let data_type = DataType::new(stringify!(MarkPriceUpdate), Some(metadata));
+    let cmd = SubscriptionCommand::new(
+        client_id,
+        venue,
+        data_type.clone(),
+        Action::Subscribe,
+        UUID4::new(),
+        UnixNanos::default(),
+        None,
+    );
+    msgbus.borrow().send(&endpoint, &cmd as &dyn Any);
+    data_engine.borrow_mut().run();
+
+    assert!(data_engine
+        .borrow()
+        .subscribed_mark_prices()
+        .contains(&instrument_id));
+    assert!(data_engine.borrow().subscribed_funding_rates().is_empty());
+
+    let cmd = SubscriptionCommand::new(
+        client_id,
+        venue,
+        data_type,
+        Action::Unsubscribe,
+        UUID4::new(),
+        UnixNanos::default(),
+        None,
+    );
+    msgbus.borrow().send(&endpoint, &cmd as &dyn Any);
+    data_engine.borrow_mut().run();
+
+    assert!(!data_engine
+        .borrow()
+        .subscribed_mark_prices()
+        .contains(&instrument_id));
+}
+
 #[rstest]
 fn test_execute_subscribe_bars(
     audusd_sim: CurrencyPair,
@@ -854,6 +937,85 @@ fn test_process_trade_tick(
     assert!(messages.contains(&trade));
 }
 
+#[rstest]
+fn test_process_mark_price(
+    msgbus: Rc<RefCell<MessageBus>>,
+    data_engine: Rc<RefCell<DataEngine>>,
+    stub_mark_price_update: MarkPriceUpdate,
+) {
+    let mark_price = stub_mark_price_update;
+    let handler = get_message_saving_handler::<MarkPriceUpdate>(None);
+    {
+        let mut msgbus = msgbus.borrow_mut();
+        let topic = msgbus
+            .switchboard
+            .get_mark_prices_topic(mark_price.instrument_id);
+        msgbus.subscribe(topic, handler.clone(), None);
+    }
+
+    let mut data_engine = data_engine.borrow_mut();
+    data_engine.process_data(Data::MarkPrice(mark_price));
+    let cache = &data_engine.get_cache();
+    let messages = get_saved_messages::<MarkPriceUpdate>(handler);
+
+    assert_eq!(
+        cache.mark_price(&mark_price.instrument_id),
+        Some(&mark_price)
+    );
+    assert_eq!(messages, vec![mark_price]);
+}
+
+#[rstest]
+fn test_process_funding_rate(
+    msgbus: Rc<RefCell<MessageBus>>,
+    data_engine: Rc<RefCell<DataEngine>>,
+    stub_funding_rate_update: FundingRateUpdate,
+) {
+    let funding_rate = stub_funding_rate_update;
+    let handler = get_message_saving_handler::<FundingRateUpdate>(None);
+    {
+        let mut msgbus = msgbus.borrow_mut();
+        let topic = msgbus
+            .switchboard
+            .get_funding_rates_topic(funding_rate.instrument_id);
+        msgbus.subscribe(topic, handler.clone(), None);
+    }
+
+    let mut data_engine = data_engine.borrow_mut();
+    data_engine.process_data(Data::FundingRate(funding_rate));
+    let cache = &data_engine.get_cache();
+    let messages = get_saved_messages::<FundingRateUpdate>(handler);
+
+    assert_eq!(
+        cache.funding_rate(&funding_rate.instrument_id),
+        Some(&funding_rate)
+    );
+    assert_eq!(messages, vec![funding_rate]);
+}
+
+#[rstest]
+fn test_process_corporate_action(
+    msgbus: Rc<RefCell<MessageBus>>,
+    data_engine: Rc<RefCell<DataEngine>>,
+    stub_corporate_action_split: CorporateAction,
+) {
+    let action = stub_corporate_action_split;
+    let handler = get_message_saving_handler::<CorporateAction>(None);
+    {
+        let mut msgbus = msgbus.borrow_mut();
+        let topic = msgbus
+            .switchboard
+            .get_corporate_actions_topic(action.instrument_id);
+        msgbus.subscribe(topic, handler.clone(), None);
+    }
+
+    let mut data_engine = data_engine.borrow_mut();
+    data_engine.process(&action as &dyn Any);
+    let messages = get_saved_messages::<CorporateAction>(handler);
+
+    assert_eq!(messages, vec![action]);
+}
+
 #[rstest]
 fn test_process_bar(
     msgbus: Rc<RefCell<MessageBus>>,
@@ -904,3 +1066,1387 @@ fn test_process_bar(
     assert_eq!(messages.len(), 1);
     assert!(messages.contains(&bar));
 }
+
+fn book_bars_command(bar_type: BarType, action: Action, price_type: &str) -> SubscriptionCommand {
+    let metadata = indexmap! {
+        "bar_type".to_string() => bar_type.to_string(),
+    };
+    let params = HashMap::from([(BOOK_PRICE_TYPE.to_string(), price_type.to_string())]);
+    SubscriptionCommand::new(
+        ClientId::default(),
+        bar_type.instrument_id().venue,
+        DataType::new(stringify!(Bar), Some(metadata)),
+        action,
+        UUID4::new(),
+        UnixNanos::default(),
+        Some(params),
+    )
+}
+
+fn sequenced_delta(sequence: u64, flags: u8) -> OrderBookDelta {
+    OrderBookDelta {
+        sequence,
+        flags,
+        ..stub_delta()
+    }
+}
+
+fn sequence_validating_engine(
+    clock: Rc<RefCell<TestClock>>,
+    cache: Rc<RefCell<Cache>>,
+    msgbus: Rc<RefCell<MessageBus>>,
+    book_snapshot: Option<OrderBookDeltas>,
+) -> DataEngine {
+    let config = DataEngineConfig {
+        validate_data_sequence: true,
+        ..Default::default()
+    };
+    let mut data_engine =
+        DataEngine::new(clock.clone(), cache.clone(), msgbus.clone(), Some(config));
+
+    let venue = stub_delta().instrument_id.venue;
+    let mut client = MockDataClient::new(cache, msgbus, ClientId::from("XNAS"), venue);
+    client.book_snapshot = book_snapshot;
+    let client_id = client.client_id;
+    let adapter = DataClientAdapter::new(client_id, venue, true, true, Box::new(client), clock);
+    data_engine.register_client(adapter, Some(venue));
+
+    data_engine
+}
+
+#[rstest]
+fn test_process_deltas_drops_duplicate_and_out_of_order_sequences(
+    clock: Rc<RefCell<TestClock>>,
+    cache: Rc<RefCell<Cache>>,
+    msgbus: Rc<RefCell<MessageBus>>,
+) {
+    let mut data_engine = sequence_validating_engine(clock, cache, msgbus.clone(), None);
+
+    let handler = get_message_saving_handler::<OrderBookDeltas>(None);
+    {
+        let mut msgbus = msgbus.borrow_mut();
+        let topic = msgbus
+            .switchboard
+            .get_deltas_topic(stub_delta().instrument_id);
+        msgbus.subscribe(topic, handler.clone(), None);
+    }
+
+    let f_last = RecordFlag::F_LAST as u8;
+    data_engine.process_data(Data::Delta(sequenced_delta(1, f_last)));
+    data_engine.process_data(Data::Delta(sequenced_delta(2, f_last)));
+    data_engine.process_data(Data::Delta(sequenced_delta(2, f_last))); // Duplicate
+    data_engine.process_data(Data::Delta(sequenced_delta(1, f_last))); // Out of order
+    data_engine.process_data(Data::Delta(sequenced_delta(3, f_last)));
+
+    let messages = get_saved_messages::<OrderBookDeltas>(handler);
+
+    assert_eq!(messages.len(), 3);
+    assert_eq!(messages[2].sequence, 3);
+    assert!(data_engine.invalid_order_books().is_empty());
+}
+
+#[rstest]
+fn test_process_deltas_with_venue_snapshot_resets_sequence(
+    clock: Rc<RefCell<TestClock>>,
+    cache: Rc<RefCell<Cache>>,
+    msgbus: Rc<RefCell<MessageBus>>,
+) {
+    let instrument_id = stub_delta().instrument_id;
+    let mut data_engine = sequence_validating_engine(clock, cache, msgbus.clone(), None);
+
+    let handler = get_message_saving_handler::<OrderBookDeltas>(None);
+    {
+        let mut msgbus = msgbus.borrow_mut();
+        let topic = msgbus.switchboard.get_deltas_topic(instrument_id);
+        msgbus.subscribe(topic, handler.clone(), None);
+    }
+
+    let f_last = RecordFlag::F_LAST as u8;
+    data_engine.process_data(Data::Delta(sequenced_delta(5, f_last)));
+    data_engine.process_data(Data::Delta(sequenced_delta(6, f_last)));
+
+    // Venue resets the book with a snapshot at a sequence prior to the last applied
+    data_engine.process_data(Data::Delta(OrderBookDelta::clear(
+        instrument_id,
+        2,
+        1.into(),
+        2.into(),
+    )));
+    data_engine.process_data(Data::Delta(sequenced_delta(
+        2,
+        RecordFlag::F_SNAPSHOT as u8 | f_last,
+    )));
+    data_engine.process_data(Data::Delta(sequenced_delta(3, f_last)));
+
+    let messages = get_saved_messages::<OrderBookDeltas>(handler);
+
+    assert_eq!(messages.len(), 5);
+    assert_eq!(messages[2].deltas[0].action, BookAction::Clear);
+    assert_eq!(messages[4].sequence, 3);
+    assert!(data_engine.invalid_order_books().is_empty());
+}
+
+#[rstest]
+fn test_process_deltas_with_gap_resyncs_from_client_snapshot(
+    clock: Rc<RefCell<TestClock>>,
+    cache: Rc<RefCell<Cache>>,
+    msgbus: Rc<RefCell<MessageBus>>,
+) {
+    let instrument_id = stub_delta().instrument_id;
+    let snapshot = OrderBookDeltas::new(
+        instrument_id,
+        vec![
+            OrderBookDelta::clear(instrument_id, 10, 1.into(), 2.into()),
+            sequenced_delta(10, RecordFlag::F_SNAPSHOT as u8 | RecordFlag::F_LAST as u8),
+        ],
+    );
+    let mut data_engine =
+        sequence_validating_engine(clock, cache, msgbus.clone(), Some(snapshot.clone()));
+
+    let handler = get_message_saving_handler::<OrderBookDeltas>(None);
+    {
+        let mut msgbus = msgbus.borrow_mut();
+        let topic = msgbus.switchboard.get_deltas_topic(instrument_id);
+        msgbus.subscribe(topic, handler.clone(), None);
+    }
+
+    let f_last = RecordFlag::F_LAST as u8;
+    data_engine.process_data(Data::Delta(sequenced_delta(1, f_last)));
+    data_engine.process_data(Data::Delta(sequenced_delta(5, f_last))); // Gap (covered by snapshot)
+    data_engine.process_data(Data::Delta(sequenced_delta(11, f_last)));
+
+    let messages = get_saved_messages::<OrderBookDeltas>(handler);
+
+    assert_eq!(messages.len(), 3);
+    assert_eq!(messages[1], snapshot);
+    assert_eq!(messages[2].sequence, 11);
+    assert!(data_engine.invalid_order_books().is_empty());
+}
+
+#[rstest]
+fn test_process_deltas_with_gap_buffers_until_venue_snapshot(
+    clock: Rc<RefCell<TestClock>>,
+    cache: Rc<RefCell<Cache>>,
+    msgbus: Rc<RefCell<MessageBus>>,
+) {
+    let instrument_id = stub_delta().instrument_id;
+    let mut data_engine = sequence_validating_engine(clock, cache, msgbus.clone(), None);
+
+    let handler = get_message_saving_handler::<OrderBookDeltas>(None);
+    {
+        let mut msgbus = msgbus.borrow_mut();
+        let topic = msgbus.switchboard.get_deltas_topic(instrument_id);
+        msgbus.subscribe(topic, handler.clone(), None);
+    }
+
+    let f_last = RecordFlag::F_LAST as u8;
+    data_engine.process_data(Data::Delta(sequenced_delta(1, f_last)));
+    data_engine.process_data(Data::Delta(sequenced_delta(4, f_last))); // Gap
+    data_engine.process_data(Data::Delta(sequenced_delta(5, f_last)));
+    data_engine.process_data(Data::Delta(sequenced_delta(6, f_last)));
+
+    assert_eq!(data_engine.invalid_order_books(), vec![instrument_id]);
+
+    // Venue streams a fresh snapshot at sequence 5
+    data_engine.process_data(Data::Delta(OrderBookDelta::clear(
+        instrument_id,
+        5,
+        1.into(),
+        2.into(),
+    )));
+    data_engine.process_data(Data::Delta(sequenced_delta(
+        5,
+        RecordFlag::F_SNAPSHOT as u8 | f_last,
+    )));
+
+    let messages = get_saved_messages::<OrderBookDeltas>(handler);
+
+    // Initial delta, then the snapshot, then the buffered delta after the snapshot
+    assert_eq!(messages.len(), 3);
+    assert_eq!(messages[1].deltas.len(), 2);
+    assert_eq!(messages[1].sequence, 5);
+    assert_eq!(messages[2].sequence, 6);
+    assert!(data_engine.invalid_order_books().is_empty());
+}
+
+#[rstest]
+fn test_process_deltas_while_resyncing_rate_limits_snapshot_requests(
+    clock: Rc<RefCell<TestClock>>,
+    cache: Rc<RefCell<Cache>>,
+    msgbus: Rc<RefCell<MessageBus>>,
+) {
+    let instrument_id = stub_delta().instrument_id;
+    let mut data_engine = sequence_validating_engine(clock.clone(), cache, msgbus, None);
+    let last_snapshot_request =
+        |data_engine: &DataEngine| data_engine.book_sequences[&instrument_id].last_snapshot_request;
+
+    let f_last = RecordFlag::F_LAST as u8;
+    data_engine.process_data(Data::Delta(sequenced_delta(1, f_last)));
+    data_engine.process_data(Data::Delta(sequenced_delta(4, f_last))); // Gap
+
+    assert_eq!(
+        last_snapshot_request(&data_engine),
+        Some(UnixNanos::default())
+    );
+
+    // Within the resync interval no further snapshot is requested
+    clock.borrow_mut().advance_time(500_000_000.into(), true);
+    data_engine.process_data(Data::Delta(sequenced_delta(5, f_last)));
+
+    assert_eq!(
+        last_snapshot_request(&data_engine),
+        Some(UnixNanos::default())
+    );
+
+    // Once the interval has elapsed the snapshot request is retried
+    clock.borrow_mut().advance_time(1_000_000_000.into(), true);
+    data_engine.process_data(Data::Delta(sequenced_delta(6, f_last)));
+
+    assert_eq!(
+        last_snapshot_request(&data_engine),
+        Some(UnixNanos::from(1_000_000_000))
+    );
+    assert_eq!(data_engine.invalid_order_books(), vec![instrument_id]);
+}
+
+fn stub_quotes_for_catalog(count: u64) -> Vec<QuoteTick> {
+    (1..=count)
+        .map(|i| QuoteTick {
+            ts_event: UnixNanos::from(i),
+            ts_init: UnixNanos::from(i),
+            ..QuoteTick::default()
+        })
+        .collect()
+}
+
+#[rstest]
+fn test_register_catalog(data_engine: Rc<RefCell<DataEngine>>) {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let mut data_engine = data_engine.borrow_mut();
+
+    data_engine.register_catalog(
+        ParquetDataCatalog::new(temp_dir.path().to_path_buf(), None),
+        None,
+    );
+    data_engine.register_catalog(
+        ParquetDataCatalog::new(temp_dir.path().to_path_buf(), None),
+        Some("research".to_string()),
+    );
+
+    assert_eq!(
+        data_engine.registered_catalogs(),
+        vec![Ustr::from("catalog_0"), Ustr::from("research")]
+    );
+}
+
+#[rstest]
+fn test_request_quote_ticks_served_from_catalog(
+    data_engine: Rc<RefCell<DataEngine>>,
+    data_client: DataClientAdapter,
+) {
+    let client_id = data_client.client_id;
+    let venue = data_client.venue;
+    data_engine.borrow_mut().register_client(data_client, None);
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let catalog = ParquetDataCatalog::new(temp_dir.path().to_path_buf(), None);
+    let quotes = stub_quotes_for_catalog(5);
+    let instrument_id = quotes[0].instrument_id;
+    let _ = catalog.write_to_parquet(quotes.clone(), None, None, None);
+    data_engine.borrow_mut().register_catalog(catalog, None);
+
+    let metadata = indexmap! {
+        "instrument_id".to_string() => instrument_id.to_string(),
+        "start".to_string() => "2".to_string(),
+        "end".to_string() => "5".to_string(),
+    };
+    let req = DataRequest {
+        correlation_id: UUID4::new(),
+        client_id,
+        venue,
+        data_type: DataType::new(stringify!(QuoteTick), Some(metadata)),
+        ts_init: UnixNanos::default(),
+        params: None,
+    };
+
+    let data_engine = data_engine.borrow();
+    data_engine.request(req);
+    let cache = data_engine.get_cache();
+
+    assert_eq!(cache.quotes(&instrument_id).unwrap().len(), 4);
+}
+
+#[rstest]
+fn test_request_quote_ticks_with_limit_from_catalog(
+    data_engine: Rc<RefCell<DataEngine>>,
+    data_client: DataClientAdapter,
+) {
+    let client_id = data_client.client_id;
+    let venue = data_client.venue;
+    data_engine.borrow_mut().register_client(data_client, None);
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let catalog = ParquetDataCatalog::new(temp_dir.path().to_path_buf(), None);
+    let quotes = stub_quotes_for_catalog(5);
+    let instrument_id = quotes[0].instrument_id;
+    let _ = catalog.write_to_parquet(quotes.clone(), None, None, None);
+    data_engine.borrow_mut().register_catalog(catalog, None);
+
+    // No `end` so the client is requested for the remainder (mock returns no data)
+    let metadata = indexmap! {
+        "instrument_id".to_string() => instrument_id.to_string(),
+        "limit".to_string() => "2".to_string(),
+    };
+    let req = DataRequest {
+        correlation_id: UUID4::new(),
+        client_id,
+        venue,
+        data_type: DataType::new(stringify!(QuoteTick), Some(metadata)),
+        ts_init: UnixNanos::default(),
+        params: None,
+    };
+
+    let data_engine = data_engine.borrow();
+    data_engine.request(req);
+    let cache = data_engine.get_cache();
+
+    let cached = cache.quotes(&instrument_id).unwrap();
+    assert_eq!(cached.len(), 2);
+    assert!(cached.contains(&quotes[4]));
+    assert!(cached.contains(&quotes[3]));
+}
+
+#[rstest]
+fn test_request_quote_ticks_from_client_before_catalog_range(
+    clock: Rc<RefCell<TestClock>>,
+    cache: Rc<RefCell<Cache>>,
+    msgbus: Rc<RefCell<MessageBus>>,
+    data_engine: Rc<RefCell<DataEngine>>,
+    client_id: ClientId,
+    venue: Venue,
+) {
+    let quotes = stub_quotes_for_catalog(5);
+    let instrument_id = quotes[0].instrument_id;
+
+    // The client holds the full history, the catalog only the most recent quotes
+    let mut client = MockDataClient::new(cache, msgbus, client_id, venue);
+    client.quotes = quotes.clone();
+    let adapter = DataClientAdapter::new(client_id, venue, true, true, Box::new(client), clock);
+    data_engine.borrow_mut().register_client(adapter, None);
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let catalog = ParquetDataCatalog::new(temp_dir.path().to_path_buf(), None);
+    let _ = catalog.write_to_parquet(quotes[2..].to_vec(), None, None, None);
+    data_engine.borrow_mut().register_catalog(catalog, None);
+
+    let metadata = indexmap! {
+        "instrument_id".to_string() => instrument_id.to_string(),
+        "start".to_string() => "1".to_string(),
+        "end".to_string() => "5".to_string(),
+    };
+    let req = DataRequest {
+        correlation_id: UUID4::new(),
+        client_id,
+        venue,
+        data_type: DataType::new(stringify!(QuoteTick), Some(metadata)),
+        ts_init: UnixNanos::default(),
+        params: None,
+    };
+
+    let data_engine = data_engine.borrow();
+    data_engine.request(req);
+    let cache = data_engine.get_cache();
+
+    let cached = cache.quotes(&instrument_id).unwrap();
+    assert_eq!(cached.len(), 5);
+    for quote in &quotes {
+        assert!(cached.contains(quote));
+    }
+}
+
+#[rstest]
+fn test_request_quote_ticks_from_client_and_catalog_applies_limit(
+    clock: Rc<RefCell<TestClock>>,
+    cache: Rc<RefCell<Cache>>,
+    msgbus: Rc<RefCell<MessageBus>>,
+    data_engine: Rc<RefCell<DataEngine>>,
+    client_id: ClientId,
+    venue: Venue,
+) {
+    let quotes = stub_quotes_for_catalog(5);
+    let instrument_id = quotes[0].instrument_id;
+
+    let mut client = MockDataClient::new(cache, msgbus, client_id, venue);
+    client.quotes = quotes.clone();
+    let adapter = DataClientAdapter::new(client_id, venue, true, true, Box::new(client), clock);
+    data_engine.borrow_mut().register_client(adapter, None);
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let catalog = ParquetDataCatalog::new(temp_dir.path().to_path_buf(), None);
+    let _ = catalog.write_to_parquet(quotes[3..].to_vec(), None, None, None);
+    data_engine.borrow_mut().register_catalog(catalog, None);
+
+    let metadata = indexmap! {
+        "instrument_id".to_string() => instrument_id.to_string(),
+        "start".to_string() => "1".to_string(),
+        "end".to_string() => "5".to_string(),
+        "limit".to_string() => "3".to_string(),
+    };
+    let req = DataRequest {
+        correlation_id: UUID4::new(),
+        client_id,
+        venue,
+        data_type: DataType::new(stringify!(QuoteTick), Some(metadata)),
+        ts_init: UnixNanos::default(),
+        params: None,
+    };
+
+    let data_engine = data_engine.borrow();
+    data_engine.request(req);
+    let cache = data_engine.get_cache();
+
+    // The most recent 3 quotes of the merged client and catalog data
+    let cached = cache.quotes(&instrument_id).unwrap();
+    assert_eq!(cached.len(), 3);
+    for quote in &quotes[2..] {
+        assert!(cached.contains(quote));
+    }
+}
+
+#[rstest]
+#[case::instrument_id("instrument_id", "AUD/USD.SIM")]
+#[case::venue("venue", "SIM")]
+fn test_request_instruments_served_from_catalog(
+    audusd_sim: CurrencyPair,
+    data_engine: Rc<RefCell<DataEngine>>,
+    data_client: DataClientAdapter,
+    #[case] key: &str,
+    #[case] value: &str,
+) {
+    let client_id = data_client.client_id;
+    let venue = data_client.venue;
+    data_engine.borrow_mut().register_client(data_client, None);
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let catalog = ParquetDataCatalog::new(temp_dir.path().to_path_buf(), None);
+    catalog
+        .write_instruments(vec![InstrumentAny::CurrencyPair(audusd_sim)])
+        .unwrap();
+    data_engine.borrow_mut().register_catalog(catalog, None);
+
+    let metadata = indexmap! {key.to_string() => value.to_string()};
+    let req = DataRequest {
+        correlation_id: UUID4::new(),
+        client_id,
+        venue,
+        data_type: DataType::new(stringify!(InstrumentAny), Some(metadata)),
+        ts_init: UnixNanos::default(),
+        params: None,
+    };
+
+    let data_engine = data_engine.borrow();
+    data_engine.request(req);
+    let cache = data_engine.get_cache();
+
+    assert_eq!(
+        cache.instrument(&audusd_sim.id),
+        Some(&InstrumentAny::CurrencyPair(audusd_sim))
+    );
+}
+
+#[rstest]
+fn test_process_order_book_deltas_aggregates_book_bars(
+    audusd_sim: CurrencyPair,
+    msgbus: Rc<RefCell<MessageBus>>,
+    data_engine: Rc<RefCell<DataEngine>>,
+) {
+    let instrument_id = audusd_sim.id;
+    let mut data_engine = data_engine.borrow_mut();
+    data_engine
+        .cache
+        .borrow_mut()
+        .add_instrument(InstrumentAny::CurrencyPair(audusd_sim))
+        .unwrap();
+
+    let bar_type = BarType::from("AUD/USD.SIM-1-TICK-MID-INTERNAL");
+    data_engine.execute(book_bars_command(bar_type, Action::Subscribe, "MID"));
+    assert_eq!(data_engine.subscribed_book_bars(), vec![bar_type]);
+
+    let handler = get_message_saving_handler::<Bar>(None);
+    let bars_handler = get_message_saving_handler::<Bar>(None);
+    {
+        let mut msgbus = msgbus.borrow_mut();
+        let topic = msgbus.switchboard.get_book_bars_topic(bar_type);
+        msgbus.subscribe(topic, handler.clone(), None);
+        let topic = msgbus.switchboard.get_bars_topic(bar_type);
+        msgbus.subscribe(topic, bars_handler.clone(), None);
+    }
+
+    let delta = |side: OrderSide, price: &str, sequence: u64| {
+        let order = BookOrder::new(side, Price::from(price), Quantity::from(100_000), 0);
+        let delta = OrderBookDelta::new(
+            instrument_id,
+            BookAction::Add,
+            order,
+            RecordFlag::F_LAST as u8,
+            sequence,
+            UnixNanos::from(sequence),
+            UnixNanos::from(sequence),
+        );
+        OrderBookDeltas_API::new(OrderBookDeltas::new(instrument_id, vec![delta]))
+    };
+
+    // No bar until both sides of the book are populated
+    data_engine.process_data(Data::Deltas(delta(OrderSide::Buy, "0.80000", 1)));
+    assert!(get_saved_messages::<Bar>(handler.clone()).is_empty());
+
+    data_engine.process_data(Data::Deltas(delta(OrderSide::Sell, "0.80010", 2)));
+    let messages = get_saved_messages::<Bar>(handler);
+
+    assert_eq!(messages.len(), 1);
+    assert_eq!(messages[0].close, Price::from("0.800050"));
+    assert_eq!(messages[0].volume, Quantity::from(100_000));
+
+    // Book bars are kept apart from bars of the same type aggregated from quotes
+    assert!(get_saved_messages::<Bar>(bars_handler).is_empty());
+    assert!(data_engine.get_cache().bar(&bar_type).is_none());
+
+    data_engine.execute(book_bars_command(bar_type, Action::Unsubscribe, "MID"));
+    assert!(data_engine.subscribed_book_bars().is_empty());
+}
+
+#[rstest]
+fn test_process_order_book_deltas_aggregates_notional_vwap_book_bars(
+    audusd_sim: CurrencyPair,
+    msgbus: Rc<RefCell<MessageBus>>,
+    data_engine: Rc<RefCell<DataEngine>>,
+) {
+    let instrument_id = audusd_sim.id;
+    let mut data_engine = data_engine.borrow_mut();
+    data_engine
+        .cache
+        .borrow_mut()
+        .add_instrument(InstrumentAny::CurrencyPair(audusd_sim))
+        .unwrap();
+
+    let bar_type = BarType::from("AUD/USD.SIM-1-TICK-ASK-INTERNAL");
+    let mut command = book_bars_command(bar_type, Action::Subscribe, "NOTIONAL_VWAP");
+    if let Some(params) = command.params.as_mut() {
+        params.insert(BOOK_SIDE.to_string(), "BUY".to_string());
+        params.insert(BOOK_NOTIONAL.to_string(), "120000.00 USD".to_string());
+    }
+    data_engine.execute(command);
+
+    let handler = get_message_saving_handler::<Bar>(None);
+    {
+        let mut msgbus = msgbus.borrow_mut();
+        let topic = msgbus.switchboard.get_book_bars_topic(bar_type);
+        msgbus.subscribe(topic, handler.clone(), None);
+    }
+
+    let deltas: Vec<OrderBookDelta> = [
+        (OrderSide::Buy, "0.70000"),
+        (OrderSide::Sell, "0.80000"),
+        (OrderSide::Sell, "0.90000"),
+    ]
+    .into_iter()
+    .enumerate()
+    .map(|(i, (side, price))| {
+        let order = BookOrder::new(side, Price::from(price), Quantity::from(100_000), 0);
+        let flags = if i == 2 { RecordFlag::F_LAST as u8 } else { 0 };
+        OrderBookDelta::new(
+            instrument_id,
+            BookAction::Add,
+            order,
+            flags,
+            1,
+            UnixNanos::from(1),
+            UnixNanos::from(1),
+        )
+    })
+    .collect();
+    let deltas = OrderBookDeltas::new(instrument_id, deltas);
+    data_engine.process_data(Data::Deltas(OrderBookDeltas_API::new(deltas)));
+
+    let messages = get_saved_messages::<Bar>(handler);
+
+    // Fills 80,000 notional at the top level and 40,000 at the next
+    assert_eq!(messages.len(), 1);
+    assert_eq!(messages[0].close, Price::from("0.830769"));
+    assert_eq!(messages[0].volume, Quantity::from(144_444));
+}
+
+#[rstest]
+fn test_process_quote_tick_records_latency(
+    clock: Rc<RefCell<TestClock>>,
+    cache: Rc<RefCell<Cache>>,
+    msgbus: Rc<RefCell<MessageBus>>,
+) {
+    let config = DataEngineConfig {
+        record_latency: true,
+        ..Default::default()
+    };
+    let mut data_engine = DataEngine::new(clock.clone(), cache, msgbus, Some(config));
+    clock
+        .borrow_mut()
+        .advance_time(UnixNanos::from(2_000), true);
+
+    let quote = QuoteTick {
+        ts_event: UnixNanos::from(1_000),
+        ts_init: UnixNanos::from(1_500),
+        ..Default::default()
+    };
+    data_engine.process_data(Data::Quote(quote));
+
+    let latency = data_engine.latency_tracker();
+    let latency = latency.borrow();
+    let feed = latency
+        .histogram(&quote.instrument_id, "QuoteTick", LatencyStage::Feed)
+        .unwrap();
+    let processing = latency
+        .histogram(&quote.instrument_id, "QuoteTick", LatencyStage::Processing)
+        .unwrap();
+
+    assert_eq!(feed.count(), 1);
+    assert_eq!(feed.max(), Some(500));
+    assert_eq!(processing.count(), 1);
+    assert_eq!(processing.max(), Some(500));
+}
+
+#[rstest]
+fn test_client_send_data_records_client_latency(
+    clock: Rc<RefCell<TestClock>>,
+    cache: Rc<RefCell<Cache>>,
+    msgbus: Rc<RefCell<MessageBus>>,
+    client_id: ClientId,
+    venue: Venue,
+) {
+    struct SavingDataQueue(Rc<RefCell<Vec<DataEvent>>>);
+
+    impl DataQueue for SavingDataQueue {
+        fn push(&mut self, event: DataEvent) {
+            self.0.borrow_mut().push(event);
+        }
+    }
+
+    let events = Rc::new(RefCell::new(Vec::new()));
+    set_data_queue(Rc::new(RefCell::new(SavingDataQueue(events.clone()))));
+
+    let config = DataEngineConfig {
+        record_latency: true,
+        ..Default::default()
+    };
+    let mut data_engine =
+        DataEngine::new(clock.clone(), cache.clone(), msgbus.clone(), Some(config));
+    let client = MockDataClient::new(cache, msgbus, client_id, venue);
+    let adapter = DataClientAdapter::new(
+        client_id,
+        venue,
+        true,
+        true,
+        Box::new(client),
+        clock.clone(),
+    );
+    data_engine.register_client(adapter, None);
+    clock
+        .borrow_mut()
+        .advance_time(UnixNanos::from(2_000), true);
+
+    let quote = QuoteTick {
+        ts_event: UnixNanos::from(1_000),
+        ts_init: UnixNanos::from(1_500),
+        ..Default::default()
+    };
+    data_engine.clients[&client_id].send_data(Data::Quote(quote));
+
+    let latency = data_engine.latency_tracker();
+    let latency = latency.borrow();
+    let client = latency
+        .histogram(&quote.instrument_id, "QuoteTick", LatencyStage::Client)
+        .unwrap();
+
+    assert_eq!(client.count(), 1);
+    assert_eq!(client.max(), Some(500));
+    assert_eq!(events.borrow().len(), 1);
+}
+
+#[rstest]
+fn test_process_quote_tick_publishes_continuous_futures_and_rolls(
+    msgbus: Rc<RefCell<MessageBus>>,
+    data_engine: Rc<RefCell<DataEngine>>,
+) {
+    let ts = |day: u32| UnixNanos::from(Utc.with_ymd_and_hms(2021, 12, day, 0, 0, 0).unwrap());
+    let front = futures_contract_es(None, None); // ESZ21 expiring 2021-12-17
+    let mut next = futures_contract_es(None, Some(ts(31)));
+    next.id = InstrumentId::from("ESH22.GLBX");
+    let continuous_id = InstrumentId::from("ES.CONT");
+    let series = ContinuousFutures::new(
+        continuous_id,
+        vec![front, next],
+        RollRule::CalendarOffset { days: 5 },
+        AdjustmentMethod::Difference,
+    )
+    .unwrap();
+
+    let mut data_engine = data_engine.borrow_mut();
+    data_engine.add_continuous_futures(series).unwrap();
+
+    let quote_handler = get_message_saving_handler::<QuoteTick>(None);
+    let roll_handler = get_message_saving_handler::<RollEvent>(None);
+    {
+        let mut msgbus = msgbus.borrow_mut();
+        let topic = msgbus.switchboard.get_quotes_topic(continuous_id);
+        msgbus.subscribe(topic, quote_handler.clone(), None);
+        let topic = msgbus.switchboard.get_rolls_topic(continuous_id);
+        msgbus.subscribe(topic, roll_handler.clone(), None);
+    }
+
+    let quote = |instrument_id: InstrumentId, bid: &str, ask: &str, ts_event: UnixNanos| {
+        QuoteTick::new(
+            instrument_id,
+            Price::from(bid),
+            Price::from(ask),
+            Quantity::from(1),
+            Quantity::from(1),
+            ts_event,
+            ts_event,
+        )
+    };
+    data_engine.process_data(Data::Quote(quote(front.id, "4700.00", "4700.50", ts(10))));
+    data_engine.process_data(Data::Quote(quote(next.id, "4690.00", "4690.50", ts(10))));
+    data_engine.process_data(Data::Quote(quote(next.id, "4680.00", "4680.50", ts(13))));
+
+    let quotes = get_saved_messages::<QuoteTick>(quote_handler);
+    let rolls = get_saved_messages::<RollEvent>(roll_handler);
+
+    assert_eq!(quotes.len(), 2);
+    assert!(quotes
+        .iter()
+        .all(|quote| quote.instrument_id == continuous_id));
+    assert_eq!(quotes[0].bid_price, Price::from("4700.00"));
+    assert_eq!(quotes[1].bid_price, Price::from("4680.00"));
+    assert_eq!(rolls.len(), 1);
+    assert_eq!(rolls[0].from_instrument_id, front.id);
+    assert_eq!(rolls[0].to_instrument_id, next.id);
+    assert_eq!(rolls[0].adjustment, Some(-20.0));
+    assert_eq!(
+        data_engine
+            .continuous_futures(&continuous_id)
+            .unwrap()
+            .rolls()
+            .len(),
+        1
+    );
+
+    // Historical bars for the continuous instrument are back-adjusted for later rolls
+    let bar_type = BarType::from("ES.CONT-1-DAY-LAST-EXTERNAL");
+    let price = Price::from("4700.00");
+    let bar = Bar::new(
+        bar_type,
+        price,
+        price,
+        price,
+        price,
+        Quantity::from(1),
+        ts(10),
+        ts(10),
+    );
+    let metadata = indexmap! {"bar_type".to_string() => bar_type.to_string()};
+    data_engine.response(DataResponse::new(
+        UUID4::new(),
+        ClientId::default(),
+        continuous_id.venue,
+        DataType::new(stringify!(Bar), Some(metadata)),
+        vec![bar],
+        ts(13),
+        None,
+    ));
+
+    assert_eq!(
+        data_engine.get_cache().bar(&bar_type).unwrap().close,
+        Price::from("4680.00")
+    );
+}
+
+#[rstest]
+fn test_process_quote_tick_updates_synthetic_instrument(
+    clock: Rc<RefCell<TestClock>>,
+    cache: Rc<RefCell<Cache>>,
+    msgbus: Rc<RefCell<MessageBus>>,
+) {
+    let synthetic = SyntheticInstrument::default();
+    cache.borrow_mut().add_synthetic(synthetic.clone()).unwrap();
+
+    let mut data_engine = DataEngine::new(clock.clone(), cache.clone(), msgbus.clone(), None);
+    let venue = Venue::from("BINANCE");
+    let client_id = ClientId::from("BINANCE");
+    let client = MockDataClient::new(cache, msgbus.clone(), client_id, venue);
+    let adapter = DataClientAdapter::new(client_id, venue, true, true, Box::new(client), clock);
+    data_engine.register_client(adapter, Some(venue));
+
+    let metadata = indexmap! {
+        "instrument_id".to_string() => synthetic.id.to_string(),
+    };
+    let cmd = SubscriptionCommand::new(
+        client_id,
+        synthetic.id.venue,
+        DataType::new(stringify!(QuoteTick), Some(metadata)),
+        Action::Subscribe,
+        UUID4::new(),
+        UnixNanos::default(),
+        None,
+    );
+    data_engine.execute(cmd);
+
+    let subscribed = data_engine.subscribed_quote_ticks();
+    assert_eq!(subscribed.len(), 2);
+    for component in &synthetic.components {
+        assert!(subscribed.contains(component));
+    }
+
+    // Save the component and synthetic quotes together to check the publishing order
+    let handler = get_message_saving_handler::<QuoteTick>(None);
+    {
+        let mut msgbus = msgbus.borrow_mut();
+        for instrument_id in [synthetic.id, synthetic.components[1]] {
+            let topic = msgbus.switchboard.get_quotes_topic(instrument_id);
+            msgbus.subscribe(topic, handler.clone(), None);
+        }
+    }
+
+    let btc_quote = QuoteTick::new(
+        synthetic.components[0],
+        Price::from("100.00"),
+        Price::from("102.00"),
+        Quantity::from(2),
+        Quantity::from(3),
+        UnixNanos::from(1),
+        UnixNanos::from(1),
+    );
+    let ltc_quote = QuoteTick::new(
+        synthetic.components[1],
+        Price::from("50.00"),
+        Price::from("52.00"),
+        Quantity::from("5.50"),
+        Quantity::from("1.25"),
+        UnixNanos::from(2),
+        UnixNanos::from(2),
+    );
+
+    // No synthetic quote until all components have quotes
+    data_engine.process_data(Data::Quote(btc_quote));
+    assert!(get_saved_messages::<QuoteTick>(handler.clone()).is_empty());
+
+    data_engine.process_data(Data::Quote(ltc_quote));
+    let messages = get_saved_messages::<QuoteTick>(handler);
+
+    assert_eq!(messages.len(), 2);
+    assert_eq!(messages[0], ltc_quote);
+    let synthetic_quote = messages[1];
+    assert_eq!(synthetic_quote.instrument_id, synthetic.id);
+    assert_eq!(synthetic_quote.bid_price, Price::from("75.00"));
+    assert_eq!(synthetic_quote.ask_price, Price::from("77.00"));
+    // Sizes are rounded down to the coarsest component size precision
+    assert_eq!(synthetic_quote.bid_size, Quantity::from(2));
+    assert_eq!(synthetic_quote.ask_size, Quantity::from(1));
+    assert_eq!(synthetic_quote.ask_size.precision, 0);
+    assert_eq!(synthetic_quote.ts_event, UnixNanos::from(2));
+    assert_eq!(
+        data_engine.get_cache().quote(&synthetic.id),
+        Some(&synthetic_quote)
+    );
+}
+
+#[rstest]
+fn test_process_trade_tick_updates_synthetic_instrument(
+    clock: Rc<RefCell<TestClock>>,
+    cache: Rc<RefCell<Cache>>,
+    msgbus: Rc<RefCell<MessageBus>>,
+) {
+    let synthetic = SyntheticInstrument::default();
+    cache.borrow_mut().add_synthetic(synthetic.clone()).unwrap();
+
+    let mut data_engine = DataEngine::new(clock.clone(), cache.clone(), msgbus.clone(), None);
+    let venue = Venue::from("BINANCE");
+    let client_id = ClientId::from("BINANCE");
+    let client = MockDataClient::new(cache, msgbus.clone(), client_id, venue);
+    let adapter = DataClientAdapter::new(client_id, venue, true, true, Box::new(client), clock);
+    data_engine.register_client(adapter, Some(venue));
+
+    let metadata = indexmap! {
+        "instrument_id".to_string() => synthetic.id.to_string(),
+    };
+    data_engine.execute(SubscriptionCommand::new(
+        client_id,
+        synthetic.id.venue,
+        DataType::new(stringify!(TradeTick), Some(metadata)),
+        Action::Subscribe,
+        UUID4::new(),
+        UnixNanos::default(),
+        None,
+    ));
+
+    let handler = get_message_saving_handler::<TradeTick>(None);
+    {
+        let mut msgbus = msgbus.borrow_mut();
+        let topic = msgbus.switchboard.get_trades_topic(synthetic.id);
+        msgbus.subscribe(topic, handler.clone(), None);
+    }
+
+    let trade = |instrument_id: InstrumentId, price: &str, size: u64, trade_id: &str| {
+        TradeTick::new(
+            instrument_id,
+            Price::from(price),
+            Quantity::from(size),
+            AggressorSide::Buyer,
+            TradeId::new(trade_id),
+            UnixNanos::from(1),
+            UnixNanos::from(1),
+        )
+    };
+    data_engine.process_data(Data::Trade(trade(
+        synthetic.components[0],
+        "100.00",
+        4,
+        "1",
+    )));
+    data_engine.process_data(Data::Trade(trade(synthetic.components[1], "50.00", 3, "2")));
+
+    let messages = get_saved_messages::<TradeTick>(handler);
+
+    assert_eq!(messages.len(), 1);
+    assert_eq!(messages[0].price, Price::from("75.00"));
+    assert_eq!(messages[0].size, Quantity::from(3));
+    assert_ne!(messages[0].trade_id, TradeId::new("2"));
+}
+
+#[rstest]
+fn test_subscribe_synthetic_without_component_client_registers_nothing(
+    clock: Rc<RefCell<TestClock>>,
+    cache: Rc<RefCell<Cache>>,
+    msgbus: Rc<RefCell<MessageBus>>,
+) {
+    let synthetic = SyntheticInstrument::default();
+    cache.borrow_mut().add_synthetic(synthetic.clone()).unwrap();
+
+    let mut data_engine = DataEngine::new(clock, cache, msgbus, None);
+
+    let metadata = indexmap! {
+        "instrument_id".to_string() => synthetic.id.to_string(),
+    };
+    data_engine.execute(SubscriptionCommand::new(
+        ClientId::from("BINANCE"),
+        synthetic.id.venue,
+        DataType::new(stringify!(QuoteTick), Some(metadata)),
+        Action::Subscribe,
+        UUID4::new(),
+        UnixNanos::default(),
+        None,
+    ));
+
+    assert!(data_engine.synthetic_quote_subscribers.is_empty());
+    assert!(data_engine.synthetic_quote_feeds.is_empty());
+}
+
+#[rstest]
+fn test_unsubscribe_synthetic_releases_component_subscriptions(
+    clock: Rc<RefCell<TestClock>>,
+    cache: Rc<RefCell<Cache>>,
+    msgbus: Rc<RefCell<MessageBus>>,
+) {
+    let synthetic = SyntheticInstrument::default();
+    cache.borrow_mut().add_synthetic(synthetic.clone()).unwrap();
+
+    let mut data_engine = DataEngine::new(clock.clone(), cache.clone(), msgbus.clone(), None);
+    let venue = Venue::from("BINANCE");
+    let client_id = ClientId::from("BINANCE");
+    let client = MockDataClient::new(cache, msgbus, client_id, venue);
+    let adapter = DataClientAdapter::new(client_id, venue, true, true, Box::new(client), clock);
+    data_engine.register_client(adapter, Some(venue));
+
+    let command = |action: Action| {
+        let metadata = indexmap! {
+            "instrument_id".to_string() => synthetic.id.to_string(),
+        };
+        SubscriptionCommand::new(
+            client_id,
+            synthetic.id.venue,
+            DataType::new(stringify!(QuoteTick), Some(metadata)),
+            action,
+            UUID4::new(),
+            UnixNanos::default(),
+            None,
+        )
+    };
+
+    data_engine.execute(command(Action::Subscribe));
+    data_engine.execute(command(Action::Subscribe));
+    data_engine.execute(command(Action::Unsubscribe));
+
+    // Components remain subscribed while the synthetic has a subscriber
+    assert_eq!(data_engine.subscribed_quote_ticks().len(), 2);
+
+    data_engine.execute(command(Action::Unsubscribe));
+
+    assert!(data_engine.subscribed_quote_ticks().is_empty());
+}
+
+#[rstest]
+fn test_process_quote_tick_with_conflated_subscription(
+    audusd_sim: CurrencyPair,
+    clock: Rc<RefCell<TestClock>>,
+    cache: Rc<RefCell<Cache>>,
+    msgbus: Rc<RefCell<MessageBus>>,
+    data_client: DataClientAdapter,
+) {
+    let mut data_engine = DataEngine::new(clock.clone(), cache, msgbus.clone(), None);
+    let client_id = data_client.client_id;
+    let venue = data_client.venue;
+    data_engine.register_client(data_client, None);
+
+    let metadata = indexmap! {
+        "instrument_id".to_string() => audusd_sim.id.to_string(),
+    };
+    let params = HashMap::from([(CONFLATE_INTERVAL_MS.to_string(), "100".to_string())]);
+    let cmd = SubscriptionCommand::new(
+        client_id,
+        venue,
+        DataType::new(stringify!(QuoteTick), Some(metadata)),
+        Action::Subscribe,
+        UUID4::new(),
+        UnixNanos::default(),
+        Some(params),
+    );
+    data_engine.execute(cmd);
+
+    let handler = get_message_saving_handler::<QuoteTick>(None);
+    {
+        let mut msgbus = msgbus.borrow_mut();
+        let topic = msgbus.switchboard.get_quotes_topic(audusd_sim.id);
+        let conflated_topic = msgbus
+            .switchboard
+            .get_conflated_topic(topic, NonZeroU64::new(100).unwrap());
+        msgbus.subscribe(conflated_topic, handler.clone(), None);
+    }
+
+    let quotes: Vec<QuoteTick> = (1..=3)
+        .map(|i| QuoteTick {
+            instrument_id: audusd_sim.id,
+            ts_event: UnixNanos::from(i),
+            ts_init: UnixNanos::from(i),
+            ..QuoteTick::default()
+        })
+        .collect();
+    for quote in &quotes {
+        data_engine.process_data(Data::Quote(*quote));
+    }
+
+    assert!(get_saved_messages::<QuoteTick>(handler.clone()).is_empty());
+
+    // Flush the conflated quotes at the end of the interval
+    let events = clock
+        .borrow_mut()
+        .advance_time(UnixNanos::from(100_000_000), true);
+    for event_handler in clock.borrow().match_handlers(events) {
+        event_handler.callback.call(event_handler.event);
+    }
+
+    let messages = get_saved_messages::<QuoteTick>(handler);
+    assert_eq!(messages.len(), 1);
+    assert_eq!(messages[0], quotes[2]);
+}
+
+#[rstest]
+fn test_conflated_subscriptions_share_stream_until_last_unsubscribe(
+    audusd_sim: CurrencyPair,
+    clock: Rc<RefCell<TestClock>>,
+    cache: Rc<RefCell<Cache>>,
+    msgbus: Rc<RefCell<MessageBus>>,
+    data_client: DataClientAdapter,
+) {
+    let mut data_engine = DataEngine::new(clock.clone(), cache, msgbus.clone(), None);
+    let client_id = data_client.client_id;
+    let venue = data_client.venue;
+    data_engine.register_client(data_client, None);
+
+    let command = |action: Action| {
+        let metadata = indexmap! {
+            "instrument_id".to_string() => audusd_sim.id.to_string(),
+        };
+        let params = HashMap::from([(CONFLATE_INTERVAL_MS.to_string(), "100".to_string())]);
+        SubscriptionCommand::new(
+            client_id,
+            venue,
+            DataType::new(stringify!(QuoteTick), Some(metadata)),
+            action,
+            UUID4::new(),
+            UnixNanos::default(),
+            Some(params),
+        )
+    };
+
+    data_engine.execute(command(Action::Subscribe));
+    data_engine.execute(command(Action::Subscribe));
+
+    let handler1 = get_message_saving_handler::<QuoteTick>(None);
+    let handler2 = get_message_saving_handler::<QuoteTick>(None);
+    let source_topic = {
+        let mut msgbus = msgbus.borrow_mut();
+        let topic = msgbus.switchboard.get_quotes_topic(audusd_sim.id);
+        let conflated_topic = msgbus
+            .switchboard
+            .get_conflated_topic(topic, NonZeroU64::new(100).unwrap());
+        msgbus.subscribe(conflated_topic, handler1.clone(), None);
+        msgbus.subscribe(conflated_topic, handler2.clone(), None);
+        topic
+    };
+
+    // Both subscriptions are served by a single conflator
+    assert_eq!(data_engine.conflators[&source_topic].len(), 1);
+
+    let flush_interval = |data_engine: &mut DataEngine, ts: u64| {
+        let quote = QuoteTick {
+            instrument_id: audusd_sim.id,
+            ts_event: UnixNanos::from(ts),
+            ts_init: UnixNanos::from(ts),
+            ..QuoteTick::default()
+        };
+        data_engine.process_data(Data::Quote(quote));
+        let events = clock
+            .borrow_mut()
+            .advance_time(UnixNanos::from(ts + 100_000_000), true);
+        for event_handler in clock.borrow().match_handlers(events) {
+            event_handler.callback.call(event_handler.event);
+        }
+        quote
+    };
+
+    let quote = flush_interval(&mut data_engine, 1);
+
+    assert_eq!(
+        get_saved_messages::<QuoteTick>(handler1.clone()),
+        vec![quote]
+    );
+    assert_eq!(
+        get_saved_messages::<QuoteTick>(handler2.clone()),
+        vec![quote]
+    );
+
+    // The stream continues while any subscription remains
+    data_engine.execute(command(Action::Unsubscribe));
+    let quote = flush_interval(&mut data_engine, 100_000_001);
+
+    assert_eq!(get_saved_messages::<QuoteTick>(handler1.clone()).len(), 2);
+    assert_eq!(get_saved_messages::<QuoteTick>(handler2.clone())[1], quote);
+
+    data_engine.execute(command(Action::Unsubscribe));
+    flush_interval(&mut data_engine, 200_000_001);
+
+    assert!(!data_engine.conflators.contains_key(&source_topic));
+    assert_eq!(get_saved_messages::<QuoteTick>(handler1).len(), 2);
+    assert_eq!(get_saved_messages::<QuoteTick>(handler2).len(), 2);
+}
+
+#[rstest]
+fn test_process_quote_tick_dropped_by_filter(
+    audusd_sim: CurrencyPair,
+    msgbus: Rc<RefCell<MessageBus>>,
+    data_engine: Rc<RefCell<DataEngine>>,
+) {
+    let mut data_engine = data_engine.borrow_mut();
+    data_engine.add_filter(Box::new(CrossedQuoteFilter::new(false)), FilterAction::Drop);
+
+    let handler = get_message_saving_handler::<QuoteTick>(None);
+    {
+        let mut msgbus = msgbus.borrow_mut();
+        let topic = msgbus.switchboard.get_quotes_topic(audusd_sim.id);
+        msgbus.subscribe(topic, handler.clone(), None);
+    }
+
+    let valid_quote = QuoteTick {
+        instrument_id: audusd_sim.id,
+        ask_price: Price::from("1.00001"),
+        ..QuoteTick::default()
+    };
+    let crossed_quote = QuoteTick {
+        bid_price: valid_quote.ask_price,
+        ask_price: valid_quote.bid_price,
+        ..valid_quote
+    };
+    data_engine.process_data(Data::Quote(crossed_quote));
+    data_engine.process_data(Data::Quote(valid_quote));
+
+    let messages = get_saved_messages::<QuoteTick>(handler);
+    assert_eq!(messages, vec![valid_quote]);
+    assert_eq!(
+        data_engine.get_cache().quote(&audusd_sim.id),
+        Some(&valid_quote)
+    );
+
+    let stats = data_engine.filter_stats();
+    assert_eq!(stats.len(), 1);
+    assert_eq!(stats[0].1.dropped, 1);
+}
+
+#[rstest]
+fn test_process_order_book_deltas_publishes_derived_depth(
+    audusd_sim: CurrencyPair,
+    msgbus: Rc<RefCell<MessageBus>>,
+    data_engine: Rc<RefCell<DataEngine>>,
+) {
+    let instrument_id = audusd_sim.id;
+    let mut data_engine = data_engine.borrow_mut();
+    data_engine
+        .cache
+        .borrow_mut()
+        .add_instrument(InstrumentAny::CurrencyPair(audusd_sim))
+        .unwrap();
+
+    data_engine
+        .subscribe_book_depth(instrument_id, BookType::L3_MBO, None, None)
+        .unwrap();
+    // Also subscribes the book updater to the depth topic
+    data_engine.execute(book_bars_command(
+        BarType::from("AUD/USD.SIM-1-TICK-MID-INTERNAL"),
+        Action::Subscribe,
+        "MID",
+    ));
+    assert_eq!(data_engine.subscribed_book_depth(), vec![instrument_id]);
+    assert!(data_engine
+        .subscribe_book_depth(instrument_id, BookType::L3_MBO, None, None)
+        .is_err());
+
+    let handler = get_message_saving_handler::<OrderBookDepth10>(None);
+    {
+        let mut msgbus = msgbus.borrow_mut();
+        let topic = msgbus.switchboard.get_depth_topic(instrument_id);
+        msgbus.subscribe(topic, handler.clone(), None);
+    }
+
+    // Eleven bid levels with two orders at the top, as a single book event
+    let deltas: Vec<OrderBookDelta> = (0..12u64)
+        .map(|i| {
+            let price = Price::new(0.8 - 0.0001 * i.saturating_sub(1) as f64, 5);
+            let order = BookOrder::new(OrderSide::Buy, price, Quantity::from(100_000), i + 1);
+            let flags = if i == 11 { RecordFlag::F_LAST as u8 } else { 0 };
+            OrderBookDelta::new(
+                instrument_id,
+                BookAction::Add,
+                order,
+                flags,
+                1,
+                UnixNanos::from(1),
+                UnixNanos::from(1),
+            )
+        })
+        .collect();
+    let (first, rest) = deltas.split_at(1);
+
+    // Not published until the event is complete
+    data_engine.process_data(Data::Delta(first[0]));
+    assert!(get_saved_messages::<OrderBookDepth10>(handler.clone()).is_empty());
+
+    let deltas = OrderBookDeltas::new(instrument_id, rest.to_vec());
+    data_engine.process_data(Data::Deltas(OrderBookDeltas_API::new(deltas)));
+    let messages = get_saved_messages::<OrderBookDepth10>(handler);
+
+    assert_eq!(messages.len(), 1);
+    let depth = messages[0];
+    assert_eq!(depth.bids[0].price, Price::from("0.80000"));
+    assert_eq!(depth.bids[0].size, Quantity::from(200_000));
+    assert_eq!(depth.bid_counts[0], 2);
+    assert_eq!(depth.bids[9].price, Price::from("0.79910"));
+    assert_eq!(depth.asks[0].side, OrderSide::NoOrderSide);
+
+    // The derived depth is not applied back to the book
+    assert_eq!(
+        data_engine
+            .get_cache()
+            .order_book(&instrument_id)
+            .unwrap()
+            .bids(None)
+            .count(),
+        11
+    );
+
+    data_engine.unsubscribe_book_depth(&instrument_id).unwrap();
+    assert!(data_engine.subscribed_book_depth().is_empty());
+    assert!(data_engine.unsubscribe_book_depth(&instrument_id).is_err());
+}
+
+#[rstest]
+fn test_derived_depth_published_at_interval_with_grouping(
+    audusd_sim: CurrencyPair,
+    clock: Rc<RefCell<TestClock>>,
+    cache: Rc<RefCell<Cache>>,
+    msgbus: Rc<RefCell<MessageBus>>,
+) {
+    let instrument_id = audusd_sim.id;
+    let mut data_engine = DataEngine::new(clock.clone(), cache, msgbus.clone(), None);
+    assert!(data_engine
+        .subscribe_book_depth(instrument_id, BookType::L2_MBP, None, Some(dec!(0)))
+        .is_err());
+    data_engine
+        .subscribe_book_depth(
+            instrument_id,
+            BookType::L2_MBP,
+            Some(NonZeroU64::new(1_000).unwrap()),
+            Some(dec!(0.001)),
+        )
+        .unwrap();
+
+    let handler = get_message_saving_handler::<OrderBookDepth10>(None);
+    {
+        let mut msgbus = msgbus.borrow_mut();
+        let topic = msgbus.switchboard.get_depth_topic(instrument_id);
+        msgbus.subscribe(topic, handler.clone(), None);
+    }
+
+    let deltas: Vec<OrderBookDelta> = [
+        (OrderSide::Buy, "0.80050"),
+        (OrderSide::Buy, "0.80010"),
+        (OrderSide::Buy, "0.79990"),
+        (OrderSide::Sell, "0.80070"),
+    ]
+    .into_iter()
+    .enumerate()
+    .map(|(i, (side, price))| {
+        let order = BookOrder::new(side, Price::from(price), Quantity::from(100_000), 0);
+        let flags = if i == 3 { RecordFlag::F_LAST as u8 } else { 0 };
+        OrderBookDelta::new(
+            instrument_id,
+            BookAction::Add,
+            order,
+            flags,
+            1,
+            UnixNanos::from(1),
+            UnixNanos::from(1),
+        )
+    })
+    .collect();
+    let deltas = OrderBookDeltas::new(instrument_id, deltas);
+    data_engine.process_data(Data::Deltas(OrderBookDeltas_API::new(deltas)));
+
+    // Not published on update when publishing at an interval
+    assert!(get_saved_messages::<OrderBookDepth10>(handler.clone()).is_empty());
+
+    let events = clock
+        .borrow_mut()
+        .advance_time(UnixNanos::from(1_000_000_000), true);
+    for event_handler in clock.borrow().match_handlers(events) {
+        event_handler.callback.call(event_handler.event);
+    }
+
+    let messages = get_saved_messages::<OrderBookDepth10>(handler);
+    assert_eq!(messages.len(), 1);
+    let depth = messages[0];
+    assert_eq!(depth.bids[0].price, Price::from("0.80000"));
+    assert_eq!(depth.bids[0].size, Quantity::from(200_000));
+    assert_eq!(depth.bid_counts[..3], [2, 1, 0]);
+    assert_eq!(depth.bids[1].price, Price::from("0.79900"));
+    assert_eq!(depth.asks[0].price, Price::from("0.80100"));
+    assert_eq!(depth.ts_init, UnixNanos::from(1_000_000_000));
+
+    data_engine.unsubscribe_book_depth(&instrument_id).unwrap();
+    assert!(clock.borrow().timer_names().is_empty());
+}
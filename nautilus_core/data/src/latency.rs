@@ -0,0 +1,375 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Latency histograms for measuring how long market data takes to flow through the system.
+
+use std::fmt::Display;
+
+use indexmap::IndexMap;
+use nautilus_core::UnixNanos;
+use nautilus_model::{
+    data::{Data, GetTsInit},
+    identifiers::InstrumentId,
+};
+use ustr::Ustr;
+
+/// The number of power of two buckets, covering the full range of `u64` nanoseconds.
+const NUM_BUCKETS: usize = 65;
+
+/// The stage of the data flow over which a latency is measured.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum LatencyStage {
+    /// From the venue event (`ts_event`) to receipt by the system (`ts_init`).
+    Feed,
+    /// From receipt by the system (`ts_init`) to handoff by the data client to the engine.
+    Client,
+    /// From receipt by the system (`ts_init`) to completion of all handlers.
+    Processing,
+}
+
+impl Display for LatencyStage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Feed => write!(f, "feed"),
+            Self::Client => write!(f, "client"),
+            Self::Processing => write!(f, "processing"),
+        }
+    }
+}
+
+/// Represents a histogram of latencies in nanoseconds.
+///
+/// Latencies are counted in buckets bounded by powers of two, so percentiles are reported
+/// as the upper bound of the bucket they fall in (clamped to the maximum recorded latency),
+/// within a factor of two of the true value.
+#[derive(Clone, Debug)]
+pub struct LatencyHistogram {
+    buckets: [u64; NUM_BUCKETS],
+    count: u64,
+    sum: u128,
+    min: u64,
+    max: u64,
+}
+
+impl Default for LatencyHistogram {
+    /// Creates a new default [`LatencyHistogram`] instance.
+    fn default() -> Self {
+        Self {
+            buckets: [0; NUM_BUCKETS],
+            count: 0,
+            sum: 0,
+            min: u64::MAX,
+            max: 0,
+        }
+    }
+}
+
+impl LatencyHistogram {
+    /// Records the given `latency_ns` in the histogram.
+    pub fn record(&mut self, latency_ns: u64) {
+        self.buckets[bucket_index(latency_ns)] += 1;
+        self.count += 1;
+        self.sum += u128::from(latency_ns);
+        self.min = self.min.min(latency_ns);
+        self.max = self.max.max(latency_ns);
+    }
+
+    /// Returns the number of latencies recorded.
+    #[must_use]
+    pub const fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Returns the minimum latency recorded (if any).
+    #[must_use]
+    pub fn min(&self) -> Option<u64> {
+        (self.count > 0).then_some(self.min)
+    }
+
+    /// Returns the maximum latency recorded (if any).
+    #[must_use]
+    pub fn max(&self) -> Option<u64> {
+        (self.count > 0).then_some(self.max)
+    }
+
+    /// Returns the mean latency recorded (if any).
+    #[must_use]
+    pub fn mean(&self) -> Option<f64> {
+        (self.count > 0).then(|| self.sum as f64 / self.count as f64)
+    }
+
+    /// Returns the latency at the given `quantile` (between 0 and 1) of those recorded (if any).
+    #[must_use]
+    pub fn percentile(&self, quantile: f64) -> Option<u64> {
+        if self.count == 0 {
+            return None;
+        }
+
+        let rank = ((quantile.clamp(0.0, 1.0) * self.count as f64).ceil() as u64).max(1);
+        let mut cumulative = 0;
+        for (i, count) in self.buckets.iter().enumerate() {
+            cumulative += count;
+            if cumulative >= rank {
+                return Some(bucket_upper_bound(i).clamp(self.min, self.max));
+            }
+        }
+
+        Some(self.max)
+    }
+
+    /// Resets the histogram to its initial state.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+impl Display for LatencyHistogram {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.count == 0 {
+            return write!(f, "count=0");
+        }
+
+        write!(
+            f,
+            "count={}, min={}ns, mean={:.0}ns, p50={}ns, p99={}ns, max={}ns",
+            self.count,
+            self.min,
+            self.mean().unwrap_or_default(),
+            self.percentile(0.5).unwrap_or_default(),
+            self.percentile(0.99).unwrap_or_default(),
+            self.max,
+        )
+    }
+}
+
+/// Records latency histograms per instrument, data type and [`LatencyStage`].
+#[derive(Clone, Debug, Default)]
+pub struct LatencyTracker {
+    histograms: IndexMap<(InstrumentId, Ustr, LatencyStage), LatencyHistogram>,
+}
+
+impl LatencyTracker {
+    /// Records the given `latency_ns` for the instrument, data type and stage.
+    pub fn record(
+        &mut self,
+        instrument_id: InstrumentId,
+        data_type: Ustr,
+        stage: LatencyStage,
+        latency_ns: u64,
+    ) {
+        self.histograms
+            .entry((instrument_id, data_type, stage))
+            .or_default()
+            .record(latency_ns);
+    }
+
+    /// Records the feed latency of the given `data`, from `ts_event` to `ts_init`.
+    ///
+    /// Data initialized before its event timestamp (due to clock skew) is recorded as zero.
+    pub fn record_feed(&mut self, data: &Data) {
+        let latency_ns = data.ts_init().saturating_sub(*data_ts_event(data));
+        self.record(
+            data.instrument_id(),
+            data_type_name(data),
+            LatencyStage::Feed,
+            latency_ns,
+        );
+    }
+
+    /// Records the client latency of the given `data`, from `ts_init` to `ts_now`.
+    pub fn record_client(&mut self, data: &Data, ts_now: UnixNanos) {
+        let latency_ns = ts_now.saturating_sub(*data.ts_init());
+        self.record(
+            data.instrument_id(),
+            data_type_name(data),
+            LatencyStage::Client,
+            latency_ns,
+        );
+    }
+
+    /// Records the processing latency of the given `data`, from `ts_init` to `ts_now`.
+    pub fn record_processing(&mut self, data: &Data, ts_now: UnixNanos) {
+        let latency_ns = ts_now.saturating_sub(*data.ts_init());
+        self.record(
+            data.instrument_id(),
+            data_type_name(data),
+            LatencyStage::Processing,
+            latency_ns,
+        );
+    }
+
+    /// Returns the histogram for the instrument, data type and stage (if any latencies recorded).
+    #[must_use]
+    pub fn histogram(
+        &self,
+        instrument_id: &InstrumentId,
+        data_type: &str,
+        stage: LatencyStage,
+    ) -> Option<&LatencyHistogram> {
+        self.histograms
+            .get(&(*instrument_id, Ustr::from(data_type), stage))
+    }
+
+    /// Returns an iterator over all histograms keyed by instrument, data type and stage.
+    pub fn histograms(
+        &self,
+    ) -> impl Iterator<Item = (&(InstrumentId, Ustr, LatencyStage), &LatencyHistogram)> {
+        self.histograms.iter()
+    }
+
+    /// Writes a summary of every histogram to the log.
+    pub fn log_summary(&self) {
+        for ((instrument_id, data_type, stage), histogram) in &self.histograms {
+            log::info!("Latency {instrument_id} {data_type} {stage}: {histogram}");
+        }
+    }
+
+    /// Clears all histograms.
+    pub fn reset(&mut self) {
+        self.histograms.clear();
+    }
+}
+
+/// Returns the bucket index for the given `latency_ns`, being the number of significant bits.
+const fn bucket_index(latency_ns: u64) -> usize {
+    (u64::BITS - latency_ns.leading_zeros()) as usize
+}
+
+/// Returns the largest latency counted in the bucket at the given `index`.
+const fn bucket_upper_bound(index: usize) -> u64 {
+    match index {
+        0 => 0,
+        64.. => u64::MAX,
+        _ => (1 << index) - 1,
+    }
+}
+
+fn data_ts_event(data: &Data) -> UnixNanos {
+    match data {
+        Data::Delta(delta) => delta.ts_event,
+        Data::Deltas(deltas) => deltas.ts_event,
+        Data::Depth10(depth) => depth.ts_event,
+        Data::Quote(quote) => quote.ts_event,
+        Data::Trade(trade) => trade.ts_event,
+        Data::Bar(bar) => bar.ts_event,
+        Data::MarkPrice(mark_price) => mark_price.ts_event,
+        Data::IndexPrice(index_price) => index_price.ts_event,
+        Data::FundingRate(funding_rate) => funding_rate.ts_event,
+    }
+}
+
+fn data_type_name(data: &Data) -> Ustr {
+    let name = match data {
+        Data::Delta(_) => stringify!(OrderBookDelta),
+        Data::Deltas(_) => stringify!(OrderBookDeltas),
+        Data::Depth10(_) => stringify!(OrderBookDepth10),
+        Data::Quote(_) => stringify!(QuoteTick),
+        Data::Trade(_) => stringify!(TradeTick),
+        Data::Bar(_) => stringify!(Bar),
+        Data::MarkPrice(_) => stringify!(MarkPriceUpdate),
+        Data::IndexPrice(_) => stringify!(IndexPriceUpdate),
+        Data::FundingRate(_) => stringify!(FundingRateUpdate),
+    };
+    Ustr::from(name)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::data::{QuoteTick, TradeTick};
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_histogram_when_empty() {
+        let histogram = LatencyHistogram::default();
+
+        assert_eq!(histogram.count(), 0);
+        assert_eq!(histogram.min(), None);
+        assert_eq!(histogram.max(), None);
+        assert_eq!(histogram.mean(), None);
+        assert_eq!(histogram.percentile(0.5), None);
+        assert_eq!(histogram.to_string(), "count=0");
+    }
+
+    #[rstest]
+    fn test_histogram_records_latencies() {
+        let mut histogram = LatencyHistogram::default();
+        for latency_ns in [100, 200, 300, 400, 10_000] {
+            histogram.record(latency_ns);
+        }
+
+        assert_eq!(histogram.count(), 5);
+        assert_eq!(histogram.min(), Some(100));
+        assert_eq!(histogram.max(), Some(10_000));
+        assert_eq!(histogram.mean(), Some(2_200.0));
+        assert_eq!(histogram.percentile(0.0), Some(127));
+        assert_eq!(histogram.percentile(0.5), Some(511));
+        assert_eq!(histogram.percentile(0.99), Some(10_000));
+    }
+
+    #[rstest]
+    #[case(0, 0)]
+    #[case(1, 1)]
+    #[case(2, 2)]
+    #[case(3, 2)]
+    #[case(1_024, 11)]
+    #[case(u64::MAX, 64)]
+    fn test_bucket_index(#[case] latency_ns: u64, #[case] expected: usize) {
+        assert_eq!(bucket_index(latency_ns), expected);
+        assert!(latency_ns <= bucket_upper_bound(expected));
+    }
+
+    #[rstest]
+    fn test_tracker_records_feed_and_processing_per_data_type() {
+        let mut tracker = LatencyTracker::default();
+        let quote = QuoteTick {
+            ts_event: UnixNanos::from(1_000),
+            ts_init: UnixNanos::from(1_500),
+            ..Default::default()
+        };
+        let trade = TradeTick {
+            ts_event: UnixNanos::from(2_000),
+            ts_init: UnixNanos::from(1_000), // Clock skew
+            ..Default::default()
+        };
+
+        tracker.record_feed(&Data::Quote(quote));
+        tracker.record_processing(&Data::Quote(quote), UnixNanos::from(1_800));
+        tracker.record_feed(&Data::Trade(trade));
+
+        let instrument_id = quote.instrument_id;
+        let feed = tracker
+            .histogram(&instrument_id, "QuoteTick", LatencyStage::Feed)
+            .unwrap();
+        let processing = tracker
+            .histogram(&instrument_id, "QuoteTick", LatencyStage::Processing)
+            .unwrap();
+        let trade_feed = tracker
+            .histogram(&trade.instrument_id, "TradeTick", LatencyStage::Feed)
+            .unwrap();
+
+        assert_eq!(feed.max(), Some(500));
+        assert_eq!(processing.max(), Some(300));
+        assert_eq!(trade_feed.max(), Some(0));
+        assert_eq!(tracker.histograms().count(), 3);
+
+        tracker.reset();
+        assert_eq!(tracker.histograms().count(), 0);
+    }
+}
@@ -38,5 +38,8 @@
 
 pub mod aggregation;
 pub mod client;
+pub mod continuous;
 pub mod engine;
+pub mod latency;
 pub mod mocks;
+pub mod session;
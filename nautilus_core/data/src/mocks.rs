@@ -17,7 +17,7 @@
 #![allow(dead_code)]
 #![allow(unused_variables)]
 
-use std::{cell::RefCell, collections::HashMap, rc::Rc};
+use std::{cell::RefCell, collections::HashMap, rc::Rc, sync::Arc};
 
 use nautilus_common::{
     cache::Cache,
@@ -26,7 +26,7 @@ use nautilus_common::{
 };
 use nautilus_core::{UnixNanos, UUID4};
 use nautilus_model::{
-    data::{Bar, BarType, DataType, QuoteTick, TradeTick},
+    data::{Bar, BarType, DataType, OrderBookDeltas, QuoteTick, TradeTick},
     enums::BookType,
     identifiers::{ClientId, InstrumentId, Venue},
     instruments::InstrumentAny,
@@ -39,6 +39,8 @@ pub struct MockDataClient {
     msgbus: Rc<RefCell<MessageBus>>,
     pub client_id: ClientId,
     pub venue: Venue,
+    pub book_snapshot: Option<OrderBookDeltas>,
+    pub quotes: Vec<QuoteTick>,
 }
 
 impl MockDataClient {
@@ -54,6 +56,8 @@ impl MockDataClient {
             msgbus,
             client_id,
             venue,
+            book_snapshot: None,
+            quotes: Vec::new(),
         }
     }
 }
@@ -140,6 +144,30 @@ impl DataClient for MockDataClient {
         Ok(())
     }
 
+    fn subscribe_mark_prices(
+        &mut self,
+        instrument_id: &InstrumentId,
+        params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn subscribe_index_prices(
+        &mut self,
+        instrument_id: &InstrumentId,
+        params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn subscribe_funding_rates(
+        &mut self,
+        instrument_id: &InstrumentId,
+        params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
     fn subscribe_bars(
         &mut self,
         bar_type: &BarType,
@@ -220,6 +248,30 @@ impl DataClient for MockDataClient {
         Ok(())
     }
 
+    fn unsubscribe_mark_prices(
+        &mut self,
+        instrument_id: &InstrumentId,
+        params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn unsubscribe_index_prices(
+        &mut self,
+        instrument_id: &InstrumentId,
+        params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn unsubscribe_funding_rates(
+        &mut self,
+        instrument_id: &InstrumentId,
+        params: &Option<HashMap<String, String>>,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
     fn unsubscribe_bars(
         &mut self,
         bar_type: &BarType,
@@ -280,7 +332,10 @@ impl DataClient for MockDataClient {
         depth: Option<usize>,
         params: &Option<HashMap<String, String>>,
     ) -> Payload {
-        todo!()
+        match &self.book_snapshot {
+            Some(snapshot) => Arc::new(snapshot.clone()),
+            None => Arc::new(()),
+        }
     }
 
     fn request_quote_ticks(
@@ -292,7 +347,15 @@ impl DataClient for MockDataClient {
         limit: Option<usize>,
         params: &Option<HashMap<String, String>>,
     ) -> Vec<QuoteTick> {
-        todo!()
+        self.quotes
+            .iter()
+            .filter(|quote| {
+                quote.instrument_id == instrument_id
+                    && start.is_none_or(|start| quote.ts_init >= start)
+                    && end.is_none_or(|end| quote.ts_init <= end)
+            })
+            .copied()
+            .collect()
     }
 
     fn request_trade_ticks(
@@ -304,7 +367,7 @@ impl DataClient for MockDataClient {
         limit: Option<usize>,
         params: &Option<HashMap<String, String>>,
     ) -> Vec<TradeTick> {
-        todo!()
+        Vec::new()
     }
 
     fn request_bars(
@@ -316,6 +379,6 @@ impl DataClient for MockDataClient {
         limit: Option<usize>,
         params: &Option<HashMap<String, String>>,
     ) -> Vec<Bar> {
-        todo!()
+        Vec::new()
     }
 }
@@ -0,0 +1,313 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Trading sessions for aligning time bars to a venue's local session close.
+
+use std::collections::HashSet;
+
+use chrono::{
+    DateTime, Datelike, LocalResult, NaiveDate, NaiveDateTime, NaiveTime, TimeDelta, TimeZone, Utc,
+    Weekday,
+};
+use chrono_tz::Tz;
+use nautilus_model::enums::BarAggregation;
+
+/// The maximum number of days searched for a session close.
+const MAX_SEARCH_DAYS: usize = 366;
+
+/// Represents the trading sessions of a venue in its local timezone.
+///
+/// Each session is identified by its session date, and closes at the `session_close` local
+/// time on that date. A session close of midnight ends the session at the end of its date.
+/// Sessions are left-open, so a timestamp exactly at a session close belongs to the session
+/// which is closing.
+#[derive(Clone, Debug)]
+pub struct TradingSession {
+    /// The timezone of the venue.
+    pub timezone: Tz,
+    /// The local time at which each session closes.
+    pub session_close: NaiveTime,
+    /// The days of the week with no trading session.
+    pub non_trading_days: HashSet<Weekday>,
+    /// The calendar dates with no trading session (e.g. exchange holidays).
+    pub holidays: HashSet<NaiveDate>,
+}
+
+impl TradingSession {
+    /// Creates a new [`TradingSession`] instance with no trading sessions at weekends.
+    #[must_use]
+    pub fn new(timezone: Tz, session_close: NaiveTime) -> Self {
+        Self {
+            timezone,
+            session_close,
+            non_trading_days: HashSet::from([Weekday::Sat, Weekday::Sun]),
+            holidays: HashSet::new(),
+        }
+    }
+
+    /// Creates a new [`TradingSession`] instance which trades every day and closes at UTC midnight.
+    #[must_use]
+    pub fn utc() -> Self {
+        Self {
+            timezone: Tz::UTC,
+            session_close: NaiveTime::MIN,
+            non_trading_days: HashSet::new(),
+            holidays: HashSet::new(),
+        }
+    }
+
+    /// Adds the given `holidays` to the session calendar.
+    #[must_use]
+    pub fn with_holidays(mut self, holidays: impl IntoIterator<Item = NaiveDate>) -> Self {
+        self.holidays.extend(holidays);
+        self
+    }
+
+    /// Returns whether there is a trading session on the given `date`.
+    #[must_use]
+    pub fn is_trading_day(&self, date: NaiveDate) -> bool {
+        !self.non_trading_days.contains(&date.weekday()) && !self.holidays.contains(&date)
+    }
+
+    /// Returns whether the given `ts` falls within a trading session.
+    #[must_use]
+    pub fn is_trading_time(&self, ts: DateTime<Utc>) -> bool {
+        self.is_trading_day(self.session_date(ts))
+    }
+
+    /// Returns the UTC close of the session for the given `date`.
+    ///
+    /// An ambiguous local close resolves to the earlier time, and a local close which does not
+    /// exist (within a DST transition gap) is shifted forward by the transition.
+    #[must_use]
+    pub fn session_close_utc(&self, date: NaiveDate) -> DateTime<Utc> {
+        let close = if self.session_close == NaiveTime::MIN {
+            date.succ_opt()
+                .expect("Date out of range")
+                .and_time(NaiveTime::MIN)
+        } else {
+            date.and_time(self.session_close)
+        };
+        resolve_local(&self.timezone, close)
+    }
+
+    /// Returns the date of the session containing the given `ts`.
+    #[must_use]
+    pub fn session_date(&self, ts: DateTime<Utc>) -> NaiveDate {
+        let date = ts.with_timezone(&self.timezone).date_naive();
+
+        if ts > self.session_close_utc(date) {
+            return date.succ_opt().expect("Date out of range");
+        }
+
+        let prev_date = date.pred_opt().expect("Date out of range");
+        if ts <= self.session_close_utc(prev_date) {
+            return prev_date;
+        }
+
+        date
+    }
+
+    /// Returns the first bar close for the given `aggregation` strictly after `ts`.
+    ///
+    /// Day bars close at every trading session close, week bars at the close of the last trading
+    /// session of each ISO week, and month bars at the close of the last trading session of each
+    /// calendar month.
+    #[must_use]
+    pub fn next_bar_close(
+        &self,
+        ts: DateTime<Utc>,
+        aggregation: BarAggregation,
+    ) -> Option<DateTime<Utc>> {
+        self.session_date(ts)
+            .iter_days()
+            .take(MAX_SEARCH_DAYS)
+            .filter(|date| self.is_bar_close(*date, aggregation))
+            .map(|date| self.session_close_utc(date))
+            .find(|close| *close > ts)
+    }
+
+    /// Returns the last bar close for the given `aggregation` at or before `ts`.
+    #[must_use]
+    pub fn previous_bar_close(
+        &self,
+        ts: DateTime<Utc>,
+        aggregation: BarAggregation,
+    ) -> Option<DateTime<Utc>> {
+        std::iter::successors(Some(self.session_date(ts)), NaiveDate::pred_opt)
+            .take(MAX_SEARCH_DAYS)
+            .filter(|date| self.is_bar_close(*date, aggregation))
+            .map(|date| self.session_close_utc(date))
+            .find(|close| *close <= ts)
+    }
+
+    fn is_bar_close(&self, date: NaiveDate, aggregation: BarAggregation) -> bool {
+        if !self.is_trading_day(date) {
+            return false;
+        }
+
+        match aggregation {
+            BarAggregation::Week => {
+                self.is_last_trading_day(date, |next| next.iso_week() == date.iso_week())
+            }
+            BarAggregation::Month => self.is_last_trading_day(date, |next| {
+                next.month() == date.month() && next.year() == date.year()
+            }),
+            _ => true,
+        }
+    }
+
+    fn is_last_trading_day(
+        &self,
+        date: NaiveDate,
+        is_same_period: impl Fn(NaiveDate) -> bool,
+    ) -> bool {
+        date.iter_days()
+            .skip(1)
+            .take_while(|next| is_same_period(*next))
+            .all(|next| !self.is_trading_day(next))
+    }
+}
+
+fn resolve_local(timezone: &Tz, local: NaiveDateTime) -> DateTime<Utc> {
+    match timezone.from_local_datetime(&local) {
+        LocalResult::Single(dt) | LocalResult::Ambiguous(dt, _) => dt.with_timezone(&Utc),
+        LocalResult::None => resolve_local(timezone, local + TimeDelta::hours(1)),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveDate, NaiveTime, TimeZone, Utc};
+    use chrono_tz::Tz;
+    use nautilus_model::enums::BarAggregation;
+    use rstest::{fixture, rstest};
+
+    use super::TradingSession;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[fixture]
+    fn cme_session() -> TradingSession {
+        TradingSession::new(
+            Tz::America__Chicago,
+            NaiveTime::from_hms_opt(16, 0, 0).unwrap(),
+        )
+    }
+
+    #[rstest]
+    #[case::standard_time(date(2024, 3, 8), Utc.with_ymd_and_hms(2024, 3, 8, 22, 0, 0).unwrap())]
+    #[case::daylight_time(date(2024, 3, 11), Utc.with_ymd_and_hms(2024, 3, 11, 21, 0, 0).unwrap())]
+    fn test_session_close_utc_handles_dst(
+        cme_session: TradingSession,
+        #[case] session_date: NaiveDate,
+        #[case] expected: chrono::DateTime<Utc>,
+    ) {
+        assert_eq!(cme_session.session_close_utc(session_date), expected);
+    }
+
+    #[rstest]
+    #[case::before_close(Utc.with_ymd_and_hms(2024, 3, 12, 15, 0, 0).unwrap(), date(2024, 3, 12))]
+    #[case::at_close(Utc.with_ymd_and_hms(2024, 3, 12, 21, 0, 0).unwrap(), date(2024, 3, 12))]
+    #[case::after_close(Utc.with_ymd_and_hms(2024, 3, 12, 21, 0, 1).unwrap(), date(2024, 3, 13))]
+    #[case::after_utc_midnight(Utc.with_ymd_and_hms(2024, 3, 13, 1, 0, 0).unwrap(), date(2024, 3, 13))]
+    fn test_session_date(
+        cme_session: TradingSession,
+        #[case] ts: chrono::DateTime<Utc>,
+        #[case] expected: NaiveDate,
+    ) {
+        assert_eq!(cme_session.session_date(ts), expected);
+    }
+
+    #[rstest]
+    fn test_session_date_with_midnight_close() {
+        let session = TradingSession::utc();
+
+        let midnight = Utc.with_ymd_and_hms(2024, 3, 12, 0, 0, 0).unwrap();
+
+        assert_eq!(session.session_date(midnight), date(2024, 3, 11));
+        assert_eq!(session.session_close_utc(date(2024, 3, 11)), midnight);
+    }
+
+    #[rstest]
+    fn test_is_trading_time_skips_weekend_and_holidays(cme_session: TradingSession) {
+        let session = cme_session.with_holidays([date(2024, 3, 11)]);
+
+        // Friday session, then the weekend and holiday Monday
+        assert!(session.is_trading_time(Utc.with_ymd_and_hms(2024, 3, 8, 20, 0, 0).unwrap()));
+        assert!(!session.is_trading_time(Utc.with_ymd_and_hms(2024, 3, 9, 12, 0, 0).unwrap()));
+        assert!(!session.is_trading_time(Utc.with_ymd_and_hms(2024, 3, 11, 12, 0, 0).unwrap()));
+        assert!(session.is_trading_time(Utc.with_ymd_and_hms(2024, 3, 11, 21, 0, 1).unwrap()));
+    }
+
+    #[rstest]
+    fn test_next_day_bar_close_skips_non_trading_days(cme_session: TradingSession) {
+        let friday_close = Utc.with_ymd_and_hms(2024, 3, 8, 22, 0, 0).unwrap();
+
+        let next = cme_session.next_bar_close(friday_close, BarAggregation::Day);
+        assert_eq!(
+            next,
+            Some(Utc.with_ymd_and_hms(2024, 3, 11, 21, 0, 0).unwrap())
+        );
+
+        let session = cme_session.with_holidays([date(2024, 3, 11)]);
+        let next = session.next_bar_close(friday_close, BarAggregation::Day);
+        assert_eq!(
+            next,
+            Some(Utc.with_ymd_and_hms(2024, 3, 12, 21, 0, 0).unwrap())
+        );
+    }
+
+    #[rstest]
+    fn test_previous_day_bar_close(cme_session: TradingSession) {
+        let sunday = Utc.with_ymd_and_hms(2024, 3, 10, 12, 0, 0).unwrap();
+        let friday_close = Utc.with_ymd_and_hms(2024, 3, 8, 22, 0, 0).unwrap();
+
+        assert_eq!(
+            cme_session.previous_bar_close(sunday, BarAggregation::Day),
+            Some(friday_close)
+        );
+        assert_eq!(
+            cme_session.previous_bar_close(friday_close, BarAggregation::Day),
+            Some(friday_close)
+        );
+    }
+
+    #[rstest]
+    fn test_week_and_month_bar_close_on_last_trading_day(cme_session: TradingSession) {
+        // Good Friday 2024-03-29 is a holiday, so the week and month close on the Thursday
+        let session = cme_session.with_holidays([date(2024, 3, 29)]);
+        let ts = Utc.with_ymd_and_hms(2024, 3, 25, 12, 0, 0).unwrap();
+        let thursday_close = Utc.with_ymd_and_hms(2024, 3, 28, 21, 0, 0).unwrap();
+
+        assert_eq!(
+            session.next_bar_close(ts, BarAggregation::Week),
+            Some(thursday_close)
+        );
+        assert_eq!(
+            session.next_bar_close(ts, BarAggregation::Month),
+            Some(thursday_close)
+        );
+        assert_eq!(
+            session.next_bar_close(thursday_close, BarAggregation::Month),
+            Some(Utc.with_ymd_and_hms(2024, 4, 30, 21, 0, 0).unwrap())
+        );
+    }
+}
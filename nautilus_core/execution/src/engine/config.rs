@@ -13,6 +13,7 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+use nautilus_model::enums::LotMatchingMethod;
 use serde::{Deserialize, Serialize};
 
 /// Configuration for `ExecutionEngine` instances.
@@ -37,6 +38,11 @@ pub struct ExecutionEngineConfig {
     #[serde(default)]
     pub snapshot_positions_interval_secs: Option<f64>,
 
+    /// The method for matching closing fills against the open lots of positions
+    /// If None then lots will not be tracked
+    #[serde(default)]
+    pub lot_matching: Option<LotMatchingMethod>,
+
     /// If debug mode is active (will provide extra debug logging)
     #[serde(default)]
     pub debug: bool,
@@ -53,6 +59,7 @@ impl Default for ExecutionEngineConfig {
             snapshot_orders: false,
             snapshot_positions: false,
             snapshot_positions_interval_secs: None,
+            lot_matching: None,
             debug: false,
         }
     }
@@ -724,7 +724,10 @@ impl ExecutionEngine {
             self.cache.borrow_mut().update_position(&position)?;
             position
         } else {
-            let position = Position::new(&instrument, fill);
+            let mut position = Position::new(&instrument, fill);
+            if self.config.lot_matching.is_some() {
+                position.set_lot_matching(self.config.lot_matching);
+            }
             self.cache
                 .borrow_mut()
                 .add_position(position.clone(), oms_type)?;
@@ -18,6 +18,7 @@ use std::collections::HashMap;
 use nautilus_common::{custom::CustomData, signal::Signal};
 use nautilus_model::{
     accounts::{any::AccountAny, base::Account},
+    currencies::register_currencies,
     data::{Bar, DataType, QuoteTick, TradeTick},
     events::{
         position::snapshot::PositionSnapshot, AccountState, OrderEvent, OrderEventAny,
@@ -107,6 +108,20 @@ impl DatabaseQueries {
             .map_err(|e| anyhow::anyhow!("Failed to load currencies: {e}"))
     }
 
+    /// Loads all currencies from the `currency` table and registers them in the currency
+    /// registry, returning the registered currencies.
+    ///
+    /// The currencies are validated for conflicts before any are registered, see
+    /// [`register_currencies`].
+    pub async fn register_currencies(
+        pool: &PgPool,
+        overwrite: bool,
+    ) -> anyhow::Result<Vec<Currency>> {
+        let currencies = Self::load_currencies(pool).await?;
+        register_currencies(&currencies, overwrite)?;
+        Ok(currencies)
+    }
+
     pub async fn load_currency(pool: &PgPool, code: &str) -> anyhow::Result<Option<Currency>> {
         sqlx::query_as::<_, CurrencyModel>("SELECT * FROM currency WHERE id = $1")
             .bind(code)
@@ -82,9 +82,112 @@ fn bench_orderbook_apply_deltas() {
     black_box(());
 }
 
+fn bench_orderbook_fixed_tick_add() {
+    let instrument_id = InstrumentId::from("AAPL.XNAS");
+    let mut book = OrderBook::new_fixed_tick(instrument_id, BookType::L3_MBO, Price::from("0.01"));
+    let order = BookOrder::new(OrderSide::Buy, Price::from("100.0"), Quantity::from(100), 1);
+
+    book.add(order, 0, 1, 1.into());
+    black_box(());
+}
+
+fn bench_orderbook_fixed_tick_update() {
+    let instrument_id = InstrumentId::from("AAPL.XNAS");
+    let mut book = OrderBook::new_fixed_tick(instrument_id, BookType::L3_MBO, Price::from("0.01"));
+    let order = BookOrder::new(OrderSide::Buy, Price::from("100.0"), Quantity::from(100), 1);
+    book.add(order, 0, 1, 1.into());
+
+    let updated_order = BookOrder::new(
+        OrderSide::Buy,
+        Price::from("101.0"),
+        Quantity::from("2.0"),
+        1,
+    );
+
+    book.update(updated_order, 0, 2, 2.into());
+    black_box(());
+}
+
+fn bench_orderbook_fixed_tick_delete() {
+    let instrument_id = InstrumentId::from("AAPL.XNAS");
+    let mut book = OrderBook::new_fixed_tick(instrument_id, BookType::L3_MBO, Price::from("0.01"));
+    let order = BookOrder::new(OrderSide::Buy, Price::from("100.0"), Quantity::from(100), 1);
+    book.add(order, 0, 1, 1.into());
+
+    book.delete(order, 0, 2, 2.into());
+    black_box(());
+}
+
+/// Builds L2 deltas which fill 100 levels per side, then repeatedly update the sizes of the
+/// top 5 levels, to approximate a market-making workload.
+fn l2_deltas(instrument_id: InstrumentId) -> OrderBookDeltas {
+    let mut deltas = Vec::new();
+    let mut sequence = 0;
+    let mut push_delta = |action, side, price_raw: i64, size: u64| {
+        sequence += 1;
+        let price = Price::from(format!("{}.{:02}", price_raw / 100, price_raw % 100));
+        deltas.push(OrderBookDelta {
+            instrument_id,
+            action,
+            order: BookOrder::new(side, price, Quantity::from(size), 0),
+            flags: 0,
+            sequence,
+            ts_event: sequence.into(),
+            ts_init: sequence.into(),
+        });
+    };
+
+    for i in 0..100 {
+        push_delta(BookAction::Add, OrderSide::Buy, 10_000 - i, 100);
+        push_delta(BookAction::Add, OrderSide::Sell, 10_001 + i, 100);
+    }
+
+    for round in 0..100 {
+        for i in 0..5 {
+            let size = 100 + round;
+            push_delta(BookAction::Update, OrderSide::Buy, 10_000 - i, size);
+            push_delta(BookAction::Update, OrderSide::Sell, 10_001 + i, size);
+        }
+    }
+
+    OrderBookDeltas {
+        instrument_id,
+        deltas,
+        flags: 0,
+        sequence,
+        ts_event: sequence.into(),
+        ts_init: sequence.into(),
+    }
+}
+
+fn bench_orderbook_l2_top_levels() {
+    let instrument_id = InstrumentId::from("AAPL.XNAS");
+    let mut book = OrderBook::new(instrument_id, BookType::L2_MBP);
+    let deltas = l2_deltas(instrument_id);
+
+    book.apply_deltas(&deltas);
+    black_box(book.bids_as_map(Some(10)));
+    black_box(book.asks_as_map(Some(10)));
+}
+
+fn bench_orderbook_fixed_tick_l2_top_levels() {
+    let instrument_id = InstrumentId::from("AAPL.XNAS");
+    let mut book = OrderBook::new_fixed_tick(instrument_id, BookType::L2_MBP, Price::from("0.01"));
+    let deltas = l2_deltas(instrument_id);
+
+    book.apply_deltas(&deltas);
+    black_box(book.bids_as_map(Some(10)));
+    black_box(book.asks_as_map(Some(10)));
+}
+
 iai::main!(
     bench_orderbook_add,
     bench_orderbook_update,
     bench_orderbook_delete,
     bench_orderbook_apply_deltas,
+    bench_orderbook_fixed_tick_add,
+    bench_orderbook_fixed_tick_update,
+    bench_orderbook_fixed_tick_delete,
+    bench_orderbook_l2_top_levels,
+    bench_orderbook_fixed_tick_l2_top_levels,
 );
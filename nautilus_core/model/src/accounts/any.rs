@@ -18,7 +18,7 @@ use std::collections::HashMap;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    accounts::{base::Account, cash::CashAccount, margin::MarginAccount},
+    accounts::{base::Account, betting::BettingAccount, cash::CashAccount, margin::MarginAccount},
     enums::{AccountType, LiquiditySide},
     events::{AccountState, OrderFilled},
     identifiers::AccountId,
@@ -30,6 +30,7 @@ use crate::{
 pub enum AccountAny {
     Margin(MarginAccount),
     Cash(CashAccount),
+    Betting(BettingAccount),
 }
 
 impl AccountAny {
@@ -38,6 +39,7 @@ impl AccountAny {
         match self {
             AccountAny::Margin(margin) => margin.id,
             AccountAny::Cash(cash) => cash.id,
+            AccountAny::Betting(betting) => betting.id,
         }
     }
 
@@ -45,6 +47,7 @@ impl AccountAny {
         match self {
             AccountAny::Margin(margin) => margin.last_event(),
             AccountAny::Cash(cash) => cash.last_event(),
+            AccountAny::Betting(betting) => betting.last_event(),
         }
     }
 
@@ -52,6 +55,7 @@ impl AccountAny {
         match self {
             AccountAny::Margin(margin) => margin.events(),
             AccountAny::Cash(cash) => cash.events(),
+            AccountAny::Betting(betting) => betting.events(),
         }
     }
 
@@ -59,6 +63,7 @@ impl AccountAny {
         match self {
             AccountAny::Margin(margin) => margin.apply(event),
             AccountAny::Cash(cash) => cash.apply(event),
+            AccountAny::Betting(betting) => betting.apply(event),
         }
     }
 
@@ -66,6 +71,7 @@ impl AccountAny {
         match self {
             AccountAny::Margin(margin) => margin.balances(),
             AccountAny::Cash(cash) => cash.balances(),
+            AccountAny::Betting(betting) => betting.balances(),
         }
     }
 
@@ -73,6 +79,7 @@ impl AccountAny {
         match self {
             AccountAny::Margin(margin) => margin.balances_locked(),
             AccountAny::Cash(cash) => cash.balances_locked(),
+            AccountAny::Betting(betting) => betting.balances_locked(),
         }
     }
 
@@ -80,6 +87,7 @@ impl AccountAny {
         match self {
             AccountAny::Margin(margin) => margin.base_currency(),
             AccountAny::Cash(cash) => cash.base_currency(),
+            AccountAny::Betting(betting) => betting.base_currency(),
         }
     }
 
@@ -105,6 +113,7 @@ impl AccountAny {
         match self {
             AccountAny::Margin(margin) => margin.calculate_pnls(instrument, fill, position),
             AccountAny::Cash(cash) => cash.calculate_pnls(instrument, fill, position),
+            AccountAny::Betting(betting) => betting.calculate_pnls(instrument, fill, position),
         }
     }
 
@@ -131,6 +140,13 @@ impl AccountAny {
                 liquidity_side,
                 use_quote_for_inverse,
             ),
+            AccountAny::Betting(betting) => betting.calculate_commission(
+                instrument,
+                last_qty,
+                last_px,
+                liquidity_side,
+                use_quote_for_inverse,
+            ),
         }
     }
 
@@ -138,6 +154,7 @@ impl AccountAny {
         match self {
             AccountAny::Margin(margin) => margin.balance(currency),
             AccountAny::Cash(cash) => cash.balance(currency),
+            AccountAny::Betting(betting) => betting.balance(currency),
         }
     }
 }
@@ -147,7 +164,7 @@ impl From<AccountState> for AccountAny {
         match event.account_type {
             AccountType::Margin => AccountAny::Margin(MarginAccount::new(event, false)),
             AccountType::Cash => AccountAny::Cash(CashAccount::new(event, false)),
-            AccountType::Betting => todo!("Betting account not implemented"),
+            AccountType::Betting => AccountAny::Betting(BettingAccount::new(event, false)),
         }
     }
 }
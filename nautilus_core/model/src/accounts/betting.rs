@@ -0,0 +1,957 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A betting account for sports exchanges, where orders are back (`SELL`) and lay (`BUY`) bets
+//! at decimal odds.
+
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+    ops::{Deref, DerefMut},
+};
+
+use nautilus_core::correctness::FAILED;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use ustr::Ustr;
+
+use crate::{
+    accounts::base::{Account, BaseAccount},
+    enums::{AccountType, LiquiditySide, OrderSide, OrderSideSpecified},
+    events::{AccountState, OrderFilled},
+    identifiers::{AccountId, InstrumentId},
+    instruments::{BettingInstrument, InstrumentAny},
+    position::Position,
+    types::{AccountBalance, Currency, Money, Price, Quantity},
+};
+
+/// Represents a bet on a selection (instrument) of a betting market.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MarketBet {
+    pub instrument_id: InstrumentId,
+    /// The side of the bet, with `Sell` to back and `Buy` to lay the selection.
+    pub side: OrderSideSpecified,
+    /// The backer's stake.
+    pub quantity: Quantity,
+    /// The decimal odds.
+    pub price: Price,
+}
+
+impl MarketBet {
+    /// Creates a new [`MarketBet`] instance.
+    #[must_use]
+    pub const fn new(
+        instrument_id: InstrumentId,
+        side: OrderSideSpecified,
+        quantity: Quantity,
+        price: Price,
+    ) -> Self {
+        Self {
+            instrument_id,
+            side,
+            quantity,
+            price,
+        }
+    }
+}
+
+/// Returns the amount won by the backer of a winning bet of the given `quantity` at the decimal
+/// odds `price` (excluding the returned stake).
+#[must_use]
+pub fn back_winnings(quantity: Quantity, price: Price) -> Decimal {
+    quantity.as_decimal() * (price.as_decimal() - Decimal::ONE)
+}
+
+/// Returns the maximum loss of a bet, being the stake to back (`Sell`) or the backer's winnings
+/// to lay (`Buy`).
+#[must_use]
+pub fn liability(quantity: Quantity, price: Price, side: OrderSideSpecified) -> Decimal {
+    match side {
+        OrderSideSpecified::Sell => quantity.as_decimal(),
+        OrderSideSpecified::Buy => back_winnings(quantity, price),
+    }
+}
+
+/// Returns the payoff of a bet if the selection wins.
+#[must_use]
+pub fn win_payoff(quantity: Quantity, price: Price, side: OrderSideSpecified) -> Decimal {
+    match side {
+        OrderSideSpecified::Sell => back_winnings(quantity, price),
+        OrderSideSpecified::Buy => -back_winnings(quantity, price),
+    }
+}
+
+/// Returns the payoff of a bet if the selection loses.
+#[must_use]
+pub fn lose_payoff(quantity: Quantity, side: OrderSideSpecified) -> Decimal {
+    match side {
+        OrderSideSpecified::Sell => -quantity.as_decimal(),
+        OrderSideSpecified::Buy => quantity.as_decimal(),
+    }
+}
+
+/// Returns the difference in payoff of a bet between the selection winning and losing.
+#[must_use]
+pub fn exposure(quantity: Quantity, price: Price, side: OrderSideSpecified) -> Decimal {
+    win_payoff(quantity, price, side) - lose_payoff(quantity, side)
+}
+
+/// Returns the net payoff of the `bets` on a market if the `winner` selection wins (or if no
+/// selection bet on wins when `None`).
+#[must_use]
+pub fn market_outcome_pnl(bets: &[MarketBet], winner: Option<InstrumentId>) -> Decimal {
+    bets.iter()
+        .map(|bet| {
+            if Some(bet.instrument_id) == winner {
+                win_payoff(bet.quantity, bet.price, bet.side)
+            } else {
+                lose_payoff(bet.quantity, bet.side)
+            }
+        })
+        .sum()
+}
+
+/// Returns the liability of the `bets` on a market, being the worst loss over the outcomes of
+/// each selection bet on winning, or none of them winning.
+///
+/// Bets on the same market offset each other, so backing and laying a selection at the same odds
+/// has no liability. The outcome of no selection bet on winning is always included, which
+/// overstates the liability of bets covering every selection of the market.
+#[must_use]
+pub fn market_liability(bets: &[MarketBet]) -> Decimal {
+    market_locked_liability(bets, &[])
+}
+
+/// Returns the liability of the `matched_bets` and `open_bets` on a market, being the worst loss
+/// over the outcomes of each selection bet on winning, or none of them winning.
+///
+/// In each outcome the matched bets offset each other (see [`market_liability`]), while each open
+/// bet may or may not be matched, so only counts when it would lose.
+#[must_use]
+pub fn market_locked_liability(matched_bets: &[MarketBet], open_bets: &[MarketBet]) -> Decimal {
+    let selections: HashSet<InstrumentId> = matched_bets
+        .iter()
+        .chain(open_bets)
+        .map(|bet| bet.instrument_id)
+        .collect();
+    let worst_pnl = selections
+        .into_iter()
+        .map(Some)
+        .chain(std::iter::once(None))
+        .map(|winner| {
+            let open_loss: Decimal = open_bets
+                .iter()
+                .map(|bet| market_outcome_pnl(&[*bet], winner).min(Decimal::ZERO))
+                .sum();
+            market_outcome_pnl(matched_bets, winner) + open_loss
+        })
+        .min()
+        .unwrap_or_default();
+    (-worst_pnl).max(Decimal::ZERO)
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.model")
+)]
+pub struct BettingAccount {
+    pub base: BaseAccount,
+    /// The commission rate charged on net market winnings.
+    pub commission_rate: Decimal,
+    /// The open (unmatched) bets by market ID and instrument ID.
+    pub market_bets: HashMap<Ustr, HashMap<InstrumentId, Vec<MarketBet>>>,
+    /// The matched bets by market ID and instrument ID.
+    pub matched_bets: HashMap<Ustr, HashMap<InstrumentId, Vec<MarketBet>>>,
+    /// The balances locked by the open and matched bets of each market ID.
+    pub market_locked: HashMap<Ustr, Money>,
+}
+
+impl BettingAccount {
+    /// Creates a new [`BettingAccount`] instance.
+    pub fn new(event: AccountState, calculate_account_state: bool) -> Self {
+        Self {
+            base: BaseAccount::new(event, calculate_account_state),
+            commission_rate: Decimal::ZERO,
+            market_bets: HashMap::new(),
+            matched_bets: HashMap::new(),
+            market_locked: HashMap::new(),
+        }
+    }
+
+    /// Sets the commission rate charged on net market winnings.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if `commission_rate` is not in the range [0, 1].
+    pub fn set_commission_rate(&mut self, commission_rate: Decimal) -> anyhow::Result<()> {
+        anyhow::ensure!(
+            (Decimal::ZERO..=Decimal::ONE).contains(&commission_rate),
+            "invalid `commission_rate` {commission_rate}, expected in range [0, 1]"
+        );
+        self.commission_rate = commission_rate;
+        Ok(())
+    }
+
+    #[must_use]
+    pub fn is_betting_account(&self) -> bool {
+        self.account_type == AccountType::Betting
+    }
+
+    #[must_use]
+    pub const fn is_unleveraged(&self) -> bool {
+        true
+    }
+
+    /// Returns the change in free balance from placing the given bet, being the negative of its
+    /// liability.
+    #[must_use]
+    pub fn balance_impact(
+        &self,
+        instrument: &InstrumentAny,
+        quantity: Quantity,
+        price: Price,
+        side: OrderSideSpecified,
+    ) -> Money {
+        Money::from_decimal(
+            -liability(quantity, price, side),
+            instrument.quote_currency(),
+        )
+        .expect(FAILED)
+    }
+
+    /// Returns the balance locked by the open bets of the given `market_id`, if any.
+    #[must_use]
+    pub fn market_locked(&self, market_id: &Ustr) -> Option<Money> {
+        self.market_locked.get(market_id).copied()
+    }
+
+    /// Updates the open (unmatched) `open_bets` and the `matched_bets` on the given `instrument`,
+    /// locking the liability of all the bets across its market.
+    ///
+    /// The locked balance is the worst loss over the outcomes of the market, with each open bet
+    /// only counted in the outcomes where it would lose (see [`market_locked_liability`]).
+    ///
+    /// Returns the balance locked for the market.
+    pub fn update_market_bets(
+        &mut self,
+        instrument: &BettingInstrument,
+        open_bets: Vec<MarketBet>,
+        matched_bets: Vec<MarketBet>,
+    ) -> Money {
+        let market_id = instrument.market_id;
+        replace_bets(&mut self.market_bets, market_id, instrument.id, open_bets);
+        replace_bets(
+            &mut self.matched_bets,
+            market_id,
+            instrument.id,
+            matched_bets,
+        );
+
+        let market_bets = |bets: &HashMap<Ustr, HashMap<InstrumentId, Vec<MarketBet>>>| {
+            bets.get(&market_id)
+                .map(|bets| bets.values().flatten().copied().collect::<Vec<_>>())
+                .unwrap_or_default()
+        };
+        let liability = market_locked_liability(
+            &market_bets(&self.matched_bets),
+            &market_bets(&self.market_bets),
+        );
+
+        let locked = Money::from_decimal(liability, instrument.currency).expect(FAILED);
+        if locked.is_zero() {
+            self.market_locked.remove(&market_id);
+        } else {
+            self.market_locked.insert(market_id, locked);
+        }
+
+        self.recalculate_balance(instrument.currency);
+        locked
+    }
+
+    /// Settles the matched bets on the given `market_id` with the `winner` selection (or no
+    /// selection bet on winning when `None`), charging the commission on net market winnings.
+    ///
+    /// The net PnL (after commission) is applied to the total balance, and the bets and balance
+    /// locked for the market are released. Returns the net PnL.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the account has no balance in `currency`.
+    pub fn settle_market(
+        &mut self,
+        market_id: Ustr,
+        winner: Option<InstrumentId>,
+        currency: Currency,
+    ) -> anyhow::Result<Money> {
+        let Some(balance) = self.balances.get(&currency).copied() else {
+            anyhow::bail!("Cannot settle market {market_id}: no balance for {currency}");
+        };
+
+        let bets: Vec<MarketBet> = self
+            .matched_bets
+            .remove(&market_id)
+            .map(|bets| bets.into_values().flatten().collect())
+            .unwrap_or_default();
+        self.market_bets.remove(&market_id);
+        self.market_locked.remove(&market_id);
+
+        let pnl = Money::from_decimal(market_outcome_pnl(&bets, winner), currency).expect(FAILED);
+        let commission = self.calculate_market_commission(&bets, winner, currency);
+        let net_pnl = pnl - commission;
+
+        self.update_balances(vec![AccountBalance::new(
+            balance.total + net_pnl,
+            balance.locked,
+            balance.free + net_pnl,
+        )]);
+        if !commission.is_zero() {
+            self.update_commissions(commission);
+        }
+
+        self.recalculate_balance(currency);
+        Ok(net_pnl)
+    }
+
+    /// Returns the commission charged on the net winnings of the `bets` on a market, if the
+    /// `winner` selection wins (or if no selection bet on wins when `None`).
+    #[must_use]
+    pub fn calculate_market_commission(
+        &self,
+        bets: &[MarketBet],
+        winner: Option<InstrumentId>,
+        currency: Currency,
+    ) -> Money {
+        let net_winnings = market_outcome_pnl(bets, winner).max(Decimal::ZERO);
+        Money::from_decimal(net_winnings * self.commission_rate, currency).expect(FAILED)
+    }
+
+    /// Recalculates the locked and free balances for the given `currency` from the balances
+    /// locked for each market.
+    pub fn recalculate_balance(&mut self, currency: Currency) {
+        let Some(current_balance) = self.balances.get(&currency).copied() else {
+            return;
+        };
+
+        let total_locked = Money::from_raw(
+            self.market_locked
+                .values()
+                .filter(|locked| locked.currency == currency)
+                .map(|locked| locked.raw)
+                .sum(),
+            currency,
+        );
+
+        let new_balance = AccountBalance::new(
+            current_balance.total,
+            total_locked,
+            current_balance.total - total_locked,
+        );
+
+        self.balances.insert(currency, new_balance);
+    }
+}
+
+fn replace_bets(
+    bets: &mut HashMap<Ustr, HashMap<InstrumentId, Vec<MarketBet>>>,
+    market_id: Ustr,
+    instrument_id: InstrumentId,
+    instrument_bets: Vec<MarketBet>,
+) {
+    let market_bets = bets.entry(market_id).or_default();
+    if instrument_bets.is_empty() {
+        market_bets.remove(&instrument_id);
+    } else {
+        market_bets.insert(instrument_id, instrument_bets);
+    }
+    if market_bets.is_empty() {
+        bets.remove(&market_id);
+    }
+}
+
+impl Account for BettingAccount {
+    fn id(&self) -> AccountId {
+        self.id
+    }
+
+    fn account_type(&self) -> AccountType {
+        self.account_type
+    }
+
+    fn base_currency(&self) -> Option<Currency> {
+        self.base_currency
+    }
+
+    fn is_cash_account(&self) -> bool {
+        self.account_type == AccountType::Cash
+    }
+
+    fn is_margin_account(&self) -> bool {
+        self.account_type == AccountType::Margin
+    }
+
+    fn calculated_account_state(&self) -> bool {
+        false // TODO (implement this logic)
+    }
+
+    fn balance_total(&self, currency: Option<Currency>) -> Option<Money> {
+        self.base_balance_total(currency)
+    }
+
+    fn balances_total(&self) -> HashMap<Currency, Money> {
+        self.base_balances_total()
+    }
+
+    fn balance_free(&self, currency: Option<Currency>) -> Option<Money> {
+        self.base_balance_free(currency)
+    }
+
+    fn balances_free(&self) -> HashMap<Currency, Money> {
+        self.base_balances_free()
+    }
+
+    fn balance_locked(&self, currency: Option<Currency>) -> Option<Money> {
+        self.base_balance_locked(currency)
+    }
+
+    fn balances_locked(&self) -> HashMap<Currency, Money> {
+        self.base_balances_locked()
+    }
+
+    fn balance(&self, currency: Option<Currency>) -> Option<&AccountBalance> {
+        self.base_balance(currency)
+    }
+
+    fn last_event(&self) -> Option<AccountState> {
+        self.base_last_event()
+    }
+
+    fn events(&self) -> Vec<AccountState> {
+        self.events.clone()
+    }
+
+    fn event_count(&self) -> usize {
+        self.events.len()
+    }
+
+    fn currencies(&self) -> Vec<Currency> {
+        self.balances.keys().copied().collect()
+    }
+
+    fn starting_balances(&self) -> HashMap<Currency, Money> {
+        self.balances_starting.clone()
+    }
+
+    fn balances(&self) -> HashMap<Currency, AccountBalance> {
+        self.balances.clone()
+    }
+
+    fn apply(&mut self, event: AccountState) {
+        self.base_apply(event);
+    }
+
+    /// Calculates the liability of a single bet, without offsetting other bets on the market.
+    fn calculate_balance_locked(
+        &mut self,
+        instrument: InstrumentAny,
+        side: OrderSide,
+        quantity: Quantity,
+        price: Price,
+        use_quote_for_inverse: Option<bool>,
+    ) -> anyhow::Result<Money> {
+        if use_quote_for_inverse.unwrap_or(false) {
+            anyhow::bail!("`use_quote_for_inverse` not applicable for betting accounts");
+        }
+        let side = match side {
+            OrderSide::Buy => OrderSideSpecified::Buy,
+            OrderSide::Sell => OrderSideSpecified::Sell,
+            OrderSide::NoOrderSide => anyhow::bail!("Invalid `OrderSide` {side}"),
+        };
+        Money::from_decimal(
+            liability(quantity, price, side),
+            instrument.quote_currency(),
+        )
+    }
+
+    /// Returns no PnL for a fill, as the liability of matched bets is locked until the market is
+    /// settled (see [`BettingAccount::settle_market`]).
+    fn calculate_pnls(
+        &self,
+        _instrument: InstrumentAny,
+        _fill: OrderFilled,
+        _position: Option<Position>,
+    ) -> anyhow::Result<Vec<Money>> {
+        Ok(vec![])
+    }
+
+    /// Calculates the commission of a fill from the instrument fees, separate to the commission
+    /// on net market winnings (see [`BettingAccount::calculate_market_commission`]).
+    fn calculate_commission(
+        &self,
+        instrument: InstrumentAny,
+        last_qty: Quantity,
+        last_px: Price,
+        liquidity_side: LiquiditySide,
+        use_quote_for_inverse: Option<bool>,
+    ) -> anyhow::Result<Money> {
+        self.base_calculate_commission(
+            instrument,
+            last_qty,
+            last_px,
+            liquidity_side,
+            use_quote_for_inverse,
+        )
+    }
+}
+
+impl Deref for BettingAccount {
+    type Target = BaseAccount;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl DerefMut for BettingAccount {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.base
+    }
+}
+
+impl PartialEq for BettingAccount {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for BettingAccount {}
+
+impl Display for BettingAccount {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "BettingAccount(id={}, type={}, base={})",
+            self.id,
+            self.account_type,
+            self.base_currency.map_or_else(
+                || "None".to_string(),
+                |base_currency| format!("{}", base_currency.code)
+            ),
+        )
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use rust_decimal::Decimal;
+    use rust_decimal_macros::dec;
+
+    use super::{
+        exposure, liability, market_liability, market_locked_liability, market_outcome_pnl,
+        MarketBet,
+    };
+    use crate::{
+        accounts::{base::Account, betting::BettingAccount, stubs::*},
+        enums::{AccountType, OrderSide, OrderSideSpecified},
+        identifiers::{AccountId, InstrumentId},
+        instruments::{stubs::betting, BettingInstrument, InstrumentAny},
+        types::{Currency, Money, Price, Quantity},
+    };
+
+    fn bet(
+        instrument_id: &str,
+        side: OrderSideSpecified,
+        quantity: &str,
+        price: &str,
+    ) -> MarketBet {
+        MarketBet::new(
+            InstrumentId::from(instrument_id),
+            side,
+            Quantity::from(quantity),
+            Price::from(price),
+        )
+    }
+
+    #[rstest]
+    fn test_display(betting_account: BettingAccount) {
+        assert_eq!(
+            format!("{betting_account}"),
+            "BettingAccount(id=BETFAIR-001, type=BETTING, base=GBP)"
+        );
+    }
+
+    #[rstest]
+    fn test_instantiate_betting_account(betting_account: BettingAccount) {
+        assert_eq!(betting_account.id, AccountId::from("BETFAIR-001"));
+        assert_eq!(betting_account.account_type, AccountType::Betting);
+        assert!(betting_account.is_betting_account());
+        assert_eq!(
+            betting_account.balance_total(None),
+            Some(Money::from("1000 GBP"))
+        );
+        assert_eq!(
+            betting_account.balance_free(None),
+            Some(Money::from("1000 GBP"))
+        );
+        assert_eq!(
+            betting_account.balance_locked(None),
+            Some(Money::from("0 GBP"))
+        );
+    }
+
+    #[rstest]
+    #[case("1.60", "10", OrderSide::Sell, "10 GBP")]
+    #[case("2.00", "10", OrderSide::Sell, "10 GBP")]
+    #[case("10.0", "20", OrderSide::Sell, "20 GBP")]
+    #[case("1.25", "10", OrderSide::Buy, "2.5 GBP")]
+    #[case("2.0", "10", OrderSide::Buy, "10 GBP")]
+    #[case("10.0", "10", OrderSide::Buy, "90 GBP")]
+    fn test_calculate_balance_locked(
+        mut betting_account: BettingAccount,
+        betting: BettingInstrument,
+        #[case] price: &str,
+        #[case] quantity: &str,
+        #[case] side: OrderSide,
+        #[case] expected: &str,
+    ) {
+        let locked = betting_account
+            .calculate_balance_locked(
+                InstrumentAny::Betting(betting),
+                side,
+                Quantity::from(quantity),
+                Price::from(price),
+                None,
+            )
+            .unwrap();
+
+        assert_eq!(locked, Money::from(expected));
+    }
+
+    #[rstest]
+    #[case(OrderSideSpecified::Sell, "5.0", "100", "-100 GBP")]
+    #[case(OrderSideSpecified::Sell, "1.50", "100", "-100 GBP")]
+    #[case(OrderSideSpecified::Buy, "5.0", "100", "-400 GBP")]
+    #[case(OrderSideSpecified::Buy, "1.5", "100", "-50 GBP")]
+    #[case(OrderSideSpecified::Buy, "5.0", "300", "-1200 GBP")]
+    #[case(OrderSideSpecified::Buy, "10.0", "100", "-900 GBP")]
+    fn test_balance_impact(
+        betting_account: BettingAccount,
+        betting: BettingInstrument,
+        #[case] side: OrderSideSpecified,
+        #[case] price: &str,
+        #[case] quantity: &str,
+        #[case] expected: &str,
+    ) {
+        let impact = betting_account.balance_impact(
+            &InstrumentAny::Betting(betting),
+            Quantity::from(quantity),
+            Price::from(price),
+            side,
+        );
+
+        assert_eq!(impact, Money::from(expected));
+    }
+
+    #[rstest]
+    fn test_exposure() {
+        let quantity = Quantity::from(10);
+        let price = Price::from("3.0");
+
+        assert_eq!(
+            liability(quantity, price, OrderSideSpecified::Sell),
+            dec!(10)
+        );
+        assert_eq!(
+            exposure(quantity, price, OrderSideSpecified::Sell),
+            dec!(30)
+        );
+        assert_eq!(
+            exposure(quantity, price, OrderSideSpecified::Buy),
+            dec!(-30)
+        );
+    }
+
+    #[rstest]
+    fn test_market_outcome_pnl() {
+        let bets = [
+            bet("1-1.BETFAIR", OrderSideSpecified::Sell, "10", "3.0"),
+            bet("1-2.BETFAIR", OrderSideSpecified::Buy, "10", "2.0"),
+        ];
+
+        // Back wins 20, lay loses 10
+        assert_eq!(
+            market_outcome_pnl(&bets, Some(InstrumentId::from("1-1.BETFAIR"))),
+            dec!(30)
+        );
+        // Back loses 10, lay loses 10
+        assert_eq!(
+            market_outcome_pnl(&bets, Some(InstrumentId::from("1-2.BETFAIR"))),
+            dec!(-20)
+        );
+        // Back loses 10, lay wins 10
+        assert_eq!(market_outcome_pnl(&bets, None), Decimal::ZERO);
+    }
+
+    #[rstest]
+    fn test_market_liability_offsets_bets() {
+        let back = bet("1-1.BETFAIR", OrderSideSpecified::Sell, "10", "3.0");
+        let lay = bet("1-1.BETFAIR", OrderSideSpecified::Buy, "10", "3.0");
+        let lay_other = bet("1-2.BETFAIR", OrderSideSpecified::Buy, "10", "2.0");
+
+        assert_eq!(market_liability(&[]), Decimal::ZERO);
+        assert_eq!(market_liability(&[back]), dec!(10));
+        assert_eq!(market_liability(&[lay]), dec!(20));
+        assert_eq!(market_liability(&[back, lay]), Decimal::ZERO);
+        assert_eq!(market_liability(&[back, lay_other]), dec!(20));
+    }
+
+    #[rstest]
+    fn test_market_locked_liability_counts_losing_open_bets() {
+        let matched_lay = bet("1-1.BETFAIR", OrderSideSpecified::Buy, "10", "3.0");
+        let open_back = bet("1-1.BETFAIR", OrderSideSpecified::Sell, "4", "3.0");
+        let open_back_other = bet("1-2.BETFAIR", OrderSideSpecified::Sell, "4", "3.0");
+
+        assert_eq!(market_locked_liability(&[matched_lay], &[]), dec!(20));
+        assert_eq!(
+            market_locked_liability(&[matched_lay], &[open_back]),
+            dec!(20)
+        );
+        // The other selection's back loses 4 when the laid selection wins
+        assert_eq!(
+            market_locked_liability(&[matched_lay], &[open_back_other]),
+            dec!(24)
+        );
+        assert_eq!(market_locked_liability(&[], &[open_back]), dec!(4));
+    }
+
+    #[rstest]
+    fn test_update_market_bets_locks_market_liability(
+        mut betting_account: BettingAccount,
+        betting: BettingInstrument,
+    ) {
+        let back = MarketBet::new(
+            betting.id,
+            OrderSideSpecified::Sell,
+            Quantity::from(10),
+            Price::from("3.00"),
+        );
+        let lay = MarketBet::new(
+            betting.id,
+            OrderSideSpecified::Buy,
+            Quantity::from(5),
+            Price::from("3.00"),
+        );
+
+        let locked = betting_account.update_market_bets(&betting, vec![back, lay], vec![]);
+
+        // Open bets do not offset each other, as either may be matched alone, but only the bets
+        // losing in an outcome count towards it
+        assert_eq!(locked, Money::from("10 GBP"));
+        assert_eq!(
+            betting_account.market_locked(&betting.market_id),
+            Some(Money::from("10 GBP"))
+        );
+        assert_eq!(
+            betting_account.balance_locked(None),
+            Some(Money::from("10 GBP"))
+        );
+        assert_eq!(
+            betting_account.balance_free(None),
+            Some(Money::from("990 GBP"))
+        );
+
+        // Matched bets offset each other
+        let locked = betting_account.update_market_bets(&betting, vec![], vec![back, lay]);
+
+        assert_eq!(locked, Money::from("5 GBP"));
+
+        let locked = betting_account.update_market_bets(&betting, vec![], vec![]);
+
+        assert_eq!(locked, Money::from("0 GBP"));
+        assert!(betting_account.market_bets.is_empty());
+        assert_eq!(betting_account.market_locked(&betting.market_id), None);
+        assert_eq!(
+            betting_account.balance_free(None),
+            Some(Money::from("1000 GBP"))
+        );
+    }
+
+    #[rstest]
+    fn test_update_market_bets_locks_matched_liability(
+        mut betting_account: BettingAccount,
+        betting: BettingInstrument,
+    ) {
+        let matched_lay = MarketBet::new(
+            betting.id,
+            OrderSideSpecified::Buy,
+            Quantity::from(10),
+            Price::from("3.00"),
+        );
+        let open_back = MarketBet::new(
+            betting.id,
+            OrderSideSpecified::Sell,
+            Quantity::from(4),
+            Price::from("3.00"),
+        );
+
+        let locked = betting_account.update_market_bets(&betting, vec![], vec![matched_lay]);
+
+        assert_eq!(locked, Money::from("20 GBP"));
+        assert_eq!(
+            betting_account.balance_free(None),
+            Some(Money::from("980 GBP"))
+        );
+
+        // The open back only loses when the matched lay wins, so adds no liability
+        let locked =
+            betting_account.update_market_bets(&betting, vec![open_back], vec![matched_lay]);
+
+        assert_eq!(locked, Money::from("20 GBP"));
+
+        // The matched lay stays locked once the open back is cancelled
+        let locked = betting_account.update_market_bets(&betting, vec![], vec![matched_lay]);
+
+        assert_eq!(locked, Money::from("20 GBP"));
+        assert!(betting_account.market_bets.is_empty());
+    }
+
+    #[rstest]
+    fn test_set_commission_rate(mut betting_account: BettingAccount) {
+        betting_account.set_commission_rate(dec!(0.05)).unwrap();
+
+        assert_eq!(betting_account.commission_rate, dec!(0.05));
+        assert!(betting_account.set_commission_rate(dec!(-0.01)).is_err());
+        assert!(betting_account.set_commission_rate(dec!(1.01)).is_err());
+        assert_eq!(betting_account.commission_rate, dec!(0.05));
+    }
+
+    #[rstest]
+    fn test_settle_market_applies_commission(
+        mut betting_account: BettingAccount,
+        betting: BettingInstrument,
+    ) {
+        betting_account.set_commission_rate(dec!(0.05)).unwrap();
+        let matched_back = MarketBet::new(
+            betting.id,
+            OrderSideSpecified::Sell,
+            Quantity::from(10),
+            Price::from("3.00"),
+        );
+        betting_account.update_market_bets(&betting, vec![], vec![matched_back]);
+
+        let net_pnl = betting_account
+            .settle_market(betting.market_id, Some(betting.id), Currency::GBP())
+            .unwrap();
+
+        // Winnings of 20 less 5% commission
+        assert_eq!(net_pnl, Money::from("19 GBP"));
+        assert_eq!(
+            betting_account.balance_total(None),
+            Some(Money::from("1019 GBP"))
+        );
+        assert_eq!(
+            betting_account.balance_free(None),
+            Some(Money::from("1019 GBP"))
+        );
+        assert_eq!(
+            betting_account.balance_locked(None),
+            Some(Money::from("0 GBP"))
+        );
+        assert_eq!(
+            betting_account.commissions.get(&Currency::GBP()),
+            Some(&1.0)
+        );
+        assert!(betting_account.matched_bets.is_empty());
+        assert_eq!(betting_account.market_locked(&betting.market_id), None);
+    }
+
+    #[rstest]
+    fn test_settle_market_when_losing_charges_no_commission(
+        mut betting_account: BettingAccount,
+        betting: BettingInstrument,
+    ) {
+        betting_account.set_commission_rate(dec!(0.05)).unwrap();
+        let matched_back = MarketBet::new(
+            betting.id,
+            OrderSideSpecified::Sell,
+            Quantity::from(10),
+            Price::from("3.00"),
+        );
+        betting_account.update_market_bets(&betting, vec![], vec![matched_back]);
+
+        let net_pnl = betting_account
+            .settle_market(betting.market_id, None, Currency::GBP())
+            .unwrap();
+
+        assert_eq!(net_pnl, Money::from("-10 GBP"));
+        assert_eq!(
+            betting_account.balance_total(None),
+            Some(Money::from("990 GBP"))
+        );
+        assert!(betting_account.commissions.is_empty());
+    }
+
+    #[rstest]
+    fn test_settle_market_without_balance_in_currency(
+        mut betting_account: BettingAccount,
+        betting: BettingInstrument,
+    ) {
+        let matched_back = MarketBet::new(
+            betting.id,
+            OrderSideSpecified::Sell,
+            Quantity::from(10),
+            Price::from("3.00"),
+        );
+        betting_account.update_market_bets(&betting, vec![], vec![matched_back]);
+
+        let result = betting_account.settle_market(betting.market_id, None, Currency::USD());
+
+        assert!(result.is_err());
+        assert!(!betting_account.matched_bets.is_empty());
+        assert_eq!(
+            betting_account.balance_total(None),
+            Some(Money::from("1000 GBP"))
+        );
+    }
+
+    #[rstest]
+    fn test_calculate_market_commission(mut betting_account: BettingAccount) {
+        betting_account.set_commission_rate(dec!(0.05)).unwrap();
+        let bets = [
+            bet("1-1.BETFAIR", OrderSideSpecified::Sell, "10", "3.0"),
+            bet("1-2.BETFAIR", OrderSideSpecified::Sell, "10", "2.0"),
+        ];
+
+        // Net winnings of 20 - 10 = 10
+        let commission = betting_account.calculate_market_commission(
+            &bets,
+            Some(InstrumentId::from("1-1.BETFAIR")),
+            Currency::GBP(),
+        );
+        assert_eq!(commission, Money::from("0.5 GBP"));
+
+        // Net loss of 10 - 10 = 0
+        let commission = betting_account.calculate_market_commission(
+            &bets,
+            Some(InstrumentId::from("1-2.BETFAIR")),
+            Currency::GBP(),
+        );
+        assert_eq!(commission, Money::from("0 GBP"));
+    }
+}
@@ -16,7 +16,10 @@
 use rstest::fixture;
 
 use crate::{
-    accounts::{base::Account, cash::CashAccount, margin::MarginAccount, AccountAny},
+    accounts::{
+        base::Account, betting::BettingAccount, cash::CashAccount, margin::MarginAccount,
+        AccountAny,
+    },
     enums::{AccountType, LiquiditySide},
     events::account::{state::AccountState, stubs::*},
     identifiers::stubs::{account_id, uuid4},
@@ -74,6 +77,11 @@ pub fn cash_account_multi(cash_account_state_multi: AccountState) -> CashAccount
     CashAccount::new(cash_account_state_multi, true)
 }
 
+#[fixture]
+pub fn betting_account(betting_account_state: AccountState) -> BettingAccount {
+    BettingAccount::new(betting_account_state, true)
+}
+
 #[must_use]
 pub fn calculate_commission(
     instrument: InstrumentAny,
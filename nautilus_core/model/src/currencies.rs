@@ -13,14 +13,20 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-//! Common `Currency` constants.
+//! Common `Currency` constants, and the registry of currencies available at runtime.
+//!
+//! User-defined currencies can be loaded into the registry from TOML or JSON configuration,
+//! see [`load_currency_definitions`] and [`register_currencies`].
 
 use std::{
-    collections::HashMap,
+    collections::{HashMap, HashSet},
+    path::Path,
     sync::{Mutex, OnceLock},
 };
 
+use anyhow::Context;
 use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
 use ustr::Ustr;
 
 use crate::{enums::CurrencyType, types::Currency};
@@ -1002,7 +1008,11 @@ impl Currency {
 }
 
 /// A map of built-in `Currency` constants.
-pub static CURRENCY_MAP: Lazy<Mutex<HashMap<String, Currency>>> = Lazy::new(|| {
+pub static CURRENCY_MAP: Lazy<Mutex<HashMap<String, Currency>>> =
+    Lazy::new(|| Mutex::new(BUILTIN_CURRENCIES.clone()));
+
+/// The built-in `Currency` constants by code, which registered currencies cannot redefine.
+static BUILTIN_CURRENCIES: Lazy<HashMap<String, Currency>> = Lazy::new(|| {
     let mut map = HashMap::new();
     ///////////////////////////////////////////////////////////////////////////
     // Fiat currencies
@@ -1080,5 +1090,383 @@ pub static CURRENCY_MAP: Lazy<Mutex<HashMap<String, Currency>>> = Lazy::new(|| {
     map.insert(Currency::USDP().code.to_string(), Currency::USDP());
     map.insert(Currency::USDT().code.to_string(), Currency::USDT());
     map.insert(Currency::ZEC().code.to_string(), Currency::ZEC());
-    Mutex::new(map)
+    map
 });
+
+///////////////////////////////////////////////////////////////////////////////
+// Currency registry
+///////////////////////////////////////////////////////////////////////////////
+
+/// Represents the definition of a user-defined currency, as loaded from configuration.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CurrencyDefinition {
+    /// The currency code (e.g., "USD", "BTC").
+    pub code: String,
+    /// The currency decimal precision.
+    pub precision: u8,
+    /// The ISO 4217 currency code (zero if not an ISO 4217 currency).
+    #[serde(default)]
+    pub iso4217: u16,
+    /// The full name of the currency.
+    pub name: String,
+    /// The currency type.
+    pub currency_type: CurrencyType,
+}
+
+impl CurrencyDefinition {
+    /// Creates the [`Currency`] for this definition.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If a correctness check fails. See [`Currency::new_checked`] for more details.
+    pub fn to_currency(&self) -> anyhow::Result<Currency> {
+        Currency::new_checked(
+            self.code.as_str(),
+            self.precision,
+            self.iso4217,
+            self.name.as_str(),
+            self.currency_type,
+        )
+        .with_context(|| format!("Invalid currency definition for '{}'", self.code))
+    }
+}
+
+impl From<Currency> for CurrencyDefinition {
+    fn from(currency: Currency) -> Self {
+        Self {
+            code: currency.code.to_string(),
+            precision: currency.precision,
+            iso4217: currency.iso4217,
+            name: currency.name.to_string(),
+            currency_type: currency.currency_type,
+        }
+    }
+}
+
+/// The currency definitions of a configuration file, under a `currencies` key.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct CurrencyDefinitions {
+    currencies: Vec<CurrencyDefinition>,
+}
+
+impl CurrencyDefinitions {
+    fn to_currencies(&self) -> anyhow::Result<Vec<Currency>> {
+        self.currencies
+            .iter()
+            .map(CurrencyDefinition::to_currency)
+            .collect()
+    }
+}
+
+/// Parses the currencies defined in the given TOML `content`, as an array of `currencies`
+/// tables.
+///
+/// # Errors
+///
+/// This function returns an error:
+/// - If the `content` is not valid TOML currency definitions.
+/// - If a currency definition is invalid.
+pub fn parse_currencies_toml(content: &str) -> anyhow::Result<Vec<Currency>> {
+    let definitions: CurrencyDefinitions =
+        toml::from_str(content).context("Failed to parse TOML currency definitions")?;
+    definitions.to_currencies()
+}
+
+/// Parses the currencies defined in the given JSON `content`, as an array under a `currencies`
+/// key.
+///
+/// # Errors
+///
+/// This function returns an error:
+/// - If the `content` is not valid JSON currency definitions.
+/// - If a currency definition is invalid.
+pub fn parse_currencies_json(content: &str) -> anyhow::Result<Vec<Currency>> {
+    let definitions: CurrencyDefinitions =
+        serde_json::from_str(content).context("Failed to parse JSON currency definitions")?;
+    definitions.to_currencies()
+}
+
+/// Loads the currencies defined in the TOML or JSON file at the given `path`, with the format
+/// determined by the file extension (`.toml` or `.json`).
+///
+/// The currencies are not registered, see [`register_currencies`].
+///
+/// # Errors
+///
+/// This function returns an error:
+/// - If the file extension is not `.toml` or `.json`.
+/// - If the file cannot be read.
+/// - If the file content is not valid currency definitions.
+pub fn load_currency_definitions<P: AsRef<Path>>(path: P) -> anyhow::Result<Vec<Currency>> {
+    let path = path.as_ref();
+    let parse = match path.extension().and_then(|ext| ext.to_str()) {
+        Some(ext) if ext.eq_ignore_ascii_case("toml") => parse_currencies_toml,
+        Some(ext) if ext.eq_ignore_ascii_case("json") => parse_currencies_json,
+        _ => anyhow::bail!(
+            "Unsupported currency definitions file {}, expected `.toml` or `.json`",
+            path.display()
+        ),
+    };
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read currency definitions {}", path.display()))?;
+    parse(&content).with_context(|| format!("Invalid currency definitions {}", path.display()))
+}
+
+/// Registers the given `currencies` in the currency registry, after validating them for
+/// conflicts.
+///
+/// The currencies are registered together, so none are registered if any conflict.
+///
+/// - A currency identical to a registered currency is accepted (as a no-op).
+/// - If `overwrite` is `true`, a currency replaces any registered currency with the same code,
+///   other than a built-in currency (which would then disagree with its constant, such as
+///   `Currency::USD()`).
+///
+/// # Errors
+///
+/// This function returns an error:
+/// - If a currency code is defined more than once in `currencies`.
+/// - If a currency differs from the built-in currency with the same code.
+/// - If a currency differs from the registered currency with the same code, and not `overwrite`.
+/// - If a non-zero ISO 4217 code is shared with a currency with a different code.
+/// - If there is a failure acquiring the lock on the currency map.
+pub fn register_currencies(currencies: &[Currency], overwrite: bool) -> anyhow::Result<()> {
+    let mut map = CURRENCY_MAP
+        .lock()
+        .map_err(|e| anyhow::anyhow!("Failed to acquire lock on `CURRENCY_MAP`: {e}"))?;
+
+    check_currency_conflicts(&map, currencies, overwrite)?;
+
+    for currency in currencies {
+        map.insert(currency.code.to_string(), *currency);
+    }
+    Ok(())
+}
+
+/// Returns all currencies in the currency registry, sorted by code.
+///
+/// # Errors
+///
+/// This function returns an error:
+/// - If there is a failure acquiring the lock on the currency map.
+pub fn registered_currencies() -> anyhow::Result<Vec<Currency>> {
+    let map = CURRENCY_MAP
+        .lock()
+        .map_err(|e| anyhow::anyhow!("Failed to acquire lock on `CURRENCY_MAP`: {e}"))?;
+    let mut currencies: Vec<Currency> = map.values().copied().collect();
+    currencies.sort_unstable_by(|a, b| a.code.as_str().cmp(b.code.as_str()));
+    Ok(currencies)
+}
+
+fn check_currency_conflicts(
+    map: &HashMap<String, Currency>,
+    currencies: &[Currency],
+    overwrite: bool,
+) -> anyhow::Result<()> {
+    let mut codes = HashSet::with_capacity(currencies.len());
+    for currency in currencies {
+        anyhow::ensure!(
+            codes.insert(currency.code),
+            "Currency '{}' defined more than once",
+            currency.code
+        );
+
+        if let Some(builtin) = BUILTIN_CURRENCIES.get(currency.code.as_str()) {
+            anyhow::ensure!(
+                CurrencyDefinition::from(*builtin) == CurrencyDefinition::from(*currency),
+                "Currency definition {currency:?} conflicts with built-in {builtin:?}"
+            );
+        }
+
+        if let Some(registered) = map.get(currency.code.as_str()) {
+            anyhow::ensure!(
+                overwrite
+                    || CurrencyDefinition::from(*registered) == CurrencyDefinition::from(*currency),
+                "Currency definition {currency:?} conflicts with registered {registered:?}"
+            );
+        }
+    }
+
+    // Registered currencies being replaced no longer hold their ISO 4217 codes
+    for currency in currencies.iter().filter(|c| c.iso4217 != 0) {
+        let conflict = map
+            .values()
+            .filter(|c| !codes.contains(&c.code))
+            .chain(currencies)
+            .find(|c| c.iso4217 == currency.iso4217 && c.code != currency.code);
+        if let Some(other) = conflict {
+            anyhow::bail!(
+                "ISO 4217 code {} of currency '{}' conflicts with currency '{}'",
+                currency.iso4217,
+                currency.code,
+                other.code
+            );
+        }
+    }
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_parse_currencies_toml() {
+        let content = r#"
+            [[currencies]]
+            code = "TOMLCOIN"
+            precision = 6
+            name = "TOML Coin"
+            currency_type = "CRYPTO"
+
+            [[currencies]]
+            code = "TOMLFIAT"
+            precision = 2
+            iso4217 = 1001
+            name = "TOML Fiat"
+            currency_type = "FIAT"
+        "#;
+
+        let currencies = parse_currencies_toml(content).unwrap();
+
+        assert_eq!(
+            currencies,
+            vec![
+                Currency::new("TOMLCOIN", 6, 0, "TOML Coin", CurrencyType::Crypto),
+                Currency::new("TOMLFIAT", 2, 1001, "TOML Fiat", CurrencyType::Fiat),
+            ]
+        );
+        assert_eq!(currencies[0].precision, 6);
+        assert_eq!(currencies[1].iso4217, 1001);
+    }
+
+    #[rstest]
+    fn test_parse_currencies_json() {
+        let content = r#"{
+            "currencies": [
+                {
+                    "code": "JSONGOLD",
+                    "precision": 4,
+                    "name": "JSON Gold",
+                    "currency_type": "COMMODITY_BACKED"
+                }
+            ]
+        }"#;
+
+        let currencies = parse_currencies_json(content).unwrap();
+
+        assert_eq!(currencies.len(), 1);
+        assert_eq!(currencies[0].code.as_str(), "JSONGOLD");
+        assert_eq!(currencies[0].precision, 4);
+        assert_eq!(currencies[0].currency_type, CurrencyType::CommodityBacked);
+    }
+
+    #[rstest]
+    #[case(
+        r#"{"currencies": [{"code": "X1", "precision": 2, "name": "X", "currency_type": "NOPE"}]}"#
+    )]
+    #[case(r#"{"currencies": [{"code": "X1", "precision": 17, "name": "X", "currency_type": "FIAT"}]}"#)]
+    #[case(
+        r#"{"currencies": [{"code": "", "precision": 2, "name": "X", "currency_type": "FIAT"}]}"#
+    )]
+    #[case(r#"{"currencies": [{"code": "X1", "precision": 2, "name": "X", "currency_type": "FIAT", "extra": 1}]}"#)]
+    #[case(r#"[{"code": "X1", "precision": 2, "name": "X", "currency_type": "FIAT"}]"#)]
+    fn test_parse_currencies_json_invalid(#[case] content: &str) {
+        assert!(parse_currencies_json(content).is_err());
+    }
+
+    #[rstest]
+    fn test_load_currency_definitions_unsupported_extension() {
+        let result = load_currency_definitions("currencies.yaml");
+        assert!(result.unwrap_err().to_string().contains("Unsupported"));
+    }
+
+    #[rstest]
+    fn test_register_currencies_and_lookup() {
+        let currencies = vec![
+            Currency::new("REGCOIN1", 8, 0, "Registry Coin 1", CurrencyType::Crypto),
+            Currency::new("REGCOIN2", 4, 0, "Registry Coin 2", CurrencyType::Crypto),
+        ];
+
+        register_currencies(&currencies, false).unwrap();
+
+        assert_eq!(Currency::from("REGCOIN1").precision, 8);
+        assert_eq!(Currency::from("REGCOIN2").precision, 4);
+        let registered = registered_currencies().unwrap();
+        assert!(registered.contains(&currencies[0]));
+        assert!(registered.contains(&Currency::USD()));
+        assert!(registered
+            .windows(2)
+            .all(|w| w[0].code.as_str() < w[1].code.as_str()));
+    }
+
+    #[rstest]
+    fn test_register_currencies_identical_to_registered() {
+        register_currencies(&[Currency::USD(), Currency::BTC()], false).unwrap();
+    }
+
+    #[rstest]
+    fn test_register_currencies_conflicting_definition() {
+        let usd = Currency::new("USD", 4, 840, "United States dollar", CurrencyType::Fiat);
+
+        let result = register_currencies(&[usd], false);
+
+        assert!(result.unwrap_err().to_string().contains("conflicts"));
+        assert_eq!(Currency::USD().precision, 2);
+        assert_eq!(Currency::from("USD").precision, 2);
+    }
+
+    #[rstest]
+    fn test_register_currencies_cannot_overwrite_builtin() {
+        let usd = Currency::new("USD", 4, 840, "United States dollar", CurrencyType::Fiat);
+
+        let result = register_currencies(&[usd], true);
+
+        assert!(result.unwrap_err().to_string().contains("built-in"));
+        assert_eq!(Currency::USD().precision, 2);
+        assert_eq!(Currency::from("USD").precision, 2);
+        register_currencies(&[Currency::USD()], true).unwrap();
+    }
+
+    #[rstest]
+    fn test_register_currencies_overwrite() {
+        let coin = Currency::new("REGCOIN3", 8, 0, "Registry Coin 3", CurrencyType::Crypto);
+        let updated = Currency::new("REGCOIN3", 6, 0, "Registry Coin 3", CurrencyType::Crypto);
+        register_currencies(&[coin], false).unwrap();
+
+        assert!(register_currencies(&[updated], false).is_err());
+        register_currencies(&[updated], true).unwrap();
+
+        assert_eq!(Currency::from("REGCOIN3").precision, 6);
+    }
+
+    #[rstest]
+    fn test_register_currencies_duplicate_codes() {
+        let coin = Currency::new("REGCOIN4", 8, 0, "Registry Coin 4", CurrencyType::Crypto);
+
+        let result = register_currencies(&[coin, coin], false);
+
+        assert!(result.unwrap_err().to_string().contains("more than once"));
+        assert!(Currency::try_from_str("REGCOIN4").is_none());
+    }
+
+    #[rstest]
+    fn test_register_currencies_conflicting_iso4217() {
+        let fiat = Currency::new("REGFIAT1", 2, 978, "Registry Fiat 1", CurrencyType::Fiat);
+        let coin = Currency::new("REGCOIN5", 8, 0, "Registry Coin 5", CurrencyType::Crypto);
+
+        let result = register_currencies(&[coin, fiat], false);
+
+        assert!(result.unwrap_err().to_string().contains("ISO 4217"));
+        assert!(Currency::try_from_str("REGCOIN5").is_none());
+        assert!(Currency::try_from_str("REGFIAT1").is_none());
+    }
+}
@@ -187,6 +187,7 @@ impl BarSpecification {
     ///  - [`BarAggregation::Minute`]
     ///  - [`BarAggregation::Hour`]
     ///  - [`BarAggregation::Day`]
+    ///  - [`BarAggregation::Week`]
     ///  - [`BarAggregation::Month`]
     pub fn is_time_aggregated(&self) -> bool {
         matches!(
@@ -196,6 +197,7 @@ impl BarSpecification {
                 | BarAggregation::Minute
                 | BarAggregation::Hour
                 | BarAggregation::Day
+                | BarAggregation::Week
                 | BarAggregation::Month
         )
     }
@@ -0,0 +1,564 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A `CorporateAction` data type representing a split or dividend for an equity instrument.
+
+use std::{
+    collections::HashMap,
+    fmt::{Display, Formatter},
+    hash::Hash,
+};
+
+use nautilus_core::{
+    correctness::{check_predicate_true, FAILED},
+    serialization::Serializable,
+    UnixNanos,
+};
+use rust_decimal::{Decimal, RoundingStrategy};
+use serde::{Deserialize, Serialize};
+
+use super::{Bar, GetTsInit, QuoteTick, TradeTick};
+use crate::{
+    enums::CorporateActionType,
+    identifiers::InstrumentId,
+    types::{fixed::FIXED_PRECISION, price::PriceRaw, Money, Price, Quantity},
+};
+
+/// Represents a corporate action (split, reverse split or cash dividend) for an instrument.
+///
+/// Splits are expressed as `split_to` shares for every `split_from` shares held, so a 2-for-1
+/// split has a `split_to` of 2 and a `split_from` of 1. Prices, sizes and positions before the
+/// `ts_effective` (ex-date) are adjusted to be comparable with those after it.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "type")]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.model")
+)]
+pub struct CorporateAction {
+    /// The instrument ID for the corporate action.
+    pub instrument_id: InstrumentId,
+    /// The corporate action type.
+    pub action_type: CorporateActionType,
+    /// The number of shares held before a split (1 for dividends).
+    pub split_from: u32,
+    /// The number of shares held after a split (1 for dividends).
+    pub split_to: u32,
+    /// The cash amount paid per share (for cash dividends).
+    pub dividend: Option<Money>,
+    /// UNIX timestamp (nanoseconds) when the corporate action takes effect (the ex-date).
+    pub ts_effective: UnixNanos,
+    /// UNIX timestamp (nanoseconds) when the corporate action event occurred.
+    pub ts_event: UnixNanos,
+    /// UNIX timestamp (nanoseconds) when the struct was initialized.
+    pub ts_init: UnixNanos,
+}
+
+impl CorporateAction {
+    /// Creates a new [`CorporateAction`] instance with correctness checking.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `split_from` or `split_to` is zero.
+    /// - If a split does not increase, or a reverse split does not decrease, the shares held.
+    /// - If a cash dividend does not have a positive `dividend`, or has a split ratio.
+    /// - If a split has a `dividend`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_checked(
+        instrument_id: InstrumentId,
+        action_type: CorporateActionType,
+        split_from: u32,
+        split_to: u32,
+        dividend: Option<Money>,
+        ts_effective: UnixNanos,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> anyhow::Result<Self> {
+        check_predicate_true(split_from > 0, "`split_from` cannot be zero")?;
+        check_predicate_true(split_to > 0, "`split_to` cannot be zero")?;
+
+        match action_type {
+            CorporateActionType::Split => {
+                check_predicate_true(split_to > split_from, "split `split_to` > `split_from`")?;
+                check_predicate_true(dividend.is_none(), "split had a `dividend`")?;
+            }
+            CorporateActionType::ReverseSplit => {
+                check_predicate_true(
+                    split_to < split_from,
+                    "reverse split `split_to` < `split_from`",
+                )?;
+                check_predicate_true(dividend.is_none(), "reverse split had a `dividend`")?;
+            }
+            CorporateActionType::CashDividend => {
+                check_predicate_true(
+                    dividend.is_some_and(|dividend| dividend.as_f64() > 0.0),
+                    "cash dividend `dividend` must be positive",
+                )?;
+                check_predicate_true(
+                    split_from == 1 && split_to == 1,
+                    "cash dividend had a split ratio",
+                )?;
+            }
+        }
+
+        Ok(Self {
+            instrument_id,
+            action_type,
+            split_from,
+            split_to,
+            dividend,
+            ts_effective,
+            ts_event,
+            ts_init,
+        })
+    }
+
+    /// Creates a new [`CorporateAction`] instance.
+    ///
+    /// # Panics
+    ///
+    /// This function panics:
+    /// - If a correctness check fails. See [`CorporateAction::new_checked`] for more details.
+    #[allow(clippy::too_many_arguments)]
+    #[must_use]
+    pub fn new(
+        instrument_id: InstrumentId,
+        action_type: CorporateActionType,
+        split_from: u32,
+        split_to: u32,
+        dividend: Option<Money>,
+        ts_effective: UnixNanos,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self::new_checked(
+            instrument_id,
+            action_type,
+            split_from,
+            split_to,
+            dividend,
+            ts_effective,
+            ts_event,
+            ts_init,
+        )
+        .expect(FAILED)
+    }
+
+    /// Returns whether the corporate action is a split or reverse split.
+    #[must_use]
+    pub fn is_split(&self) -> bool {
+        matches!(
+            self.action_type,
+            CorporateActionType::Split | CorporateActionType::ReverseSplit
+        )
+    }
+
+    /// Returns the factor by which sizes and position quantities before the ex-date are multiplied.
+    #[must_use]
+    pub fn size_factor(&self) -> f64 {
+        f64::from(self.split_to) / f64::from(self.split_from)
+    }
+
+    /// Returns the given `qty` multiplied by the split ratio.
+    ///
+    /// The result keeps the precision of `qty`, extended when needed to hold a fractional
+    /// result exactly (such as after a reverse split), so historical sizes and split adjusted
+    /// position quantities are never rounded away.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the adjusted quantity is outside the valid range.
+    pub fn adjust_quantity(&self, qty: Quantity) -> anyhow::Result<Quantity> {
+        if !self.is_split() {
+            return Ok(qty);
+        }
+
+        let value = (qty.as_decimal() * Decimal::from(self.split_to)
+            / Decimal::from(self.split_from))
+        .normalize();
+        let precision = value.scale().min(u32::from(FIXED_PRECISION)) as u8;
+        Quantity::from_decimal(value, qty.precision.max(precision))
+    }
+
+    /// Returns the factor by which prices before the ex-date are multiplied.
+    ///
+    /// For cash dividends this is the ratio of the `reference_price` (the last price before the
+    /// ex-date) less the dividend, to the `reference_price`, or 1 without a reference price.
+    #[must_use]
+    pub fn price_factor(&self, reference_price: Option<Decimal>) -> Decimal {
+        match (self.dividend, reference_price) {
+            _ if self.is_split() => Decimal::from(self.split_from) / Decimal::from(self.split_to),
+            (Some(dividend), Some(price)) if price > dividend.as_decimal() => {
+                (price - dividend.as_decimal()) / price
+            }
+            _ => Decimal::ONE,
+        }
+    }
+
+    /// Returns the given `bars` with those before the ex-date adjusted for the corporate action.
+    #[must_use]
+    pub fn adjust_bars(&self, bars: &[Bar]) -> Vec<Bar> {
+        let reference_price = bars
+            .iter()
+            .rfind(|bar| bar.ts_event < self.ts_effective)
+            .map(|bar| bar.close.as_decimal());
+        let price_factor = self.price_factor(reference_price);
+
+        bars.iter()
+            .map(|bar| {
+                if bar.ts_event >= self.ts_effective {
+                    return *bar;
+                }
+                Bar {
+                    open: adjust_price(bar.open, price_factor),
+                    high: adjust_price(bar.high, price_factor),
+                    low: adjust_price(bar.low, price_factor),
+                    close: adjust_price(bar.close, price_factor),
+                    volume: self.adjust_size(bar.volume),
+                    ..*bar
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the given `quotes` with those before the ex-date adjusted for the corporate action.
+    #[must_use]
+    pub fn adjust_quotes(&self, quotes: &[QuoteTick]) -> Vec<QuoteTick> {
+        let reference_price = quotes
+            .iter()
+            .rfind(|quote| quote.ts_event < self.ts_effective)
+            .map(|quote| {
+                (quote.bid_price.as_decimal() + quote.ask_price.as_decimal()) / Decimal::TWO
+            });
+        let price_factor = self.price_factor(reference_price);
+
+        quotes
+            .iter()
+            .map(|quote| {
+                if quote.ts_event >= self.ts_effective {
+                    return *quote;
+                }
+                QuoteTick {
+                    bid_price: adjust_price(quote.bid_price, price_factor),
+                    ask_price: adjust_price(quote.ask_price, price_factor),
+                    bid_size: self.adjust_size(quote.bid_size),
+                    ask_size: self.adjust_size(quote.ask_size),
+                    ..*quote
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the given `trades` with those before the ex-date adjusted for the corporate action.
+    #[must_use]
+    pub fn adjust_trades(&self, trades: &[TradeTick]) -> Vec<TradeTick> {
+        let reference_price = trades
+            .iter()
+            .rfind(|trade| trade.ts_event < self.ts_effective)
+            .map(|trade| trade.price.as_decimal());
+        let price_factor = self.price_factor(reference_price);
+
+        trades
+            .iter()
+            .map(|trade| {
+                if trade.ts_event >= self.ts_effective {
+                    return *trade;
+                }
+                TradeTick {
+                    price: adjust_price(trade.price, price_factor),
+                    size: self.adjust_size(trade.size),
+                    ..*trade
+                }
+            })
+            .collect()
+    }
+
+    fn adjust_size(&self, size: Quantity) -> Quantity {
+        self.adjust_quantity(size).expect(FAILED)
+    }
+
+    /// Returns the metadata for the type, for use with serialization formats.
+    #[must_use]
+    pub fn get_metadata(instrument_id: &InstrumentId) -> HashMap<String, String> {
+        let mut metadata = HashMap::new();
+        metadata.insert("instrument_id".to_string(), instrument_id.to_string());
+        metadata
+    }
+}
+
+/// Returns the given `price` multiplied by `factor`, rounded once to the price precision.
+fn adjust_price(price: Price, factor: Decimal) -> Price {
+    let value = (price.as_decimal() * factor).round_dp_with_strategy(
+        u32::from(price.precision),
+        RoundingStrategy::MidpointAwayFromZero,
+    );
+    let raw = value.mantissa() * 10_i128.pow(u32::from(FIXED_PRECISION) - value.scale());
+    #[allow(clippy::useless_conversion)] // Required for precision modes
+    let raw = PriceRaw::try_from(raw).expect(FAILED);
+    Price::from_raw(raw, price.precision)
+}
+
+impl Display for CorporateAction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.dividend {
+            Some(dividend) => write!(
+                f,
+                "{},{},{},{},{}",
+                self.instrument_id, self.action_type, dividend, self.ts_effective, self.ts_init,
+            ),
+            None => write!(
+                f,
+                "{},{},{}:{},{},{}",
+                self.instrument_id,
+                self.action_type,
+                self.split_to,
+                self.split_from,
+                self.ts_effective,
+                self.ts_init,
+            ),
+        }
+    }
+}
+
+impl Serializable for CorporateAction {}
+
+impl GetTsInit for CorporateAction {
+    fn ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::serialization::Serializable;
+    use rstest::rstest;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+    use crate::data::{
+        stubs::{stub_corporate_action_dividend, stub_corporate_action_split},
+        BarType,
+    };
+
+    #[rstest]
+    fn test_to_string(
+        stub_corporate_action_split: CorporateAction,
+        stub_corporate_action_dividend: CorporateAction,
+    ) {
+        assert_eq!(
+            stub_corporate_action_split.to_string(),
+            "AAPL.XNAS,SPLIT,4:1,10,1"
+        );
+        assert_eq!(
+            stub_corporate_action_dividend.to_string(),
+            "AAPL.XNAS,CASH_DIVIDEND,0.50 USD,10,1"
+        );
+    }
+
+    #[rstest]
+    #[case(CorporateActionType::Split, 1, 1, None)]
+    #[case(CorporateActionType::ReverseSplit, 1, 4, None)]
+    #[case(CorporateActionType::Split, 0, 4, None)]
+    #[case(CorporateActionType::CashDividend, 1, 1, None)]
+    #[case(CorporateActionType::CashDividend, 1, 2, Some(Money::from("0.50 USD")))]
+    #[case(CorporateActionType::Split, 1, 2, Some(Money::from("0.50 USD")))]
+    fn test_new_checked_with_invalid_args_returns_error(
+        #[case] action_type: CorporateActionType,
+        #[case] split_from: u32,
+        #[case] split_to: u32,
+        #[case] dividend: Option<Money>,
+    ) {
+        let result = CorporateAction::new_checked(
+            InstrumentId::from("AAPL.XNAS"),
+            action_type,
+            split_from,
+            split_to,
+            dividend,
+            UnixNanos::from(10),
+            UnixNanos::from(1),
+            UnixNanos::from(1),
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_price_and_size_factors(
+        stub_corporate_action_split: CorporateAction,
+        stub_corporate_action_dividend: CorporateAction,
+    ) {
+        assert_eq!(stub_corporate_action_split.size_factor(), 4.0);
+        assert_eq!(
+            stub_corporate_action_split.price_factor(Some(dec!(100))),
+            dec!(0.25)
+        );
+        assert_eq!(stub_corporate_action_dividend.size_factor(), 1.0);
+        assert_eq!(
+            stub_corporate_action_dividend.price_factor(Some(dec!(50))),
+            dec!(0.99)
+        );
+        assert_eq!(
+            stub_corporate_action_dividend.price_factor(None),
+            Decimal::ONE
+        );
+    }
+
+    #[rstest]
+    fn test_adjust_bars_for_split(stub_corporate_action_split: CorporateAction) {
+        let bar_type = BarType::from("AAPL.XNAS-1-DAY-LAST-EXTERNAL");
+        let before = Bar::new(
+            bar_type,
+            Price::from("400.00"),
+            Price::from("420.00"),
+            Price::from("396.00"),
+            Price::from("408.00"),
+            Quantity::from(1_000),
+            UnixNanos::from(5),
+            UnixNanos::from(5),
+        );
+        let after = Bar::new(
+            bar_type,
+            Price::from("102.00"),
+            Price::from("103.00"),
+            Price::from("101.00"),
+            Price::from("102.50"),
+            Quantity::from(4_000),
+            UnixNanos::from(10),
+            UnixNanos::from(10),
+        );
+
+        let adjusted = stub_corporate_action_split.adjust_bars(&[before, after]);
+
+        assert_eq!(adjusted[0].open, Price::from("100.00"));
+        assert_eq!(adjusted[0].high, Price::from("105.00"));
+        assert_eq!(adjusted[0].low, Price::from("99.00"));
+        assert_eq!(adjusted[0].close, Price::from("102.00"));
+        assert_eq!(adjusted[0].volume, Quantity::from(4_000));
+        assert_eq!(adjusted[1], after);
+    }
+
+    #[rstest]
+    fn test_adjust_trades_for_dividend(stub_corporate_action_dividend: CorporateAction) {
+        let before = TradeTick {
+            instrument_id: InstrumentId::from("AAPL.XNAS"),
+            price: Price::from("50.00"),
+            size: Quantity::from(100),
+            ts_event: UnixNanos::from(5),
+            ts_init: UnixNanos::from(5),
+            ..Default::default()
+        };
+        let after = TradeTick {
+            price: Price::from("49.60"),
+            ts_event: UnixNanos::from(10),
+            ts_init: UnixNanos::from(10),
+            ..before
+        };
+
+        let adjusted = stub_corporate_action_dividend.adjust_trades(&[before, after]);
+
+        assert_eq!(adjusted[0].price, Price::from("49.50"));
+        assert_eq!(adjusted[0].size, Quantity::from(100));
+        assert_eq!(adjusted[1], after);
+    }
+
+    #[rstest]
+    fn test_adjust_quotes_for_reverse_split() {
+        let action = CorporateAction::new(
+            InstrumentId::from("AAPL.XNAS"),
+            CorporateActionType::ReverseSplit,
+            10,
+            1,
+            None,
+            UnixNanos::from(10),
+            UnixNanos::from(1),
+            UnixNanos::from(1),
+        );
+        let quote = QuoteTick {
+            bid_price: Price::from("1.00"),
+            ask_price: Price::from("1.02"),
+            bid_size: Quantity::from(1_000),
+            ask_size: Quantity::from(2_000),
+            ts_event: UnixNanos::from(5),
+            ts_init: UnixNanos::from(5),
+            ..Default::default()
+        };
+
+        let adjusted = action.adjust_quotes(&[quote]);
+
+        assert_eq!(adjusted[0].bid_price, Price::from("10.00"));
+        assert_eq!(adjusted[0].ask_price, Price::from("10.20"));
+        assert_eq!(adjusted[0].bid_size, Quantity::from(100));
+        assert_eq!(adjusted[0].ask_size, Quantity::from(200));
+    }
+
+    #[rstest]
+    fn test_adjust_trades_for_split_rounds_to_price_precision() {
+        let action = CorporateAction::new(
+            InstrumentId::from("AAPL.XNAS"),
+            CorporateActionType::Split,
+            1,
+            3,
+            None,
+            UnixNanos::from(10),
+            UnixNanos::from(1),
+            UnixNanos::from(1),
+        );
+        let trade = TradeTick {
+            instrument_id: InstrumentId::from("AAPL.XNAS"),
+            price: Price::from("100.01"),
+            size: Quantity::from(100),
+            ts_event: UnixNanos::from(5),
+            ts_init: UnixNanos::from(5),
+            ..Default::default()
+        };
+
+        let adjusted = action.adjust_trades(&[trade]);
+
+        assert_eq!(adjusted[0].price, Price::from("33.34"));
+        assert_eq!(adjusted[0].price.precision, 2);
+        assert_eq!(adjusted[0].size, Quantity::from(300));
+    }
+
+    #[rstest]
+    fn test_adjust_quantity_keeps_fractional_size() {
+        let action = CorporateAction::new(
+            InstrumentId::from("AAPL.XNAS"),
+            CorporateActionType::ReverseSplit,
+            4,
+            1,
+            None,
+            UnixNanos::from(10),
+            UnixNanos::from(1),
+            UnixNanos::from(1),
+        );
+
+        let adjusted = action.adjust_quantity(Quantity::from(10)).unwrap();
+
+        assert_eq!(adjusted, Quantity::from("2.5"));
+        assert_eq!(adjusted.precision, 1);
+    }
+
+    #[rstest]
+    fn test_json_serialization(stub_corporate_action_dividend: CorporateAction) {
+        let serialized = stub_corporate_action_dividend.as_json_bytes().unwrap();
+        let deserialized = CorporateAction::from_json_bytes(serialized.as_ref()).unwrap();
+        assert_eq!(deserialized, stub_corporate_action_dividend);
+    }
+}
@@ -0,0 +1,175 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A `FundingRateUpdate` data type for perpetual swap funding rates.
+
+use std::{
+    collections::HashMap,
+    fmt::{Display, Formatter},
+};
+
+use indexmap::IndexMap;
+use nautilus_core::{
+    correctness::{check_predicate_true, FAILED},
+    serialization::Serializable,
+    UnixNanos,
+};
+use serde::{Deserialize, Serialize};
+
+use super::GetTsInit;
+use crate::identifiers::InstrumentId;
+
+/// Represents a funding rate update for a perpetual swap instrument.
+///
+/// The rate is expressed as a fraction of position notional per funding interval (e.g. a rate of
+/// 0.0001 is 0.01%). A positive rate means longs pay shorts, a negative rate means shorts pay longs.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.model")
+)]
+pub struct FundingRateUpdate {
+    /// The instrument ID for the funding rate.
+    pub instrument_id: InstrumentId,
+    /// The funding rate for the current interval.
+    pub rate: f64,
+    /// UNIX timestamp (nanoseconds) when the funding rate event occurred.
+    pub ts_event: UnixNanos,
+    /// UNIX timestamp (nanoseconds) when the struct was initialized.
+    pub ts_init: UnixNanos,
+}
+
+impl FundingRateUpdate {
+    /// Creates a new [`FundingRateUpdate`] instance with correctness checking.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `rate` is not finite.
+    ///
+    /// # Notes
+    ///
+    /// PyO3 requires a `Result` type for proper error handling and stacktrace printing in Python.
+    pub fn new_checked(
+        instrument_id: InstrumentId,
+        rate: f64,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> anyhow::Result<Self> {
+        check_predicate_true(rate.is_finite(), "`rate` was not finite")?;
+
+        Ok(Self {
+            instrument_id,
+            rate,
+            ts_event,
+            ts_init,
+        })
+    }
+
+    /// Creates a new [`FundingRateUpdate`] instance.
+    ///
+    /// # Panics
+    ///
+    /// This function panics:
+    /// - If `rate` is not finite.
+    #[must_use]
+    pub fn new(
+        instrument_id: InstrumentId,
+        rate: f64,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self::new_checked(instrument_id, rate, ts_event, ts_init).expect(FAILED)
+    }
+
+    /// Returns the metadata for the type, for use with serialization formats.
+    #[must_use]
+    pub fn get_metadata(instrument_id: &InstrumentId) -> HashMap<String, String> {
+        let mut metadata = HashMap::new();
+        metadata.insert("instrument_id".to_string(), instrument_id.to_string());
+        metadata
+    }
+
+    /// Returns the field map for the type, for use with Arrow schemas.
+    #[must_use]
+    pub fn get_fields() -> IndexMap<String, String> {
+        let mut metadata = IndexMap::new();
+        metadata.insert("rate".to_string(), "Float64".to_string());
+        metadata.insert("ts_event".to_string(), "UInt64".to_string());
+        metadata.insert("ts_init".to_string(), "UInt64".to_string());
+        metadata
+    }
+}
+
+impl Display for FundingRateUpdate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{},{},{},{}",
+            self.instrument_id, self.rate, self.ts_event, self.ts_init
+        )
+    }
+}
+
+impl Serializable for FundingRateUpdate {}
+
+impl GetTsInit for FundingRateUpdate {
+    fn ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::{serialization::Serializable, UnixNanos};
+    use rstest::rstest;
+
+    use crate::{
+        data::{stubs::stub_funding_rate_update, FundingRateUpdate},
+        identifiers::InstrumentId,
+    };
+
+    #[rstest]
+    fn test_new_checked_with_non_finite_rate_error() {
+        let result = FundingRateUpdate::new_checked(
+            InstrumentId::from("BTCUSDT-PERP.BINANCE"),
+            f64::NAN,
+            UnixNanos::from(1),
+            UnixNanos::from(2),
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_to_string(stub_funding_rate_update: FundingRateUpdate) {
+        assert_eq!(
+            stub_funding_rate_update.to_string(),
+            "BTCUSDT-PERP.BINANCE,0.0001,1,2"
+        );
+    }
+
+    #[rstest]
+    fn test_json_serialization(stub_funding_rate_update: FundingRateUpdate) {
+        let serialized = stub_funding_rate_update.as_json_bytes().unwrap();
+        let deserialized = FundingRateUpdate::from_json_bytes(serialized.as_ref()).unwrap();
+        assert_eq!(deserialized, stub_funding_rate_update);
+    }
+}
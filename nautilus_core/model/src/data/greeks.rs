@@ -78,6 +78,235 @@ pub fn imply_vol(s: f64, r: f64, b: f64, is_call: bool, k: f64, t: f64, price: f
     implied_black_volatility(forward_price, forward, k, t, is_call)
 }
 
+// dF_t = F_t * sigma * dW_t (futures)
+// dC_t = r * C_t * dt (cash numeraire)
+/// Computes the price and greeks of a European option on a futures contract under the Black-76
+/// model, with delta and gamma with respect to the futures price `f`.
+#[must_use]
+pub fn black76_greeks(
+    f: f64,
+    r: f64,
+    sigma: f64,
+    is_call: bool,
+    k: f64,
+    t: f64,
+    multiplier: f64,
+) -> BlackScholesGreeksResult {
+    // A futures contract has zero cost of carry
+    black_scholes_greeks(f, r, 0.0, sigma, is_call, k, t, multiplier)
+}
+
+/// Returns the implied volatility of a European option on a futures contract under the Black-76
+/// model, from its discounted (present value) `price`.
+#[must_use]
+pub fn imply_vol_black76(f: f64, r: f64, is_call: bool, k: f64, t: f64, price: f64) -> f64 {
+    let forward_price = price * (r * t).exp();
+
+    implied_black_volatility(forward_price, f, k, t, is_call)
+}
+
+const BAW_MAX_ITERATIONS: usize = 100;
+const BAW_TOLERANCE: f64 = 1e-6;
+
+/// Returns the price of an American option using the Barone-Adesi and Whaley (1987) quadratic
+/// approximation, with the same parameters as [`black_scholes_greeks`].
+///
+/// Calls with a cost of carry `b` of at least the rate `r` are never optimally exercised early,
+/// so are priced as European (as are puts when `r` is not positive). At or after expiry
+/// (`t` not positive) the price is the intrinsic value.
+#[must_use]
+pub fn american_option_price_baw(
+    s: f64,
+    r: f64,
+    b: f64,
+    sigma: f64,
+    is_call: bool,
+    k: f64,
+    t: f64,
+) -> f64 {
+    if t <= 0.0 {
+        return intrinsic_value(s, is_call, k);
+    }
+
+    let european = black_scholes_greeks(s, r, b, sigma, is_call, k, t, 1.0).price;
+    if (is_call && b >= r) || (!is_call && r <= 0.0) {
+        return european;
+    }
+
+    let phi = if is_call { 1.0 } else { -1.0 };
+    let s_crit = baw_critical_price(r, b, sigma, is_call, k, t);
+    if phi * (s - s_crit) >= 0.0 {
+        return phi * (s - k); // Exercised immediately
+    }
+
+    let q = baw_q(r, b, sigma, t, phi, true);
+    let d1 = black_scholes_d1(s_crit, b, sigma, k, t);
+    let a = phi * (s_crit / q) * (1.0 - ((b - r) * t).exp() * norm_cdf(phi * d1));
+
+    european + a * (s / s_crit).powf(q)
+}
+
+/// Returns the price of an American option from a Cox-Ross-Rubinstein binomial tree with the
+/// given number of `steps`, with the same parameters as [`black_scholes_greeks`].
+///
+/// At or after expiry (`t` not positive) the price is the intrinsic value.
+#[must_use]
+#[allow(clippy::too_many_arguments)]
+pub fn american_option_price_binomial(
+    s: f64,
+    r: f64,
+    b: f64,
+    sigma: f64,
+    is_call: bool,
+    k: f64,
+    t: f64,
+    steps: usize,
+) -> f64 {
+    if t <= 0.0 {
+        return intrinsic_value(s, is_call, k);
+    }
+
+    let steps = steps.max(1);
+    let dt = t / steps as f64;
+    let u = (sigma * dt.sqrt()).exp();
+    let d = 1.0 / u;
+    let p = ((b * dt).exp() - d) / (u - d);
+    let discount = (-r * dt).exp();
+    let phi = if is_call { 1.0 } else { -1.0 };
+
+    // Node `i` at step `n` has `i` up moves, so an underlying price of `s * u^(2i - n)`
+    let payoff = |i: usize, n: usize| {
+        let s_node = s * u.powi(2 * i as i32 - n as i32);
+        (phi * (s_node - k)).max(0.0)
+    };
+
+    let mut values: Vec<f64> = (0..=steps).map(|i| payoff(i, steps)).collect();
+    for n in (0..steps).rev() {
+        for i in 0..=n {
+            let continuation = discount * (p * values[i + 1] + (1.0 - p) * values[i]);
+            values[i] = continuation.max(payoff(i, n));
+        }
+    }
+
+    values[0]
+}
+
+/// Computes the price and greeks of an American option from the Barone-Adesi and Whaley
+/// approximation, with the same parameters and conventions as [`black_scholes_greeks`].
+///
+/// The greeks are computed by central finite differences of the approximated price. At or after
+/// expiry (`t` not positive) the price is the intrinsic value, with zero greeks.
+#[must_use]
+#[allow(clippy::too_many_arguments)]
+pub fn american_option_greeks(
+    s: f64,
+    r: f64,
+    b: f64,
+    sigma: f64,
+    is_call: bool,
+    k: f64,
+    t: f64,
+    multiplier: f64,
+) -> BlackScholesGreeksResult {
+    if t <= 0.0 {
+        return BlackScholesGreeksResult {
+            price: multiplier * intrinsic_value(s, is_call, k),
+            delta: 0.0,
+            gamma: 0.0,
+            vega: 0.0,
+            theta: 0.0,
+        };
+    }
+
+    let price_fn =
+        |s: f64, sigma: f64, t: f64| american_option_price_baw(s, r, b, sigma, is_call, k, t);
+    let ds = s * 1e-4;
+    let eps = 1e-4_f64.min(0.5 * t);
+
+    let price = price_fn(s, sigma, t);
+    let price_up = price_fn(s + ds, sigma, t);
+    let price_down = price_fn(s - ds, sigma, t);
+
+    let delta = (price_up - price_down) / (2.0 * ds);
+    let gamma = (price_up + price_down - 2.0 * price) / (ds * ds);
+    let vega = (price_fn(s, sigma + eps, t) - price_fn(s, sigma - eps, t)) / (2.0 * eps) * 0.01; // in absolute percent change
+    let theta = (price_fn(s, sigma, t - eps) - price_fn(s, sigma, t + eps)) / (2.0 * eps)
+        * 0.0027378507871321013; // 1 / 365.25 in change per calendar day
+
+    BlackScholesGreeksResult {
+        price: multiplier * price,
+        delta: multiplier * delta,
+        gamma: multiplier * gamma,
+        vega: multiplier * vega,
+        theta: multiplier * theta,
+    }
+}
+
+fn intrinsic_value(s: f64, is_call: bool, k: f64) -> f64 {
+    if is_call {
+        (s - k).max(0.0)
+    } else {
+        (k - s).max(0.0)
+    }
+}
+
+fn black_scholes_d1(s: f64, b: f64, sigma: f64, k: f64, t: f64) -> f64 {
+    ((s / k).ln() + (b + 0.5 * sigma.powi(2)) * t) / (sigma * t.sqrt())
+}
+
+// The exponent of the early exercise premium, for a finite expiry or the perpetual option
+fn baw_q(r: f64, b: f64, sigma: f64, t: f64, phi: f64, is_finite: bool) -> f64 {
+    let n = 2.0 * b / sigma.powi(2);
+    let m = 2.0 * r / sigma.powi(2);
+    let m = if is_finite {
+        m / (1.0 - (-r * t).exp())
+    } else {
+        m
+    };
+
+    0.5 * (-(n - 1.0) + phi * ((n - 1.0).powi(2) + 4.0 * m).sqrt())
+}
+
+// Solves for the critical underlying price beyond which the option is exercised, by Newton's
+// method from the seed value of Barone-Adesi and Whaley
+fn baw_critical_price(r: f64, b: f64, sigma: f64, is_call: bool, k: f64, t: f64) -> f64 {
+    let phi = if is_call { 1.0 } else { -1.0 };
+    let scaled_vol = sigma * t.sqrt();
+    let q = baw_q(r, b, sigma, t, phi, true);
+    let s_perpetual = k / (1.0 - 1.0 / baw_q(r, b, sigma, t, phi, false));
+
+    let mut s_i = if is_call {
+        let h = -(b * t + 2.0 * scaled_vol) * k / (s_perpetual - k);
+        k + (s_perpetual - k) * (1.0 - h.exp())
+    } else {
+        let h = (b * t - 2.0 * scaled_vol) * k / (k - s_perpetual);
+        s_perpetual + (k - s_perpetual) * h.exp()
+    };
+
+    let carry = ((b - r) * t).exp();
+    for _ in 0..BAW_MAX_ITERATIONS {
+        let d1 = black_scholes_d1(s_i, b, sigma, k, t);
+        let lhs = phi * (s_i - k);
+        let rhs = black_scholes_greeks(s_i, r, b, sigma, is_call, k, t, 1.0).price
+            + phi * (1.0 - carry * norm_cdf(phi * d1)) * s_i / q;
+
+        if ((lhs - rhs) / k).abs() < BAW_TOLERANCE {
+            break;
+        }
+
+        let pdf_term = carry * norm_pdf(d1) / scaled_vol;
+        s_i = if is_call {
+            let slope = carry * norm_cdf(d1) * (1.0 - 1.0 / q) + (1.0 - pdf_term) / q;
+            (k + rhs - slope * s_i) / (1.0 - slope)
+        } else {
+            let slope = -carry * norm_cdf(-d1) * (1.0 - 1.0 / q) - (1.0 + pdf_term) / q;
+            (k - rhs + slope * s_i) / (1.0 + slope)
+        };
+    }
+
+    s_i
+}
+
 #[repr(C)]
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
 #[cfg_attr(
@@ -300,4 +529,117 @@ mod tests {
             "Theta difference exceeds tolerance"
         );
     }
+
+    #[rstest]
+    fn test_black76_put_call_parity() {
+        let (f, k, t, r, sigma) = (100.0, 95.0, 0.5, 0.03, 0.25);
+
+        let call = black76_greeks(f, r, sigma, true, k, t, 1.0);
+        let put = black76_greeks(f, r, sigma, false, k, t, 1.0);
+
+        let parity = (-r * t).exp() * (f - k);
+        assert!((call.price - put.price - parity).abs() < 1e-10);
+        assert!((call.delta - put.delta - (-r * t).exp()).abs() < 1e-10);
+    }
+
+    #[rstest]
+    fn test_imply_vol_black76_round_trip() {
+        let (f, k, t, r, sigma) = (100.0, 105.0, 0.75, 0.02, 0.3);
+        let price = black76_greeks(f, r, sigma, true, k, t, 1.0).price;
+
+        let vol = imply_vol_black76(f, r, true, k, t, price);
+
+        assert!(
+            (vol - sigma).abs() < 1e-8,
+            "Vol difference exceeds tolerance"
+        );
+    }
+
+    #[rstest]
+    #[case(90.0, 10.0)]
+    #[case(100.0, 1.876_922)]
+    #[case(110.0, 0.040_996)]
+    fn test_american_put_price_baw_futures_option(#[case] s: f64, #[case] expected: f64) {
+        let price = american_option_price_baw(s, 0.1, 0.0, 0.15, false, 100.0, 0.1);
+
+        assert!(
+            (price - expected).abs() < 1e-4,
+            "Price {price} differs from {expected}"
+        );
+    }
+
+    #[rstest]
+    #[case(true, 0.08, 0.04, 0.3, 0.5)]
+    #[case(false, 0.05, 0.05, 0.2, 1.0)]
+    #[case(false, 0.1, 0.0, 0.15, 0.1)]
+    fn test_american_price_baw_close_to_binomial(
+        #[case] is_call: bool,
+        #[case] r: f64,
+        #[case] b: f64,
+        #[case] sigma: f64,
+        #[case] t: f64,
+    ) {
+        let (s, k) = (100.0, 100.0);
+
+        let baw = american_option_price_baw(s, r, b, sigma, is_call, k, t);
+        let binomial = american_option_price_binomial(s, r, b, sigma, is_call, k, t, 1_000);
+        let european = black_scholes_greeks(s, r, b, sigma, is_call, k, t, 1.0).price;
+
+        assert!(
+            (baw - binomial).abs() < 0.02,
+            "BAW {baw} vs binomial {binomial}"
+        );
+        assert!(baw >= european);
+        // Allow for the discretization error of the tree at the money
+        assert!(
+            binomial >= european - 5e-3,
+            "Binomial {binomial} vs european {european}"
+        );
+    }
+
+    #[rstest]
+    fn test_american_call_without_early_exercise_is_european() {
+        let (s, k, t, r, b, sigma) = (100.0, 100.1, 1.0, 0.01, 0.01, 0.2);
+
+        let american = american_option_greeks(s, r, b, sigma, true, k, t, 1.0);
+        let european = black_scholes_greeks(s, r, b, sigma, true, k, t, 1.0);
+
+        let tolerance = 1e-5;
+        assert!((american.price - european.price).abs() < tolerance);
+        assert!((american.delta - european.delta).abs() < tolerance);
+        assert!((american.gamma - european.gamma).abs() < tolerance);
+        assert!((american.vega - european.vega).abs() < tolerance);
+        assert!((american.theta - european.theta).abs() < tolerance);
+    }
+
+    #[rstest]
+    fn test_american_put_greeks_deep_in_the_money_exercised() {
+        let greeks = american_option_greeks(60.0, 0.1, 0.1, 0.2, false, 100.0, 1.0, 1.0);
+
+        assert!((greeks.price - 40.0).abs() < 1e-10);
+        assert!((greeks.delta + 1.0).abs() < 1e-8);
+        assert!(greeks.gamma.abs() < 1e-6);
+    }
+
+    #[rstest]
+    #[case(true, 110.0, 10.0)]
+    #[case(false, 110.0, 0.0)]
+    #[case(false, 90.0, 10.0)]
+    fn test_american_option_greeks_at_expiry(
+        #[case] is_call: bool,
+        #[case] s: f64,
+        #[case] expected: f64,
+    ) {
+        let greeks = american_option_greeks(s, 0.05, 0.05, 0.2, is_call, 100.0, 0.0, 10.0);
+
+        assert_eq!(greeks.price, 10.0 * expected);
+        assert_eq!(greeks.delta, 0.0);
+        assert_eq!(greeks.gamma, 0.0);
+        assert_eq!(greeks.vega, 0.0);
+        assert_eq!(greeks.theta, 0.0);
+        assert_eq!(
+            american_option_price_binomial(s, 0.05, 0.05, 0.2, is_call, 100.0, 0.0, 100),
+            expected
+        );
+    }
 }
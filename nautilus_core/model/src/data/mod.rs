@@ -16,14 +16,18 @@
 //! Data types for the trading domain model.
 
 pub mod bar;
+pub mod corporate;
 pub mod delta;
 pub mod deltas;
 pub mod depth;
+pub mod funding;
 pub mod greeks;
 pub mod order;
+pub mod prices;
 pub mod quote;
 pub mod status;
 pub mod trade;
+pub mod volatility;
 
 #[cfg(feature = "stubs")]
 pub mod stubs;
@@ -43,14 +47,18 @@ use serde_json::to_string;
 // Re-exports
 #[rustfmt::skip]  // Keep these grouped
 pub use bar::{Bar, BarSpecification, BarType};
+pub use corporate::CorporateAction;
 pub use delta::OrderBookDelta;
 pub use deltas::{OrderBookDeltas, OrderBookDeltas_API};
 pub use depth::{OrderBookDepth10, DEPTH10_LEN};
+pub use funding::FundingRateUpdate;
 pub use greeks::{black_scholes_greeks, BlackScholesGreeksResult};
 pub use order::{BookOrder, NULL_ORDER};
+pub use prices::{IndexPriceUpdate, MarkPriceUpdate};
 pub use quote::QuoteTick;
 pub use status::InstrumentStatus;
 pub use trade::TradeTick;
+pub use volatility::{SmileFitMethod, VolatilitySmile, VolatilitySurface};
 
 use crate::{
     enums::BookType,
@@ -71,6 +79,9 @@ pub enum Data {
     Quote(QuoteTick),
     Trade(TradeTick),
     Bar(Bar),
+    MarkPrice(MarkPriceUpdate),
+    IndexPrice(IndexPriceUpdate),
+    FundingRate(FundingRateUpdate),
 }
 
 macro_rules! impl_try_from_data {
@@ -94,6 +105,9 @@ impl_try_from_data!(Depth10, OrderBookDepth10);
 impl_try_from_data!(Quote, QuoteTick);
 impl_try_from_data!(Trade, TradeTick);
 impl_try_from_data!(Bar, Bar);
+impl_try_from_data!(MarkPrice, MarkPriceUpdate);
+impl_try_from_data!(IndexPrice, IndexPriceUpdate);
+impl_try_from_data!(FundingRate, FundingRateUpdate);
 
 pub fn to_variant<T: TryFrom<Data>>(data: Vec<Data>) -> Vec<T> {
     data.into_iter()
@@ -111,6 +125,9 @@ impl Data {
             Self::Quote(quote) => quote.instrument_id,
             Self::Trade(trade) => trade.instrument_id,
             Self::Bar(bar) => bar.bar_type.instrument_id(),
+            Self::MarkPrice(mark_price) => mark_price.instrument_id,
+            Self::IndexPrice(index_price) => index_price.instrument_id,
+            Self::FundingRate(funding_rate) => funding_rate.instrument_id,
         }
     }
 
@@ -133,6 +150,9 @@ impl GetTsInit for Data {
             Self::Quote(q) => q.ts_init,
             Self::Trade(t) => t.ts_init,
             Self::Bar(b) => b.ts_init,
+            Self::MarkPrice(p) => p.ts_init,
+            Self::IndexPrice(p) => p.ts_init,
+            Self::FundingRate(f) => f.ts_init,
         }
     }
 }
@@ -178,6 +198,24 @@ impl From<Bar> for Data {
     }
 }
 
+impl From<MarkPriceUpdate> for Data {
+    fn from(value: MarkPriceUpdate) -> Self {
+        Self::MarkPrice(value)
+    }
+}
+
+impl From<IndexPriceUpdate> for Data {
+    fn from(value: IndexPriceUpdate) -> Self {
+        Self::IndexPrice(value)
+    }
+}
+
+impl From<FundingRateUpdate> for Data {
+    fn from(value: FundingRateUpdate) -> Self {
+        Self::FundingRate(value)
+    }
+}
+
 // TODO: https://blog.rust-lang.org/2024/03/30/i128-layout-update.html
 // i128 and u128 is now FFI compatible. However, since the clippy lint
 // hasn't been removed yet. We'll suppress with #[cfg_attr(feature = "high-precision", allow(improper_ctypes_definitions))]
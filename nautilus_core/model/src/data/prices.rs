@@ -0,0 +1,233 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! `MarkPriceUpdate` and `IndexPriceUpdate` data types for derivatives reference prices.
+
+use std::{
+    collections::HashMap,
+    fmt::{Display, Formatter},
+    hash::Hash,
+};
+
+use indexmap::IndexMap;
+use nautilus_core::{serialization::Serializable, UnixNanos};
+use serde::{Deserialize, Serialize};
+
+use super::GetTsInit;
+use crate::{identifiers::InstrumentId, types::Price};
+
+/// Represents a mark price update for an instrument.
+///
+/// The mark price is the reference price a venue uses for unrealized PnL, margin and
+/// liquidation calculations, and is typically derived from an index price plus a basis.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "type")]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.model")
+)]
+pub struct MarkPriceUpdate {
+    /// The instrument ID for the mark price.
+    pub instrument_id: InstrumentId,
+    /// The mark price value.
+    pub value: Price,
+    /// UNIX timestamp (nanoseconds) when the price event occurred.
+    pub ts_event: UnixNanos,
+    /// UNIX timestamp (nanoseconds) when the struct was initialized.
+    pub ts_init: UnixNanos,
+}
+
+impl MarkPriceUpdate {
+    /// Creates a new [`MarkPriceUpdate`] instance.
+    #[must_use]
+    pub fn new(
+        instrument_id: InstrumentId,
+        value: Price,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self {
+            instrument_id,
+            value,
+            ts_event,
+            ts_init,
+        }
+    }
+
+    /// Returns the metadata for the type, for use with serialization formats.
+    #[must_use]
+    pub fn get_metadata(
+        instrument_id: &InstrumentId,
+        price_precision: u8,
+    ) -> HashMap<String, String> {
+        let mut metadata = HashMap::new();
+        metadata.insert("instrument_id".to_string(), instrument_id.to_string());
+        metadata.insert("price_precision".to_string(), price_precision.to_string());
+        metadata
+    }
+
+    /// Returns the field map for the type, for use with Arrow schemas.
+    #[must_use]
+    pub fn get_fields() -> IndexMap<String, String> {
+        let mut metadata = IndexMap::new();
+        metadata.insert("value".to_string(), "Int64".to_string());
+        metadata.insert("ts_event".to_string(), "UInt64".to_string());
+        metadata.insert("ts_init".to_string(), "UInt64".to_string());
+        metadata
+    }
+}
+
+impl Display for MarkPriceUpdate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{},{},{},{}",
+            self.instrument_id, self.value, self.ts_event, self.ts_init
+        )
+    }
+}
+
+impl Serializable for MarkPriceUpdate {}
+
+impl GetTsInit for MarkPriceUpdate {
+    fn ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+}
+
+/// Represents an index price update for an instrument.
+///
+/// The index price is the underlying reference price (usually a weighted spot price across
+/// several venues) which perpetual and futures contracts are anchored to.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "type")]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.model")
+)]
+pub struct IndexPriceUpdate {
+    /// The instrument ID for the index price.
+    pub instrument_id: InstrumentId,
+    /// The index price value.
+    pub value: Price,
+    /// UNIX timestamp (nanoseconds) when the price event occurred.
+    pub ts_event: UnixNanos,
+    /// UNIX timestamp (nanoseconds) when the struct was initialized.
+    pub ts_init: UnixNanos,
+}
+
+impl IndexPriceUpdate {
+    /// Creates a new [`IndexPriceUpdate`] instance.
+    #[must_use]
+    pub fn new(
+        instrument_id: InstrumentId,
+        value: Price,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self {
+            instrument_id,
+            value,
+            ts_event,
+            ts_init,
+        }
+    }
+
+    /// Returns the metadata for the type, for use with serialization formats.
+    #[must_use]
+    pub fn get_metadata(
+        instrument_id: &InstrumentId,
+        price_precision: u8,
+    ) -> HashMap<String, String> {
+        let mut metadata = HashMap::new();
+        metadata.insert("instrument_id".to_string(), instrument_id.to_string());
+        metadata.insert("price_precision".to_string(), price_precision.to_string());
+        metadata
+    }
+
+    /// Returns the field map for the type, for use with Arrow schemas.
+    #[must_use]
+    pub fn get_fields() -> IndexMap<String, String> {
+        let mut metadata = IndexMap::new();
+        metadata.insert("value".to_string(), "Int64".to_string());
+        metadata.insert("ts_event".to_string(), "UInt64".to_string());
+        metadata.insert("ts_init".to_string(), "UInt64".to_string());
+        metadata
+    }
+}
+
+impl Display for IndexPriceUpdate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{},{},{},{}",
+            self.instrument_id, self.value, self.ts_event, self.ts_init
+        )
+    }
+}
+
+impl Serializable for IndexPriceUpdate {}
+
+impl GetTsInit for IndexPriceUpdate {
+    fn ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::serialization::Serializable;
+    use rstest::rstest;
+
+    use crate::data::{
+        stubs::{stub_index_price_update, stub_mark_price_update},
+        IndexPriceUpdate, MarkPriceUpdate,
+    };
+
+    #[rstest]
+    fn test_mark_price_to_string(stub_mark_price_update: MarkPriceUpdate) {
+        assert_eq!(
+            stub_mark_price_update.to_string(),
+            "BTCUSDT-PERP.BINANCE,100000.00,1,2"
+        );
+    }
+
+    #[rstest]
+    fn test_index_price_to_string(stub_index_price_update: IndexPriceUpdate) {
+        assert_eq!(
+            stub_index_price_update.to_string(),
+            "BTCUSDT-PERP.BINANCE,99990.00,1,2"
+        );
+    }
+
+    #[rstest]
+    fn test_mark_price_json_serialization(stub_mark_price_update: MarkPriceUpdate) {
+        let serialized = stub_mark_price_update.as_json_bytes().unwrap();
+        let deserialized = MarkPriceUpdate::from_json_bytes(serialized.as_ref()).unwrap();
+        assert_eq!(deserialized, stub_mark_price_update);
+    }
+
+    #[rstest]
+    fn test_index_price_msgpack_serialization(stub_index_price_update: IndexPriceUpdate) {
+        let serialized = stub_index_price_update.as_msgpack_bytes().unwrap();
+        let deserialized = IndexPriceUpdate::from_msgpack_bytes(serialized.as_ref()).unwrap();
+        assert_eq!(deserialized, stub_index_price_update);
+    }
+}
@@ -19,17 +19,18 @@ use nautilus_core::UnixNanos;
 use rstest::fixture;
 
 use super::{
-    Bar, BarSpecification, BarType, InstrumentStatus, OrderBookDelta, OrderBookDeltas,
-    OrderBookDepth10, QuoteTick, TradeTick, DEPTH10_LEN,
+    Bar, BarSpecification, BarType, CorporateAction, FundingRateUpdate, IndexPriceUpdate,
+    InstrumentStatus, MarkPriceUpdate, OrderBookDelta, OrderBookDeltas, OrderBookDepth10,
+    QuoteTick, TradeTick, DEPTH10_LEN,
 };
 use crate::{
     data::order::BookOrder,
     enums::{
-        AggregationSource, AggressorSide, BarAggregation, BookAction, MarketStatusAction,
-        OrderSide, PriceType,
+        AggregationSource, AggressorSide, BarAggregation, BookAction, CorporateActionType,
+        MarketStatusAction, OrderSide, PriceType,
     },
     identifiers::{InstrumentId, Symbol, TradeId, Venue},
-    types::{Price, Quantity},
+    types::{Money, Price, Quantity},
 };
 
 impl Default for QuoteTick {
@@ -360,6 +361,64 @@ pub fn stub_instrument_status() -> InstrumentStatus {
     )
 }
 
+#[fixture]
+pub fn stub_corporate_action_split() -> CorporateAction {
+    CorporateAction::new(
+        InstrumentId::from("AAPL.XNAS"),
+        CorporateActionType::Split,
+        1,
+        4,
+        None,
+        UnixNanos::from(10),
+        UnixNanos::from(1),
+        UnixNanos::from(1),
+    )
+}
+
+#[fixture]
+pub fn stub_corporate_action_dividend() -> CorporateAction {
+    CorporateAction::new(
+        InstrumentId::from("AAPL.XNAS"),
+        CorporateActionType::CashDividend,
+        1,
+        1,
+        Some(Money::from("0.50 USD")),
+        UnixNanos::from(10),
+        UnixNanos::from(1),
+        UnixNanos::from(1),
+    )
+}
+
+#[fixture]
+pub fn stub_mark_price_update() -> MarkPriceUpdate {
+    MarkPriceUpdate::new(
+        InstrumentId::from("BTCUSDT-PERP.BINANCE"),
+        Price::from("100000.00"),
+        UnixNanos::from(1),
+        UnixNanos::from(2),
+    )
+}
+
+#[fixture]
+pub fn stub_index_price_update() -> IndexPriceUpdate {
+    IndexPriceUpdate::new(
+        InstrumentId::from("BTCUSDT-PERP.BINANCE"),
+        Price::from("99990.00"),
+        UnixNanos::from(1),
+        UnixNanos::from(2),
+    )
+}
+
+#[fixture]
+pub fn stub_funding_rate_update() -> FundingRateUpdate {
+    FundingRateUpdate::new(
+        InstrumentId::from("BTCUSDT-PERP.BINANCE"),
+        0.0001,
+        UnixNanos::from(1),
+        UnixNanos::from(2),
+    )
+}
+
 pub struct OrderBookDeltaTestBuilder {
     instrument_id: InstrumentId,
     action: Option<BookAction>,
@@ -0,0 +1,840 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Volatility smiles and surfaces fitted to the implied volatilities of option quotes.
+
+use std::collections::BTreeMap;
+
+use nautilus_core::{datetime::NANOSECONDS_IN_SECOND, UnixNanos};
+use ustr::Ustr;
+
+use super::{
+    greeks::{black_scholes_greeks, imply_vol_black76, BlackScholesGreeksResult},
+    QuoteTick,
+};
+use crate::{enums::OptionKind, instruments::OptionContract, types::Price};
+
+const SECONDS_IN_YEAR: f64 = 365.25 * 86_400.0;
+
+/// The minimum number of strikes required to fit an SVI smile.
+pub(crate) const SVI_MIN_POINTS: usize = 5;
+
+const SVI_GRID_POINTS: usize = 21;
+const SVI_GRID_ROUNDS: usize = 8;
+
+/// The method used to fit a volatility smile across strikes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SmileFitMethod {
+    /// The raw stochastic volatility inspired (SVI) parameterization of Gatheral, fitted to
+    /// implied variance by least squares. Smiles with fewer than five strikes are fitted with a
+    /// spline instead.
+    Svi,
+    /// A natural cubic spline through the implied volatilities, held flat beyond the outermost
+    /// strikes.
+    Spline,
+}
+
+/// The parameters of a raw SVI smile, giving the implied variance at log-moneyness `k` as
+/// `a + b * (rho * (k - m) + sqrt((k - m)^2 + sigma^2))`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SviParams {
+    /// The overall level of variance.
+    pub a: f64,
+    /// The slope of the wings.
+    pub b: f64,
+    /// The skew, being the rotation of the smile (in the range [-1, 1]).
+    pub rho: f64,
+    /// The log-moneyness at the vertex of the smile.
+    pub m: f64,
+    /// The curvature at the vertex of the smile.
+    pub sigma: f64,
+}
+
+impl SviParams {
+    /// Returns the implied variance at log-moneyness `k`.
+    #[must_use]
+    pub fn variance(&self, k: f64) -> f64 {
+        let x = k - self.m;
+        self.a + self.b * (self.rho * x + (x * x + self.sigma * self.sigma).sqrt())
+    }
+}
+
+#[derive(Clone, Debug)]
+struct CubicSpline {
+    xs: Vec<f64>,
+    ys: Vec<f64>,
+    second_derivs: Vec<f64>,
+}
+
+impl CubicSpline {
+    // Expects at least one point, with strictly increasing `xs`
+    fn new(xs: Vec<f64>, ys: Vec<f64>) -> Self {
+        let n = xs.len();
+        let mut second_derivs = vec![0.0; n];
+
+        if n > 2 {
+            // Solve the tridiagonal system for a natural spline (zero end second derivatives)
+            let mut u = vec![0.0; n];
+            for i in 1..n - 1 {
+                let sig = (xs[i] - xs[i - 1]) / (xs[i + 1] - xs[i - 1]);
+                let p = sig * second_derivs[i - 1] + 2.0;
+                second_derivs[i] = (sig - 1.0) / p;
+                let slope_diff = (ys[i + 1] - ys[i]) / (xs[i + 1] - xs[i])
+                    - (ys[i] - ys[i - 1]) / (xs[i] - xs[i - 1]);
+                u[i] = (6.0 * slope_diff / (xs[i + 1] - xs[i - 1]) - sig * u[i - 1]) / p;
+            }
+            second_derivs[n - 1] = 0.0;
+            for i in (0..n - 1).rev() {
+                second_derivs[i] = second_derivs[i] * second_derivs[i + 1] + u[i];
+            }
+        }
+
+        Self {
+            xs,
+            ys,
+            second_derivs,
+        }
+    }
+
+    fn value(&self, x: f64) -> f64 {
+        let n = self.xs.len();
+        if x <= self.xs[0] {
+            return self.ys[0];
+        }
+        if x >= self.xs[n - 1] {
+            return self.ys[n - 1];
+        }
+
+        let hi = self.xs.partition_point(|xi| *xi < x);
+        let lo = hi - 1;
+        let h = self.xs[hi] - self.xs[lo];
+        let a = (self.xs[hi] - x) / h;
+        let b = (x - self.xs[lo]) / h;
+
+        a * self.ys[lo]
+            + b * self.ys[hi]
+            + ((a.powi(3) - a) * self.second_derivs[lo] + (b.powi(3) - b) * self.second_derivs[hi])
+                * (h * h)
+                / 6.0
+    }
+}
+
+#[derive(Clone, Debug)]
+enum SmileFit {
+    Svi(SviParams),
+    Spline(CubicSpline),
+}
+
+#[derive(Clone, Copy, Debug)]
+struct SmilePoint {
+    vol: f64,
+    forward: f64,
+}
+
+/// A volatility smile for a single option expiry, fitted to the implied volatilities quoted
+/// across strikes.
+///
+/// Each strike is recorded with the forward at the time of its quote, with the smile fitted by
+/// log-moneyness `ln(K / F)`. The smile is refitted on each update.
+#[derive(Clone, Debug)]
+pub struct VolatilitySmile {
+    /// UNIX timestamp (nanoseconds) for the expiry of the options in the smile.
+    pub expiration_ns: UnixNanos,
+    /// The method used to fit the smile.
+    pub method: SmileFitMethod,
+    points: BTreeMap<Price, SmilePoint>,
+    fit: Option<SmileFit>,
+}
+
+impl VolatilitySmile {
+    /// Creates a new [`VolatilitySmile`] instance.
+    #[must_use]
+    pub fn new(expiration_ns: UnixNanos, method: SmileFitMethod) -> Self {
+        Self {
+            expiration_ns,
+            method,
+            points: BTreeMap::new(),
+            fit: None,
+        }
+    }
+
+    /// Updates the implied `vol` at the given `strike`, quoted with the given `forward`, and
+    /// refits the smile.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `vol` is not positive and finite.
+    /// - If `forward` is not positive and finite.
+    pub fn update(&mut self, strike: Price, vol: f64, forward: f64) -> anyhow::Result<()> {
+        anyhow::ensure!(
+            vol.is_finite() && vol > 0.0,
+            "`vol` was not positive and finite, was {vol}"
+        );
+        anyhow::ensure!(
+            forward.is_finite() && forward > 0.0,
+            "`forward` was not positive and finite, was {forward}"
+        );
+        anyhow::ensure!(strike.as_f64() > 0.0, "`strike` was not positive");
+
+        self.points.insert(strike, SmilePoint { vol, forward });
+        self.refit();
+        Ok(())
+    }
+
+    /// Returns the number of strikes in the smile.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    /// Returns whether the smile has no strikes.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// Returns the fitted SVI parameters, if the smile was fitted with SVI.
+    #[must_use]
+    pub fn svi_params(&self) -> Option<SviParams> {
+        match self.fit {
+            Some(SmileFit::Svi(params)) => Some(params),
+            _ => None,
+        }
+    }
+
+    /// Returns the fitted implied volatility at log-moneyness `k`, if the smile has any strikes.
+    #[must_use]
+    pub fn vol_at_moneyness(&self, k: f64) -> Option<f64> {
+        match self.fit.as_ref()? {
+            SmileFit::Svi(params) => Some(params.variance(k).max(0.0).sqrt()),
+            SmileFit::Spline(spline) => Some(spline.value(k).max(0.0)),
+        }
+    }
+
+    /// Returns the fitted implied volatility at the given `strike` and `forward`, if the smile
+    /// has any strikes.
+    #[must_use]
+    pub fn vol(&self, strike: f64, forward: f64) -> Option<f64> {
+        self.vol_at_moneyness((strike / forward).ln())
+    }
+
+    fn refit(&mut self) {
+        let mut points: Vec<(f64, f64)> = self
+            .points
+            .iter()
+            .map(|(strike, point)| ((strike.as_f64() / point.forward).ln(), point.vol))
+            .collect();
+        points.sort_by(|a, b| a.0.total_cmp(&b.0));
+        points.dedup_by(|a, b| a.0 == b.0);
+
+        if self.method == SmileFitMethod::Svi && points.len() >= SVI_MIN_POINTS {
+            let variances: Vec<(f64, f64)> =
+                points.iter().map(|(k, vol)| (*k, vol * vol)).collect();
+            if let Some(params) = fit_svi(&variances) {
+                self.fit = Some(SmileFit::Svi(params));
+                return;
+            }
+        }
+
+        self.fit = if points.is_empty() {
+            None
+        } else {
+            let (xs, ys) = points.into_iter().unzip();
+            Some(SmileFit::Spline(CubicSpline::new(xs, ys)))
+        };
+    }
+}
+
+// Fits the raw SVI parameters to the given (log-moneyness, variance) points, using the
+// quasi-explicit method of Zeliade: for a given `m` and `sigma` the variance is linear in
+// `a`, `b * rho` and `b`, which are solved by least squares, with `m` and `sigma` found by
+// successively refined grid searches
+fn fit_svi(points: &[(f64, f64)]) -> Option<SviParams> {
+    let k_min = points.first()?.0;
+    let k_max = points.last()?.0;
+    let span = (k_max - k_min).max(1e-4);
+
+    let (mut m_lo, mut m_hi) = (k_min - span, k_max + span);
+    let (mut ln_sigma_lo, mut ln_sigma_hi) = (1e-4_f64.ln(), (2.0 * span.max(1e-3)).ln());
+    let mut best: Option<(f64, SviParams)> = None;
+
+    let step =
+        |lo: f64, hi: f64, i: usize| lo + (hi - lo) * i as f64 / (SVI_GRID_POINTS - 1) as f64;
+
+    for _ in 0..SVI_GRID_ROUNDS {
+        for i in 0..SVI_GRID_POINTS {
+            let m = step(m_lo, m_hi, i);
+            for j in 0..SVI_GRID_POINTS {
+                let sigma = step(ln_sigma_lo, ln_sigma_hi, j).exp();
+                if let Some((error, params)) = fit_svi_linear(points, m, sigma) {
+                    if best.is_none_or(|(best_error, _)| error < best_error) {
+                        best = Some((error, params));
+                    }
+                }
+            }
+        }
+
+        let (_, params) = best?;
+        let m_width = 2.0 * (m_hi - m_lo) / (SVI_GRID_POINTS - 1) as f64;
+        let ln_sigma_width = 2.0 * (ln_sigma_hi - ln_sigma_lo) / (SVI_GRID_POINTS - 1) as f64;
+        (m_lo, m_hi) = (params.m - m_width, params.m + m_width);
+        let ln_sigma = params.sigma.ln();
+        (ln_sigma_lo, ln_sigma_hi) = (ln_sigma - ln_sigma_width, ln_sigma + ln_sigma_width);
+    }
+
+    best.map(|(_, params)| params)
+}
+
+// Solves for `a`, `b` and `rho` by least squares for the given `m` and `sigma`, returning the
+// sum of squared errors with the parameters (if the solution is a valid smile)
+fn fit_svi_linear(points: &[(f64, f64)], m: f64, sigma: f64) -> Option<(f64, SviParams)> {
+    let mut normal = [[0.0; 3]; 3];
+    let mut rhs = [0.0; 3];
+    for (k, variance) in points {
+        let x = k - m;
+        let features = [1.0, x, (x * x + sigma * sigma).sqrt()];
+        for ((row, target), feature) in normal.iter_mut().zip(rhs.iter_mut()).zip(features) {
+            *target += feature * variance;
+            for (cell, other) in row.iter_mut().zip(features) {
+                *cell += feature * other;
+            }
+        }
+    }
+
+    let [a, b_rho, b] = solve_3x3(normal, rhs)?;
+    if b < 0.0 || b_rho.abs() > b {
+        return None;
+    }
+
+    let params = SviParams {
+        a,
+        b,
+        rho: if b > 0.0 { b_rho / b } else { 0.0 },
+        m,
+        sigma,
+    };
+    let error = points
+        .iter()
+        .map(|(k, variance)| (params.variance(*k) - variance).powi(2))
+        .sum();
+
+    Some((error, params))
+}
+
+// Solves the linear system by Gaussian elimination with partial pivoting
+fn solve_3x3(mut matrix: [[f64; 3]; 3], mut rhs: [f64; 3]) -> Option<[f64; 3]> {
+    for col in 0..3 {
+        let pivot =
+            (col..3).max_by(|a, b| matrix[*a][col].abs().total_cmp(&matrix[*b][col].abs()))?;
+        if matrix[pivot][col].abs() < 1e-14 {
+            return None; // Singular
+        }
+        matrix.swap(col, pivot);
+        rhs.swap(col, pivot);
+
+        let pivot_row = matrix[col];
+        for row in col + 1..3 {
+            let factor = matrix[row][col] / pivot_row[col];
+            for (value, pivot_value) in matrix[row][col..].iter_mut().zip(&pivot_row[col..]) {
+                *value -= factor * pivot_value;
+            }
+            rhs[row] -= factor * rhs[col];
+        }
+    }
+
+    let mut solution = [0.0; 3];
+    for row in (0..3).rev() {
+        let dot: f64 = (row + 1..3).map(|j| matrix[row][j] * solution[j]).sum();
+        solution[row] = (rhs[row] - dot) / matrix[row][row];
+    }
+    Some(solution)
+}
+
+/// A volatility surface for the options on an underlying, made up of a smile for each expiry
+/// which is interpolated in strike and time.
+///
+/// Volatilities are implied from option prices under the Black-76 model on the forward
+/// `F = S * exp(b * t)` of the underlying price `S`, with cost of carry `b` (zero for options on
+/// futures), discounted at the rate `r`. Between expiries, total implied variance is
+/// interpolated linearly in time at constant log-moneyness, while beyond the first and last
+/// expiries the implied volatility of the nearest smile is used.
+#[derive(Clone, Debug)]
+pub struct VolatilitySurface {
+    /// The underlying for the options in the surface.
+    pub underlying: Ustr,
+    /// The method used to fit the smile for each expiry.
+    pub method: SmileFitMethod,
+    /// The risk-free interest rate.
+    pub r: f64,
+    /// The cost of carry of the underlying.
+    pub b: f64,
+    smiles: BTreeMap<UnixNanos, VolatilitySmile>,
+}
+
+impl VolatilitySurface {
+    /// Creates a new empty [`VolatilitySurface`] instance.
+    #[must_use]
+    pub fn new(underlying: Ustr, method: SmileFitMethod, r: f64, b: f64) -> Self {
+        Self {
+            underlying,
+            method,
+            r,
+            b,
+            smiles: BTreeMap::new(),
+        }
+    }
+
+    /// Creates a new [`VolatilitySurface`] instance from the mid prices of the given option
+    /// `quotes` across strikes and expiries, with the given `underlying_price`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If any quote fails to update the surface (see [`VolatilitySurface::update`]).
+    pub fn from_quotes<'a>(
+        underlying: Ustr,
+        method: SmileFitMethod,
+        r: f64,
+        b: f64,
+        underlying_price: f64,
+        quotes: impl IntoIterator<Item = (&'a OptionContract, &'a QuoteTick)>,
+    ) -> anyhow::Result<Self> {
+        let mut surface = Self::new(underlying, method, r, b);
+        for (contract, quote) in quotes {
+            surface.update_quote(contract, quote, underlying_price)?;
+        }
+        Ok(surface)
+    }
+
+    /// Updates the surface from the mid price of the given option `quote`, returning the
+    /// implied volatility.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the quote fails to update the surface (see [`VolatilitySurface::update`]).
+    pub fn update_quote(
+        &mut self,
+        contract: &OptionContract,
+        quote: &QuoteTick,
+        underlying_price: f64,
+    ) -> anyhow::Result<f64> {
+        anyhow::ensure!(
+            quote.instrument_id == contract.id,
+            "Quote instrument ID {} does not match contract {}",
+            quote.instrument_id,
+            contract.id
+        );
+        let mid = (quote.bid_price.as_f64() + quote.ask_price.as_f64()) / 2.0;
+        self.update(contract, mid, underlying_price, quote.ts_event)
+    }
+
+    /// Updates the surface from the given option `price` at time `ts`, returning the implied
+    /// volatility.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the `contract` is not for the surface underlying.
+    /// - If the `contract` has expired at `ts`.
+    /// - If no valid volatility is implied by the `price`.
+    pub fn update(
+        &mut self,
+        contract: &OptionContract,
+        price: f64,
+        underlying_price: f64,
+        ts: UnixNanos,
+    ) -> anyhow::Result<f64> {
+        anyhow::ensure!(
+            contract.underlying == self.underlying,
+            "Contract {} underlying {} is not the surface underlying {}",
+            contract.id,
+            contract.underlying,
+            self.underlying
+        );
+        let t = year_fraction(ts, contract.expiration_ns);
+        anyhow::ensure!(t > 0.0, "Contract {} has expired", contract.id);
+
+        let forward = underlying_price * (self.b * t).exp();
+        let is_call = contract.option_kind == OptionKind::Call;
+        let strike = contract.strike_price;
+        let vol = imply_vol_black76(forward, self.r, is_call, strike.as_f64(), t, price);
+        anyhow::ensure!(
+            vol.is_finite() && vol > 0.0 && vol < f64::MAX,
+            "No valid volatility implied for {} from price {price}",
+            contract.id
+        );
+
+        let method = self.method;
+        self.smiles
+            .entry(contract.expiration_ns)
+            .or_insert_with(|| VolatilitySmile::new(contract.expiration_ns, method))
+            .update(strike, vol, forward)?;
+
+        Ok(vol)
+    }
+
+    /// Returns the smile for the given `expiration_ns`, if any.
+    #[must_use]
+    pub fn smile(&self, expiration_ns: UnixNanos) -> Option<&VolatilitySmile> {
+        self.smiles.get(&expiration_ns)
+    }
+
+    /// Returns the expiries with a smile in the surface, in ascending order.
+    #[must_use]
+    pub fn expirations(&self) -> Vec<UnixNanos> {
+        self.smiles.keys().copied().collect()
+    }
+
+    /// Returns the implied volatility at the given `strike` and `expiration_ns` at time `ts`,
+    /// interpolated from the surface with the given `underlying_price`.
+    ///
+    /// Returns `None` if the expiry is not after `ts`, or there are no unexpired smiles.
+    #[must_use]
+    pub fn vol(
+        &self,
+        strike: f64,
+        expiration_ns: UnixNanos,
+        underlying_price: f64,
+        ts: UnixNanos,
+    ) -> Option<f64> {
+        let t = year_fraction(ts, expiration_ns);
+        if t <= 0.0 {
+            return None;
+        }
+
+        let k = (strike / (underlying_price * (self.b * t).exp())).ln();
+        let is_live = |(expiry, _): &(&UnixNanos, &VolatilitySmile)| **expiry > ts;
+        let before = self.smiles.range(..expiration_ns).rev().find(is_live);
+        let after = self.smiles.range(expiration_ns..).find(is_live);
+
+        match (before, after) {
+            (_, Some((expiry, smile))) if *expiry == expiration_ns => smile.vol_at_moneyness(k),
+            (Some((expiry_1, smile_1)), Some((expiry_2, smile_2))) => {
+                let t_1 = year_fraction(ts, *expiry_1);
+                let t_2 = year_fraction(ts, *expiry_2);
+                let variance_1 = smile_1.vol_at_moneyness(k)?.powi(2) * t_1;
+                let variance_2 = smile_2.vol_at_moneyness(k)?.powi(2) * t_2;
+                let variance = variance_1 + (variance_2 - variance_1) * (t - t_1) / (t_2 - t_1);
+                Some((variance.max(0.0) / t).sqrt())
+            }
+            (Some((_, smile)), None) | (None, Some((_, smile))) => smile.vol_at_moneyness(k),
+            (None, None) => None,
+        }
+    }
+
+    /// Computes the price and greeks of the given option `contract` at time `ts`, using the
+    /// implied volatility from the surface with the given `underlying_price`.
+    ///
+    /// Returns `None` if no volatility can be interpolated for the contract.
+    #[must_use]
+    pub fn greeks(
+        &self,
+        contract: &OptionContract,
+        underlying_price: f64,
+        ts: UnixNanos,
+    ) -> Option<BlackScholesGreeksResult> {
+        let strike = contract.strike_price.as_f64();
+        let vol = self.vol(strike, contract.expiration_ns, underlying_price, ts)?;
+        let t = year_fraction(ts, contract.expiration_ns);
+
+        Some(black_scholes_greeks(
+            underlying_price,
+            self.r,
+            self.b,
+            vol,
+            contract.option_kind == OptionKind::Call,
+            strike,
+            t,
+            contract.multiplier.as_f64(),
+        ))
+    }
+}
+
+fn year_fraction(ts: UnixNanos, expiration_ns: UnixNanos) -> f64 {
+    let seconds =
+        (expiration_ns.as_u64() as f64 - ts.as_u64() as f64) / NANOSECONDS_IN_SECOND as f64;
+    seconds / SECONDS_IN_YEAR
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::{datetime::NANOSECONDS_IN_SECOND, UnixNanos};
+    use rstest::rstest;
+
+    use super::{
+        CubicSpline, SmileFitMethod, SviParams, VolatilitySmile, VolatilitySurface, SECONDS_IN_YEAR,
+    };
+    use crate::{
+        data::{greeks::black76_greeks, QuoteTick},
+        enums::OptionKind,
+        identifiers::InstrumentId,
+        instruments::{stubs::option_contract_appl, OptionContract},
+        types::{Price, Quantity},
+    };
+
+    const YEAR_NS: u64 = (SECONDS_IN_YEAR as u64) * NANOSECONDS_IN_SECOND;
+
+    const SVI: SviParams = SviParams {
+        a: 0.04,
+        b: 0.1,
+        rho: -0.4,
+        m: 0.05,
+        sigma: 0.1,
+    };
+
+    fn contract(option_kind: OptionKind, strike: f64, expiration_ns: u64) -> OptionContract {
+        let base = option_contract_appl();
+        let id = format!("AAPL-{option_kind}-{strike}-{expiration_ns}.OPRA");
+        OptionContract {
+            id: InstrumentId::from(id.as_str()),
+            option_kind,
+            strike_price: Price::new(strike, 2),
+            expiration_ns: expiration_ns.into(),
+            ..base
+        }
+    }
+
+    // Returns the price of an option with the volatility given by the `SVI` smile
+    fn svi_price(contract: &OptionContract, forward: f64, r: f64, vol_scale: f64) -> f64 {
+        let k = contract.strike_price.as_f64();
+        let t = contract.expiration_ns.as_u64() as f64 / YEAR_NS as f64;
+        let vol = SVI.variance((k / forward).ln()).sqrt() * vol_scale;
+        black76_greeks(
+            forward,
+            r,
+            vol,
+            contract.option_kind == OptionKind::Call,
+            k,
+            t,
+            1.0,
+        )
+        .price
+    }
+
+    #[rstest]
+    fn test_cubic_spline_interpolates_points() {
+        let spline = CubicSpline::new(vec![0.0, 1.0, 2.0, 3.0], vec![1.0, 2.0, 0.0, 1.0]);
+
+        assert_eq!(spline.value(1.0), 2.0);
+        assert_eq!(spline.value(2.0), 0.0);
+        assert_eq!(spline.value(-1.0), 1.0); // Flat extrapolation
+        assert_eq!(spline.value(5.0), 1.0);
+        assert!(spline.value(0.5) > 1.0 && spline.value(0.5) < 2.5);
+    }
+
+    #[rstest]
+    fn test_smile_fits_svi() {
+        let mut smile = VolatilitySmile::new(UnixNanos::from(YEAR_NS), SmileFitMethod::Svi);
+        for i in -6..=6 {
+            let k = f64::from(i) * 0.05;
+            let strike = Price::new(100.0 * k.exp(), 6);
+            let forward = strike.as_f64() / k.exp();
+            smile
+                .update(strike, SVI.variance(k).sqrt(), forward)
+                .unwrap();
+        }
+
+        let params = smile.svi_params().unwrap();
+
+        assert_eq!(smile.len(), 13);
+        for i in -8..=8 {
+            let k = f64::from(i) * 0.05;
+            assert!((params.variance(k) - SVI.variance(k)).abs() < 1e-6);
+        }
+        assert!((smile.vol(100.0, 100.0).unwrap() - SVI.variance(0.0).sqrt()).abs() < 1e-5);
+    }
+
+    #[rstest]
+    fn test_smile_with_few_strikes_uses_spline() {
+        let mut smile = VolatilitySmile::new(UnixNanos::from(YEAR_NS), SmileFitMethod::Svi);
+        smile.update(Price::from("90.00"), 0.25, 100.0).unwrap();
+        smile.update(Price::from("110.00"), 0.21, 100.0).unwrap();
+
+        assert!(smile.svi_params().is_none());
+        assert!((smile.vol(90.0, 100.0).unwrap() - 0.25).abs() < 1e-12);
+        assert!((smile.vol(80.0, 100.0).unwrap() - 0.25).abs() < 1e-12);
+        assert!(smile.update(Price::from("100.00"), 0.0, 100.0).is_err());
+    }
+
+    #[rstest]
+    #[case(SmileFitMethod::Svi)]
+    #[case(SmileFitMethod::Spline)]
+    fn test_surface_from_quotes_reproduces_vols(#[case] method: SmileFitMethod) {
+        let (forward, r) = (100.0, 0.02);
+        let strikes = [80.0, 85.0, 90.0, 95.0, 100.0, 105.0, 110.0, 115.0, 120.0];
+        let contracts: Vec<OptionContract> = strikes
+            .iter()
+            .map(|k| {
+                let option_kind = if *k < forward {
+                    OptionKind::Put
+                } else {
+                    OptionKind::Call
+                };
+                contract(option_kind, *k, YEAR_NS)
+            })
+            .collect();
+        let quotes: Vec<QuoteTick> = contracts
+            .iter()
+            .map(|contract| {
+                let price = svi_price(contract, forward, r, 1.0);
+                QuoteTick::new(
+                    contract.id,
+                    Price::new(price - 0.005, 6),
+                    Price::new(price + 0.005, 6),
+                    Quantity::from(1),
+                    Quantity::from(1),
+                    UnixNanos::default(),
+                    UnixNanos::default(),
+                )
+            })
+            .collect();
+
+        let surface = VolatilitySurface::from_quotes(
+            contracts[0].underlying,
+            method,
+            r,
+            0.0,
+            forward,
+            contracts.iter().zip(quotes.iter()),
+        )
+        .unwrap();
+
+        assert_eq!(surface.expirations(), vec![UnixNanos::from(YEAR_NS)]);
+        assert_eq!(surface.smile(UnixNanos::from(YEAR_NS)).unwrap().len(), 9);
+        for k in [80.0, 92.5, 100.0, 107.5, 120.0] {
+            let vol = surface
+                .vol(k, UnixNanos::from(YEAR_NS), forward, UnixNanos::default())
+                .unwrap();
+            let expected = SVI.variance((k / forward).ln()).sqrt();
+            assert!(
+                (vol - expected).abs() < 1e-3,
+                "Vol {vol} at {k} vs {expected}"
+            );
+        }
+    }
+
+    #[rstest]
+    fn test_surface_interpolates_total_variance_in_time() {
+        let (forward, r) = (100.0, 0.0);
+        let mut surface = VolatilitySurface::new(
+            option_contract_appl().underlying,
+            SmileFitMethod::Spline,
+            r,
+            0.0,
+        );
+        for (expiry, vol_scale) in [(YEAR_NS, 1.0), (2 * YEAR_NS, 1.5)] {
+            for k in [90.0, 100.0, 110.0] {
+                let contract = contract(OptionKind::Call, k, expiry);
+                let price = svi_price(&contract, forward, r, vol_scale);
+                surface
+                    .update(&contract, price, forward, UnixNanos::default())
+                    .unwrap();
+            }
+        }
+
+        let vol_1 = SVI.variance(0.0).sqrt();
+        let vol_2 = vol_1 * 1.5;
+        let expected = ((vol_1.powi(2) + 2.0 * vol_2.powi(2)) / 2.0 / 1.5).sqrt();
+        let vol = surface
+            .vol(
+                100.0,
+                UnixNanos::from(YEAR_NS * 3 / 2),
+                forward,
+                UnixNanos::default(),
+            )
+            .unwrap();
+
+        assert!((vol - expected).abs() < 1e-6, "Vol {vol} vs {expected}");
+
+        // Beyond the last expiry the nearest smile is used
+        let vol_far = surface
+            .vol(
+                100.0,
+                UnixNanos::from(3 * YEAR_NS),
+                forward,
+                UnixNanos::default(),
+            )
+            .unwrap();
+        assert!((vol_far - vol_2).abs() < 1e-6);
+
+        // Expired options are not interpolated
+        assert!(surface
+            .vol(
+                100.0,
+                UnixNanos::from(YEAR_NS),
+                forward,
+                UnixNanos::from(YEAR_NS)
+            )
+            .is_none());
+    }
+
+    #[rstest]
+    fn test_surface_update_from_live_quote_and_greeks() {
+        let (forward, r) = (100.0, 0.01);
+        let call = contract(OptionKind::Call, 100.0, YEAR_NS);
+        let mut surface = VolatilitySurface::new(call.underlying, SmileFitMethod::Spline, r, 0.0);
+
+        let vol = surface
+            .update(
+                &call,
+                svi_price(&call, forward, r, 1.0),
+                forward,
+                UnixNanos::default(),
+            )
+            .unwrap();
+        let updated_vol = surface
+            .update(
+                &call,
+                svi_price(&call, forward, r, 1.2),
+                forward,
+                UnixNanos::default(),
+            )
+            .unwrap();
+
+        assert!((updated_vol / vol - 1.2).abs() < 1e-6);
+        let greeks = surface
+            .greeks(&call, forward, UnixNanos::default())
+            .unwrap();
+        let expected = black76_greeks(forward, r, updated_vol, true, 100.0, 1.0, 1.0);
+        assert!((greeks.price - expected.price).abs() < 1e-8);
+        assert!((greeks.delta - expected.delta).abs() < 1e-8);
+    }
+
+    #[rstest]
+    fn test_surface_update_with_invalid_inputs() {
+        let call = contract(OptionKind::Call, 100.0, YEAR_NS);
+        let mut surface = VolatilitySurface::new(call.underlying, SmileFitMethod::Svi, 0.0, 0.0);
+        let other = OptionContract {
+            underlying: "MSFT".into(),
+            ..call
+        };
+
+        assert!(surface
+            .update(&other, 5.0, 100.0, UnixNanos::default())
+            .is_err());
+        assert!(surface
+            .update(&call, 5.0, 100.0, UnixNanos::from(YEAR_NS))
+            .is_err());
+        assert!(surface
+            .update(&call, 0.0, 100.0, UnixNanos::default())
+            .is_err());
+        assert!(surface.expirations().is_empty());
+    }
+}
@@ -466,6 +466,38 @@ pub enum ContingencyType {
     Ouo = 3,
 }
 
+/// The type of corporate action affecting an equity instrument.
+#[repr(C)]
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Display,
+    Hash,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    AsRefStr,
+    FromRepr,
+    EnumIter,
+    EnumString,
+)]
+#[strum(ascii_case_insensitive)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(eq, eq_int, module = "nautilus_trader.core.nautilus_pyo3.model.enums")
+)]
+pub enum CorporateActionType {
+    /// A stock split, increasing the number of shares held.
+    Split = 1,
+    /// A reverse stock split, decreasing the number of shares held.
+    ReverseSplit = 2,
+    /// A cash dividend paid per share held.
+    CashDividend = 3,
+}
+
 /// The broad currency type.
 #[repr(C)]
 #[derive(
@@ -561,6 +593,40 @@ pub enum LiquiditySide {
     Taker = 2,
 }
 
+/// The method for matching closing fills against the open lots of a position.
+///
+/// Specific lots may also be closed by lot ID, see `Position::apply_with_lots`.
+#[repr(C)]
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Display,
+    Hash,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    AsRefStr,
+    FromRepr,
+    EnumIter,
+    EnumString,
+)]
+#[strum(ascii_case_insensitive)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(eq, eq_int, module = "nautilus_trader.core.nautilus_pyo3.model.enums")
+)]
+pub enum LotMatchingMethod {
+    /// First in, first out: the earliest open lot is closed first.
+    Fifo = 1,
+    /// Last in, first out: the latest open lot is closed first.
+    Lifo = 2,
+    /// The open lot with the highest open price is closed first.
+    HighestCost = 3,
+}
+
 /// The status of an individual market on a trading venue.
 #[repr(C)]
 #[derive(
@@ -1246,14 +1312,17 @@ enum_strum_serde!(BarIntervalType);
 enum_strum_serde!(BookAction);
 enum_strum_serde!(BookType);
 enum_strum_serde!(ContingencyType);
+enum_strum_serde!(CorporateActionType);
 enum_strum_serde!(CurrencyType);
 enum_strum_serde!(InstrumentCloseType);
 enum_strum_serde!(LiquiditySide);
+enum_strum_serde!(LotMatchingMethod);
 enum_strum_serde!(MarketStatus);
 enum_strum_serde!(MarketStatusAction);
 enum_strum_serde!(OmsType);
 enum_strum_serde!(OptionKind);
 enum_strum_serde!(OrderSide);
+enum_strum_serde!(OrderSideSpecified);
 enum_strum_serde!(OrderStatus);
 enum_strum_serde!(OrderType);
 enum_strum_serde!(PositionSide);
@@ -19,7 +19,10 @@ use rstest::fixture;
 use crate::{
     enums::AccountType,
     events::AccountState,
-    identifiers::stubs::{account_id, uuid4},
+    identifiers::{
+        stubs::{account_id, uuid4},
+        AccountId,
+    },
     types::{
         stubs::{stub_account_balance, stub_margin_balance},
         AccountBalance, Currency, Money,
@@ -147,3 +150,22 @@ pub fn margin_account_state() -> AccountState {
         Some(Currency::USD()),
     )
 }
+
+#[fixture]
+pub fn betting_account_state() -> AccountState {
+    AccountState::new(
+        AccountId::from("BETFAIR-001"),
+        AccountType::Betting,
+        vec![AccountBalance::new(
+            Money::from("1000 GBP"),
+            Money::from("0 GBP"),
+            Money::from("1000 GBP"),
+        )],
+        vec![],
+        true,
+        uuid4(),
+        0.into(),
+        0.into(),
+        Some(Currency::GBP()),
+    )
+}
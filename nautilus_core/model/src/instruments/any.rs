@@ -15,12 +15,14 @@
 
 use nautilus_core::UnixNanos;
 use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
 use ustr::Ustr;
 
 use super::{
-    betting::BettingInstrument, binary_option::BinaryOption, crypto_future::CryptoFuture,
-    crypto_perpetual::CryptoPerpetual, currency_pair::CurrencyPair, equity::Equity,
-    futures_contract::FuturesContract, futures_spread::FuturesSpread,
+    betting::BettingInstrument, binary_option::BinaryOption, cfd::Cfd, commodity::Commodity,
+    crypto_future::CryptoFuture, crypto_option::CryptoOption, crypto_perpetual::CryptoPerpetual,
+    currency_pair::CurrencyPair, equity::Equity, futures_contract::FuturesContract,
+    futures_spread::FuturesSpread, index_instrument::IndexInstrument,
     option_contract::OptionContract, option_spread::OptionSpread, Instrument,
 };
 use crate::{
@@ -29,16 +31,20 @@ use crate::{
     types::{Currency, Money, Price, Quantity},
 };
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum InstrumentAny {
     Betting(BettingInstrument),
     BinaryOption(BinaryOption),
+    Cfd(Cfd),
+    Commodity(Commodity),
     CryptoFuture(CryptoFuture),
+    CryptoOption(CryptoOption),
     CryptoPerpetual(CryptoPerpetual),
     CurrencyPair(CurrencyPair),
     Equity(Equity),
     FuturesContract(FuturesContract),
     FuturesSpread(FuturesSpread),
+    IndexInstrument(IndexInstrument),
     OptionContract(OptionContract),
     OptionSpread(OptionSpread),
 }
@@ -50,12 +56,16 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => Box::new(inst),
             Self::BinaryOption(inst) => Box::new(inst),
+            Self::Cfd(inst) => Box::new(inst),
+            Self::Commodity(inst) => Box::new(inst),
             Self::CryptoFuture(inst) => Box::new(inst),
+            Self::CryptoOption(inst) => Box::new(inst),
             Self::CryptoPerpetual(inst) => Box::new(inst),
             Self::CurrencyPair(inst) => Box::new(inst),
             Self::Equity(inst) => Box::new(inst),
             Self::FuturesContract(inst) => Box::new(inst),
             Self::FuturesSpread(inst) => Box::new(inst),
+            Self::IndexInstrument(inst) => Box::new(inst),
             Self::OptionContract(inst) => Box::new(inst),
             Self::OptionSpread(inst) => Box::new(inst),
         }
@@ -66,12 +76,16 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.instrument_class(),
             Self::BinaryOption(inst) => inst.instrument_class(),
+            Self::Cfd(inst) => inst.instrument_class(),
+            Self::Commodity(inst) => inst.instrument_class(),
             Self::CryptoFuture(inst) => inst.instrument_class(),
+            Self::CryptoOption(inst) => inst.instrument_class(),
             Self::CryptoPerpetual(inst) => inst.instrument_class(),
             Self::CurrencyPair(inst) => inst.instrument_class(),
             Self::Equity(inst) => inst.instrument_class(),
             Self::FuturesContract(inst) => inst.instrument_class(),
             Self::FuturesSpread(inst) => inst.instrument_class(),
+            Self::IndexInstrument(inst) => inst.instrument_class(),
             Self::OptionContract(inst) => inst.instrument_class(),
             Self::OptionSpread(inst) => inst.instrument_class(),
         }
@@ -82,12 +96,16 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.id,
             Self::BinaryOption(inst) => inst.id,
+            Self::Cfd(inst) => inst.id,
+            Self::Commodity(inst) => inst.id,
             Self::CryptoFuture(inst) => inst.id,
+            Self::CryptoOption(inst) => inst.id,
             Self::CryptoPerpetual(inst) => inst.id,
             Self::CurrencyPair(inst) => inst.id,
             Self::Equity(inst) => inst.id,
             Self::FuturesContract(inst) => inst.id,
             Self::FuturesSpread(inst) => inst.id,
+            Self::IndexInstrument(inst) => inst.id,
             Self::OptionContract(inst) => inst.id,
             Self::OptionSpread(inst) => inst.id,
         }
@@ -98,12 +116,16 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.id.symbol,
             Self::BinaryOption(inst) => inst.id.symbol,
+            Self::Cfd(inst) => inst.id.symbol,
+            Self::Commodity(inst) => inst.id.symbol,
             Self::CryptoFuture(inst) => inst.id.symbol,
+            Self::CryptoOption(inst) => inst.id.symbol,
             Self::CryptoPerpetual(inst) => inst.id.symbol,
             Self::CurrencyPair(inst) => inst.id.symbol,
             Self::Equity(inst) => inst.id.symbol,
             Self::FuturesContract(inst) => inst.id.symbol,
             Self::FuturesSpread(inst) => inst.id.symbol,
+            Self::IndexInstrument(inst) => inst.id.symbol,
             Self::OptionContract(inst) => inst.id.symbol,
             Self::OptionSpread(inst) => inst.id.symbol,
         }
@@ -114,12 +136,16 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.id.venue,
             Self::BinaryOption(inst) => inst.id.venue,
+            Self::Cfd(inst) => inst.id.venue,
+            Self::Commodity(inst) => inst.id.venue,
             Self::CryptoFuture(inst) => inst.id.venue,
+            Self::CryptoOption(inst) => inst.id.venue,
             Self::CryptoPerpetual(inst) => inst.id.venue,
             Self::CurrencyPair(inst) => inst.id.venue,
             Self::Equity(inst) => inst.id.venue,
             Self::FuturesContract(inst) => inst.id.venue,
             Self::FuturesSpread(inst) => inst.id.venue,
+            Self::IndexInstrument(inst) => inst.id.venue,
             Self::OptionContract(inst) => inst.id.venue,
             Self::OptionSpread(inst) => inst.id.venue,
         }
@@ -130,12 +156,16 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.raw_symbol(),
             Self::BinaryOption(inst) => inst.raw_symbol(),
+            Self::Cfd(inst) => inst.raw_symbol(),
+            Self::Commodity(inst) => inst.raw_symbol(),
             Self::CryptoFuture(inst) => inst.raw_symbol(),
+            Self::CryptoOption(inst) => inst.raw_symbol(),
             Self::CryptoPerpetual(inst) => inst.raw_symbol(),
             Self::CurrencyPair(inst) => inst.raw_symbol(),
             Self::Equity(inst) => inst.raw_symbol(),
             Self::FuturesContract(inst) => inst.raw_symbol(),
             Self::FuturesSpread(inst) => inst.raw_symbol(),
+            Self::IndexInstrument(inst) => inst.raw_symbol(),
             Self::OptionContract(inst) => inst.raw_symbol(),
             Self::OptionSpread(inst) => inst.raw_symbol(),
         }
@@ -146,12 +176,16 @@ impl InstrumentAny {
         match self {
             Self::Betting(_) => None,
             Self::BinaryOption(_) => None,
+            Self::Cfd(_) => None,
+            Self::Commodity(_) => None,
             Self::CryptoFuture(inst) => Some(&inst.underlying.code),
+            Self::CryptoOption(inst) => Some(&inst.underlying.code),
             Self::CryptoPerpetual(_) => None,
             Self::CurrencyPair(_) => None,
             Self::Equity(_) => None,
             Self::FuturesContract(inst) => Some(&inst.underlying),
             Self::FuturesSpread(inst) => Some(&inst.underlying),
+            Self::IndexInstrument(_) => None,
             Self::OptionContract(inst) => Some(&inst.underlying),
             Self::OptionSpread(inst) => Some(&inst.underlying),
         }
@@ -162,12 +196,16 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.base_currency(),
             Self::BinaryOption(inst) => inst.base_currency(),
+            Self::Cfd(inst) => inst.base_currency(),
+            Self::Commodity(inst) => inst.base_currency(),
             Self::CryptoFuture(inst) => inst.base_currency(),
+            Self::CryptoOption(inst) => inst.base_currency(),
             Self::CryptoPerpetual(inst) => inst.base_currency(),
             Self::CurrencyPair(inst) => inst.base_currency(),
             Self::Equity(inst) => inst.base_currency(),
             Self::FuturesContract(inst) => inst.base_currency(),
             Self::FuturesSpread(inst) => inst.base_currency(),
+            Self::IndexInstrument(inst) => inst.base_currency(),
             Self::OptionContract(inst) => inst.base_currency(),
             Self::OptionSpread(inst) => inst.base_currency(),
         }
@@ -178,12 +216,16 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.quote_currency(),
             Self::BinaryOption(inst) => inst.quote_currency(),
+            Self::Cfd(inst) => inst.quote_currency(),
+            Self::Commodity(inst) => inst.quote_currency(),
             Self::CryptoFuture(inst) => inst.quote_currency(),
+            Self::CryptoOption(inst) => inst.quote_currency(),
             Self::CryptoPerpetual(inst) => inst.quote_currency(),
             Self::CurrencyPair(inst) => inst.quote_currency(),
             Self::Equity(inst) => inst.quote_currency(),
             Self::FuturesContract(inst) => inst.quote_currency(),
             Self::FuturesSpread(inst) => inst.quote_currency(),
+            Self::IndexInstrument(inst) => inst.quote_currency(),
             Self::OptionContract(inst) => inst.quote_currency(),
             Self::OptionSpread(inst) => inst.quote_currency(),
         }
@@ -194,12 +236,16 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.settlement_currency(),
             Self::BinaryOption(inst) => inst.settlement_currency(),
+            Self::Cfd(inst) => inst.settlement_currency(),
+            Self::Commodity(inst) => inst.settlement_currency(),
             Self::CryptoFuture(inst) => inst.settlement_currency(),
+            Self::CryptoOption(inst) => inst.settlement_currency(),
             Self::CryptoPerpetual(inst) => inst.settlement_currency(),
             Self::CurrencyPair(inst) => inst.settlement_currency(),
             Self::Equity(inst) => inst.settlement_currency(),
             Self::FuturesContract(inst) => inst.settlement_currency(),
             Self::FuturesSpread(inst) => inst.settlement_currency(),
+            Self::IndexInstrument(inst) => inst.settlement_currency(),
             Self::OptionContract(inst) => inst.settlement_currency(),
             Self::OptionSpread(inst) => inst.settlement_currency(),
         }
@@ -210,12 +256,16 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.is_inverse(),
             Self::BinaryOption(inst) => inst.is_inverse(),
+            Self::Cfd(inst) => inst.is_inverse(),
+            Self::Commodity(inst) => inst.is_inverse(),
             Self::CryptoFuture(inst) => inst.is_inverse(),
+            Self::CryptoOption(inst) => inst.is_inverse(),
             Self::CryptoPerpetual(inst) => inst.is_inverse(),
             Self::CurrencyPair(inst) => inst.is_inverse(),
             Self::Equity(inst) => inst.is_inverse(),
             Self::FuturesContract(inst) => inst.is_inverse(),
             Self::FuturesSpread(inst) => inst.is_inverse(),
+            Self::IndexInstrument(inst) => inst.is_inverse(),
             Self::OptionContract(inst) => inst.is_inverse(),
             Self::OptionSpread(inst) => inst.is_inverse(),
         }
@@ -226,12 +276,16 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.price_precision(),
             Self::BinaryOption(inst) => inst.price_precision(),
+            Self::Cfd(inst) => inst.price_precision(),
+            Self::Commodity(inst) => inst.price_precision(),
             Self::CryptoFuture(inst) => inst.price_precision(),
+            Self::CryptoOption(inst) => inst.price_precision(),
             Self::CryptoPerpetual(inst) => inst.price_precision(),
             Self::CurrencyPair(inst) => inst.price_precision(),
             Self::Equity(inst) => inst.price_precision(),
             Self::FuturesContract(inst) => inst.price_precision(),
             Self::FuturesSpread(inst) => inst.price_precision(),
+            Self::IndexInstrument(inst) => inst.price_precision(),
             Self::OptionContract(inst) => inst.price_precision(),
             Self::OptionSpread(inst) => inst.price_precision(),
         }
@@ -242,12 +296,16 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.size_precision(),
             Self::BinaryOption(inst) => inst.size_precision(),
+            Self::Cfd(inst) => inst.size_precision(),
+            Self::Commodity(inst) => inst.size_precision(),
             Self::CryptoFuture(inst) => inst.size_precision(),
+            Self::CryptoOption(inst) => inst.size_precision(),
             Self::CryptoPerpetual(inst) => inst.size_precision(),
             Self::CurrencyPair(inst) => inst.size_precision(),
             Self::Equity(inst) => inst.size_precision(),
             Self::FuturesContract(inst) => inst.size_precision(),
             Self::FuturesSpread(inst) => inst.size_precision(),
+            Self::IndexInstrument(inst) => inst.size_precision(),
             Self::OptionContract(inst) => inst.size_precision(),
             Self::OptionSpread(inst) => inst.size_precision(),
         }
@@ -258,12 +316,16 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.price_increment(),
             Self::BinaryOption(inst) => inst.price_increment(),
+            Self::Cfd(inst) => inst.price_increment(),
+            Self::Commodity(inst) => inst.price_increment(),
             Self::CryptoFuture(inst) => inst.price_increment(),
+            Self::CryptoOption(inst) => inst.price_increment(),
             Self::CryptoPerpetual(inst) => inst.price_increment(),
             Self::CurrencyPair(inst) => inst.price_increment(),
             Self::Equity(inst) => inst.price_increment(),
             Self::FuturesContract(inst) => inst.price_increment(),
             Self::FuturesSpread(inst) => inst.price_increment(),
+            Self::IndexInstrument(inst) => inst.price_increment(),
             Self::OptionContract(inst) => inst.price_increment(),
             Self::OptionSpread(inst) => inst.price_increment(),
         }
@@ -274,12 +336,16 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.size_increment(),
             Self::BinaryOption(inst) => inst.size_increment(),
+            Self::Cfd(inst) => inst.size_increment(),
+            Self::Commodity(inst) => inst.size_increment(),
             Self::CryptoFuture(inst) => inst.size_increment(),
+            Self::CryptoOption(inst) => inst.size_increment(),
             Self::CryptoPerpetual(inst) => inst.size_increment(),
             Self::CurrencyPair(inst) => inst.size_increment(),
             Self::Equity(inst) => inst.size_increment(),
             Self::FuturesContract(inst) => inst.size_increment(),
             Self::FuturesSpread(inst) => inst.size_increment(),
+            Self::IndexInstrument(inst) => inst.size_increment(),
             Self::OptionContract(inst) => inst.size_increment(),
             Self::OptionSpread(inst) => inst.size_increment(),
         }
@@ -290,12 +356,16 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.multiplier(),
             Self::BinaryOption(inst) => inst.multiplier(),
+            Self::Cfd(inst) => inst.multiplier(),
+            Self::Commodity(inst) => inst.multiplier(),
             Self::CryptoFuture(inst) => inst.multiplier(),
+            Self::CryptoOption(inst) => inst.multiplier(),
             Self::CryptoPerpetual(inst) => inst.multiplier(),
             Self::CurrencyPair(inst) => inst.multiplier(),
             Self::Equity(inst) => inst.multiplier(),
             Self::FuturesContract(inst) => inst.multiplier(),
             Self::FuturesSpread(inst) => inst.multiplier(),
+            Self::IndexInstrument(inst) => inst.multiplier(),
             Self::OptionContract(inst) => inst.multiplier(),
             Self::OptionSpread(inst) => inst.multiplier(),
         }
@@ -306,12 +376,16 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.activation_ns(),
             Self::BinaryOption(inst) => inst.activation_ns(),
+            Self::Cfd(inst) => inst.activation_ns(),
+            Self::Commodity(inst) => inst.activation_ns(),
             Self::CryptoFuture(inst) => inst.activation_ns(),
+            Self::CryptoOption(inst) => inst.activation_ns(),
             Self::CryptoPerpetual(inst) => inst.activation_ns(),
             Self::CurrencyPair(inst) => inst.activation_ns(),
             Self::Equity(inst) => inst.activation_ns(),
             Self::FuturesContract(inst) => inst.activation_ns(),
             Self::FuturesSpread(inst) => inst.activation_ns(),
+            Self::IndexInstrument(inst) => inst.activation_ns(),
             Self::OptionContract(inst) => inst.activation_ns(),
             Self::OptionSpread(inst) => inst.activation_ns(),
         }
@@ -322,12 +396,16 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.expiration_ns(),
             Self::BinaryOption(inst) => inst.expiration_ns(),
+            Self::Cfd(inst) => inst.expiration_ns(),
+            Self::Commodity(inst) => inst.expiration_ns(),
             Self::CryptoFuture(inst) => inst.expiration_ns(),
+            Self::CryptoOption(inst) => inst.expiration_ns(),
             Self::CryptoPerpetual(inst) => inst.expiration_ns(),
             Self::CurrencyPair(inst) => inst.expiration_ns(),
             Self::Equity(inst) => inst.expiration_ns(),
             Self::FuturesContract(inst) => inst.expiration_ns(),
             Self::FuturesSpread(inst) => inst.expiration_ns(),
+            Self::IndexInstrument(inst) => inst.expiration_ns(),
             Self::OptionContract(inst) => inst.expiration_ns(),
             Self::OptionSpread(inst) => inst.expiration_ns(),
         }
@@ -337,12 +415,16 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.max_quantity(),
             Self::BinaryOption(inst) => inst.max_quantity(),
+            Self::Cfd(inst) => inst.max_quantity(),
+            Self::Commodity(inst) => inst.max_quantity(),
             Self::CryptoFuture(inst) => inst.max_quantity(),
+            Self::CryptoOption(inst) => inst.max_quantity(),
             Self::CryptoPerpetual(inst) => inst.max_quantity(),
             Self::CurrencyPair(inst) => inst.max_quantity(),
             Self::Equity(inst) => inst.max_quantity(),
             Self::FuturesContract(inst) => inst.max_quantity(),
             Self::FuturesSpread(inst) => inst.max_quantity(),
+            Self::IndexInstrument(inst) => inst.max_quantity(),
             Self::OptionContract(inst) => inst.max_quantity(),
             Self::OptionSpread(inst) => inst.max_quantity(),
         }
@@ -352,12 +434,16 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.min_quantity(),
             Self::BinaryOption(inst) => inst.min_quantity(),
+            Self::Cfd(inst) => inst.min_quantity(),
+            Self::Commodity(inst) => inst.min_quantity(),
             Self::CryptoFuture(inst) => inst.min_quantity(),
+            Self::CryptoOption(inst) => inst.min_quantity(),
             Self::CryptoPerpetual(inst) => inst.min_quantity(),
             Self::CurrencyPair(inst) => inst.min_quantity(),
             Self::Equity(inst) => inst.min_quantity(),
             Self::FuturesContract(inst) => inst.min_quantity(),
             Self::FuturesSpread(inst) => inst.min_quantity(),
+            Self::IndexInstrument(inst) => inst.min_quantity(),
             Self::OptionContract(inst) => inst.min_quantity(),
             Self::OptionSpread(inst) => inst.min_quantity(),
         }
@@ -367,12 +453,16 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.max_notional(),
             Self::BinaryOption(inst) => inst.max_notional(),
+            Self::Cfd(inst) => inst.max_notional(),
+            Self::Commodity(inst) => inst.max_notional(),
             Self::CryptoFuture(inst) => inst.max_notional(),
+            Self::CryptoOption(inst) => inst.max_notional(),
             Self::CryptoPerpetual(inst) => inst.max_notional(),
             Self::CurrencyPair(inst) => inst.max_notional(),
             Self::Equity(inst) => inst.max_notional(),
             Self::FuturesContract(inst) => inst.max_notional(),
             Self::FuturesSpread(inst) => inst.max_notional(),
+            Self::IndexInstrument(inst) => inst.max_notional(),
             Self::OptionContract(inst) => inst.max_notional(),
             Self::OptionSpread(inst) => inst.max_notional(),
         }
@@ -382,27 +472,75 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.min_notional(),
             Self::BinaryOption(inst) => inst.min_notional(),
+            Self::Cfd(inst) => inst.min_notional(),
+            Self::Commodity(inst) => inst.min_notional(),
             Self::CryptoFuture(inst) => inst.min_notional(),
+            Self::CryptoOption(inst) => inst.min_notional(),
             Self::CryptoPerpetual(inst) => inst.min_notional(),
             Self::CurrencyPair(inst) => inst.min_notional(),
             Self::Equity(inst) => inst.min_notional(),
             Self::FuturesContract(inst) => inst.min_notional(),
             Self::FuturesSpread(inst) => inst.min_notional(),
+            Self::IndexInstrument(inst) => inst.min_notional(),
             Self::OptionContract(inst) => inst.min_notional(),
             Self::OptionSpread(inst) => inst.min_notional(),
         }
     }
 
+    #[must_use]
+    pub fn ts_event(&self) -> UnixNanos {
+        match self {
+            Self::Betting(inst) => inst.ts_event(),
+            Self::BinaryOption(inst) => inst.ts_event(),
+            Self::Cfd(inst) => inst.ts_event(),
+            Self::Commodity(inst) => inst.ts_event(),
+            Self::CryptoFuture(inst) => inst.ts_event(),
+            Self::CryptoOption(inst) => inst.ts_event(),
+            Self::CryptoPerpetual(inst) => inst.ts_event(),
+            Self::CurrencyPair(inst) => inst.ts_event(),
+            Self::Equity(inst) => inst.ts_event(),
+            Self::FuturesContract(inst) => inst.ts_event(),
+            Self::FuturesSpread(inst) => inst.ts_event(),
+            Self::IndexInstrument(inst) => inst.ts_event(),
+            Self::OptionContract(inst) => inst.ts_event(),
+            Self::OptionSpread(inst) => inst.ts_event(),
+        }
+    }
+
+    #[must_use]
+    pub fn ts_init(&self) -> UnixNanos {
+        match self {
+            Self::Betting(inst) => inst.ts_init(),
+            Self::BinaryOption(inst) => inst.ts_init(),
+            Self::Cfd(inst) => inst.ts_init(),
+            Self::Commodity(inst) => inst.ts_init(),
+            Self::CryptoFuture(inst) => inst.ts_init(),
+            Self::CryptoOption(inst) => inst.ts_init(),
+            Self::CryptoPerpetual(inst) => inst.ts_init(),
+            Self::CurrencyPair(inst) => inst.ts_init(),
+            Self::Equity(inst) => inst.ts_init(),
+            Self::FuturesContract(inst) => inst.ts_init(),
+            Self::FuturesSpread(inst) => inst.ts_init(),
+            Self::IndexInstrument(inst) => inst.ts_init(),
+            Self::OptionContract(inst) => inst.ts_init(),
+            Self::OptionSpread(inst) => inst.ts_init(),
+        }
+    }
+
     pub fn make_price(&self, value: f64) -> Price {
         match self {
             Self::Betting(inst) => inst.make_price(value),
             Self::BinaryOption(inst) => inst.make_price(value),
+            Self::Cfd(inst) => inst.make_price(value),
+            Self::Commodity(inst) => inst.make_price(value),
             Self::CryptoFuture(inst) => inst.make_price(value),
+            Self::CryptoOption(inst) => inst.make_price(value),
             Self::CryptoPerpetual(inst) => inst.make_price(value),
             Self::CurrencyPair(inst) => inst.make_price(value),
             Self::Equity(inst) => inst.make_price(value),
             Self::FuturesContract(inst) => inst.make_price(value),
             Self::FuturesSpread(inst) => inst.make_price(value),
+            Self::IndexInstrument(inst) => inst.make_price(value),
             Self::OptionContract(inst) => inst.make_price(value),
             Self::OptionSpread(inst) => inst.make_price(value),
         }
@@ -412,12 +550,16 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.make_qty(value),
             Self::BinaryOption(inst) => inst.make_qty(value),
+            Self::Cfd(inst) => inst.make_qty(value),
+            Self::Commodity(inst) => inst.make_qty(value),
             Self::CryptoFuture(inst) => inst.make_qty(value),
+            Self::CryptoOption(inst) => inst.make_qty(value),
             Self::CryptoPerpetual(inst) => inst.make_qty(value),
             Self::CurrencyPair(inst) => inst.make_qty(value),
             Self::Equity(inst) => inst.make_qty(value),
             Self::FuturesContract(inst) => inst.make_qty(value),
             Self::FuturesSpread(inst) => inst.make_qty(value),
+            Self::IndexInstrument(inst) => inst.make_qty(value),
             Self::OptionContract(inst) => inst.make_qty(value),
             Self::OptionSpread(inst) => inst.make_qty(value),
         }
@@ -437,9 +579,18 @@ impl InstrumentAny {
             Self::BinaryOption(inst) => {
                 inst.calculate_notional_value(quantity, price, use_quote_for_inverse)
             }
+            Self::Cfd(inst) => {
+                inst.calculate_notional_value(quantity, price, use_quote_for_inverse)
+            }
+            Self::Commodity(inst) => {
+                inst.calculate_notional_value(quantity, price, use_quote_for_inverse)
+            }
             Self::CryptoFuture(inst) => {
                 inst.calculate_notional_value(quantity, price, use_quote_for_inverse)
             }
+            Self::CryptoOption(inst) => {
+                inst.calculate_notional_value(quantity, price, use_quote_for_inverse)
+            }
             Self::CryptoPerpetual(inst) => {
                 inst.calculate_notional_value(quantity, price, use_quote_for_inverse)
             }
@@ -455,6 +606,9 @@ impl InstrumentAny {
             Self::FuturesSpread(inst) => {
                 inst.calculate_notional_value(quantity, price, use_quote_for_inverse)
             }
+            Self::IndexInstrument(inst) => {
+                inst.calculate_notional_value(quantity, price, use_quote_for_inverse)
+            }
             Self::OptionContract(inst) => {
                 inst.calculate_notional_value(quantity, price, use_quote_for_inverse)
             }
@@ -470,12 +624,16 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.maker_fee(),
             Self::BinaryOption(inst) => inst.maker_fee(),
+            Self::Cfd(inst) => inst.maker_fee(),
+            Self::Commodity(inst) => inst.maker_fee(),
             Self::CryptoFuture(inst) => inst.maker_fee(),
+            Self::CryptoOption(inst) => inst.maker_fee(),
             Self::CryptoPerpetual(inst) => inst.maker_fee(),
             Self::CurrencyPair(inst) => inst.maker_fee(),
             Self::Equity(inst) => inst.maker_fee(),
             Self::FuturesContract(inst) => inst.maker_fee(),
             Self::FuturesSpread(inst) => inst.maker_fee(),
+            Self::IndexInstrument(inst) => inst.maker_fee(),
             Self::OptionContract(inst) => inst.maker_fee(),
             Self::OptionSpread(inst) => inst.maker_fee(),
         }
@@ -487,12 +645,16 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.taker_fee(),
             Self::BinaryOption(inst) => inst.taker_fee(),
+            Self::Cfd(inst) => inst.taker_fee(),
+            Self::Commodity(inst) => inst.taker_fee(),
             Self::CryptoFuture(inst) => inst.taker_fee(),
+            Self::CryptoOption(inst) => inst.taker_fee(),
             Self::CryptoPerpetual(inst) => inst.taker_fee(),
             Self::CurrencyPair(inst) => inst.taker_fee(),
             Self::Equity(inst) => inst.taker_fee(),
             Self::FuturesContract(inst) => inst.taker_fee(),
             Self::FuturesSpread(inst) => inst.taker_fee(),
+            Self::IndexInstrument(inst) => inst.taker_fee(),
             Self::OptionContract(inst) => inst.taker_fee(),
             Self::OptionSpread(inst) => inst.taker_fee(),
         }
@@ -502,12 +664,16 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.calculate_base_quantity(quantity, last_px),
             Self::BinaryOption(inst) => inst.calculate_base_quantity(quantity, last_px),
+            Self::Cfd(inst) => inst.calculate_base_quantity(quantity, last_px),
+            Self::Commodity(inst) => inst.calculate_base_quantity(quantity, last_px),
             Self::CryptoFuture(inst) => inst.calculate_base_quantity(quantity, last_px),
+            Self::CryptoOption(inst) => inst.calculate_base_quantity(quantity, last_px),
             Self::CryptoPerpetual(inst) => inst.calculate_base_quantity(quantity, last_px),
             Self::CurrencyPair(inst) => inst.calculate_base_quantity(quantity, last_px),
             Self::Equity(inst) => inst.calculate_base_quantity(quantity, last_px),
             Self::FuturesContract(inst) => inst.calculate_base_quantity(quantity, last_px),
             Self::FuturesSpread(inst) => inst.calculate_base_quantity(quantity, last_px),
+            Self::IndexInstrument(inst) => inst.calculate_base_quantity(quantity, last_px),
             Self::OptionContract(inst) => inst.calculate_base_quantity(quantity, last_px),
             Self::OptionSpread(inst) => inst.calculate_base_quantity(quantity, last_px),
         }
@@ -18,12 +18,16 @@
 pub mod any;
 pub mod betting;
 pub mod binary_option;
+pub mod cfd;
+pub mod commodity;
 pub mod crypto_future;
+pub mod crypto_option;
 pub mod crypto_perpetual;
 pub mod currency_pair;
 pub mod equity;
 pub mod futures_contract;
 pub mod futures_spread;
+pub mod index_instrument;
 pub mod option_contract;
 pub mod option_spread;
 pub mod synthetic;
@@ -31,17 +35,19 @@ pub mod synthetic;
 #[cfg(feature = "stubs")]
 pub mod stubs;
 
-use nautilus_core::UnixNanos;
+use nautilus_core::{correctness::FAILED, UnixNanos};
 use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 use ustr::Ustr;
 
 // Re-exports
 pub use crate::instruments::{
-    any::InstrumentAny, betting::BettingInstrument, binary_option::BinaryOption,
-    crypto_future::CryptoFuture, crypto_perpetual::CryptoPerpetual, currency_pair::CurrencyPair,
-    equity::Equity, futures_contract::FuturesContract, futures_spread::FuturesSpread,
-    option_contract::OptionContract, option_spread::OptionSpread, synthetic::SyntheticInstrument,
+    any::InstrumentAny, betting::BettingInstrument, binary_option::BinaryOption, cfd::Cfd,
+    commodity::Commodity, crypto_future::CryptoFuture, crypto_option::CryptoOption,
+    crypto_perpetual::CryptoPerpetual, currency_pair::CurrencyPair, equity::Equity,
+    futures_contract::FuturesContract, futures_spread::FuturesSpread,
+    index_instrument::IndexInstrument, option_contract::OptionContract,
+    option_spread::OptionSpread, synthetic::SyntheticInstrument,
 };
 use crate::{
     enums::{AssetClass, InstrumentClass, OptionKind},
@@ -115,6 +121,8 @@ pub trait Instrument: 'static + Send {
     /// Calculates the notional value from the given parameters.
     /// The `use_quote_for_inverse` flag is only applicable for inverse instruments.
     ///
+    /// The value is calculated exactly, then rounded once to the currency precision.
+    ///
     /// # Panics
     ///
     /// This function panics:
@@ -128,22 +136,23 @@ pub trait Instrument: 'static + Send {
         let use_quote_for_inverse = use_quote_for_inverse.unwrap_or(false);
         let (amount, currency) = if self.is_inverse() {
             if use_quote_for_inverse {
-                (quantity.as_f64(), self.quote_currency())
+                (quantity.as_decimal(), self.quote_currency())
             } else {
                 let amount =
-                    quantity.as_f64() * self.multiplier().as_f64() * (1.0 / price.as_f64());
+                    quantity.as_decimal() * self.multiplier().as_decimal() / price.as_decimal();
                 let currency = self
                     .base_currency()
                     .expect("Error: no base currency for notional calculation");
                 (amount, currency)
             }
         } else {
-            let amount = quantity.as_f64() * self.multiplier().as_f64() * price.as_f64();
+            let amount =
+                quantity.as_decimal() * self.multiplier().as_decimal() * price.as_decimal();
             let currency = self.quote_currency();
             (amount, currency)
         };
 
-        Money::new(amount, currency)
+        Money::from_decimal(amount, currency).expect(FAILED)
     }
 
     /// Returns the equivalent quantity of the base asset.
@@ -28,7 +28,8 @@ use crate::{
     enums::{AssetClass, OptionKind},
     identifiers::{InstrumentId, Symbol, Venue},
     instruments::{
-        CryptoFuture, CryptoPerpetual, CurrencyPair, Equity, FuturesContract, OptionContract,
+        Cfd, Commodity, CryptoFuture, CryptoOption, CryptoPerpetual, CurrencyPair, Equity,
+        FuturesContract, IndexInstrument, OptionContract,
     },
     types::{Currency, Money, Price, Quantity},
 };
@@ -596,3 +597,124 @@ pub fn binary_option() -> BinaryOption {
         UnixNanos::default(),
     )
 }
+
+////////////////////////////////////////////////////////////////////////////////
+// Cfd
+////////////////////////////////////////////////////////////////////////////////
+
+#[fixture]
+pub fn cfd_xauusd() -> Cfd {
+    Cfd::new(
+        InstrumentId::from("XAUUSD.OANDA"),
+        Symbol::from("XAU/USD"),
+        AssetClass::Commodity,
+        Some(Currency::XAU()),
+        Currency::USD(),
+        2,
+        1,
+        Price::from("0.01"),
+        Quantity::from("0.1"),
+        None,
+        None,
+        Some(Quantity::from("0.1")),
+        None,
+        None,
+        None,
+        None,
+        Some(dec!(0.05)),
+        Some(dec!(0.025)),
+        Some(dec!(0.0)),
+        Some(dec!(0.0)),
+        UnixNanos::default(),
+        UnixNanos::default(),
+    )
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Commodity
+////////////////////////////////////////////////////////////////////////////////
+
+#[fixture]
+pub fn commodity_xauusd() -> Commodity {
+    Commodity::new(
+        InstrumentId::from("XAUUSD.SIM"),
+        Symbol::from("XAU/USD"),
+        Currency::USD(),
+        2,
+        1,
+        Price::from("0.01"),
+        Quantity::from("0.1"),
+        None,
+        None,
+        Some(Quantity::from("0.1")),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        UnixNanos::default(),
+        UnixNanos::default(),
+    )
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// CryptoOption
+////////////////////////////////////////////////////////////////////////////////
+
+#[fixture]
+pub fn crypto_option_btc_deribit() -> CryptoOption {
+    let activation = Utc.with_ymd_and_hms(2024, 1, 26, 8, 0, 0).unwrap();
+    let expiration = Utc.with_ymd_and_hms(2024, 3, 29, 8, 0, 0).unwrap();
+    CryptoOption::new(
+        InstrumentId::from("BTC-29MAR24-60000-C.DERIBIT"),
+        Symbol::from("BTC-29MAR24-60000-C"),
+        Currency::BTC(),
+        Currency::USD(),
+        Currency::BTC(),
+        true,
+        OptionKind::Call,
+        Price::from("60000.0"),
+        UnixNanos::from(activation.timestamp_nanos_opt().unwrap() as u64),
+        UnixNanos::from(expiration.timestamp_nanos_opt().unwrap() as u64),
+        1,
+        1,
+        Price::from("0.1"),
+        Quantity::from("0.1"),
+        Some(Quantity::from(1)),
+        Some(Quantity::from(1)),
+        None,
+        Some(Quantity::from("0.1")),
+        None,
+        None,
+        None,
+        None,
+        Some(dec!(0.0)),
+        Some(dec!(0.0)),
+        Some(dec!(0.0003)),
+        Some(dec!(0.0003)),
+        UnixNanos::default(),
+        UnixNanos::default(),
+    )
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// IndexInstrument
+////////////////////////////////////////////////////////////////////////////////
+
+#[fixture]
+pub fn index_instrument_spx() -> IndexInstrument {
+    IndexInstrument::new(
+        InstrumentId::from("SPX.CBOE"),
+        Symbol::from("SPX"),
+        Currency::USD(),
+        2,
+        0,
+        Price::from("0.01"),
+        Quantity::from(1),
+        UnixNanos::default(),
+        UnixNanos::default(),
+    )
+}
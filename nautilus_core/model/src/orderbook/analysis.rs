@@ -15,9 +15,10 @@
 
 //! Functions related to order book analysis.
 
-use std::collections::BTreeMap;
+use indexmap::IndexMap;
+use rust_decimal::Decimal;
 
-use super::{BookLevel, BookPrice, OrderBook};
+use super::{BookLevel, OrderBook};
 use crate::{
     enums::{BookType, OrderSide},
     orderbook::BookIntegrityError,
@@ -27,22 +28,22 @@ use crate::{
 /// Calculates the estimated fill quantity for a specified price from a set of
 /// order book levels and order side.
 #[must_use]
-pub fn get_quantity_for_price(
+pub fn get_quantity_for_price<'a>(
     price: Price,
     order_side: OrderSide,
-    levels: &BTreeMap<BookPrice, BookLevel>,
+    levels: impl IntoIterator<Item = &'a BookLevel>,
 ) -> f64 {
     let mut matched_size: f64 = 0.0;
 
-    for (book_price, level) in levels {
+    for level in levels {
         match order_side {
             OrderSide::Buy => {
-                if book_price.value > price {
+                if level.price.value > price {
                     break;
                 }
             }
             OrderSide::Sell => {
-                if book_price.value < price {
+                if level.price.value < price {
                     break;
                 }
             }
@@ -57,14 +58,17 @@ pub fn get_quantity_for_price(
 /// Calculates the estimated average price for a specified quantity from a set of
 /// order book levels.
 #[must_use]
-pub fn get_avg_px_for_quantity(qty: Quantity, levels: &BTreeMap<BookPrice, BookLevel>) -> f64 {
+pub fn get_avg_px_for_quantity<'a>(
+    qty: Quantity,
+    levels: impl IntoIterator<Item = &'a BookLevel>,
+) -> f64 {
     let mut cumulative_size_raw: QuantityRaw = 0;
     let mut cumulative_value = 0.0;
 
-    for (book_price, level) in levels {
+    for level in levels {
         let size_this_level = level.size_raw().min(qty.raw - cumulative_size_raw);
         cumulative_size_raw += size_this_level;
-        cumulative_value += book_price.value.as_f64() * size_this_level as f64;
+        cumulative_value += level.price.value.as_f64() * size_this_level as f64;
 
         if cumulative_size_raw >= qty.raw {
             break;
@@ -81,19 +85,20 @@ pub fn get_avg_px_for_quantity(qty: Quantity, levels: &BTreeMap<BookPrice, BookL
 /// Calculates the estimated average price for a specified exposure from a set of
 /// order book levels.
 #[must_use]
-pub fn get_avg_px_qty_for_exposure(
+pub fn get_avg_px_qty_for_exposure<'a>(
     target_exposure: Quantity,
-    levels: &BTreeMap<BookPrice, BookLevel>,
+    levels: impl IntoIterator<Item = &'a BookLevel>,
 ) -> (f64, f64, f64) {
     let mut cumulative_exposure = 0.0;
     let mut cumulative_size_raw: QuantityRaw = 0;
+    let mut levels = levels.into_iter().peekable();
     let mut final_price = levels
-        .first_key_value()
-        .map(|(price, _)| price.value.as_f64())
+        .peek()
+        .map(|level| level.price.value.as_f64())
         .unwrap_or(0.0);
 
-    for (book_price, level) in levels {
-        let price = book_price.value.as_f64();
+    for level in levels {
+        let price = level.price.value.as_f64();
         final_price = price;
 
         let level_exposure = price * level.size_raw() as f64;
@@ -104,7 +109,7 @@ pub fn get_avg_px_qty_for_exposure(
         cumulative_exposure += price * size_this_level as f64;
         cumulative_size_raw += size_this_level;
 
-        if cumulative_exposure >= target_exposure.as_f64() {
+        if cumulative_exposure >= target_exposure.raw as f64 {
             break;
         }
     }
@@ -121,6 +126,140 @@ pub fn get_avg_px_qty_for_exposure(
     }
 }
 
+/// Calculates the cumulative size at each price level from a set of order book levels,
+/// ordered from the top of book outwards.
+#[must_use]
+pub fn get_cumulative_depth<'a>(
+    levels: impl IntoIterator<Item = &'a BookLevel>,
+) -> IndexMap<Decimal, Decimal> {
+    let mut cumulative_size = Decimal::ZERO;
+    levels
+        .into_iter()
+        .map(|level| {
+            cumulative_size += level.size_decimal();
+            (level.price.value.as_decimal(), cumulative_size)
+        })
+        .collect()
+}
+
+/// Calculates the estimated average price to fill the specified `notional` (price * size) from
+/// a set of order book levels.
+///
+/// Returns `None` if `notional` is not positive or the levels cannot fill the full notional.
+#[must_use]
+pub fn get_avg_px_for_notional<'a>(
+    notional: f64,
+    levels: impl IntoIterator<Item = &'a BookLevel>,
+) -> Option<f64> {
+    if notional <= 0.0 {
+        return None;
+    }
+
+    let mut remaining_notional = notional;
+    let mut cumulative_size = 0.0;
+
+    for level in levels {
+        let price = level.price.value.as_f64();
+        let size = level.size();
+        let level_notional = price * size;
+
+        if level_notional >= remaining_notional {
+            cumulative_size += remaining_notional / price;
+            return Some(notional / cumulative_size);
+        }
+
+        remaining_notional -= level_notional;
+        cumulative_size += size;
+    }
+
+    None
+}
+
+/// Calculates the slippage in basis points of an `avg_px` relative to a `reference_px` for an
+/// order on the given `order_side`, where a positive value is a cost.
+#[must_use]
+pub fn get_slippage_bps(avg_px: f64, reference_px: f64, order_side: OrderSide) -> f64 {
+    match order_side {
+        OrderSide::Buy => (avg_px - reference_px) / reference_px * 10_000.0,
+        OrderSide::Sell => (reference_px - avg_px) / reference_px * 10_000.0,
+        _ => panic!("Invalid `OrderSide` {order_side}"),
+    }
+}
+
+/// Calculates the total size available within `bps` basis points of a `reference_px` from a set
+/// of order book levels.
+#[must_use]
+pub fn get_quantity_within_bps<'a>(
+    reference_px: f64,
+    bps: f64,
+    levels: impl IntoIterator<Item = &'a BookLevel>,
+) -> f64 {
+    let max_distance = reference_px * bps / 10_000.0;
+    levels
+        .into_iter()
+        .take_while(|level| (level.price.value.as_f64() - reference_px).abs() <= max_distance)
+        .map(BookLevel::size)
+        .sum()
+}
+
+/// Calculates the order imbalance between a bid and ask size, in the range [-1, 1] where a
+/// positive value indicates more size on the bid.
+///
+/// Returns `None` if both sizes are zero.
+#[must_use]
+pub fn get_imbalance(bid_size: f64, ask_size: f64) -> Option<f64> {
+    let total_size = bid_size + ask_size;
+    if total_size <= 0.0 {
+        return None;
+    }
+    Some((bid_size - ask_size) / total_size)
+}
+
+/// Calculates the order imbalance across the bid and ask levels, where the level at index `i`
+/// from the top of book is weighted by `decay` to the power of `i`.
+///
+/// A `decay` of 1.0 weights all levels equally. Returns `None` if there is no size on either side.
+#[must_use]
+pub fn get_weighted_imbalance<'a>(
+    bids: impl IntoIterator<Item = &'a BookLevel>,
+    asks: impl IntoIterator<Item = &'a BookLevel>,
+    decay: f64,
+) -> Option<f64> {
+    let bid_size = weighted_size(bids, decay);
+    let ask_size = weighted_size(asks, decay);
+    get_imbalance(bid_size, ask_size)
+}
+
+fn weighted_size<'a>(levels: impl IntoIterator<Item = &'a BookLevel>, decay: f64) -> f64 {
+    let mut weight = 1.0;
+    levels
+        .into_iter()
+        .map(|level| {
+            let size = level.size() * weight;
+            weight *= decay;
+            size
+        })
+        .sum()
+}
+
+/// Calculates the microprice from the top bid and ask levels, being the mid price weighted by the
+/// size on the opposite side of the book.
+///
+/// Returns `None` if both levels have no size.
+#[must_use]
+pub fn get_microprice(bid_level: &BookLevel, ask_level: &BookLevel) -> Option<f64> {
+    let bid_size = bid_level.size();
+    let ask_size = ask_level.size();
+    let total_size = bid_size + ask_size;
+    if total_size <= 0.0 {
+        return None;
+    }
+
+    let bid_price = bid_level.price.value.as_f64();
+    let ask_price = ask_level.price.value.as_f64();
+    Some((bid_price * ask_size + ask_price * bid_size) / total_size)
+}
+
 pub fn book_check_integrity(book: &OrderBook) -> Result<(), BookIntegrityError> {
     match book.book_type {
         BookType::L1_MBP => {
@@ -21,12 +21,23 @@ use indexmap::IndexMap;
 use nautilus_core::UnixNanos;
 use rust_decimal::Decimal;
 
-use super::{aggregation::pre_process_order, analysis, display::pprint_book, level::BookLevel};
+use super::{
+    aggregation::pre_process_order,
+    analysis,
+    checksum::{self, BookChecksumFormat},
+    depth, diff,
+    display::pprint_book,
+    level::BookLevel,
+    own::{OwnBookLadder, OwnOrderBook},
+};
 use crate::{
     data::{BookOrder, OrderBookDelta, OrderBookDeltas, OrderBookDepth10, QuoteTick, TradeTick},
     enums::{BookAction, BookType, OrderSide, OrderSideSpecified},
-    identifiers::InstrumentId,
-    orderbook::{ladder::BookLadder, InvalidBookOperation},
+    identifiers::{ClientOrderId, InstrumentId},
+    orderbook::{
+        ladder::BookLadder, ring::RING_LEVELS_DEFAULT_CAPACITY, BookIntegrityError,
+        InvalidBookOperation,
+    },
     types::{Price, Quantity},
 };
 
@@ -92,6 +103,42 @@ impl OrderBook {
         }
     }
 
+    /// Creates a new [`OrderBook`] instance with ring buffer price ladders for an instrument
+    /// with a fixed `price_increment`.
+    ///
+    /// Price levels are stored contiguously by tick offset, giving O(1) level access and
+    /// cache-friendly iteration from the top of book. All order prices must be a multiple of
+    /// `price_increment`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics:
+    /// - If `price_increment` is not positive.
+    #[must_use]
+    pub fn new_fixed_tick(
+        instrument_id: InstrumentId,
+        book_type: BookType,
+        price_increment: Price,
+    ) -> Self {
+        Self {
+            instrument_id,
+            book_type,
+            sequence: 0,
+            ts_last: UnixNanos::default(),
+            count: 0,
+            bids: BookLadder::new_fixed_tick(
+                OrderSide::Buy,
+                price_increment,
+                RING_LEVELS_DEFAULT_CAPACITY,
+            ),
+            asks: BookLadder::new_fixed_tick(
+                OrderSide::Sell,
+                price_increment,
+                RING_LEVELS_DEFAULT_CAPACITY,
+            ),
+        }
+    }
+
     /// Resets the order book to its initial empty state.
     pub fn reset(&mut self) {
         self.bids.clear();
@@ -179,12 +226,11 @@ impl OrderBook {
         self.bids.clear();
         self.asks.clear();
 
-        for order in depth.bids {
-            self.add(order, depth.flags, depth.sequence, depth.ts_event);
-        }
-
-        for order in depth.asks {
-            self.add(order, depth.flags, depth.sequence, depth.ts_event);
+        for order in depth.bids.into_iter().chain(depth.asks) {
+            // Skip any padding for empty levels
+            if order.size.is_positive() {
+                self.add(order, depth.flags, depth.sequence, depth.ts_event);
+            }
         }
     }
 
@@ -212,6 +258,68 @@ impl OrderBook {
             .collect()
     }
 
+    /// Returns bid price levels as a map of price to size, net of our own orders in `own_book`.
+    ///
+    /// Levels with no remaining market size are excluded, with `depth` applied after filtering.
+    pub fn bids_filtered_as_map(
+        &self,
+        depth: Option<usize>,
+        own_book: Option<&OwnOrderBook>,
+    ) -> IndexMap<Decimal, Decimal> {
+        filter_levels_as_map(self.bids(None), own_book.map(|own| &own.bids), depth)
+    }
+
+    /// Returns ask price levels as a map of price to size, net of our own orders in `own_book`.
+    ///
+    /// Levels with no remaining market size are excluded, with `depth` applied after filtering.
+    pub fn asks_filtered_as_map(
+        &self,
+        depth: Option<usize>,
+        own_book: Option<&OwnOrderBook>,
+    ) -> IndexMap<Decimal, Decimal> {
+        filter_levels_as_map(self.asks(None), own_book.map(|own| &own.asks), depth)
+    }
+
+    /// Returns the best bid price excluding levels made up entirely of our own orders.
+    #[must_use]
+    pub fn best_bid_price_filtered(&self, own_book: &OwnOrderBook) -> Option<Price> {
+        first_price_with_market_size(self.bids(None), &own_book.bids)
+    }
+
+    /// Returns the best ask price excluding levels made up entirely of our own orders.
+    #[must_use]
+    pub fn best_ask_price_filtered(&self, own_book: &OwnOrderBook) -> Option<Price> {
+        first_price_with_market_size(self.asks(None), &own_book.asks)
+    }
+
+    /// Returns the market size ahead of our own order in the queue, net of our own orders.
+    ///
+    /// The queue position within a price level is unknown from aggregated data, so all market
+    /// size at the order's price is conservatively counted as ahead. Returns `None` if the order
+    /// is not resting in `own_book`.
+    #[must_use]
+    pub fn depth_ahead(
+        &self,
+        own_book: &OwnOrderBook,
+        client_order_id: &ClientOrderId,
+    ) -> Option<Decimal> {
+        let own_order = own_book.get_order(client_order_id)?;
+        let (ladder, own_ladder) = match own_order.side {
+            OrderSideSpecified::Buy => (&self.bids, &own_book.bids),
+            OrderSideSpecified::Sell => (&self.asks, &own_book.asks),
+        };
+        let own_price = own_order.to_book_price();
+
+        let depth = ladder
+            .levels
+            .values()
+            .take_while(|level| level.price <= own_price)
+            .map(|level| net_level_size(level, own_ladder))
+            .sum();
+
+        Some(depth)
+    }
+
     /// Groups bid levels by price, up to specified depth.
     pub fn group_bids(
         &self,
@@ -241,12 +349,7 @@ impl OrderBook {
         let depth = depth.unwrap_or(usize::MAX);
 
         for level in levels_iter {
-            let price = level.price.value.as_decimal();
-            let grouped_price = if is_bid {
-                (price / group_size).floor() * group_size
-            } else {
-                (price / group_size).ceil() * group_size
-            };
+            let grouped_price = group_price(level.price.value.as_decimal(), group_size, is_bid);
             let size = level.size_decimal();
 
             levels
@@ -329,7 +432,7 @@ impl OrderBook {
             OrderSideSpecified::Sell => &self.bids.levels,
         };
 
-        analysis::get_avg_px_for_quantity(qty, levels)
+        analysis::get_avg_px_for_quantity(qty, levels.values())
     }
 
     /// Calculates average price and quantity for target exposure. Returns (price, quantity, executed_exposure).
@@ -344,7 +447,7 @@ impl OrderBook {
             OrderSideSpecified::Sell => &self.bids.levels,
         };
 
-        analysis::get_avg_px_qty_for_exposure(target_exposure, levels)
+        analysis::get_avg_px_qty_for_exposure(target_exposure, levels.values())
     }
 
     /// Returns the total quantity available at specified price level.
@@ -355,7 +458,77 @@ impl OrderBook {
             OrderSideSpecified::Sell => &self.bids.levels,
         };
 
-        analysis::get_quantity_for_price(price, order_side, levels)
+        analysis::get_quantity_for_price(price, order_side, levels.values())
+    }
+
+    /// Returns the cumulative size at each bid price level, up to specified depth.
+    pub fn bids_cumulative_depth(&self, depth: Option<usize>) -> IndexMap<Decimal, Decimal> {
+        analysis::get_cumulative_depth(self.bids(depth))
+    }
+
+    /// Returns the cumulative size at each ask price level, up to specified depth.
+    pub fn asks_cumulative_depth(&self, depth: Option<usize>) -> IndexMap<Decimal, Decimal> {
+        analysis::get_cumulative_depth(self.asks(depth))
+    }
+
+    /// Calculates the slippage in basis points from the midpoint to fill each of the given
+    /// `notionals` (price * size). Returns a list of (notional, slippage) tuples, where the
+    /// slippage is `None` if the book has no midpoint or cannot fill the full notional.
+    #[must_use]
+    pub fn slippage_curve(
+        &self,
+        notionals: &[f64],
+        order_side: OrderSide,
+    ) -> Vec<(f64, Option<f64>)> {
+        let levels = match order_side.as_specified() {
+            OrderSideSpecified::Buy => &self.asks.levels,
+            OrderSideSpecified::Sell => &self.bids.levels,
+        };
+        let midpoint = self.midpoint();
+
+        notionals
+            .iter()
+            .map(|&notional| {
+                let slippage = midpoint.and_then(|midpoint| {
+                    analysis::get_avg_px_for_notional(notional, levels.values())
+                        .map(|avg_px| analysis::get_slippage_bps(avg_px, midpoint, order_side))
+                });
+                (notional, slippage)
+            })
+            .collect()
+    }
+
+    /// Returns the total (bid, ask) size within `bps` basis points of the midpoint, if available.
+    #[must_use]
+    pub fn liquidity_within_bps(&self, bps: f64) -> Option<(f64, f64)> {
+        let midpoint = self.midpoint()?;
+        Some((
+            analysis::get_quantity_within_bps(midpoint, bps, self.bids(None)),
+            analysis::get_quantity_within_bps(midpoint, bps, self.asks(None)),
+        ))
+    }
+
+    /// Returns the order imbalance between the bid and ask level at each depth from the top of
+    /// book, up to specified depth.
+    #[must_use]
+    pub fn level_imbalances(&self, depth: Option<usize>) -> Vec<f64> {
+        self.bids(depth)
+            .zip(self.asks(depth))
+            .filter_map(|(bid, ask)| analysis::get_imbalance(bid.size(), ask.size()))
+            .collect()
+    }
+
+    /// Returns the order imbalance across bid and ask levels up to specified depth, with each
+    /// level weighted by `decay` to the power of its depth from the top of book.
+    #[must_use]
+    pub fn weighted_imbalance(&self, depth: Option<usize>, decay: f64) -> Option<f64> {
+        analysis::get_weighted_imbalance(self.bids(depth), self.asks(depth), decay)
+    }
+
+    /// Returns the microprice from the top bid and ask levels if both exist.
+    #[must_use]
+    pub fn microprice(&self) -> Option<f64> {
+        analysis::get_microprice(self.bids.top()?, self.asks.top()?)
     }
 
     /// Simulates fills for an order, returning list of (price, quantity) tuples.
@@ -367,6 +540,41 @@ impl OrderBook {
         }
     }
 
+    /// Computes the CRC32 checksum of the top `depth` levels of each side in the given `format`.
+    #[must_use]
+    pub fn checksum(&self, format: BookChecksumFormat, depth: usize) -> u32 {
+        checksum::book_checksum(self, format, depth)
+    }
+
+    /// Validates the CRC32 checksum of the top `depth` levels of each side against `expected`.
+    pub fn validate_checksum(
+        &self,
+        format: BookChecksumFormat,
+        depth: usize,
+        expected: u32,
+    ) -> Result<(), BookIntegrityError> {
+        checksum::book_check_checksum(self, format, depth, expected)
+    }
+
+    /// Returns the minimal list of deltas which transform this book into the `other` book.
+    #[must_use]
+    pub fn diff(&self, other: &Self, ts_init: UnixNanos) -> Vec<OrderBookDelta> {
+        diff::book_diff(self, other, ts_init)
+    }
+
+    /// Returns the minimal list of deltas which transform this book into the `depth` snapshot.
+    #[must_use]
+    pub fn diff_depth(&self, depth: &OrderBookDepth10) -> Vec<OrderBookDelta> {
+        diff::book_diff_depth(self, depth)
+    }
+
+    /// Returns an [`OrderBookDepth10`] snapshot of the top levels of each side, optionally
+    /// grouped into price buckets of `group_size`.
+    #[must_use]
+    pub fn to_depth10(&self, group_size: Option<Decimal>, ts_init: UnixNanos) -> OrderBookDepth10 {
+        depth::book_to_depth10(self, group_size, ts_init)
+    }
+
     /// Return a formatted string representation of the order book.
     #[must_use]
     pub fn pprint(&self, num_levels: usize) -> String {
@@ -450,6 +658,47 @@ impl OrderBook {
     }
 }
 
+fn net_level_size(level: &BookLevel, own_ladder: &OwnBookLadder) -> Decimal {
+    (level.size_decimal() - own_ladder.size_at(level.price.value)).max(Decimal::ZERO)
+}
+
+/// Returns the `price` rounded to a multiple of `group_size`, down for bids and up for asks so
+/// that grouped levels never improve on the prices they contain.
+pub(crate) fn group_price(price: Decimal, group_size: Decimal, is_bid: bool) -> Decimal {
+    if is_bid {
+        (price / group_size).floor() * group_size
+    } else {
+        (price / group_size).ceil() * group_size
+    }
+}
+
+fn filter_levels_as_map<'a>(
+    levels_iter: impl Iterator<Item = &'a BookLevel>,
+    own_ladder: Option<&OwnBookLadder>,
+    depth: Option<usize>,
+) -> IndexMap<Decimal, Decimal> {
+    levels_iter
+        .map(|level| {
+            let size = match own_ladder {
+                Some(own_ladder) => net_level_size(level, own_ladder),
+                None => level.size_decimal(),
+            };
+            (level.price.value.as_decimal(), size)
+        })
+        .filter(|(_, size)| *size > Decimal::ZERO)
+        .take(depth.unwrap_or(usize::MAX))
+        .collect()
+}
+
+fn first_price_with_market_size<'a>(
+    mut levels_iter: impl Iterator<Item = &'a BookLevel>,
+    own_ladder: &OwnBookLadder,
+) -> Option<Price> {
+    levels_iter
+        .find(|level| net_level_size(level, own_ladder) > Decimal::ZERO)
+        .map(|level| level.price.value)
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
@@ -460,9 +709,12 @@ mod tests {
 
     use crate::{
         data::{depth::OrderBookDepth10, order::BookOrder, stubs::*, QuoteTick, TradeTick},
-        enums::{AggressorSide, BookType, OrderSide},
-        identifiers::{InstrumentId, TradeId},
-        orderbook::{analysis::book_check_integrity, BookIntegrityError, BookPrice, OrderBook},
+        enums::{AggressorSide, BookType, OrderSide, OrderSideSpecified},
+        identifiers::{ClientOrderId, InstrumentId, StrategyId, TradeId},
+        orderbook::{
+            analysis::book_check_integrity, BookIntegrityError, BookPrice, OrderBook, OwnBookOrder,
+            OwnOrderBook,
+        },
         types::{Price, Quantity},
     };
 
@@ -821,6 +1073,22 @@ mod tests {
         // );
     }
 
+    #[rstest]
+    fn test_get_price_for_exposure_across_levels() {
+        let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+        let mut book = OrderBook::new(instrument_id, BookType::L2_MBP);
+        for (price, size) in [("101.00", 30), ("102.00", 10)] {
+            let order =
+                BookOrder::new(OrderSide::Sell, Price::from(price), Quantity::from(size), 0);
+            book.add(order, 0, 0, 1.into());
+        }
+
+        assert_eq!(
+            book.get_avg_px_qty_for_exposure(Quantity::from(4050), OrderSide::Buy),
+            (101.25, 40.0, 102.0)
+        );
+    }
+
     #[rstest]
     fn test_apply_depth(stub_depth10: OrderBookDepth10) {
         let depth = stub_depth10;
@@ -1100,4 +1368,285 @@ mod tests {
         assert_eq!(grouped_asks.get(&dec!(102.0)), Some(&dec!(3000))); // 1000 + 2000 grouped
         assert_eq!(grouped_asks.get(&dec!(104.0)), Some(&dec!(3000)));
     }
+
+    fn book_with_own_orders() -> (OrderBook, OwnOrderBook) {
+        let instrument_id = InstrumentId::from("AUD/USD.SIM");
+        let mut book = OrderBook::new(instrument_id, BookType::L2_MBP);
+        let orders = [
+            (OrderSide::Buy, "1.00000", 100),
+            (OrderSide::Buy, "0.99990", 300),
+            (OrderSide::Buy, "0.99980", 500),
+            (OrderSide::Sell, "1.00010", 200),
+            (OrderSide::Sell, "1.00020", 400),
+        ];
+        for (i, (side, price, size)) in orders.into_iter().enumerate() {
+            let order = BookOrder::new(side, Price::from(price), Quantity::from(size), i as u64);
+            book.add(order, 0, i as u64, 1.into());
+        }
+
+        // Our own orders make up all of the best bid and part of the next bid level
+        let mut own_book = OwnOrderBook::new(instrument_id);
+        for (id, side, price, size) in [
+            ("O-1", OrderSideSpecified::Buy, "1.00000", 100),
+            ("O-2", OrderSideSpecified::Buy, "0.99990", 100),
+            ("O-3", OrderSideSpecified::Sell, "1.00020", 50),
+        ] {
+            own_book.update(OwnBookOrder::new(
+                ClientOrderId::from(id),
+                StrategyId::from("S-001"),
+                side,
+                Price::from(price),
+                Quantity::from(size),
+                1.into(),
+            ));
+        }
+
+        (book, own_book)
+    }
+
+    #[rstest]
+    fn test_bids_filtered_as_map() {
+        let (book, own_book) = book_with_own_orders();
+
+        let filtered = book.bids_filtered_as_map(Some(1), Some(&own_book));
+        let unfiltered = book.bids_filtered_as_map(Some(1), None);
+
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered.get(&dec!(0.99990)), Some(&dec!(200)));
+        assert_eq!(unfiltered.get(&dec!(1.00000)), Some(&dec!(100)));
+    }
+
+    #[rstest]
+    fn test_asks_filtered_as_map() {
+        let (book, own_book) = book_with_own_orders();
+
+        let filtered = book.asks_filtered_as_map(None, Some(&own_book));
+
+        assert_eq!(filtered.get(&dec!(1.00010)), Some(&dec!(200)));
+        assert_eq!(filtered.get(&dec!(1.00020)), Some(&dec!(350)));
+    }
+
+    #[rstest]
+    fn test_best_prices_filtered() {
+        let (book, own_book) = book_with_own_orders();
+
+        assert_eq!(book.best_bid_price(), Some(Price::from("1.00000")));
+        assert_eq!(
+            book.best_bid_price_filtered(&own_book),
+            Some(Price::from("0.99990"))
+        );
+        assert_eq!(
+            book.best_ask_price_filtered(&own_book),
+            Some(Price::from("1.00010"))
+        );
+    }
+
+    #[rstest]
+    fn test_depth_ahead() {
+        let (book, own_book) = book_with_own_orders();
+
+        assert_eq!(
+            book.depth_ahead(&own_book, &ClientOrderId::from("O-1")),
+            Some(dec!(0))
+        );
+        assert_eq!(
+            book.depth_ahead(&own_book, &ClientOrderId::from("O-2")),
+            Some(dec!(200))
+        );
+        assert_eq!(
+            book.depth_ahead(&own_book, &ClientOrderId::from("O-3")),
+            Some(dec!(550))
+        );
+        assert_eq!(
+            book.depth_ahead(&own_book, &ClientOrderId::from("O-4")),
+            None
+        );
+    }
+
+    #[rstest]
+    fn test_fixed_tick_apply_depth(stub_depth10: OrderBookDepth10) {
+        let instrument_id = InstrumentId::from("AAPL.XNAS");
+        let mut book = OrderBook::new(instrument_id, BookType::L2_MBP);
+        let mut fixed_tick_book =
+            OrderBook::new_fixed_tick(instrument_id, BookType::L2_MBP, Price::from("0.01"));
+
+        book.apply_depth(&stub_depth10);
+        fixed_tick_book.apply_depth(&stub_depth10);
+
+        assert_eq!(fixed_tick_book.best_bid_price(), book.best_bid_price());
+        assert_eq!(fixed_tick_book.best_ask_price(), book.best_ask_price());
+        assert_eq!(fixed_tick_book.bids_as_map(None), book.bids_as_map(None));
+        assert_eq!(fixed_tick_book.asks_as_map(None), book.asks_as_map(None));
+        assert_eq!(fixed_tick_book.pprint(3), book.pprint(3));
+    }
+
+    #[rstest]
+    fn test_fixed_tick_matches_tree_book_for_deltas() {
+        let instrument_id = InstrumentId::from("AAPL.XNAS");
+        let mut book = OrderBook::new(instrument_id, BookType::L3_MBO);
+        let mut fixed_tick_book =
+            OrderBook::new_fixed_tick(instrument_id, BookType::L3_MBO, Price::from("0.01"));
+
+        let orders = [
+            (OrderSide::Buy, "100.00", 10, 1),
+            (OrderSide::Buy, "99.98", 20, 2),
+            (OrderSide::Buy, "100.00", 30, 3),
+            (OrderSide::Sell, "100.02", 40, 4),
+            (OrderSide::Sell, "100.05", 50, 5),
+        ];
+        for (i, (side, price, size, order_id)) in orders.into_iter().enumerate() {
+            let order = BookOrder::new(side, Price::from(price), Quantity::from(size), order_id);
+            book.add(order, 0, i as u64, 1.into());
+            fixed_tick_book.add(order, 0, i as u64, 1.into());
+        }
+
+        // Move the best ask away and cancel the best bids, shifting both anchors
+        let moved = BookOrder::new(
+            OrderSide::Sell,
+            Price::from("100.10"),
+            Quantity::from(40),
+            4,
+        );
+        book.update(moved, 0, 10, 2.into());
+        fixed_tick_book.update(moved, 0, 10, 2.into());
+        for order_id in [1, 3] {
+            let order = BookOrder::new(
+                OrderSide::Buy,
+                Price::from("100.00"),
+                Quantity::from(0),
+                order_id,
+            );
+            book.delete(order, 0, 11, 3.into());
+            fixed_tick_book.delete(order, 0, 11, 3.into());
+        }
+
+        assert_eq!(fixed_tick_book.best_bid_price(), Some(Price::from("99.98")));
+        assert_eq!(
+            fixed_tick_book.best_ask_price(),
+            Some(Price::from("100.05"))
+        );
+        assert_eq!(fixed_tick_book.bids_as_map(None), book.bids_as_map(None));
+        assert_eq!(fixed_tick_book.asks_as_map(None), book.asks_as_map(None));
+        assert_eq!(
+            fixed_tick_book.get_avg_px_for_quantity(Quantity::from(60), OrderSide::Buy),
+            book.get_avg_px_for_quantity(Quantity::from(60), OrderSide::Buy),
+        );
+        assert_eq!(
+            fixed_tick_book.get_quantity_for_price(Price::from("100.10"), OrderSide::Buy),
+            90.0,
+        );
+        assert!(book_check_integrity(&fixed_tick_book).is_ok());
+    }
+
+    #[rstest]
+    fn test_fixed_tick_reset_retains_ladder() {
+        let instrument_id = InstrumentId::from("AAPL.XNAS");
+        let mut book =
+            OrderBook::new_fixed_tick(instrument_id, BookType::L2_MBP, Price::from("0.01"));
+        let order = BookOrder::new(OrderSide::Buy, Price::from("100.00"), Quantity::from(10), 0);
+        book.add(order, 0, 1, 1.into());
+
+        book.reset();
+        book.add(order, 0, 1, 1.into());
+
+        assert_eq!(book.best_bid_price(), Some(Price::from("100.00")));
+        assert_eq!(book.bids(None).count(), 1);
+    }
+
+    fn analytics_book() -> OrderBook {
+        let mut book = OrderBook::new(InstrumentId::from("AAPL.XNAS"), BookType::L2_MBP);
+        let orders = [
+            (OrderSide::Buy, "99.00", 100),
+            (OrderSide::Buy, "98.00", 200),
+            (OrderSide::Buy, "97.00", 300),
+            (OrderSide::Sell, "101.00", 300),
+            (OrderSide::Sell, "102.00", 100),
+            (OrderSide::Sell, "103.00", 100),
+        ];
+        for (i, (side, price, size)) in orders.into_iter().enumerate() {
+            let order = BookOrder::new(side, Price::from(price), Quantity::from(size), 0);
+            book.add(order, 0, i as u64, 1.into());
+        }
+        book
+    }
+
+    #[rstest]
+    fn test_cumulative_depth() {
+        let book = analytics_book();
+
+        let bids = book.bids_cumulative_depth(None);
+        let asks = book.asks_cumulative_depth(Some(2));
+
+        assert_eq!(
+            bids.into_iter().collect::<Vec<_>>(),
+            vec![
+                (dec!(99.00), dec!(100)),
+                (dec!(98.00), dec!(300)),
+                (dec!(97.00), dec!(600)),
+            ]
+        );
+        assert_eq!(
+            asks.into_iter().collect::<Vec<_>>(),
+            vec![(dec!(101.00), dec!(300)), (dec!(102.00), dec!(400))]
+        );
+    }
+
+    #[rstest]
+    fn test_slippage_curve() {
+        let book = analytics_book();
+
+        let curve = book.slippage_curve(&[10_100.0, 40_500.0, 1_000_000.0], OrderSide::Buy);
+
+        // Midpoint is 100.0, the first notional fills entirely at 101.0 for 100 bps
+        assert_eq!(curve[0].0, 10_100.0);
+        assert!((curve[0].1.unwrap() - 100.0).abs() < 1e-9);
+        // 30,300 at 101.0 then 10,200 at 102.0 for 400 units at an average of 101.25
+        assert!((curve[1].1.unwrap() - 125.0).abs() < 1e-9);
+        assert_eq!(curve[2], (1_000_000.0, None));
+    }
+
+    #[rstest]
+    fn test_slippage_curve_with_empty_book() {
+        let book = OrderBook::new(InstrumentId::from("AAPL.XNAS"), BookType::L2_MBP);
+
+        let curve = book.slippage_curve(&[100.0], OrderSide::Sell);
+
+        assert_eq!(curve, vec![(100.0, None)]);
+    }
+
+    #[rstest]
+    fn test_liquidity_within_bps() {
+        let book = analytics_book();
+
+        assert_eq!(book.liquidity_within_bps(100.0), Some((100.0, 300.0)));
+        assert_eq!(book.liquidity_within_bps(200.0), Some((300.0, 400.0)));
+        assert_eq!(book.liquidity_within_bps(50.0), Some((0.0, 0.0)));
+    }
+
+    #[rstest]
+    fn test_imbalances() {
+        let book = analytics_book();
+
+        assert_eq!(book.level_imbalances(None), vec![-0.5, 1.0 / 3.0, 0.5]);
+        assert_eq!(book.weighted_imbalance(Some(1), 0.5), Some(-0.5));
+        // Bids 100 + 100 + 75 = 275 against asks 300 + 50 + 25 = 375
+        assert_eq!(book.weighted_imbalance(None, 0.5), Some(-100.0 / 650.0));
+    }
+
+    #[rstest]
+    fn test_microprice() {
+        let book = analytics_book();
+
+        // (99 * 300 + 101 * 100) / 400
+        assert_eq!(book.microprice(), Some(99.5));
+    }
+
+    #[rstest]
+    fn test_microprice_with_one_sided_book() {
+        let mut book = OrderBook::new(InstrumentId::from("AAPL.XNAS"), BookType::L2_MBP);
+        let order = BookOrder::new(OrderSide::Buy, Price::from("99.00"), Quantity::from(1), 0);
+        book.add(order, 0, 1, 1.into());
+
+        assert_eq!(book.microprice(), None);
+    }
 }
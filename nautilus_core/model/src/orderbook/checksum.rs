@@ -0,0 +1,210 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Functions for computing and validating CRC32 checksums over the top levels of an order book.
+
+use std::fmt::Write;
+
+use super::{BookIntegrityError, OrderBook};
+
+/// The format of the string a venue computes an order book checksum over.
+///
+/// Prices and sizes are formatted with their own precision, which should match the precision the
+/// venue publishes them with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum BookChecksumFormat {
+    /// Bid and ask levels interleaved from the top of book, with each price and size separated
+    /// by a colon, e.g. `bid_px:bid_sz:ask_px:ask_sz:...` (as used by OKX).
+    ///
+    /// Where one side has fewer levels, the remaining levels of the other side are appended.
+    Interleaved,
+    /// Ask levels then bid levels from the top of book, with each price and size stripped of the
+    /// decimal point and leading zeros and concatenated without separators (as used by Kraken).
+    Concatenated,
+}
+
+/// Computes the CRC32 checksum of the top `depth` levels of each side of the `book`, in the
+/// given `format`.
+///
+/// Venues which publish the checksum as a signed 32-bit integer can be compared by casting the
+/// published value to `u32`.
+#[must_use]
+pub fn book_checksum(book: &OrderBook, format: BookChecksumFormat, depth: usize) -> u32 {
+    crc32(checksum_string(book, format, depth).as_bytes())
+}
+
+/// Checks the CRC32 checksum of the top `depth` levels of each side of the `book` against the
+/// `expected` value, returning an integrity error on mismatch so the book can be resynced.
+pub fn book_check_checksum(
+    book: &OrderBook,
+    format: BookChecksumFormat,
+    depth: usize,
+    expected: u32,
+) -> Result<(), BookIntegrityError> {
+    let checksum = book_checksum(book, format, depth);
+    if checksum != expected {
+        return Err(BookIntegrityError::ChecksumMismatch(expected, checksum));
+    }
+    Ok(())
+}
+
+fn checksum_string(book: &OrderBook, format: BookChecksumFormat, depth: usize) -> String {
+    let mut buf = String::new();
+
+    match format {
+        BookChecksumFormat::Interleaved => {
+            let mut bids = book.bids(Some(depth));
+            let mut asks = book.asks(Some(depth));
+            loop {
+                let (bid, ask) = (bids.next(), asks.next());
+                if bid.is_none() && ask.is_none() {
+                    break;
+                }
+                for level in [bid, ask].into_iter().flatten() {
+                    if !buf.is_empty() {
+                        buf.push(':');
+                    }
+                    write!(buf, "{}:{}", level.price.value, level.size_decimal())
+                        .expect("writing to a `String`");
+                }
+            }
+        }
+        BookChecksumFormat::Concatenated => {
+            for level in book.asks(Some(depth)).chain(book.bids(Some(depth))) {
+                write_stripped(&mut buf, &level.price.value.to_string());
+                write_stripped(&mut buf, &level.size_decimal().to_string());
+            }
+        }
+    }
+
+    buf
+}
+
+fn write_stripped(buf: &mut String, value: &str) {
+    let digits: String = value.chars().filter(|c| *c != '.').collect();
+    let stripped = digits.trim_start_matches('0');
+    buf.push_str(if stripped.is_empty() { "0" } else { stripped });
+}
+
+const CRC32_POLYNOMIAL: u32 = 0xEDB8_8320;
+
+const CRC32_TABLE: [u32; 256] = {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ CRC32_POLYNOMIAL
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+};
+
+/// Computes the standard (IEEE 802.3) CRC32 checksum of the given `bytes`, as used by zlib.
+#[must_use]
+pub fn crc32(bytes: &[u8]) -> u32 {
+    let crc = bytes.iter().fold(u32::MAX, |crc, byte| {
+        CRC32_TABLE[((crc ^ u32::from(*byte)) & 0xFF) as usize] ^ (crc >> 8)
+    });
+    !crc
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::{book_check_checksum, book_checksum, checksum_string, crc32, BookChecksumFormat};
+    use crate::{
+        data::BookOrder,
+        enums::{BookType, OrderSide},
+        identifiers::InstrumentId,
+        orderbook::{BookIntegrityError, OrderBook},
+        types::{Price, Quantity},
+    };
+
+    fn book() -> OrderBook {
+        let mut book = OrderBook::new(InstrumentId::from("ETHUSDT.OKX"), BookType::L2_MBP);
+        let orders = [
+            (OrderSide::Buy, "99.00", 100),
+            (OrderSide::Buy, "98.00", 200),
+            (OrderSide::Sell, "101.00", 300),
+        ];
+        for (i, (side, price, size)) in orders.into_iter().enumerate() {
+            let order = BookOrder::new(side, Price::from(price), Quantity::from(size), 0);
+            book.add(order, 0, i as u64, 1.into());
+        }
+        book
+    }
+
+    #[rstest]
+    #[case(b"", 0)]
+    #[case(b"123456789", 0xCBF4_3926)]
+    #[case(b"3366.1:7:3366.8:9:3366:6:3368:8", (-1_881_014_294_i32) as u32)]
+    fn test_crc32(#[case] bytes: &[u8], #[case] expected: u32) {
+        assert_eq!(crc32(bytes), expected);
+    }
+
+    #[rstest]
+    #[case(
+        BookChecksumFormat::Interleaved,
+        "99.00:100:101.00:300:98.00:200",
+        3_508_232_474
+    )]
+    #[case(BookChecksumFormat::Concatenated, "1010030099001009800200", 61_214_396)]
+    fn test_book_checksum(
+        #[case] format: BookChecksumFormat,
+        #[case] expected_string: &str,
+        #[case] expected: u32,
+    ) {
+        let book = book();
+
+        assert_eq!(checksum_string(&book, format, 10), expected_string);
+        assert_eq!(book_checksum(&book, format, 10), expected);
+    }
+
+    #[rstest]
+    fn test_book_checksum_limits_depth() {
+        let book = book();
+
+        assert_eq!(
+            checksum_string(&book, BookChecksumFormat::Interleaved, 1),
+            "99.00:100:101.00:300"
+        );
+    }
+
+    #[rstest]
+    fn test_book_check_checksum() {
+        let book = book();
+
+        assert!(
+            book_check_checksum(&book, BookChecksumFormat::Interleaved, 10, 3_508_232_474).is_ok()
+        );
+        assert_eq!(
+            book_check_checksum(&book, BookChecksumFormat::Interleaved, 10, 1),
+            Err(BookIntegrityError::ChecksumMismatch(1, 3_508_232_474))
+        );
+    }
+}
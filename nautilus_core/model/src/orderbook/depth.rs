@@ -0,0 +1,239 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Functions for deriving fixed depth snapshots from the top levels of an order book.
+
+use indexmap::IndexMap;
+use nautilus_core::UnixNanos;
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+
+use super::{book::group_price, BookLevel, OrderBook};
+use crate::{
+    data::{depth::DEPTH10_LEN, BookOrder, OrderBookDepth10, NULL_ORDER},
+    enums::{OrderSide, RecordFlag},
+    types::{fixed::FIXED_PRECISION, Price, Quantity},
+};
+
+/// Returns an [`OrderBookDepth10`] snapshot of the top levels of each side of the `book`.
+///
+/// When a `group_size` is given, levels are first grouped into price buckets consistent with
+/// [`OrderBook::group_bids`] and [`OrderBook::group_asks`], with the count for each bucket being
+/// the total number of orders it contains. Sides with fewer than ten levels are padded with
+/// [`NULL_ORDER`]s and zero counts.
+///
+/// The snapshot takes the sequence and last event timestamp of the `book`.
+#[must_use]
+pub fn book_to_depth10(
+    book: &OrderBook,
+    group_size: Option<Decimal>,
+    ts_init: UnixNanos,
+) -> OrderBookDepth10 {
+    let (bids, bid_counts) = depth_side(book.bids(None), OrderSide::Buy, group_size);
+    let (asks, ask_counts) = depth_side(book.asks(None), OrderSide::Sell, group_size);
+
+    OrderBookDepth10::new(
+        book.instrument_id,
+        bids,
+        asks,
+        bid_counts,
+        ask_counts,
+        RecordFlag::F_LAST as u8,
+        book.sequence,
+        book.ts_last,
+        ts_init,
+    )
+}
+
+fn depth_side<'a>(
+    levels: impl Iterator<Item = &'a BookLevel>,
+    side: OrderSide,
+    group_size: Option<Decimal>,
+) -> ([BookOrder; DEPTH10_LEN], [u32; DEPTH10_LEN]) {
+    let mut orders = [NULL_ORDER; DEPTH10_LEN];
+    let mut counts = [0u32; DEPTH10_LEN];
+
+    let Some(group_size) = group_size else {
+        for (i, level) in levels.take(DEPTH10_LEN).enumerate() {
+            let size = Quantity::from_raw(level.size_raw(), size_precision(level));
+            orders[i] = BookOrder::new(side, level.price.value, size, 0);
+            counts[i] = level.len() as u32;
+        }
+        return (orders, counts);
+    };
+
+    let mut groups: IndexMap<Decimal, (Decimal, u32)> = IndexMap::new();
+    let mut price_precision = (group_size.scale() as u8).min(FIXED_PRECISION);
+    let mut size_prec = 0;
+
+    for level in levels {
+        let price = group_price(
+            level.price.value.as_decimal(),
+            group_size,
+            side == OrderSide::Buy,
+        );
+        if groups.len() == DEPTH10_LEN && !groups.contains_key(&price) {
+            break;
+        }
+
+        price_precision = price_precision.max(level.price.value.precision);
+        size_prec = size_prec.max(size_precision(level));
+
+        let (size, count) = groups.entry(price).or_default();
+        *size += level.size_decimal();
+        *count += level.len() as u32;
+    }
+
+    for (i, (price, (size, count))) in groups.into_iter().enumerate() {
+        orders[i] = BookOrder::new(
+            side,
+            Price::new(price.to_f64().unwrap_or_default(), price_precision),
+            Quantity::new(size.to_f64().unwrap_or_default(), size_prec),
+            0,
+        );
+        counts[i] = count;
+    }
+
+    (orders, counts)
+}
+
+fn size_precision(level: &BookLevel) -> u8 {
+    level.first().map_or(0, |order| order.size.precision)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use rust_decimal_macros::dec;
+
+    use super::book_to_depth10;
+    use crate::{
+        data::{BookOrder, NULL_ORDER},
+        enums::{BookType, OrderSide, RecordFlag},
+        identifiers::InstrumentId,
+        orderbook::OrderBook,
+        types::{Price, Quantity},
+    };
+
+    fn l3_book() -> OrderBook {
+        let mut book = OrderBook::new(InstrumentId::from("AAPL.XNAS"), BookType::L3_MBO);
+        let orders = [
+            (OrderSide::Buy, "99.90", 100, 1),
+            (OrderSide::Buy, "99.90", 50, 2),
+            (OrderSide::Buy, "99.70", 200, 3),
+            (OrderSide::Buy, "99.40", 300, 4),
+            (OrderSide::Sell, "100.10", 100, 5),
+            (OrderSide::Sell, "100.60", 25, 6),
+        ];
+        for (i, (side, price, size, order_id)) in orders.into_iter().enumerate() {
+            let order = BookOrder::new(side, Price::from(price), Quantity::from(size), order_id);
+            book.add(order, 0, i as u64 + 1, (i as u64 + 1).into());
+        }
+        book
+    }
+
+    #[rstest]
+    fn test_depth10_from_empty_book() {
+        let book = OrderBook::new(InstrumentId::from("AAPL.XNAS"), BookType::L2_MBP);
+
+        let depth = book_to_depth10(&book, None, 1.into());
+
+        assert!(depth
+            .bids
+            .iter()
+            .chain(depth.asks.iter())
+            .all(|order| order.side == OrderSide::NoOrderSide && order.size.raw == 0));
+        assert_eq!(depth.bid_counts, [0; 10]);
+        assert_eq!(depth.ask_counts, [0; 10]);
+    }
+
+    #[rstest]
+    fn test_depth10_aggregates_l3_levels() {
+        let book = l3_book();
+
+        let depth = book_to_depth10(&book, None, 10.into());
+
+        assert_eq!(depth.instrument_id, book.instrument_id);
+        assert_eq!(depth.bids[0].price, Price::from("99.90"));
+        assert_eq!(depth.bids[0].size, Quantity::from(150));
+        assert_eq!(depth.bids[2].price, Price::from("99.40"));
+        assert_eq!(depth.bids[3].side, NULL_ORDER.side);
+        assert_eq!(depth.bid_counts[..4], [2, 1, 1, 0]);
+        assert_eq!(depth.asks[0].price, Price::from("100.10"));
+        assert_eq!(depth.ask_counts[..3], [1, 1, 0]);
+        assert_eq!(depth.flags, RecordFlag::F_LAST as u8);
+        assert_eq!(depth.sequence, 6);
+        assert_eq!(depth.ts_event, 6);
+        assert_eq!(depth.ts_init, 10);
+    }
+
+    #[rstest]
+    fn test_depth10_grouped() {
+        let book = l3_book();
+
+        let depth = book_to_depth10(&book, Some(dec!(0.5)), 10.into());
+
+        // 99.90 and 99.70 group down to 99.50, 99.40 groups down to 99.00
+        assert_eq!(depth.bids[0].price, Price::from("99.50"));
+        assert_eq!(depth.bids[0].size, Quantity::from(350));
+        assert_eq!(depth.bids[1].price, Price::from("99.00"));
+        assert_eq!(depth.bids[1].size, Quantity::from(300));
+        assert_eq!(depth.bid_counts[..3], [3, 1, 0]);
+        // 100.10 groups up to 100.50, 100.60 groups up to 101.00
+        assert_eq!(depth.asks[0].price, Price::from("100.50"));
+        assert_eq!(depth.asks[1].price, Price::from("101.00"));
+        assert_eq!(depth.ask_counts[..3], [1, 1, 0]);
+
+        let grouped_bids = book.group_bids(dec!(0.5), None);
+        let depth_bids: Vec<_> = depth
+            .bids
+            .iter()
+            .take(grouped_bids.len())
+            .map(|order| (order.price.as_decimal(), order.size.as_decimal()))
+            .collect();
+        assert_eq!(depth_bids, grouped_bids.into_iter().collect::<Vec<_>>());
+    }
+
+    #[rstest]
+    fn test_depth10_limits_to_ten_levels() {
+        let mut book = OrderBook::new(InstrumentId::from("AAPL.XNAS"), BookType::L2_MBP);
+        for i in 0..20u32 {
+            let price = Price::new(100.0 - f64::from(i), 2);
+            let order = BookOrder::new(OrderSide::Buy, price, Quantity::from(10), 0);
+            book.add(order, 0, u64::from(i), u64::from(i).into());
+        }
+
+        let depth = book_to_depth10(&book, None, 1.into());
+        let grouped = book_to_depth10(&book, Some(dec!(2)), 1.into());
+
+        assert_eq!(depth.bids[9].price, Price::from("91.00"));
+        assert_eq!(grouped.bids[9].price, Price::from("82.00"));
+        assert_eq!(grouped.bid_counts, [1, 2, 2, 2, 2, 2, 2, 2, 2, 2]);
+    }
+
+    #[rstest]
+    fn test_depth10_applied_to_book_matches_top_levels() {
+        let book = l3_book();
+        let depth = book_to_depth10(&book, None, 10.into());
+
+        let mut l2_book = OrderBook::new(book.instrument_id, BookType::L2_MBP);
+        l2_book.apply_depth(&depth);
+
+        assert_eq!(l2_book.bids_as_map(None), book.bids_as_map(None));
+        assert_eq!(l2_book.asks_as_map(None), book.asks_as_map(None));
+    }
+}
@@ -0,0 +1,294 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Functions for diffing order books into the deltas which transform one book into another.
+
+use std::collections::HashMap;
+
+use nautilus_core::UnixNanos;
+
+use super::{BookIntegrityError, BookLevel, OrderBook};
+use crate::{
+    data::{
+        depth::DEPTH10_LEN,
+        order::{BookOrder, OrderId},
+        OrderBookDelta, OrderBookDepth10,
+    },
+    enums::{BookAction, OrderSide, RecordFlag},
+};
+
+/// Returns the minimal list of deltas which transform the `from` book into the `to` book.
+///
+/// Orders are matched by order ID (the price for L2 books, and the side for L1 books), giving a
+/// delete for each order only in `from`, an update for each order with a changed price or size,
+/// and an add for each order only in `to`. Deltas take the sequence and last event timestamp of
+/// the `to` book, with the last delta flagged as `F_LAST`.
+///
+/// Both books are expected to have the same book type.
+#[must_use]
+pub fn book_diff(from: &OrderBook, to: &OrderBook, ts_init: UnixNanos) -> Vec<OrderBookDelta> {
+    let mut changes = diff_levels(from.bids(None), to.bids(None));
+    changes.extend(diff_levels(from.asks(None), to.asks(None)));
+    to_deltas(from, changes, to.sequence, to.ts_last, ts_init)
+}
+
+/// Returns the minimal list of deltas which transform the `book` into the state of the given
+/// `depth` snapshot.
+///
+/// Consistent with [`OrderBook::apply_depth`], levels in the `book` beyond the snapshot are
+/// deleted. Deltas take the sequence and timestamps of the `depth`, with the last delta flagged
+/// as `F_LAST`.
+#[must_use]
+pub fn book_diff_depth(book: &OrderBook, depth: &OrderBookDepth10) -> Vec<OrderBookDelta> {
+    let snapshot = depth_to_book(book, depth);
+    let mut changes = diff_levels(book.bids(None), snapshot.bids(None));
+    changes.extend(diff_levels(book.asks(None), snapshot.asks(None)));
+    to_deltas(book, changes, depth.sequence, depth.ts_event, depth.ts_init)
+}
+
+/// Checks the top levels of the `book` against the given `depth` snapshot, returning an
+/// integrity error with the number of differing orders on mismatch so the book can be resynced.
+pub fn book_check_depth(
+    book: &OrderBook,
+    depth: &OrderBookDepth10,
+) -> Result<(), BookIntegrityError> {
+    let snapshot = depth_to_book(book, depth);
+    let mut changes = diff_levels(book.bids(Some(DEPTH10_LEN)), snapshot.bids(None));
+    changes.extend(diff_levels(
+        book.asks(Some(DEPTH10_LEN)),
+        snapshot.asks(None),
+    ));
+
+    if !changes.is_empty() {
+        return Err(BookIntegrityError::SnapshotMismatch(changes.len()));
+    }
+    Ok(())
+}
+
+fn depth_to_book(book: &OrderBook, depth: &OrderBookDepth10) -> OrderBook {
+    let mut snapshot = OrderBook::new(book.instrument_id, book.book_type);
+    for order in depth.bids.iter().chain(depth.asks.iter()) {
+        // Skip any padding for empty levels
+        if order.side == OrderSide::NoOrderSide || !order.size.is_positive() {
+            continue;
+        }
+        snapshot.add(*order, depth.flags, depth.sequence, depth.ts_event);
+    }
+    snapshot
+}
+
+fn diff_levels<'a>(
+    from: impl Iterator<Item = &'a BookLevel>,
+    to: impl Iterator<Item = &'a BookLevel>,
+) -> Vec<(BookAction, BookOrder)> {
+    let from_orders: Vec<&BookOrder> = from.flat_map(|level| level.orders.values()).collect();
+    let to_orders: Vec<&BookOrder> = to.flat_map(|level| level.orders.values()).collect();
+
+    let from_by_id: HashMap<OrderId, &BookOrder> = from_orders
+        .iter()
+        .map(|order| (order.order_id, *order))
+        .collect();
+    let to_by_id: HashMap<OrderId, &BookOrder> = to_orders
+        .iter()
+        .map(|order| (order.order_id, *order))
+        .collect();
+
+    let mut changes: Vec<(BookAction, BookOrder)> = from_orders
+        .iter()
+        .filter(|order| !to_by_id.contains_key(&order.order_id))
+        .map(|order| (BookAction::Delete, **order))
+        .collect();
+
+    for order in to_orders {
+        match from_by_id.get(&order.order_id) {
+            None => changes.push((BookAction::Add, *order)),
+            Some(prev) if prev.price != order.price || prev.size != order.size => {
+                changes.push((BookAction::Update, *order));
+            }
+            Some(_) => {}
+        }
+    }
+
+    changes
+}
+
+fn to_deltas(
+    book: &OrderBook,
+    changes: Vec<(BookAction, BookOrder)>,
+    sequence: u64,
+    ts_event: UnixNanos,
+    ts_init: UnixNanos,
+) -> Vec<OrderBookDelta> {
+    let last_index = changes.len().saturating_sub(1);
+    changes
+        .into_iter()
+        .enumerate()
+        .map(|(i, (action, order))| {
+            let flags = if i == last_index {
+                RecordFlag::F_LAST as u8
+            } else {
+                0
+            };
+            OrderBookDelta::new(
+                book.instrument_id,
+                action,
+                order,
+                flags,
+                sequence,
+                ts_event,
+                ts_init,
+            )
+        })
+        .collect()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::{book_check_depth, book_diff, book_diff_depth};
+    use crate::{
+        data::{depth::OrderBookDepth10, order::BookOrder, stubs::stub_depth10, OrderBookDeltas},
+        enums::{BookAction, BookType, OrderSide, RecordFlag},
+        identifiers::InstrumentId,
+        orderbook::{BookIntegrityError, OrderBook},
+        types::{Price, Quantity},
+    };
+
+    fn book_from(book_type: BookType, orders: &[(OrderSide, &str, u64, u64)]) -> OrderBook {
+        let mut book = OrderBook::new(InstrumentId::from("AAPL.XNAS"), book_type);
+        for (i, (side, price, size, order_id)) in orders.iter().enumerate() {
+            let order =
+                BookOrder::new(*side, Price::from(*price), Quantity::from(*size), *order_id);
+            book.add(order, 0, i as u64, (i as u64).into());
+        }
+        book
+    }
+
+    #[rstest]
+    fn test_diff_identical_books_is_empty() {
+        let orders = [
+            (OrderSide::Buy, "99.00", 100, 0),
+            (OrderSide::Sell, "101.00", 100, 0),
+        ];
+        let from = book_from(BookType::L2_MBP, &orders);
+        let to = book_from(BookType::L2_MBP, &orders);
+
+        assert!(book_diff(&from, &to, 1.into()).is_empty());
+    }
+
+    #[rstest]
+    fn test_diff_l2_books() {
+        let from = book_from(
+            BookType::L2_MBP,
+            &[
+                (OrderSide::Buy, "99.00", 100, 0),
+                (OrderSide::Buy, "98.00", 200, 0),
+                (OrderSide::Sell, "101.00", 100, 0),
+            ],
+        );
+        let to = book_from(
+            BookType::L2_MBP,
+            &[
+                (OrderSide::Buy, "99.00", 150, 0),
+                (OrderSide::Sell, "101.00", 100, 0),
+                (OrderSide::Sell, "102.00", 300, 0),
+            ],
+        );
+
+        let deltas = book_diff(&from, &to, 5.into());
+
+        let actions: Vec<(BookAction, Price, Quantity)> = deltas
+            .iter()
+            .map(|delta| (delta.action, delta.order.price, delta.order.size))
+            .collect();
+        assert_eq!(
+            actions,
+            vec![
+                (
+                    BookAction::Delete,
+                    Price::from("98.00"),
+                    Quantity::from(200)
+                ),
+                (
+                    BookAction::Update,
+                    Price::from("99.00"),
+                    Quantity::from(150)
+                ),
+                (BookAction::Add, Price::from("102.00"), Quantity::from(300)),
+            ]
+        );
+        assert_eq!(deltas[0].flags, 0);
+        assert_eq!(deltas[2].flags, RecordFlag::F_LAST as u8);
+        assert!(deltas.iter().all(|delta| delta.sequence == to.sequence));
+        assert!(deltas.iter().all(|delta| delta.ts_init == 5));
+    }
+
+    #[rstest]
+    fn test_diff_applied_to_book_reproduces_target() {
+        let mut from = book_from(
+            BookType::L3_MBO,
+            &[
+                (OrderSide::Buy, "99.00", 100, 1),
+                (OrderSide::Buy, "99.00", 50, 2),
+                (OrderSide::Sell, "101.00", 100, 3),
+            ],
+        );
+        let to = book_from(
+            BookType::L3_MBO,
+            &[
+                (OrderSide::Buy, "98.00", 100, 1),
+                (OrderSide::Sell, "101.00", 100, 3),
+                (OrderSide::Sell, "101.00", 25, 4),
+            ],
+        );
+
+        let deltas = book_diff(&from, &to, 5.into());
+        from.apply_deltas(&OrderBookDeltas::new(from.instrument_id, deltas));
+
+        assert!(book_diff(&from, &to, 5.into()).is_empty());
+        assert_eq!(from.bids_as_map(None), to.bids_as_map(None));
+        assert_eq!(from.asks_as_map(None), to.asks_as_map(None));
+    }
+
+    #[rstest]
+    fn test_diff_depth(stub_depth10: OrderBookDepth10) {
+        let mut book = OrderBook::new(InstrumentId::from("AAPL.XNAS"), BookType::L2_MBP);
+        assert_eq!(book_diff_depth(&book, &stub_depth10).len(), 20);
+        assert_eq!(
+            book_check_depth(&book, &stub_depth10),
+            Err(BookIntegrityError::SnapshotMismatch(20))
+        );
+
+        book.apply_depth(&stub_depth10);
+
+        assert!(book_diff_depth(&book, &stub_depth10).is_empty());
+        assert!(book_check_depth(&book, &stub_depth10).is_ok());
+    }
+
+    #[rstest]
+    fn test_check_depth_ignores_levels_beyond_snapshot(stub_depth10: OrderBookDepth10) {
+        let mut book = OrderBook::new(InstrumentId::from("AAPL.XNAS"), BookType::L2_MBP);
+        book.apply_depth(&stub_depth10);
+        let deep_bid = BookOrder::new(OrderSide::Buy, Price::from("1.00"), Quantity::from(1), 0);
+        book.add(deep_bid, 0, 1, 1.into());
+
+        assert!(book_check_depth(&book, &stub_depth10).is_ok());
+        assert_eq!(book_diff_depth(&book, &stub_depth10).len(), 1);
+    }
+}
@@ -17,7 +17,7 @@
 
 use tabled::{settings::Style, Table, Tabled};
 
-use super::{BookLevel, BookPrice};
+use super::BookLevel;
 use crate::orderbook::ladder::BookLadder;
 
 #[derive(Tabled)]
@@ -30,16 +30,16 @@ struct BookLevelDisplay {
 /// Return a [`String`] representation of the order book in a human-readable table format.
 #[must_use]
 pub(crate) fn pprint_book(bids: &BookLadder, asks: &BookLadder, num_levels: usize) -> String {
-    let ask_levels: Vec<(&BookPrice, &BookLevel)> =
-        asks.levels.iter().take(num_levels).rev().collect();
-    let bid_levels: Vec<(&BookPrice, &BookLevel)> = bids.levels.iter().take(num_levels).collect();
-    let levels: Vec<(&BookPrice, &BookLevel)> = ask_levels.into_iter().chain(bid_levels).collect();
+    let mut ask_levels: Vec<&BookLevel> = asks.levels.values().take(num_levels).collect();
+    ask_levels.reverse();
+    let bid_levels: Vec<&BookLevel> = bids.levels.values().take(num_levels).collect();
+    let levels: Vec<&BookLevel> = ask_levels.into_iter().chain(bid_levels).collect();
 
     let data: Vec<BookLevelDisplay> = levels
         .iter()
-        .map(|(book_price, level)| {
-            let is_bid_level = bids.levels.contains_key(book_price);
-            let is_ask_level = asks.levels.contains_key(book_price);
+        .map(|level| {
+            let is_bid_level = bids.levels.contains_key(&level.price);
+            let is_ask_level = asks.levels.contains_key(&level.price);
 
             let bid_sizes: Vec<String> = level
                 .orders
@@ -42,4 +42,8 @@ pub enum BookIntegrityError {
     TooManyOrders(OrderSide, usize),
     #[error("Integrity error: number of {0} levels > 1 for L1_MBP book, was {1}")]
     TooManyLevels(OrderSide, usize),
+    #[error("Integrity error: checksum mismatch, expected {0} but computed {1}")]
+    ChecksumMismatch(u32, u32),
+    #[error("Integrity error: book differs from snapshot by {0} deltas")]
+    SnapshotMismatch(usize),
 }
@@ -20,7 +20,7 @@
 
 use std::{
     cmp::Ordering,
-    collections::{BTreeMap, HashMap},
+    collections::{btree_map, BTreeMap, HashMap},
     fmt::{Display, Formatter},
 };
 
@@ -29,7 +29,10 @@ use nautilus_core::UnixNanos;
 use crate::{
     data::order::{BookOrder, OrderId},
     enums::{OrderSide, OrderSideSpecified},
-    orderbook::BookLevel,
+    orderbook::{
+        ring::{RingLevels, RingLevelsIter},
+        BookLevel,
+    },
     types::{Price, Quantity},
 };
 
@@ -79,21 +82,177 @@ impl Display for BookPrice {
     }
 }
 
+/// Represents the price level storage for one side of an order book.
+#[derive(Clone, Debug)]
+pub(crate) enum LadderLevels {
+    /// Levels in a sorted tree, supporting arbitrary prices.
+    Tree(BTreeMap<BookPrice, BookLevel>),
+    /// Levels on a ring buffer indexed by tick, for instruments with a fixed tick size.
+    Ring(RingLevels),
+}
+
+impl LadderLevels {
+    /// Returns the number of price levels.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        match self {
+            Self::Tree(levels) => levels.len(),
+            Self::Ring(levels) => levels.len(),
+        }
+    }
+
+    /// Returns true if there are no price levels.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        match self {
+            Self::Tree(levels) => levels.is_empty(),
+            Self::Ring(levels) => levels.is_empty(),
+        }
+    }
+
+    /// Removes all price levels.
+    pub fn clear(&mut self) {
+        match self {
+            Self::Tree(levels) => levels.clear(),
+            Self::Ring(levels) => levels.clear(),
+        }
+    }
+
+    /// Returns the best price level, if any.
+    #[must_use]
+    pub fn first(&self) -> Option<&BookLevel> {
+        match self {
+            Self::Tree(levels) => levels.values().next(),
+            Self::Ring(levels) => levels.first(),
+        }
+    }
+
+    /// Returns true if there is a level at the given `price`.
+    #[must_use]
+    pub fn contains_key(&self, price: &BookPrice) -> bool {
+        match self {
+            Self::Tree(levels) => levels.contains_key(price),
+            Self::Ring(levels) => levels.contains_key(price),
+        }
+    }
+
+    /// Returns a mutable reference to the price level at the given `price`, if any.
+    pub fn get_mut(&mut self, price: &BookPrice) -> Option<&mut BookLevel> {
+        match self {
+            Self::Tree(levels) => levels.get_mut(price),
+            Self::Ring(levels) => levels.get_mut(price),
+        }
+    }
+
+    /// Inserts the `level` at the given `price`, replacing any existing level.
+    ///
+    /// Ring buffer levels fall back to tree levels if the `price` cannot be held on the ring
+    /// (an off-tick price, or a span between the best and worst levels beyond the maximum
+    /// ring capacity). The fallback is permanent for the ladder, so a warning is logged.
+    pub fn insert(&mut self, price: BookPrice, level: BookLevel) {
+        if let Self::Ring(levels) = self {
+            if let Err(e) = levels.check_insert(&price) {
+                log::warn!(
+                    "Falling back to tree price levels for {} levels from {:?} to {:?}: {e}",
+                    levels.len(),
+                    levels.first().map(|level| level.price.value),
+                    levels.values().last().map(|level| level.price.value),
+                );
+                let levels = levels
+                    .drain()
+                    .into_iter()
+                    .map(|level| (level.price, level))
+                    .collect();
+                *self = Self::Tree(levels);
+            }
+        }
+
+        match self {
+            Self::Tree(levels) => {
+                levels.insert(price, level);
+            }
+            Self::Ring(levels) => {
+                // Checked above, so the insert cannot fail
+                let _ = levels.insert(price, level);
+            }
+        }
+    }
+
+    /// Removes and returns the price level at the given `price`, if any.
+    pub fn remove(&mut self, price: &BookPrice) -> Option<BookLevel> {
+        match self {
+            Self::Tree(levels) => levels.remove(price),
+            Self::Ring(levels) => levels.remove(price),
+        }
+    }
+
+    /// Returns an iterator over the price levels from best to worst.
+    #[must_use]
+    pub fn values(&self) -> LadderLevelsIter<'_> {
+        match self {
+            Self::Tree(levels) => LadderLevelsIter::Tree(levels.values()),
+            Self::Ring(levels) => LadderLevelsIter::Ring(levels.values()),
+        }
+    }
+}
+
+/// An iterator over the price levels of a [`LadderLevels`] from best to worst.
+#[derive(Debug)]
+pub(crate) enum LadderLevelsIter<'a> {
+    Tree(btree_map::Values<'a, BookPrice, BookLevel>),
+    Ring(RingLevelsIter<'a>),
+}
+
+impl<'a> Iterator for LadderLevelsIter<'a> {
+    type Item = &'a BookLevel;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Self::Tree(iter) => iter.next(),
+            Self::Ring(iter) => iter.next(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            Self::Tree(iter) => iter.size_hint(),
+            Self::Ring(iter) => iter.size_hint(),
+        }
+    }
+}
+
 /// Represents a ladder of price levels for one side of an order book.
 #[derive(Clone, Debug)]
 pub(crate) struct BookLadder {
     pub side: OrderSide,
-    pub levels: BTreeMap<BookPrice, BookLevel>,
+    pub levels: LadderLevels,
     pub cache: HashMap<u64, BookPrice>,
 }
 
 impl BookLadder {
-    /// Creates a new [`Ladder`] instance.
+    /// Creates a new [`BookLadder`] instance with tree-based level storage.
     #[must_use]
     pub fn new(side: OrderSide) -> Self {
         Self {
             side,
-            levels: BTreeMap::new(),
+            levels: LadderLevels::Tree(BTreeMap::new()),
+            cache: HashMap::new(),
+        }
+    }
+
+    /// Creates a new [`BookLadder`] instance with ring buffer level storage for a fixed
+    /// `price_increment`, starting with `capacity` price level slots.
+    ///
+    /// # Panics
+    ///
+    /// This function panics:
+    /// - If `price_increment` is not positive.
+    #[must_use]
+    pub fn new_fixed_tick(side: OrderSide, price_increment: Price, capacity: usize) -> Self {
+        Self {
+            side,
+            levels: LadderLevels::Ring(RingLevels::new(side, price_increment, capacity)),
             cache: HashMap::new(),
         }
     }
@@ -200,10 +359,7 @@ impl BookLadder {
     /// Returns the best price level in the ladder.
     #[must_use]
     pub fn top(&self) -> Option<&BookLevel> {
-        match self.levels.iter().next() {
-            Some((_, l)) => Option::Some(l),
-            None => Option::None,
-        }
+        self.levels.first()
     }
 
     /// Simulates fills for an order against this ladder's liquidity.
@@ -253,7 +409,7 @@ mod tests {
     use crate::{
         data::order::BookOrder,
         enums::OrderSide,
-        orderbook::ladder::{BookLadder, BookPrice},
+        orderbook::ladder::{BookLadder, BookPrice, LadderLevels},
         types::{Price, Quantity},
     };
 
@@ -719,4 +875,56 @@ mod tests {
         assert_eq!(ladder_buy.top().unwrap().price.value, min_price);
         assert_eq!(ladder_sell.top().unwrap().price.value, max_price);
     }
+
+    #[rstest]
+    fn test_fixed_tick_ladder_update_and_simulate_fills() {
+        let mut ladder = BookLadder::new_fixed_tick(OrderSide::Sell, Price::from("0.01"), 4);
+        let order1 = BookOrder::new(OrderSide::Sell, Price::from("10.00"), Quantity::from(20), 0);
+        let order2 = BookOrder::new(OrderSide::Sell, Price::from("10.01"), Quantity::from(30), 1);
+        let order3 = BookOrder::new(OrderSide::Sell, Price::from("10.08"), Quantity::from(50), 2);
+        ladder.add_bulk(vec![order1, order2, order3]);
+
+        // Moving the best order to a worse price shifts the top of the ladder
+        let moved = BookOrder::new(OrderSide::Sell, Price::from("10.02"), Quantity::from(20), 0);
+        ladder.update(moved);
+
+        assert_eq!(ladder.len(), 3);
+        assert_eq!(ladder.top().unwrap().price.value, Price::from("10.01"));
+
+        let order = BookOrder::new(OrderSide::Buy, Price::from("10.05"), Quantity::from(60), 3);
+        let fills = ladder.simulate_fills(&order);
+
+        assert_eq!(
+            fills,
+            vec![
+                (Price::from("10.01"), Quantity::from(30)),
+                (Price::from("10.02"), Quantity::from(20)),
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_fixed_tick_ladder_falls_back_to_tree_for_off_tick_price() {
+        let mut ladder = BookLadder::new_fixed_tick(OrderSide::Buy, Price::from("0.05"), 4);
+        let order1 = BookOrder::new(OrderSide::Buy, Price::from("10.00"), Quantity::from(20), 0);
+        let order2 = BookOrder::new(OrderSide::Buy, Price::from("10.02"), Quantity::from(30), 1);
+        let order3 = BookOrder::new(OrderSide::Buy, Price::from("9.95"), Quantity::from(40), 2);
+        ladder.add_bulk(vec![order1, order2, order3]);
+
+        assert!(matches!(ladder.levels, LadderLevels::Tree(_)));
+        assert_eq!(ladder.len(), 3);
+        assert_eq!(ladder.top().unwrap().price.value, Price::from("10.02"));
+        assert_eq!(
+            ladder
+                .levels
+                .values()
+                .map(|level| level.price.value)
+                .collect::<Vec<_>>(),
+            vec![
+                Price::from("10.02"),
+                Price::from("10.00"),
+                Price::from("9.95")
+            ]
+        );
+    }
 }
@@ -18,15 +18,24 @@
 pub mod aggregation;
 pub mod analysis;
 pub mod book;
+pub mod checksum;
+pub mod depth;
+pub mod diff;
 pub mod display;
 pub mod error;
 pub mod ladder;
 pub mod level;
+pub mod own;
+pub mod resilience;
+pub mod ring;
 
 // Re-exports
 pub use crate::orderbook::{
     book::OrderBook,
+    checksum::BookChecksumFormat,
     error::{BookIntegrityError, InvalidBookOperation},
     ladder::BookPrice,
     level::BookLevel,
+    own::{OwnBookOrder, OwnOrderBook},
+    resilience::BookResilience,
 };
@@ -0,0 +1,521 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! An own order book which tracks our working orders by price level.
+//!
+//! The own book is an overlay for a market [`OrderBook`](super::OrderBook), allowing the
+//! market liquidity to be queried net of our own resting orders.
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::{Display, Formatter},
+};
+
+use indexmap::IndexMap;
+use nautilus_core::UnixNanos;
+use rust_decimal::Decimal;
+
+use crate::{
+    enums::{OrderSide, OrderSideSpecified},
+    identifiers::{ClientOrderId, InstrumentId, StrategyId},
+    orderbook::BookPrice,
+    orders::OrderAny,
+    types::{Price, Quantity},
+};
+
+/// Represents one of our own working orders resting in an order book.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OwnBookOrder {
+    /// The client order ID.
+    pub client_order_id: ClientOrderId,
+    /// The strategy ID which owns the order.
+    pub strategy_id: StrategyId,
+    /// The order side.
+    pub side: OrderSideSpecified,
+    /// The order price.
+    pub price: Price,
+    /// The order size (leaves quantity).
+    pub size: Quantity,
+    /// UNIX timestamp (nanoseconds) when the order was last updated.
+    pub ts_last: UnixNanos,
+}
+
+impl OwnBookOrder {
+    /// Creates a new [`OwnBookOrder`] instance.
+    #[must_use]
+    pub fn new(
+        client_order_id: ClientOrderId,
+        strategy_id: StrategyId,
+        side: OrderSideSpecified,
+        price: Price,
+        size: Quantity,
+        ts_last: UnixNanos,
+    ) -> Self {
+        Self {
+            client_order_id,
+            strategy_id,
+            side,
+            price,
+            size,
+            ts_last,
+        }
+    }
+
+    /// Returns a [`BookPrice`] from this order.
+    #[must_use]
+    pub fn to_book_price(&self) -> BookPrice {
+        BookPrice::new(self.price, self.side.as_order_side())
+    }
+
+    /// Returns an [`OwnBookOrder`] for the given `order`, or `None` if the order is not
+    /// currently resting in the book (e.g. it is closed, or is an untriggered stop).
+    #[must_use]
+    pub fn from_order(order: &OrderAny) -> Option<Self> {
+        let is_resting = match order {
+            OrderAny::Limit(_) | OrderAny::MarketToLimit(_) => true,
+            OrderAny::StopLimit(order) => order.is_triggered,
+            OrderAny::LimitIfTouched(order) => order.is_triggered,
+            OrderAny::TrailingStopLimit(order) => order.is_triggered,
+            _ => false,
+        };
+
+        if !is_resting || !order.is_open() {
+            return None;
+        }
+
+        let price = order.price()?;
+        let size = order.leaves_qty();
+        if !size.is_positive() {
+            return None;
+        }
+
+        Some(Self::new(
+            order.client_order_id(),
+            order.strategy_id(),
+            order.order_side_specified(),
+            price,
+            size,
+            order.ts_last(),
+        ))
+    }
+}
+
+impl Display for OwnBookOrder {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}(client_order_id={}, strategy_id={}, side={}, price={}, size={})",
+            stringify!(OwnBookOrder),
+            self.client_order_id,
+            self.strategy_id,
+            self.side,
+            self.price,
+            self.size,
+        )
+    }
+}
+
+/// Represents a discrete price level of our own orders, in FIFO insertion order.
+#[derive(Clone, Debug)]
+pub struct OwnBookLevel {
+    pub price: BookPrice,
+    pub orders: IndexMap<ClientOrderId, OwnBookOrder>,
+}
+
+impl OwnBookLevel {
+    /// Creates a new [`OwnBookLevel`] from an order, using the order's price and side.
+    #[must_use]
+    pub fn from_order(order: OwnBookOrder) -> Self {
+        let mut orders = IndexMap::new();
+        orders.insert(order.client_order_id, order);
+        Self {
+            price: order.to_book_price(),
+            orders,
+        }
+    }
+
+    /// Returns the number of orders at this price level.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.orders.len()
+    }
+
+    /// Returns true if this price level has no orders.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.orders.is_empty()
+    }
+
+    /// Returns the total size of all orders at this price level as a decimal.
+    #[must_use]
+    pub fn size_decimal(&self) -> Decimal {
+        self.orders.values().map(|o| o.size.as_decimal()).sum()
+    }
+}
+
+/// Represents a ladder of our own price levels for one side of an order book.
+#[derive(Clone, Debug)]
+pub(crate) struct OwnBookLadder {
+    pub side: OrderSide,
+    pub levels: BTreeMap<BookPrice, OwnBookLevel>,
+    pub cache: HashMap<ClientOrderId, BookPrice>,
+}
+
+impl OwnBookLadder {
+    /// Creates a new [`OwnBookLadder`] instance.
+    #[must_use]
+    pub fn new(side: OrderSide) -> Self {
+        Self {
+            side,
+            levels: BTreeMap::new(),
+            cache: HashMap::new(),
+        }
+    }
+
+    /// Removes all orders and price levels from the ladder.
+    pub fn clear(&mut self) {
+        self.levels.clear();
+        self.cache.clear();
+    }
+
+    /// Adds or updates an order in the ladder, moving it to a new price level if needed.
+    ///
+    /// An order which stays at the same price keeps its queue position within the level.
+    pub fn update(&mut self, order: OwnBookOrder) {
+        let book_price = order.to_book_price();
+
+        if let Some(current) = self.cache.get(&order.client_order_id).copied() {
+            if current.value == book_price.value {
+                if let Some(level) = self.levels.get_mut(&current) {
+                    level.orders.insert(order.client_order_id, order);
+                    return;
+                }
+            }
+            self.remove(&order.client_order_id);
+        }
+
+        self.cache.insert(order.client_order_id, book_price);
+        match self.levels.get_mut(&book_price) {
+            Some(level) => {
+                level.orders.insert(order.client_order_id, order);
+            }
+            None => {
+                self.levels
+                    .insert(book_price, OwnBookLevel::from_order(order));
+            }
+        }
+    }
+
+    /// Removes an order by its client order ID from the ladder.
+    pub fn remove(&mut self, client_order_id: &ClientOrderId) -> Option<OwnBookOrder> {
+        let price = self.cache.remove(client_order_id)?;
+        let level = self.levels.get_mut(&price)?;
+        let order = level.orders.shift_remove(client_order_id);
+        if level.is_empty() {
+            self.levels.remove(&price);
+        }
+        order
+    }
+
+    /// Returns the order for the given `client_order_id` if it is in the ladder.
+    #[must_use]
+    pub fn get(&self, client_order_id: &ClientOrderId) -> Option<&OwnBookOrder> {
+        let price = self.cache.get(client_order_id)?;
+        self.levels.get(price)?.orders.get(client_order_id)
+    }
+
+    /// Returns the total size of our orders at the given `price`.
+    #[must_use]
+    pub fn size_at(&self, price: Price) -> Decimal {
+        self.levels
+            .get(&BookPrice::new(price, self.side))
+            .map_or(Decimal::ZERO, OwnBookLevel::size_decimal)
+    }
+}
+
+/// Provides an order book of our own working orders for a single instrument.
+///
+/// The book is kept in sync by passing each order to [`OwnOrderBook::update_from_order`]
+/// after an order event has been applied, which will add, move, resize or remove the order
+/// according to its current state.
+#[derive(Clone, Debug)]
+pub struct OwnOrderBook {
+    /// The instrument ID for the order book.
+    pub instrument_id: InstrumentId,
+    /// The timestamp of the last order update applied to the order book.
+    pub ts_last: UnixNanos,
+    /// The current count of updates applied to the order book.
+    pub count: u64,
+    pub(crate) bids: OwnBookLadder,
+    pub(crate) asks: OwnBookLadder,
+}
+
+impl Display for OwnOrderBook {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}(instrument_id={}, orders={})",
+            stringify!(OwnOrderBook),
+            self.instrument_id,
+            self.bids.cache.len() + self.asks.cache.len(),
+        )
+    }
+}
+
+impl OwnOrderBook {
+    /// Creates a new [`OwnOrderBook`] instance.
+    #[must_use]
+    pub fn new(instrument_id: InstrumentId) -> Self {
+        Self {
+            instrument_id,
+            ts_last: UnixNanos::default(),
+            count: 0,
+            bids: OwnBookLadder::new(OrderSide::Buy),
+            asks: OwnBookLadder::new(OrderSide::Sell),
+        }
+    }
+
+    /// Resets the order book to its initial empty state.
+    pub fn reset(&mut self) {
+        self.bids.clear();
+        self.asks.clear();
+        self.ts_last = UnixNanos::default();
+        self.count = 0;
+    }
+
+    /// Adds or updates the given own order in the book.
+    pub fn update(&mut self, order: OwnBookOrder) {
+        // An order cannot change side, but remove from the other side defensively
+        match order.side {
+            OrderSideSpecified::Buy => {
+                self.asks.remove(&order.client_order_id);
+                self.bids.update(order);
+            }
+            OrderSideSpecified::Sell => {
+                self.bids.remove(&order.client_order_id);
+                self.asks.update(order);
+            }
+        }
+
+        self.increment(order.ts_last);
+    }
+
+    /// Removes the order with the given `client_order_id` from the book.
+    pub fn delete(&mut self, client_order_id: &ClientOrderId, ts_event: UnixNanos) {
+        if self.bids.remove(client_order_id).is_some()
+            || self.asks.remove(client_order_id).is_some()
+        {
+            self.increment(ts_event);
+        }
+    }
+
+    /// Synchronizes the book with the current state of the given `order`.
+    ///
+    /// Resting orders are added or updated at their price with their leaves quantity, all
+    /// other orders (closed, untriggered, or without a price) are removed.
+    pub fn update_from_order(&mut self, order: &OrderAny) {
+        match OwnBookOrder::from_order(order) {
+            Some(own_order) => self.update(own_order),
+            None => self.delete(&order.client_order_id(), order.ts_last()),
+        }
+    }
+
+    /// Returns the own order for the given `client_order_id` if it is resting in the book.
+    #[must_use]
+    pub fn get_order(&self, client_order_id: &ClientOrderId) -> Option<&OwnBookOrder> {
+        self.bids
+            .get(client_order_id)
+            .or_else(|| self.asks.get(client_order_id))
+    }
+
+    /// Returns all own orders resting in the book for the given `strategy_id`.
+    #[must_use]
+    pub fn orders_for_strategy(&self, strategy_id: &StrategyId) -> Vec<OwnBookOrder> {
+        self.bids(None)
+            .chain(self.asks(None))
+            .flat_map(|level| level.orders.values())
+            .filter(|order| order.strategy_id == *strategy_id)
+            .copied()
+            .collect()
+    }
+
+    /// Returns an iterator over own bid price levels.
+    pub fn bids(&self, depth: Option<usize>) -> impl Iterator<Item = &OwnBookLevel> {
+        self.bids.levels.values().take(depth.unwrap_or(usize::MAX))
+    }
+
+    /// Returns an iterator over own ask price levels.
+    pub fn asks(&self, depth: Option<usize>) -> impl Iterator<Item = &OwnBookLevel> {
+        self.asks.levels.values().take(depth.unwrap_or(usize::MAX))
+    }
+
+    /// Returns own bid price levels as a map of price to size.
+    pub fn bids_as_map(&self, depth: Option<usize>) -> IndexMap<Decimal, Decimal> {
+        self.bids(depth)
+            .map(|level| (level.price.value.as_decimal(), level.size_decimal()))
+            .collect()
+    }
+
+    /// Returns own ask price levels as a map of price to size.
+    pub fn asks_as_map(&self, depth: Option<usize>) -> IndexMap<Decimal, Decimal> {
+        self.asks(depth)
+            .map(|level| (level.price.value.as_decimal(), level.size_decimal()))
+            .collect()
+    }
+
+    /// Returns the total size of own orders at the given `price` on the given `side`.
+    #[must_use]
+    pub fn size_at(&self, side: OrderSide, price: Price) -> Decimal {
+        match side.as_specified() {
+            OrderSideSpecified::Buy => self.bids.size_at(price),
+            OrderSideSpecified::Sell => self.asks.size_at(price),
+        }
+    }
+
+    fn increment(&mut self, ts_event: UnixNanos) {
+        self.ts_last = ts_event;
+        self.count += 1;
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use indexmap::IndexMap;
+    use nautilus_core::UnixNanos;
+    use rstest::{fixture, rstest};
+    use rust_decimal::Decimal;
+    use rust_decimal_macros::dec;
+
+    use super::{OwnBookOrder, OwnOrderBook};
+    use crate::{
+        enums::{OrderSide, OrderSideSpecified, OrderType},
+        identifiers::{ClientOrderId, InstrumentId, StrategyId},
+        orders::builder::OrderTestBuilder,
+        types::{Price, Quantity},
+    };
+
+    #[fixture]
+    fn own_book() -> OwnOrderBook {
+        OwnOrderBook::new(InstrumentId::from("AUD/USD.SIM"))
+    }
+
+    fn own_order(id: &str, side: OrderSideSpecified, price: &str, size: i64) -> OwnBookOrder {
+        OwnBookOrder::new(
+            ClientOrderId::from(id),
+            StrategyId::from("S-001"),
+            side,
+            Price::from(price),
+            Quantity::from(size),
+            UnixNanos::default(),
+        )
+    }
+
+    #[rstest]
+    fn test_update_adds_orders_by_price_level(mut own_book: OwnOrderBook) {
+        own_book.update(own_order("O-1", OrderSideSpecified::Buy, "1.00000", 100));
+        own_book.update(own_order("O-2", OrderSideSpecified::Buy, "1.00000", 50));
+        own_book.update(own_order("O-3", OrderSideSpecified::Buy, "0.99990", 10));
+        own_book.update(own_order("O-4", OrderSideSpecified::Sell, "1.00010", 20));
+
+        let expected_bids: IndexMap<Decimal, Decimal> =
+            IndexMap::from([(dec!(1.00000), dec!(150)), (dec!(0.99990), dec!(10))]);
+        let expected_asks: IndexMap<Decimal, Decimal> = IndexMap::from([(dec!(1.00010), dec!(20))]);
+        assert_eq!(own_book.count, 4);
+        assert_eq!(own_book.bids_as_map(None), expected_bids);
+        assert_eq!(own_book.asks_as_map(None), expected_asks);
+        assert_eq!(
+            own_book.size_at(OrderSide::Buy, Price::from("1.00000")),
+            dec!(150)
+        );
+        assert_eq!(
+            own_book.size_at(OrderSide::Sell, Price::from("1.00000")),
+            dec!(0)
+        );
+    }
+
+    #[rstest]
+    fn test_update_moves_order_to_new_price(mut own_book: OwnOrderBook) {
+        own_book.update(own_order("O-1", OrderSideSpecified::Buy, "1.00000", 100));
+        own_book.update(own_order("O-1", OrderSideSpecified::Buy, "0.99990", 80));
+
+        assert_eq!(own_book.bids(None).count(), 1);
+        let order = own_book.get_order(&ClientOrderId::from("O-1")).unwrap();
+        assert_eq!(order.price, Price::from("0.99990"));
+        assert_eq!(order.size, Quantity::from(80));
+    }
+
+    #[rstest]
+    fn test_update_in_place_keeps_queue_position(mut own_book: OwnOrderBook) {
+        own_book.update(own_order("O-1", OrderSideSpecified::Sell, "1.00010", 100));
+        own_book.update(own_order("O-2", OrderSideSpecified::Sell, "1.00010", 100));
+        own_book.update(own_order("O-1", OrderSideSpecified::Sell, "1.00010", 40));
+
+        let level = own_book.asks(None).next().unwrap();
+        let ids: Vec<&ClientOrderId> = level.orders.keys().collect();
+        assert_eq!(
+            ids,
+            vec![&ClientOrderId::from("O-1"), &ClientOrderId::from("O-2")]
+        );
+        assert_eq!(level.size_decimal(), dec!(140));
+    }
+
+    #[rstest]
+    fn test_delete_removes_empty_level(mut own_book: OwnOrderBook) {
+        own_book.update(own_order("O-1", OrderSideSpecified::Buy, "1.00000", 100));
+        own_book.delete(&ClientOrderId::from("O-1"), UnixNanos::from(1));
+
+        assert_eq!(own_book.bids(None).count(), 0);
+        assert!(own_book.get_order(&ClientOrderId::from("O-1")).is_none());
+        assert_eq!(own_book.ts_last, UnixNanos::from(1));
+    }
+
+    #[rstest]
+    fn test_delete_unknown_order_is_noop(mut own_book: OwnOrderBook) {
+        own_book.delete(&ClientOrderId::from("O-1"), UnixNanos::from(1));
+        assert_eq!(own_book.count, 0);
+    }
+
+    #[rstest]
+    fn test_orders_for_strategy(mut own_book: OwnOrderBook) {
+        own_book.update(own_order("O-1", OrderSideSpecified::Buy, "1.00000", 100));
+        let mut other = own_order("O-2", OrderSideSpecified::Sell, "1.00010", 100);
+        other.strategy_id = StrategyId::from("S-002");
+        own_book.update(other);
+
+        let orders = own_book.orders_for_strategy(&StrategyId::from("S-002"));
+
+        assert_eq!(orders, vec![other]);
+    }
+
+    #[rstest]
+    fn test_from_order_ignores_non_resting_orders() {
+        let market = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(InstrumentId::from("AUD/USD.SIM"))
+            .quantity(Quantity::from(100))
+            .build();
+        let initialized_limit = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(InstrumentId::from("AUD/USD.SIM"))
+            .price(Price::from("1.00000"))
+            .quantity(Quantity::from(100))
+            .build();
+
+        assert!(OwnBookOrder::from_order(&market).is_none());
+        // Not yet accepted by the venue, so not resting in the book
+        assert!(OwnBookOrder::from_order(&initialized_limit).is_none());
+    }
+}
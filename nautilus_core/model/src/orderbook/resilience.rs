@@ -0,0 +1,330 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Tracks how quickly order book depth refills after being consumed by trades.
+
+use nautilus_core::{
+    correctness::{check_in_range_inclusive_f64, check_positive_u64, FAILED},
+    UnixNanos,
+};
+
+use crate::{
+    data::TradeTick,
+    enums::{AggressorSide, OrderSide, OrderSideSpecified},
+    orderbook::{BookLevel, OrderBook},
+};
+
+/// Tracks the resilience of an order book, being how quickly the size at each level from the top
+/// of book refills after being consumed by trades.
+///
+/// On each trade, the level sizes on the side of the book taken by the aggressor are captured from
+/// the last observed book as a baseline. A level is refilled once a later book has first shown
+/// the level depleted below `refill_ratio` of its baseline size, and then recovered back to it.
+/// The time from the trade to the recovery is recorded per level.
+///
+/// Levels are identified by their depth from the top of book rather than by price, so when a
+/// trade consumes the whole top level the next level in the book becomes the new top level.
+#[derive(Clone, Debug)]
+pub struct BookResilience {
+    /// The number of levels from the top of book tracked on each side.
+    pub depth: usize,
+    /// The fraction of the baseline size a level must recover to for it to be refilled.
+    pub refill_ratio: f64,
+    bids: SideResilience,
+    asks: SideResilience,
+}
+
+#[derive(Clone, Debug)]
+struct SideResilience {
+    last_sizes: Vec<f64>,
+    pending: Vec<Option<PendingRefill>>,
+    refill_counts: Vec<u64>,
+    refill_totals_ns: Vec<u64>,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct PendingRefill {
+    baseline_size: f64,
+    ts_trade: UnixNanos,
+    is_depleted: bool,
+}
+
+impl SideResilience {
+    fn new(depth: usize) -> Self {
+        Self {
+            last_sizes: vec![0.0; depth],
+            pending: vec![None; depth],
+            refill_counts: vec![0; depth],
+            refill_totals_ns: vec![0; depth],
+        }
+    }
+
+    fn handle_trade(&mut self, ts_trade: UnixNanos) {
+        for (pending, &baseline_size) in self.pending.iter_mut().zip(&self.last_sizes) {
+            // Levels still recovering from an earlier trade keep their original baseline
+            let is_recovering = pending.is_some_and(|refill| refill.is_depleted);
+            if !is_recovering && baseline_size > 0.0 {
+                *pending = Some(PendingRefill {
+                    baseline_size,
+                    ts_trade,
+                    is_depleted: false,
+                });
+            }
+        }
+    }
+
+    fn handle_levels<'a>(
+        &mut self,
+        levels: impl Iterator<Item = &'a BookLevel>,
+        refill_ratio: f64,
+        ts_event: UnixNanos,
+    ) {
+        self.last_sizes.fill(0.0);
+        for (last_size, level) in self.last_sizes.iter_mut().zip(levels) {
+            *last_size = level.size();
+        }
+
+        for (i, pending) in self.pending.iter_mut().enumerate() {
+            let Some(refill) = pending else {
+                continue;
+            };
+
+            let is_refilled = self.last_sizes[i] >= refill.baseline_size * refill_ratio;
+            if !is_refilled {
+                refill.is_depleted = true;
+            } else if refill.is_depleted {
+                self.refill_counts[i] += 1;
+                self.refill_totals_ns[i] +=
+                    ts_event.as_u64().saturating_sub(refill.ts_trade.as_u64());
+                *pending = None;
+            }
+        }
+    }
+
+    fn avg_refill_time_ns(&self, level: usize) -> Option<f64> {
+        let count = *self.refill_counts.get(level)?;
+        if count == 0 {
+            return None;
+        }
+        Some(self.refill_totals_ns[level] as f64 / count as f64)
+    }
+}
+
+impl BookResilience {
+    /// Creates a new [`BookResilience`] instance with correctness checking.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `depth` is not positive.
+    /// - If `refill_ratio` is not in the range (0, 1].
+    pub fn new_checked(depth: usize, refill_ratio: f64) -> anyhow::Result<Self> {
+        check_positive_u64(depth as u64, stringify!(depth))?;
+        check_in_range_inclusive_f64(refill_ratio, 0.0, 1.0, stringify!(refill_ratio))?;
+        anyhow::ensure!(refill_ratio > 0.0, "`refill_ratio` was not positive");
+
+        Ok(Self {
+            depth,
+            refill_ratio,
+            bids: SideResilience::new(depth),
+            asks: SideResilience::new(depth),
+        })
+    }
+
+    /// Creates a new [`BookResilience`] instance.
+    ///
+    /// # Panics
+    ///
+    /// This function panics:
+    /// - If `depth` is not positive.
+    /// - If `refill_ratio` is not in the range (0, 1].
+    #[must_use]
+    pub fn new(depth: usize, refill_ratio: f64) -> Self {
+        Self::new_checked(depth, refill_ratio).expect(FAILED)
+    }
+
+    /// Handles the given `trade`, capturing the sizes of the book levels taken by the aggressor
+    /// as the baseline to refill.
+    ///
+    /// Trades with no aggressor side are ignored.
+    pub fn handle_trade(&mut self, trade: &TradeTick) {
+        match trade.aggressor_side {
+            AggressorSide::Buyer => self.asks.handle_trade(trade.ts_event),
+            AggressorSide::Seller => self.bids.handle_trade(trade.ts_event),
+            AggressorSide::NoAggressor => {}
+        }
+    }
+
+    /// Handles the current state of the given `book`, recording the refill time of any levels
+    /// which have recovered since a trade.
+    ///
+    /// The book should be handled after each update, including before the first trade, so that
+    /// the level sizes prior to each trade are known.
+    pub fn handle_book(&mut self, book: &OrderBook) {
+        let ts_event = book.ts_last;
+        self.bids
+            .handle_levels(book.bids(Some(self.depth)), self.refill_ratio, ts_event);
+        self.asks
+            .handle_levels(book.asks(Some(self.depth)), self.refill_ratio, ts_event);
+    }
+
+    /// Returns the average time in nanoseconds for the given book `side` to refill at `level`
+    /// (zero being the top of book), if any refills have been recorded.
+    #[must_use]
+    pub fn avg_refill_time_ns(&self, side: OrderSide, level: usize) -> Option<f64> {
+        self.side(side).avg_refill_time_ns(level)
+    }
+
+    /// Returns the average refill time in nanoseconds for each level on the given book `side`.
+    #[must_use]
+    pub fn avg_refill_times_ns(&self, side: OrderSide) -> Vec<Option<f64>> {
+        let side = self.side(side);
+        (0..self.depth)
+            .map(|level| side.avg_refill_time_ns(level))
+            .collect()
+    }
+
+    /// Returns the number of refills recorded for the given book `side` at `level`.
+    #[must_use]
+    pub fn refill_count(&self, side: OrderSide, level: usize) -> u64 {
+        self.side(side)
+            .refill_counts
+            .get(level)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Resets the tracker to its initial state.
+    pub fn reset(&mut self) {
+        self.bids = SideResilience::new(self.depth);
+        self.asks = SideResilience::new(self.depth);
+    }
+
+    fn side(&self, side: OrderSide) -> &SideResilience {
+        match side.as_specified() {
+            OrderSideSpecified::Buy => &self.bids,
+            OrderSideSpecified::Sell => &self.asks,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::BookResilience;
+    use crate::{
+        data::{BookOrder, TradeTick},
+        enums::{AggressorSide, BookType, OrderSide},
+        identifiers::{InstrumentId, TradeId},
+        orderbook::OrderBook,
+        types::{Price, Quantity},
+    };
+
+    fn set_ask(book: &mut OrderBook, price: &str, size: u64, ts_event: u64) {
+        let order = BookOrder::new(OrderSide::Sell, Price::from(price), Quantity::from(size), 0);
+        book.update(order, 0, ts_event, ts_event.into());
+    }
+
+    fn buy_trade(price: &str, size: u64, ts_event: u64) -> TradeTick {
+        TradeTick::new(
+            InstrumentId::from("AAPL.XNAS"),
+            Price::from(price),
+            Quantity::from(size),
+            AggressorSide::Buyer,
+            TradeId::from(ts_event.to_string().as_str()),
+            ts_event.into(),
+            ts_event.into(),
+        )
+    }
+
+    #[rstest]
+    fn test_new_checked_with_invalid_args() {
+        assert!(BookResilience::new_checked(0, 1.0).is_err());
+        assert!(BookResilience::new_checked(5, 0.0).is_err());
+        assert!(BookResilience::new_checked(5, 1.5).is_err());
+    }
+
+    #[rstest]
+    fn test_refill_time_after_trade() {
+        let mut book = OrderBook::new(InstrumentId::from("AAPL.XNAS"), BookType::L2_MBP);
+        let mut resilience = BookResilience::new(2, 0.9);
+
+        set_ask(&mut book, "100.00", 100, 1);
+        set_ask(&mut book, "100.01", 200, 2);
+        resilience.handle_book(&book);
+
+        resilience.handle_trade(&buy_trade("100.00", 80, 10));
+        set_ask(&mut book, "100.00", 20, 10);
+        resilience.handle_book(&book);
+
+        // Partial recovery is not yet a refill
+        set_ask(&mut book, "100.00", 50, 20);
+        resilience.handle_book(&book);
+        assert_eq!(resilience.refill_count(OrderSide::Sell, 0), 0);
+
+        set_ask(&mut book, "100.00", 95, 40);
+        resilience.handle_book(&book);
+
+        assert_eq!(resilience.refill_count(OrderSide::Sell, 0), 1);
+        assert_eq!(
+            resilience.avg_refill_time_ns(OrderSide::Sell, 0),
+            Some(30.0)
+        );
+        assert_eq!(
+            resilience.avg_refill_times_ns(OrderSide::Sell),
+            vec![Some(30.0), None]
+        );
+        assert_eq!(resilience.refill_count(OrderSide::Buy, 0), 0);
+    }
+
+    #[rstest]
+    fn test_level_never_depleted_is_not_refilled() {
+        let mut book = OrderBook::new(InstrumentId::from("AAPL.XNAS"), BookType::L2_MBP);
+        let mut resilience = BookResilience::new(1, 1.0);
+
+        set_ask(&mut book, "100.00", 100, 1);
+        resilience.handle_book(&book);
+        resilience.handle_trade(&buy_trade("100.00", 1, 2));
+        set_ask(&mut book, "100.00", 100, 3);
+        resilience.handle_book(&book);
+
+        assert_eq!(resilience.refill_count(OrderSide::Sell, 0), 0);
+        assert_eq!(resilience.avg_refill_time_ns(OrderSide::Sell, 0), None);
+    }
+
+    #[rstest]
+    fn test_reset() {
+        let mut book = OrderBook::new(InstrumentId::from("AAPL.XNAS"), BookType::L2_MBP);
+        let mut resilience = BookResilience::new(1, 1.0);
+
+        set_ask(&mut book, "100.00", 100, 1);
+        resilience.handle_book(&book);
+        resilience.handle_trade(&buy_trade("100.00", 100, 2));
+        set_ask(&mut book, "100.00", 10, 3);
+        resilience.handle_book(&book);
+        set_ask(&mut book, "100.00", 100, 4);
+        resilience.handle_book(&book);
+        assert_eq!(resilience.refill_count(OrderSide::Sell, 0), 1);
+
+        resilience.reset();
+
+        assert_eq!(resilience.refill_count(OrderSide::Sell, 0), 0);
+        assert_eq!(resilience.avg_refill_times_ns(OrderSide::Sell), vec![None]);
+    }
+}
@@ -0,0 +1,583 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Represents a contiguous ring buffer of price levels for instruments with a fixed tick size.
+
+use crate::{
+    enums::{OrderSide, OrderSideSpecified},
+    orderbook::{BookLevel, BookPrice},
+    types::{price::PriceRaw, Price},
+};
+
+/// The default number of price level slots for a new [`RingLevels`] buffer.
+pub(crate) const RING_LEVELS_DEFAULT_CAPACITY: usize = 1024;
+
+/// The maximum number of price level slots for a [`RingLevels`] buffer.
+pub(crate) const RING_LEVELS_MAX_CAPACITY: usize = 1 << 16;
+
+/// Represents the price levels for one side of an order book on a contiguous ring buffer.
+///
+/// Each level lives in the slot given by its tick index, where the tick index of a price is its
+/// raw value divided by the tick size (negated for bids so that lower indices are always better
+/// prices). The best occupied index is the anchor, which moves with the top of book without
+/// shifting any levels. Level lookup by price is O(1), and iterating levels from the top of book
+/// scans adjacent slots in priority order.
+///
+/// The buffer grows to the next power of two whenever the span between the best and worst levels
+/// exceeds its capacity (up to [`RING_LEVELS_MAX_CAPACITY`]), and shrinks back towards its initial
+/// capacity when the span drops to a quarter of its capacity.
+#[derive(Clone, Debug)]
+pub(crate) struct RingLevels {
+    side: OrderSide,
+    price_increment: Price,
+    min_capacity: usize,
+    slots: Vec<Option<BookLevel>>,
+    mask: usize,
+    anchor: PriceRaw,
+    last: PriceRaw,
+    len: usize,
+}
+
+impl RingLevels {
+    /// Creates a new [`RingLevels`] instance.
+    ///
+    /// The `capacity` is rounded up to the next power of two, and capped at
+    /// [`RING_LEVELS_MAX_CAPACITY`].
+    ///
+    /// # Panics
+    ///
+    /// This function panics:
+    /// - If `price_increment` is not positive.
+    #[must_use]
+    pub fn new(side: OrderSide, price_increment: Price, capacity: usize) -> Self {
+        assert!(
+            price_increment.raw > 0,
+            "`price_increment` was not positive, was {price_increment}"
+        );
+
+        let capacity = capacity
+            .max(1)
+            .next_power_of_two()
+            .min(RING_LEVELS_MAX_CAPACITY);
+        Self {
+            side,
+            price_increment,
+            min_capacity: capacity,
+            slots: vec![None; capacity],
+            mask: capacity - 1,
+            anchor: 0,
+            last: 0,
+            len: 0,
+        }
+    }
+
+    /// Returns the number of price level slots in the buffer.
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.slots.len()
+    }
+
+    /// Returns the number of price levels.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if there are no price levels.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Removes all price levels, retaining the allocated capacity.
+    pub fn clear(&mut self) {
+        if self.is_empty() {
+            return;
+        }
+
+        for index in self.anchor..=self.last {
+            let slot = self.slot(index);
+            self.slots[slot] = None;
+        }
+        self.len = 0;
+    }
+
+    /// Returns the best price level, if any.
+    #[must_use]
+    pub fn first(&self) -> Option<&BookLevel> {
+        if self.is_empty() {
+            return None;
+        }
+        self.slots[self.slot(self.anchor)].as_ref()
+    }
+
+    /// Returns true if there is a level at the given `price`.
+    #[must_use]
+    pub fn contains_key(&self, price: &BookPrice) -> bool {
+        self.get(price).is_some()
+    }
+
+    /// Returns the price level at the given `price`, if any.
+    #[must_use]
+    pub fn get(&self, price: &BookPrice) -> Option<&BookLevel> {
+        let index = self
+            .index(price.value)
+            .filter(|index| self.in_range(*index))?;
+        self.slots[self.slot(index)].as_ref()
+    }
+
+    /// Returns a mutable reference to the price level at the given `price`, if any.
+    pub fn get_mut(&mut self, price: &BookPrice) -> Option<&mut BookLevel> {
+        let index = self
+            .index(price.value)
+            .filter(|index| self.in_range(*index))?;
+        let slot = self.slot(index);
+        self.slots[slot].as_mut()
+    }
+
+    /// Checks whether a level can be inserted at the given `price`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `price` is not a multiple of the price increment.
+    /// - If the span between the best and worst levels would exceed [`RING_LEVELS_MAX_CAPACITY`].
+    pub fn check_insert(&self, price: &BookPrice) -> anyhow::Result<()> {
+        self.checked_index(price).map(|_| ())
+    }
+
+    /// Inserts the `level` at the given `price`, replacing any existing level.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the level cannot be inserted, see
+    /// [`RingLevels::check_insert`].
+    pub fn insert(&mut self, price: BookPrice, level: BookLevel) -> anyhow::Result<()> {
+        let index = self.checked_index(&price)?;
+
+        let span = self.span_with(index);
+        if span > self.capacity() {
+            self.resize(span.next_power_of_two());
+        }
+
+        if self.is_empty() {
+            self.anchor = index;
+            self.last = index;
+        } else {
+            self.anchor = self.anchor.min(index);
+            self.last = self.last.max(index);
+        }
+
+        let slot = self.slot(index);
+        if self.slots[slot].replace(level).is_none() {
+            self.len += 1;
+        }
+
+        Ok(())
+    }
+
+    /// Removes and returns the price level at the given `price`, if any.
+    pub fn remove(&mut self, price: &BookPrice) -> Option<BookLevel> {
+        let index = self
+            .index(price.value)
+            .filter(|index| self.in_range(*index))?;
+
+        let slot = self.slot(index);
+        let level = self.slots[slot].take()?;
+        self.len -= 1;
+
+        if self.is_empty() {
+            self.anchor = 0;
+            self.last = 0;
+        } else if index == self.anchor {
+            while self.slots[self.slot(self.anchor)].is_none() {
+                self.anchor += 1;
+            }
+        } else if index == self.last {
+            while self.slots[self.slot(self.last)].is_none() {
+                self.last -= 1;
+            }
+        }
+
+        let span = (self.last - self.anchor) as usize + 1;
+        if self.capacity() > self.min_capacity && span <= self.capacity() / 4 {
+            self.resize((span * 2).next_power_of_two().max(self.min_capacity));
+        }
+
+        Some(level)
+    }
+
+    /// Returns an iterator over the price levels from best to worst.
+    #[must_use]
+    pub fn values(&self) -> RingLevelsIter<'_> {
+        RingLevelsIter {
+            levels: self,
+            next: self.anchor,
+            remaining: self.len,
+        }
+    }
+
+    /// Removes and returns all price levels from best to worst.
+    pub fn drain(&mut self) -> Vec<BookLevel> {
+        let mut levels = Vec::with_capacity(self.len);
+        if !self.is_empty() {
+            for index in self.anchor..=self.last {
+                let slot = self.slot(index);
+                if let Some(level) = self.slots[slot].take() {
+                    levels.push(level);
+                }
+            }
+        }
+        self.len = 0;
+        self.anchor = 0;
+        self.last = 0;
+        levels
+    }
+
+    /// Returns the tick index for the given `price`, or `None` if the price is off tick.
+    fn index(&self, price: Price) -> Option<PriceRaw> {
+        let increment = self.price_increment.raw;
+        if price.raw % increment != 0 {
+            return None;
+        }
+
+        let tick = price.raw / increment;
+        match self.side.as_specified() {
+            OrderSideSpecified::Buy => Some(-tick),
+            OrderSideSpecified::Sell => Some(tick),
+        }
+    }
+
+    fn checked_index(&self, price: &BookPrice) -> anyhow::Result<PriceRaw> {
+        let Some(index) = self.index(price.value) else {
+            anyhow::bail!(
+                "Price {} was not a multiple of the price increment {}",
+                price.value,
+                self.price_increment,
+            );
+        };
+
+        let span = self.span_with(index);
+        if span > RING_LEVELS_MAX_CAPACITY {
+            anyhow::bail!(
+                "Price {} would span {span} ticks, exceeding the maximum capacity {RING_LEVELS_MAX_CAPACITY}",
+                price.value,
+            );
+        }
+
+        Ok(index)
+    }
+
+    /// Returns the number of ticks between the best and worst levels including the given `index`.
+    fn span_with(&self, index: PriceRaw) -> usize {
+        if self.is_empty() {
+            return 1;
+        }
+        let low = self.anchor.min(index);
+        let high = self.last.max(index);
+        usize::try_from(high - low).map_or(usize::MAX, |span| span.saturating_add(1))
+    }
+
+    fn in_range(&self, index: PriceRaw) -> bool {
+        !self.is_empty() && index >= self.anchor && index <= self.last
+    }
+
+    #[inline]
+    fn slot(&self, index: PriceRaw) -> usize {
+        // Truncation is intended, masking the low bits of a power of two capacity wraps the
+        // index into the buffer (including for negative indices)
+        (index as usize) & self.mask
+    }
+
+    fn resize(&mut self, capacity: usize) {
+        let mut slots = vec![None; capacity];
+        let mask = capacity - 1;
+
+        if !self.is_empty() {
+            for index in self.anchor..=self.last {
+                let slot = self.slot(index);
+                if let Some(level) = self.slots[slot].take() {
+                    slots[(index as usize) & mask] = Some(level);
+                }
+            }
+        }
+
+        self.slots = slots;
+        self.mask = mask;
+    }
+}
+
+/// An iterator over the price levels of a [`RingLevels`] buffer from best to worst.
+#[derive(Debug)]
+pub(crate) struct RingLevelsIter<'a> {
+    levels: &'a RingLevels,
+    next: PriceRaw,
+    remaining: usize,
+}
+
+impl<'a> Iterator for RingLevelsIter<'a> {
+    type Item = &'a BookLevel;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.remaining > 0 {
+            let slot = self.levels.slot(self.next);
+            self.next += 1;
+            if let Some(level) = self.levels.slots[slot].as_ref() {
+                self.remaining -= 1;
+                return Some(level);
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl ExactSizeIterator for RingLevelsIter<'_> {}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::{RingLevels, RING_LEVELS_MAX_CAPACITY};
+    use crate::{
+        data::order::BookOrder,
+        enums::OrderSide,
+        orderbook::{BookLevel, BookPrice},
+        types::{Price, Quantity},
+    };
+
+    fn level(side: OrderSide, price: &str, size: u64) -> (BookPrice, BookLevel) {
+        let order = BookOrder::new(side, Price::from(price), Quantity::from(size), 0);
+        (order.to_book_price(), BookLevel::from_order(order))
+    }
+
+    fn prices(levels: &RingLevels) -> Vec<Price> {
+        levels.values().map(|level| level.price.value).collect()
+    }
+
+    #[rstest]
+    fn test_new_rounds_capacity_to_power_of_two() {
+        let levels = RingLevels::new(OrderSide::Buy, Price::from("0.01"), 100);
+
+        assert_eq!(levels.capacity(), 128);
+        assert!(levels.is_empty());
+        assert!(levels.first().is_none());
+    }
+
+    #[rstest]
+    #[should_panic(expected = "not positive")]
+    fn test_new_with_zero_price_increment_panics() {
+        let _ = RingLevels::new(OrderSide::Buy, Price::from("0.00"), 8);
+    }
+
+    #[rstest]
+    fn test_insert_off_tick_price_errors() {
+        let mut levels = RingLevels::new(OrderSide::Sell, Price::from("0.05"), 8);
+        let (price, level) = level(OrderSide::Sell, "10.02", 1);
+
+        let result = levels.insert(price, level);
+
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("not a multiple of the price increment"));
+        assert!(levels.is_empty());
+    }
+
+    #[rstest]
+    fn test_insert_beyond_max_capacity_errors() {
+        let mut levels = RingLevels::new(OrderSide::Sell, Price::from("1"), 8);
+        let (near_price, near_level) = level(OrderSide::Sell, "1", 1);
+        levels.insert(near_price, near_level).unwrap();
+        let far = (RING_LEVELS_MAX_CAPACITY + 1).to_string();
+        let (far_price, far_level) = level(OrderSide::Sell, &far, 1);
+
+        let result = levels.insert(far_price, far_level);
+
+        assert!(result.unwrap_err().to_string().contains("maximum capacity"));
+        assert_eq!(levels.len(), 1);
+        assert_eq!(levels.capacity(), 8);
+    }
+
+    #[rstest]
+    fn test_remove_shrinks_buffer_when_span_drops() {
+        let mut levels = RingLevels::new(OrderSide::Sell, Price::from("0.01"), 4);
+        for price in ["10.00", "10.01", "10.60"] {
+            let (price, level) = level(OrderSide::Sell, price, 1);
+            levels.insert(price, level).unwrap();
+        }
+        assert_eq!(levels.capacity(), 64);
+
+        levels.remove(&BookPrice::new(Price::from("10.60"), OrderSide::Sell));
+
+        assert_eq!(levels.capacity(), 4);
+        assert_eq!(
+            prices(&levels),
+            vec![Price::from("10.00"), Price::from("10.01")]
+        );
+    }
+
+    #[rstest]
+    fn test_drain_returns_levels_best_to_worst() {
+        let mut levels = RingLevels::new(OrderSide::Buy, Price::from("0.01"), 8);
+        for price in ["10.00", "10.02"] {
+            let (price, level) = level(OrderSide::Buy, price, 1);
+            levels.insert(price, level).unwrap();
+        }
+
+        let drained: Vec<Price> = levels
+            .drain()
+            .into_iter()
+            .map(|level| level.price.value)
+            .collect();
+
+        assert_eq!(drained, vec![Price::from("10.02"), Price::from("10.00")]);
+        assert!(levels.is_empty());
+        assert!(levels.first().is_none());
+    }
+
+    #[rstest]
+    fn test_get_off_tick_price_returns_none() {
+        let mut levels = RingLevels::new(OrderSide::Sell, Price::from("0.05"), 8);
+        let (price, level) = level(OrderSide::Sell, "10.05", 1);
+        levels.insert(price, level).unwrap();
+
+        let off_tick = BookPrice::new(Price::from("10.02"), OrderSide::Sell);
+        assert!(levels.get(&off_tick).is_none());
+        assert!(levels.remove(&off_tick).is_none());
+    }
+
+    #[rstest]
+    #[case(OrderSide::Buy, vec!["10.02", "10.01", "10.00"])]
+    #[case(OrderSide::Sell, vec!["10.00", "10.01", "10.02"])]
+    fn test_values_iterate_best_to_worst(#[case] side: OrderSide, #[case] expected: Vec<&str>) {
+        let mut levels = RingLevels::new(side, Price::from("0.01"), 8);
+        for price in ["10.01", "10.00", "10.02"] {
+            let (price, level) = level(side, price, 1);
+            levels.insert(price, level).unwrap();
+        }
+
+        let expected: Vec<Price> = expected.into_iter().map(Price::from).collect();
+        assert_eq!(levels.len(), 3);
+        assert_eq!(prices(&levels), expected);
+        assert_eq!(levels.first().unwrap().price.value, expected[0]);
+    }
+
+    #[rstest]
+    fn test_remove_moves_anchor_to_next_level() {
+        let mut levels = RingLevels::new(OrderSide::Sell, Price::from("0.01"), 8);
+        for price in ["10.00", "10.03", "10.05"] {
+            let (price, level) = level(OrderSide::Sell, price, 1);
+            levels.insert(price, level).unwrap();
+        }
+
+        let best = BookPrice::new(Price::from("10.00"), OrderSide::Sell);
+        assert!(levels.remove(&best).is_some());
+        assert!(levels.remove(&best).is_none());
+
+        assert_eq!(levels.len(), 2);
+        assert_eq!(levels.first().unwrap().price.value, Price::from("10.03"));
+        assert!(!levels.contains_key(&best));
+    }
+
+    #[rstest]
+    fn test_remove_last_level_empties_buffer() {
+        let mut levels = RingLevels::new(OrderSide::Buy, Price::from("0.01"), 8);
+        let (price, level) = level(OrderSide::Buy, "10.00", 1);
+        levels.insert(price, level).unwrap();
+
+        levels.remove(&price);
+
+        assert!(levels.is_empty());
+        assert!(levels.values().next().is_none());
+        assert!(levels.get(&price).is_none());
+    }
+
+    #[rstest]
+    fn test_insert_beyond_capacity_grows_buffer() {
+        let mut levels = RingLevels::new(OrderSide::Sell, Price::from("0.01"), 4);
+        for price in ["10.00", "10.02", "10.10", "9.95"] {
+            let (price, level) = level(OrderSide::Sell, price, 1);
+            levels.insert(price, level).unwrap();
+        }
+
+        assert_eq!(levels.capacity(), 16);
+        assert_eq!(
+            prices(&levels),
+            vec![
+                Price::from("9.95"),
+                Price::from("10.00"),
+                Price::from("10.02"),
+                Price::from("10.10"),
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_anchor_moves_across_buffer_wrap() {
+        let mut levels = RingLevels::new(OrderSide::Buy, Price::from("1"), 4);
+        for (i, price) in ["100", "101", "102", "103", "104", "105"]
+            .iter()
+            .enumerate()
+        {
+            let (book_price, level) = level(OrderSide::Buy, price, 1);
+            levels.insert(book_price, level).unwrap();
+            if i >= 2 {
+                let worst = levels.values().last().unwrap().price;
+                levels.remove(&worst);
+            }
+        }
+
+        assert_eq!(levels.capacity(), 4);
+        assert_eq!(
+            prices(&levels),
+            vec![Price::from("105"), Price::from("104")]
+        );
+    }
+
+    #[rstest]
+    fn test_get_mut_updates_level_in_place() {
+        let mut levels = RingLevels::new(OrderSide::Sell, Price::from("0.01"), 8);
+        let (price, level) = level(OrderSide::Sell, "10.00", 1);
+        levels.insert(price, level).unwrap();
+
+        let order = BookOrder::new(OrderSide::Sell, Price::from("10.00"), Quantity::from(2), 1);
+        levels.get_mut(&price).unwrap().add(order);
+
+        assert_eq!(levels.get(&price).unwrap().len(), 2);
+        assert_eq!(levels.len(), 1);
+    }
+
+    #[rstest]
+    fn test_clear() {
+        let mut levels = RingLevels::new(OrderSide::Sell, Price::from("0.01"), 8);
+        for price in ["10.00", "10.01"] {
+            let (price, level) = level(OrderSide::Sell, price, 1);
+            levels.insert(price, level).unwrap();
+        }
+
+        levels.clear();
+
+        assert!(levels.is_empty());
+        assert_eq!(levels.capacity(), 8);
+        assert!(levels.values().next().is_none());
+    }
+}
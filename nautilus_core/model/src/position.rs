@@ -21,11 +21,16 @@ use std::{
     hash::{Hash, Hasher},
 };
 
-use nautilus_core::UnixNanos;
+use nautilus_core::{correctness::FAILED, UnixNanos};
+use rust_decimal::{
+    prelude::{FromPrimitive, ToPrimitive},
+    Decimal, RoundingStrategy,
+};
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    enums::{OrderSide, OrderSideSpecified, PositionSide},
+    data::CorporateAction,
+    enums::{LotMatchingMethod, OrderSide, OrderSideSpecified, PositionSide},
     events::OrderFilled,
     identifiers::{
         AccountId, ClientOrderId, InstrumentId, PositionId, StrategyId, Symbol, TradeId, TraderId,
@@ -35,10 +40,25 @@ use crate::{
     types::{Currency, Money, Price, Quantity},
 };
 
+/// The number of decimal places to which average prices are rounded.
+const AVG_PX_DECIMALS: u32 = 16;
+
 /// Represents a position in a market.
 ///
 /// The position ID may be assigned at the trading venue, or can be system
 /// generated depending on a strategies OMS (Order Management System) settings.
+///
+/// Quantities are accumulated with exact fixed-point arithmetic, and average prices and PnL
+/// are calculated with exact `Decimal` arithmetic, with the following rounding rules
+/// (all rounding midpoint values away from zero):
+/// - Average open and close prices are rounded to 16 decimal places.
+/// - The PnL of each closing fill is rounded once to the settlement currency precision,
+///   then any commission in the settlement currency is deducted exactly.
+/// - The realized PnL is the exact sum of the rounded PnL of each fill.
+/// - Unrealized PnL and notional values are rounded once to the currency precision.
+///
+/// The `f64` fields (`signed_qty`, `avg_px_open`, `avg_px_close`, `realized_return`) are
+/// derived from this exact state, and are never used in further calculations.
 #[repr(C)]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(
@@ -73,12 +93,99 @@ pub struct Position {
     pub duration_ns: u64,
     pub avg_px_open: f64,
     pub avg_px_close: Option<f64>,
+    /// The exact average open price, from which `avg_px_open` is derived.
+    pub avg_px_open_exact: Decimal,
+    /// The exact average close price, from which `avg_px_close` is derived.
+    pub avg_px_close_exact: Option<Decimal>,
     pub realized_return: f64,
     pub realized_pnl: Option<Money>,
     pub trade_ids: Vec<TradeId>,
     pub buy_qty: Quantity,
     pub sell_qty: Quantity,
     pub commissions: HashMap<Currency, Money>,
+    /// The method for matching closing fills against open lots, if lots are tracked.
+    #[serde(default)]
+    pub lot_matching: Option<LotMatchingMethod>,
+    /// The open lots of the position, in the order they were opened.
+    #[serde(default)]
+    pub lots: Vec<PositionLot>,
+    /// The lots (or parts of lots) closed since the position was opened.
+    #[serde(default)]
+    pub closed_lots: Vec<ClosedLot>,
+    /// The lot IDs given for fills applied with `apply_with_lots`, keyed by the fill trade ID.
+    #[serde(default)]
+    pub lot_selections: HashMap<TradeId, Vec<TradeId>>,
+}
+
+/// Represents an open lot of a position, being the quantity of an opening fill which remains
+/// open.
+///
+/// Lots are identified by the trade ID of their opening fill.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PositionLot {
+    /// The trade ID of the opening fill.
+    pub lot_id: TradeId,
+    /// The order side of the opening fill.
+    pub side: OrderSide,
+    /// The quantity opened by the fill.
+    pub quantity: Quantity,
+    /// The quantity remaining open.
+    pub remaining_qty: Quantity,
+    /// The price of the opening fill.
+    pub px_open: Price,
+    /// UNIX timestamp (nanoseconds) when the lot was opened.
+    pub ts_opened: UnixNanos,
+}
+
+/// Represents the closing of a quantity of an open lot by a closing fill.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ClosedLot {
+    /// The trade ID of the opening fill of the lot.
+    pub lot_id: TradeId,
+    /// The trade ID of the closing fill.
+    pub closing_trade_id: TradeId,
+    /// The order side of the opening fill of the lot.
+    pub side: OrderSide,
+    /// The quantity of the lot closed.
+    pub quantity: Quantity,
+    /// The price of the opening fill of the lot.
+    pub px_open: Price,
+    /// The price of the closing fill.
+    pub px_close: Price,
+    /// The realized PnL of the closed quantity (before commissions).
+    pub realized_pnl: Money,
+    /// UNIX timestamp (nanoseconds) when the lot was opened.
+    pub ts_opened: UnixNanos,
+    /// UNIX timestamp (nanoseconds) when the quantity was closed.
+    pub ts_closed: UnixNanos,
+}
+
+impl ClosedLot {
+    /// Returns the holding period of the closed quantity in nanoseconds.
+    #[must_use]
+    pub fn holding_period_ns(&self) -> u64 {
+        self.ts_closed
+            .as_u64()
+            .saturating_sub(self.ts_opened.as_u64())
+    }
+}
+
+impl Display for ClosedLot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}(lot_id={}, closing_trade_id={}, side={}, quantity={}, px_open={}, px_close={}, realized_pnl={}, holding_period_ns={})",
+            stringify!(ClosedLot),
+            self.lot_id,
+            self.closing_trade_id,
+            self.side,
+            self.quantity,
+            self.px_open,
+            self.px_close,
+            self.realized_pnl,
+            self.holding_period_ns(),
+        )
+    }
 }
 
 impl Position {
@@ -121,14 +228,34 @@ impl Position {
             duration_ns: 0,
             avg_px_open: fill.last_px.as_f64(),
             avg_px_close: None,
+            avg_px_open_exact: fill.last_px.as_decimal(),
+            avg_px_close_exact: None,
             realized_return: 0.0,
             realized_pnl: None,
+            lot_matching: None,
+            lots: Vec::new(),
+            closed_lots: Vec::new(),
+            lot_selections: HashMap::new(),
         };
         item.apply(&fill);
         item
     }
 
     pub fn apply(&mut self, fill: &OrderFilled) {
+        self.apply_with_lots(fill, &[]);
+    }
+
+    /// Applies the given `fill` to the position, with any quantity it closes matched against the
+    /// open lots with the given `lot_ids` first (in order), then by the lot matching method.
+    ///
+    /// The `lot_ids` are kept with the position, so are applied if lots are tracked (or rebuilt)
+    /// later, see [`Position::set_lot_matching`].
+    ///
+    /// # Panics
+    ///
+    /// This function panics:
+    /// - If the `fill.trade_id` has already been applied to the position.
+    pub fn apply_with_lots(&mut self, fill: &OrderFilled, lot_ids: &[TradeId]) {
         assert!(
             !self.trade_ids.contains(&fill.trade_id),
             "`fill.trade_id` already contained in `trade_ids"
@@ -150,12 +277,21 @@ impl Position {
             self.duration_ns = 0;
             self.avg_px_open = fill.last_px.as_f64();
             self.avg_px_close = None;
+            self.avg_px_open_exact = fill.last_px.as_decimal();
+            self.avg_px_close_exact = None;
             self.realized_return = 0.0;
             self.realized_pnl = None;
+            self.lots.clear();
+            self.closed_lots.clear();
+            self.lot_selections.clear();
         }
 
         self.events.push(*fill);
         self.trade_ids.push(fill.trade_id);
+        if !lot_ids.is_empty() {
+            self.lot_selections.insert(fill.trade_id, lot_ids.to_vec());
+        }
+        self.update_lots(fill, lot_ids);
 
         // Calculate cumulative commissions
         if let Some(commission) = fill.commission {
@@ -168,18 +304,23 @@ impl Position {
         }
 
         // Calculate avg prices, points, return, PnL
-        match fill.specified_side() {
+        let signed_qty = self.signed_decimal_qty();
+        let signed_qty = match fill.specified_side() {
             OrderSideSpecified::Buy => {
                 self.handle_buy_order_fill(fill);
+                signed_qty + fill.last_qty.as_decimal()
             }
             OrderSideSpecified::Sell => {
                 self.handle_sell_order_fill(fill);
+                signed_qty - fill.last_qty.as_decimal()
             }
-        }
+        };
 
         // Set quantities
+        self.signed_qty = signed_qty.to_f64().unwrap_or_default();
         // SAFETY: size_precision is valid from instrument
-        self.quantity = Quantity::new(self.signed_qty.abs(), self.size_precision);
+        self.quantity =
+            Quantity::from_decimal(signed_qty.abs(), self.size_precision).expect(FAILED);
         if self.quantity > self.peak_qty {
             self.peak_qty.raw = self.quantity.raw;
         }
@@ -206,172 +347,209 @@ impl Position {
     }
 
     pub fn handle_buy_order_fill(&mut self, fill: &OrderFilled) {
-        // Handle case where commission could be None or not settlement currency
-        let mut realized_pnl = if let Some(commission) = fill.commission {
-            if commission.currency == self.settlement_currency {
-                -commission.as_f64()
-            } else {
-                0.0
-            }
-        } else {
-            0.0
-        };
+        let mut realized_pnl = self.fill_commission_pnl(fill);
 
-        let last_px = fill.last_px.as_f64();
-        let last_qty = fill.last_qty.as_f64();
+        let last_px = fill.last_px.as_decimal();
+        let last_qty = fill.last_qty.as_decimal();
         let last_qty_object = fill.last_qty;
 
-        if self.signed_qty > 0.0 {
-            self.avg_px_open = self.calculate_avg_px_open_px(last_px, last_qty);
-        } else if self.signed_qty < 0.0 {
+        if self.side == PositionSide::Long {
+            self.set_avg_px_open(self.calculate_avg_px_open_px(last_px, last_qty));
+        } else if self.side == PositionSide::Short {
             // SHORT POSITION
             let avg_px_close = self.calculate_avg_px_close_px(last_px, last_qty);
-            self.avg_px_close = Some(avg_px_close);
-            self.realized_return = self.calculate_return(self.avg_px_open, avg_px_close);
-            realized_pnl += self.calculate_pnl_raw(self.avg_px_open, last_px, last_qty);
-        }
-
-        if self.realized_pnl.is_none() {
-            self.realized_pnl = Some(Money::new(realized_pnl, self.settlement_currency));
-        } else {
-            self.realized_pnl = Some(Money::new(
-                self.realized_pnl.unwrap().as_f64() + realized_pnl,
-                self.settlement_currency,
-            ));
+            self.set_avg_px_close(avg_px_close);
+            self.realized_return = self.calculate_return(self.avg_px_open_exact, avg_px_close);
+            realized_pnl += self.calculate_pnl_exact(self.avg_px_open_exact, last_px, last_qty);
         }
 
-        self.signed_qty += last_qty;
+        self.realized_pnl = Some(
+            self.realized_pnl
+                .map_or(realized_pnl, |pnl| pnl + realized_pnl),
+        );
         self.buy_qty += last_qty_object;
     }
 
     pub fn handle_sell_order_fill(&mut self, fill: &OrderFilled) {
-        // Handle case where commission could be None or not settlement currency
-        let mut realized_pnl = if let Some(commission) = fill.commission {
-            if commission.currency == self.settlement_currency {
-                -commission.as_f64()
-            } else {
-                0.0
-            }
-        } else {
-            0.0
-        };
+        let mut realized_pnl = self.fill_commission_pnl(fill);
 
-        let last_px = fill.last_px.as_f64();
-        let last_qty = fill.last_qty.as_f64();
+        let last_px = fill.last_px.as_decimal();
+        let last_qty = fill.last_qty.as_decimal();
         let last_qty_object = fill.last_qty;
 
-        if self.signed_qty < 0.0 {
-            self.avg_px_open = self.calculate_avg_px_open_px(last_px, last_qty);
-        } else if self.signed_qty > 0.0 {
+        if self.side == PositionSide::Short {
+            self.set_avg_px_open(self.calculate_avg_px_open_px(last_px, last_qty));
+        } else if self.side == PositionSide::Long {
             let avg_px_close = self.calculate_avg_px_close_px(last_px, last_qty);
-            self.avg_px_close = Some(avg_px_close);
-            self.realized_return = self.calculate_return(self.avg_px_open, avg_px_close);
-            realized_pnl += self.calculate_pnl_raw(self.avg_px_open, last_px, last_qty);
+            self.set_avg_px_close(avg_px_close);
+            self.realized_return = self.calculate_return(self.avg_px_open_exact, avg_px_close);
+            realized_pnl += self.calculate_pnl_exact(self.avg_px_open_exact, last_px, last_qty);
         }
 
-        if self.realized_pnl.is_none() {
-            self.realized_pnl = Some(Money::new(realized_pnl, self.settlement_currency));
-        } else {
-            self.realized_pnl = Some(Money::new(
-                self.realized_pnl.unwrap().as_f64() + realized_pnl,
-                self.settlement_currency,
-            ));
+        self.realized_pnl = Some(
+            self.realized_pnl
+                .map_or(realized_pnl, |pnl| pnl + realized_pnl),
+        );
+        self.sell_qty += last_qty_object;
+    }
+
+    /// Returns the realized PnL impact of the commission for the given `fill`, being the negated
+    /// commission if in the settlement currency, otherwise zero.
+    fn fill_commission_pnl(&self, fill: &OrderFilled) -> Money {
+        // Handle case where commission could be None or not settlement currency
+        match fill.commission {
+            Some(commission) if commission.currency == self.settlement_currency => -commission,
+            _ => Money::zero(self.settlement_currency),
         }
+    }
 
-        self.signed_qty -= last_qty;
-        self.sell_qty += last_qty_object;
+    fn set_avg_px_open(&mut self, avg_px_open: Decimal) {
+        self.avg_px_open_exact = avg_px_open;
+        self.avg_px_open = avg_px_open.to_f64().unwrap_or_default();
+    }
+
+    fn set_avg_px_close(&mut self, avg_px_close: Decimal) {
+        self.avg_px_close_exact = Some(avg_px_close);
+        self.avg_px_close = avg_px_close.to_f64();
     }
 
+    /// Returns the exact signed quantity of the position (positive for long, negative for short,
+    /// and zero when flat).
+    fn signed_decimal_qty(&self) -> Decimal {
+        match self.side {
+            PositionSide::Long => self.quantity.as_decimal(),
+            PositionSide::Short => -self.quantity.as_decimal(),
+            _ => Decimal::ZERO, // FLAT
+        }
+    }
+
+    /// Calculates the average price of `qty` at `avg_px` combined with `last_qty` at `last_px`,
+    /// rounded to 16 decimal places.
     #[must_use]
-    pub fn calculate_avg_px(&self, qty: f64, avg_pg: f64, last_px: f64, last_qty: f64) -> f64 {
-        let start_cost = avg_pg * qty;
+    pub fn calculate_avg_px(
+        &self,
+        qty: Decimal,
+        avg_px: Decimal,
+        last_px: Decimal,
+        last_qty: Decimal,
+    ) -> Decimal {
+        let start_cost = avg_px * qty;
         let event_cost = last_px * last_qty;
-        (start_cost + event_cost) / (qty + last_qty)
+        ((start_cost + event_cost) / (qty + last_qty))
+            .round_dp_with_strategy(AVG_PX_DECIMALS, RoundingStrategy::MidpointAwayFromZero)
     }
 
     #[must_use]
-    pub fn calculate_avg_px_open_px(&self, last_px: f64, last_qty: f64) -> f64 {
-        self.calculate_avg_px(self.quantity.as_f64(), self.avg_px_open, last_px, last_qty)
+    pub fn calculate_avg_px_open_px(&self, last_px: Decimal, last_qty: Decimal) -> Decimal {
+        self.calculate_avg_px(
+            self.quantity.as_decimal(),
+            self.avg_px_open_exact,
+            last_px,
+            last_qty,
+        )
     }
 
     #[must_use]
-    pub fn calculate_avg_px_close_px(&self, last_px: f64, last_qty: f64) -> f64 {
-        if self.avg_px_close.is_none() {
+    pub fn calculate_avg_px_close_px(&self, last_px: Decimal, last_qty: Decimal) -> Decimal {
+        let Some(avg_px_close) = self.avg_px_close_exact else {
             return last_px;
-        }
+        };
         let closing_qty = if self.side == PositionSide::Long {
             self.sell_qty
         } else {
             self.buy_qty
         };
-        self.calculate_avg_px(
-            closing_qty.as_f64(),
-            self.avg_px_close.unwrap(),
-            last_px,
-            last_qty,
-        )
+        self.calculate_avg_px(closing_qty.as_decimal(), avg_px_close, last_px, last_qty)
     }
 
+    /// Returns the total PnL of the position at the `last` price, being the exact sum of the
+    /// realized and unrealized PnL.
     #[must_use]
     pub fn total_pnl(&self, last: Price) -> Money {
-        let realized_pnl = self.realized_pnl.map_or(0.0, |pnl| pnl.as_f64());
-        Money::new(
-            realized_pnl + self.unrealized_pnl(last).as_f64(),
-            self.settlement_currency,
-        )
+        let realized_pnl = self
+            .realized_pnl
+            .unwrap_or_else(|| Money::zero(self.settlement_currency));
+        realized_pnl + self.unrealized_pnl(last)
     }
 
-    fn calculate_points(&self, avg_px_open: f64, avg_px_close: f64) -> f64 {
+    fn calculate_points(&self, avg_px_open: Decimal, avg_px_close: Decimal) -> Decimal {
         match self.side {
             PositionSide::Long => avg_px_close - avg_px_open,
             PositionSide::Short => avg_px_open - avg_px_close,
-            _ => 0.0, // FLAT
+            _ => Decimal::ZERO, // FLAT
         }
     }
 
-    fn calculate_points_inverse(&self, avg_px_open: f64, avg_px_close: f64) -> f64 {
-        let inverse_open = 1.0 / avg_px_open;
-        let inverse_close = 1.0 / avg_px_close;
+    fn calculate_points_inverse(&self, avg_px_open: Decimal, avg_px_close: Decimal) -> Decimal {
+        if avg_px_open.is_zero() || avg_px_close.is_zero() {
+            return Decimal::ZERO;
+        }
+        let inverse_open = Decimal::ONE / avg_px_open;
+        let inverse_close = Decimal::ONE / avg_px_close;
         match self.side {
             PositionSide::Long => inverse_open - inverse_close,
             PositionSide::Short => inverse_close - inverse_open,
-            _ => 0.0, // FLAT
+            _ => Decimal::ZERO, // FLAT
         }
     }
 
+    /// Calculates the PnL for the given prices and `quantity`, rounded once to the settlement
+    /// currency precision.
+    ///
+    /// The `f64` prices are first converted to decimals with their excess binary precision
+    /// removed (so `0.1` is exactly `0.1`).
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `avg_px_open` or `avg_px_close` is not finite.
     #[must_use]
     pub fn calculate_pnl(&self, avg_px_open: f64, avg_px_close: f64, quantity: Quantity) -> Money {
-        let pnl_raw = self.calculate_pnl_raw(avg_px_open, avg_px_close, quantity.as_f64());
-        Money::new(pnl_raw, self.settlement_currency)
+        let avg_px_open = Decimal::from_f64(avg_px_open).expect(FAILED);
+        let avg_px_close = Decimal::from_f64(avg_px_close).expect(FAILED);
+        self.calculate_pnl_exact(avg_px_open, avg_px_close, quantity.as_decimal())
     }
 
+    /// Returns the unrealized PnL of the position at the `last` price, rounded once to the
+    /// settlement currency precision.
     #[must_use]
     pub fn unrealized_pnl(&self, last: Price) -> Money {
         if self.side == PositionSide::Flat {
-            Money::new(0.0, self.settlement_currency)
+            Money::zero(self.settlement_currency)
         } else {
-            let avg_px_open = self.avg_px_open;
-            let avg_px_close = last.as_f64();
-            let quantity = self.quantity.as_f64();
-            let pnl = self.calculate_pnl_raw(avg_px_open, avg_px_close, quantity);
-            Money::new(pnl, self.settlement_currency)
+            self.calculate_pnl_exact(
+                self.avg_px_open_exact,
+                last.as_decimal(),
+                self.quantity.as_decimal(),
+            )
         }
     }
 
+    /// Calculates the return for the given prices, or zero if `avg_px_open` is zero.
     #[must_use]
-    pub fn calculate_return(&self, avg_px_open: f64, avg_px_close: f64) -> f64 {
-        self.calculate_points(avg_px_open, avg_px_close) / avg_px_open
+    pub fn calculate_return(&self, avg_px_open: Decimal, avg_px_close: Decimal) -> f64 {
+        if avg_px_open.is_zero() {
+            return 0.0;
+        }
+        (self.calculate_points(avg_px_open, avg_px_close) / avg_px_open)
+            .to_f64()
+            .unwrap_or_default()
     }
 
-    fn calculate_pnl_raw(&self, avg_px_open: f64, avg_px_close: f64, quantity: f64) -> f64 {
-        let quantity = quantity.min(self.signed_qty.abs());
-        if self.is_inverse {
-            quantity
-                * self.multiplier.as_f64()
-                * self.calculate_points_inverse(avg_px_open, avg_px_close)
+    fn calculate_pnl_exact(
+        &self,
+        avg_px_open: Decimal,
+        avg_px_close: Decimal,
+        quantity: Decimal,
+    ) -> Money {
+        // Cap at the signed quantity, which is not rounded to the size precision
+        let quantity = quantity.min(Decimal::from_f64(self.signed_qty.abs()).expect(FAILED));
+        let points = if self.is_inverse {
+            self.calculate_points_inverse(avg_px_open, avg_px_close)
         } else {
-            quantity * self.multiplier.as_f64() * self.calculate_points(avg_px_open, avg_px_close)
-        }
+            self.calculate_points(avg_px_open, avg_px_close)
+        };
+        let pnl = quantity * self.multiplier.as_decimal() * points;
+        Money::from_decimal(pnl, self.settlement_currency).expect(FAILED)
     }
 
     #[must_use]
@@ -435,18 +613,16 @@ impl Position {
         result
     }
 
+    /// Returns the notional value of the position at the `last` price, rounded once to the
+    /// currency precision.
     #[must_use]
     pub fn notional_value(&self, last: Price) -> Money {
+        let quantity = self.quantity.as_decimal() * self.multiplier.as_decimal();
         if self.is_inverse {
-            Money::new(
-                self.quantity.as_f64() * self.multiplier.as_f64() * (1.0 / last.as_f64()),
-                self.base_currency.unwrap(),
-            )
+            Money::from_decimal(quantity / last.as_decimal(), self.base_currency.unwrap())
+                .expect(FAILED)
         } else {
-            Money::new(
-                self.quantity.as_f64() * last.as_f64() * self.multiplier.as_f64(),
-                self.quote_currency,
-            )
+            Money::from_decimal(quantity * last.as_decimal(), self.quote_currency).expect(FAILED)
         }
     }
 
@@ -487,6 +663,243 @@ impl Position {
     pub fn commissions(&self) -> Vec<Money> {
         self.commissions.values().copied().collect()
     }
+
+    /// Sets the method for matching closing fills against open lots, rebuilding the lots from
+    /// the fills applied since the position was opened (or stopping lot tracking if `None`).
+    ///
+    /// Fills applied with specific lot IDs close those lots first again, with only the remainder
+    /// matched using the given `method`.
+    pub fn set_lot_matching(&mut self, method: Option<LotMatchingMethod>) {
+        self.lot_matching = method;
+        self.lots.clear();
+        self.closed_lots.clear();
+
+        let events = self.events.clone();
+        for fill in &events {
+            let lot_ids = self
+                .lot_selections
+                .get(&fill.trade_id)
+                .cloned()
+                .unwrap_or_default();
+            self.update_lots(fill, &lot_ids);
+        }
+    }
+
+    /// Returns the realized PnL of the closed lots (before commissions), if lots are tracked.
+    ///
+    /// This differs from the `realized_pnl` calculated from the average open price when only
+    /// part of the position has been closed.
+    #[must_use]
+    pub fn lots_realized_pnl(&self) -> Option<Money> {
+        self.lot_matching?;
+        let pnl = self
+            .closed_lots
+            .iter()
+            .fold(Money::zero(self.settlement_currency), |pnl, lot| {
+                pnl + lot.realized_pnl
+            });
+        Some(pnl)
+    }
+
+    /// Returns the unrealized PnL of the open lots at the `last` price, if lots are tracked.
+    ///
+    /// The PnL of the lots is summed exactly, then rounded once to the settlement currency
+    /// precision.
+    #[must_use]
+    pub fn lots_unrealized_pnl(&self, last: Price) -> Option<Money> {
+        self.lot_matching?;
+        let pnl = self
+            .lots
+            .iter()
+            .map(|lot| self.calculate_lot_pnl_exact(lot.side, lot.px_open, last, lot.remaining_qty))
+            .sum();
+        Some(Money::from_decimal(pnl, self.settlement_currency).expect(FAILED))
+    }
+
+    fn update_lots(&mut self, fill: &OrderFilled, lot_ids: &[TradeId]) {
+        let Some(method) = self.lot_matching else {
+            return; // Lots not tracked
+        };
+
+        let mut remaining_raw = fill.last_qty.raw;
+
+        while remaining_raw > 0 {
+            let Some(index) = self.next_lot_index(method, fill.order_side, lot_ids) else {
+                break; // No open lots to close
+            };
+
+            let lot = self.lots[index];
+            let closed_raw = lot.remaining_qty.raw.min(remaining_raw);
+            let closed_qty = Quantity::from_raw(closed_raw, self.size_precision);
+            let pnl = self.calculate_lot_pnl_exact(lot.side, lot.px_open, fill.last_px, closed_qty);
+
+            self.closed_lots.push(ClosedLot {
+                lot_id: lot.lot_id,
+                closing_trade_id: fill.trade_id,
+                side: lot.side,
+                quantity: closed_qty,
+                px_open: lot.px_open,
+                px_close: fill.last_px,
+                realized_pnl: Money::from_decimal(pnl, self.settlement_currency).expect(FAILED),
+                ts_opened: lot.ts_opened,
+                ts_closed: fill.ts_event,
+            });
+
+            remaining_raw -= closed_raw;
+            if closed_raw == lot.remaining_qty.raw {
+                self.lots.remove(index);
+            } else {
+                self.lots[index].remaining_qty =
+                    Quantity::from_raw(lot.remaining_qty.raw - closed_raw, self.size_precision);
+            }
+        }
+
+        // Any quantity not closing a lot opens a new lot
+        if remaining_raw > 0 {
+            let quantity = Quantity::from_raw(remaining_raw, self.size_precision);
+            self.lots.push(PositionLot {
+                lot_id: fill.trade_id,
+                side: fill.order_side,
+                quantity,
+                remaining_qty: quantity,
+                px_open: fill.last_px,
+                ts_opened: fill.ts_event,
+            });
+        }
+    }
+
+    fn next_lot_index(
+        &self,
+        method: LotMatchingMethod,
+        closing_side: OrderSide,
+        lot_ids: &[TradeId],
+    ) -> Option<usize> {
+        let open_lots = || {
+            self.lots
+                .iter()
+                .enumerate()
+                .filter(move |(_, lot)| lot.side != closing_side)
+        };
+
+        let specific_lot = lot_ids.iter().find_map(|lot_id| {
+            open_lots()
+                .find(|(_, lot)| lot.lot_id == *lot_id)
+                .map(|(index, _)| index)
+        });
+        if specific_lot.is_some() {
+            return specific_lot;
+        }
+
+        let lot = match method {
+            LotMatchingMethod::Fifo => open_lots().next(),
+            LotMatchingMethod::Lifo => open_lots().next_back(),
+            LotMatchingMethod::HighestCost => open_lots().reduce(|highest, next| {
+                if next.1.px_open > highest.1.px_open {
+                    next
+                } else {
+                    highest
+                }
+            }),
+        };
+        lot.map(|(index, _)| index)
+    }
+
+    fn calculate_lot_pnl_exact(
+        &self,
+        side: OrderSide,
+        px_open: Price,
+        px_close: Price,
+        quantity: Quantity,
+    ) -> Decimal {
+        let (px_open, px_close) = (px_open.as_decimal(), px_close.as_decimal());
+        let points = if self.is_inverse {
+            if px_open.is_zero() || px_close.is_zero() {
+                return Decimal::ZERO;
+            }
+            Decimal::ONE / px_open - Decimal::ONE / px_close
+        } else {
+            px_close - px_open
+        };
+        let pnl = points * quantity.as_decimal() * self.multiplier.as_decimal();
+        if side == OrderSide::Buy {
+            pnl
+        } else {
+            -pnl
+        }
+    }
+
+    /// Applies the given corporate `action` to the position.
+    ///
+    /// Splits and reverse splits scale the position quantities by the split ratio, and the
+    /// average prices inversely, so the position value is unchanged. Quantities are scaled with
+    /// [`CorporateAction::adjust_quantity`], the same as historical sizes, so the size precision
+    /// is increased as required rather than rounding fractional shares away.
+    /// Cash dividends do not change the position, see [`Position::calculate_dividend`].
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the `action.instrument_id` is not equal to the position `instrument_id`.
+    /// - If a scaled quantity is out of range.
+    pub fn apply_corporate_action(&mut self, action: &CorporateAction) -> anyhow::Result<()> {
+        anyhow::ensure!(
+            action.instrument_id == self.instrument_id,
+            "`action.instrument_id` {} was not equal to `self.instrument_id` {}",
+            action.instrument_id,
+            self.instrument_id,
+        );
+
+        if !action.is_split() {
+            return Ok(());
+        }
+
+        let quantity = action.adjust_quantity(self.quantity)?;
+        let peak_qty = action.adjust_quantity(self.peak_qty)?;
+        let buy_qty = action.adjust_quantity(self.buy_qty)?;
+        let sell_qty = action.adjust_quantity(self.sell_qty)?;
+        let precision = [quantity, peak_qty, buy_qty, sell_qty]
+            .iter()
+            .map(|qty| qty.precision)
+            .max()
+            .unwrap_or(self.size_precision);
+        let with_precision = |qty: Quantity| Quantity::from_decimal(qty.as_decimal(), precision);
+
+        self.quantity = with_precision(quantity)?;
+        self.peak_qty = with_precision(peak_qty)?;
+        self.buy_qty = with_precision(buy_qty)?;
+        self.sell_qty = with_precision(sell_qty)?;
+        self.size_precision = precision;
+        self.signed_qty = self.signed_decimal_qty().to_f64().unwrap_or_default();
+
+        let split_to = Decimal::from(action.split_to);
+        let split_from = Decimal::from(action.split_from);
+        let scale_px = |px: Decimal| {
+            (px * split_from / split_to)
+                .round_dp_with_strategy(AVG_PX_DECIMALS, RoundingStrategy::MidpointAwayFromZero)
+        };
+        self.set_avg_px_open(scale_px(self.avg_px_open_exact));
+        if let Some(avg_px_close) = self.avg_px_close_exact {
+            self.set_avg_px_close(scale_px(avg_px_close));
+        }
+
+        Ok(())
+    }
+
+    /// Returns the cash dividend payable on the position for the given corporate `action`.
+    ///
+    /// The amount is negative for short positions, which pay the dividend.
+    #[must_use]
+    pub fn calculate_dividend(&self, action: &CorporateAction) -> Option<Money> {
+        if action.instrument_id != self.instrument_id || !self.is_open() {
+            return None;
+        }
+
+        action.dividend.map(|dividend| {
+            let amount =
+                dividend.as_decimal() * self.signed_decimal_qty() * self.multiplier.as_decimal();
+            Money::from_decimal(amount, dividend.currency).expect(FAILED)
+        })
+    }
 }
 
 impl PartialEq<Self> for Position {
@@ -529,9 +942,16 @@ mod tests {
     use rstest::rstest;
 
     use crate::{
-        enums::{LiquiditySide, OrderSide, OrderType, PositionSide},
+        data::CorporateAction,
+        enums::{
+            CorporateActionType, LiquiditySide, LotMatchingMethod, OrderSide, OrderType,
+            PositionSide,
+        },
         events::OrderFilled,
-        identifiers::{stubs::uuid4, AccountId, PositionId, StrategyId, TradeId, VenueOrderId},
+        identifiers::{
+            stubs::uuid4, AccountId, ClientOrderId, InstrumentId, PositionId, StrategyId, TradeId,
+            TraderId, VenueOrderId,
+        },
         instruments::{stubs::*, CryptoPerpetual, CurrencyPair, InstrumentAny},
         orders::{builder::OrderTestBuilder, stubs::TestOrderEventStubs},
         position::Position,
@@ -551,6 +971,117 @@ mod tests {
         assert_eq!(display, "Position(SHORT 1 AUD/USD.SIM, id=1)");
     }
 
+    #[rstest]
+    fn test_apply_corporate_action_split(mut stub_position_long: Position) {
+        let action = CorporateAction::new(
+            stub_position_long.instrument_id,
+            CorporateActionType::Split,
+            1,
+            4,
+            None,
+            UnixNanos::from(10),
+            UnixNanos::from(1),
+            UnixNanos::from(1),
+        );
+
+        stub_position_long.apply_corporate_action(&action).unwrap();
+
+        assert_eq!(stub_position_long.signed_qty, 4.0);
+        assert_eq!(stub_position_long.quantity, Quantity::from(4));
+        assert_eq!(stub_position_long.peak_qty, Quantity::from(4));
+        assert_eq!(stub_position_long.buy_qty, Quantity::from(4));
+        assert_eq!(stub_position_long.avg_px_open, 0.25005);
+        assert_eq!(
+            stub_position_long.notional_value(Price::from("0.25005")),
+            Money::from("1.00 USD")
+        );
+    }
+
+    #[rstest]
+    fn test_apply_corporate_action_split_keeps_fractional_quantity(
+        mut stub_position_long: Position,
+    ) {
+        let action = CorporateAction::new(
+            stub_position_long.instrument_id,
+            CorporateActionType::Split,
+            2,
+            3,
+            None,
+            UnixNanos::from(10),
+            UnixNanos::from(1),
+            UnixNanos::from(1),
+        );
+
+        stub_position_long.apply_corporate_action(&action).unwrap();
+
+        assert_eq!(stub_position_long.signed_qty, 1.5);
+        assert_eq!(stub_position_long.quantity, Quantity::from("1.5"));
+        assert_eq!(stub_position_long.size_precision, 1);
+        assert_eq!(
+            stub_position_long.notional_value(Price::from("0.6668")),
+            Money::from("1.00 USD")
+        );
+    }
+
+    #[rstest]
+    fn test_apply_corporate_action_with_other_instrument_errors(mut stub_position_long: Position) {
+        let action = CorporateAction::new(
+            InstrumentId::from("AAPL.XNAS"),
+            CorporateActionType::Split,
+            1,
+            2,
+            None,
+            UnixNanos::from(10),
+            UnixNanos::from(1),
+            UnixNanos::from(1),
+        );
+
+        assert!(stub_position_long.apply_corporate_action(&action).is_err());
+        assert_eq!(stub_position_long.quantity, Quantity::from(1));
+    }
+
+    #[rstest]
+    fn test_apply_corporate_action_dividend_leaves_position_unchanged(
+        mut stub_position_long: Position,
+    ) {
+        let action = CorporateAction::new(
+            stub_position_long.instrument_id,
+            CorporateActionType::CashDividend,
+            1,
+            1,
+            Some(Money::from("0.10 USD")),
+            UnixNanos::from(10),
+            UnixNanos::from(1),
+            UnixNanos::from(1),
+        );
+
+        stub_position_long.apply_corporate_action(&action).unwrap();
+
+        assert_eq!(stub_position_long.quantity, Quantity::from(1));
+        assert_eq!(stub_position_long.avg_px_open, 1.0002);
+    }
+
+    #[rstest]
+    #[case(stub_position_long(audusd_sim()), "0.10 USD")]
+    #[case(stub_position_short(audusd_sim()), "-0.10 USD")]
+    fn test_calculate_dividend(#[case] position: Position, #[case] expected: &str) {
+        let action = CorporateAction::new(
+            position.instrument_id,
+            CorporateActionType::CashDividend,
+            1,
+            1,
+            Some(Money::from("0.10 USD")),
+            UnixNanos::from(10),
+            UnixNanos::from(1),
+            UnixNanos::from(1),
+        );
+
+        assert_eq!(
+            position.calculate_dividend(&action),
+            Some(Money::from(expected))
+        );
+    }
+
     #[rstest]
     #[should_panic(expected = "`fill.trade_id` already contained in `trade_ids")]
     fn test_two_trades_with_same_trade_id_error(audusd_sim: CurrencyPair) {
@@ -866,7 +1397,7 @@ mod tests {
         assert!(!position.is_short());
         assert!(!position.is_open());
         assert!(position.is_closed());
-        assert_eq!(position.realized_return, 9.999_900_000_998_888e-5);
+        assert_eq!(position.realized_return, 9.999_900_000_999_99e-5);
         assert_eq!(position.realized_pnl, Some(Money::from("13.0 USD")));
         assert_eq!(position.unrealized_pnl(last), Money::from("0 USD"));
         assert_eq!(position.commissions(), vec![Money::from("2 USD")]);
@@ -1689,6 +2220,59 @@ mod tests {
         );
     }
 
+    #[rstest]
+    fn test_calculate_pnl_for_inverse_with_zero_price(xbtusd_bitmex: CryptoPerpetual) {
+        let xbtusd_bitmex = InstrumentAny::CryptoPerpetual(xbtusd_bitmex);
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(xbtusd_bitmex.id())
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from("100000"))
+            .build();
+        let fill = TestOrderEventStubs::order_filled(
+            &order,
+            &xbtusd_bitmex,
+            None,
+            Some(PositionId::from("P-123456")),
+            Some(Price::from("10000.0")),
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        let position = Position::new(&xbtusd_bitmex, fill.into());
+
+        let pnl = position.calculate_pnl(10000.0, 0.0, Quantity::from("100000"));
+
+        assert_eq!(pnl, Money::from("0 BTC"));
+    }
+
+    #[rstest]
+    #[should_panic(expected = "Condition failed")]
+    fn test_calculate_pnl_with_non_finite_price_panics(audusd_sim: CurrencyPair) {
+        let audusd_sim = InstrumentAny::CurrencyPair(audusd_sim);
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(audusd_sim.id())
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from(100_000))
+            .build();
+        let fill = TestOrderEventStubs::order_filled(
+            &order,
+            &audusd_sim,
+            None,
+            Some(PositionId::from("P-123456")),
+            Some(Price::from("1.00001")),
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        let position = Position::new(&audusd_sim, fill.into());
+
+        let _ = position.calculate_pnl(1.00001, f64::NAN, Quantity::from(100_000));
+    }
+
     #[rstest]
     fn test_calculate_pnl_for_inverse2(ethusdt_bitmex: CryptoPerpetual) {
         let ethusdt_bitmex = InstrumentAny::CryptoPerpetual(ethusdt_bitmex);
@@ -1945,4 +2529,239 @@ mod tests {
         let position = Position::new(&audusd_sim, fill);
         assert_eq!(position.realized_pnl, Some(Money::from("0 USD")));
     }
+
+    fn lot_fill(
+        instrument: &InstrumentAny,
+        trade_id: &str,
+        side: OrderSide,
+        quantity: u64,
+        price: &str,
+        ts_event: u64,
+    ) -> OrderFilled {
+        OrderFilled::new(
+            TraderId::from("TRADER-001"),
+            StrategyId::new("S-001"),
+            instrument.id(),
+            ClientOrderId::from(format!("O-{trade_id}").as_str()),
+            VenueOrderId::from(trade_id),
+            AccountId::new("SIM-001"),
+            TradeId::new(trade_id),
+            side,
+            OrderType::Market,
+            Quantity::from(quantity),
+            Price::from(price),
+            instrument.quote_currency(),
+            LiquiditySide::Taker,
+            uuid4(),
+            ts_event.into(),
+            ts_event.into(),
+            false,
+            Some(PositionId::new("P-1")),
+            None,
+        )
+    }
+
+    fn lot_position(instrument: &InstrumentAny, method: LotMatchingMethod) -> Position {
+        let mut position = Position::new(
+            instrument,
+            lot_fill(instrument, "1", OrderSide::Buy, 100, "10.00", 1),
+        );
+        position.set_lot_matching(Some(method));
+        position.apply(&lot_fill(instrument, "2", OrderSide::Buy, 100, "12.00", 2));
+        position
+    }
+
+    #[rstest]
+    #[case(LotMatchingMethod::Fifo, "1", "300 USD")]
+    #[case(LotMatchingMethod::Lifo, "2", "100 USD")]
+    #[case(LotMatchingMethod::HighestCost, "2", "100 USD")]
+    fn test_lot_matching_realized_pnl(
+        #[case] method: LotMatchingMethod,
+        #[case] expected_lot_id: &str,
+        #[case] expected_pnl: &str,
+        audusd_sim: CurrencyPair,
+    ) {
+        let audusd_sim = InstrumentAny::CurrencyPair(audusd_sim);
+        let mut position = lot_position(&audusd_sim, method);
+
+        position.apply(&lot_fill(
+            &audusd_sim,
+            "3",
+            OrderSide::Sell,
+            100,
+            "13.00",
+            5,
+        ));
+
+        assert_eq!(position.closed_lots.len(), 1);
+        let closed_lot = position.closed_lots[0];
+        assert_eq!(closed_lot.lot_id, TradeId::new(expected_lot_id));
+        assert_eq!(closed_lot.closing_trade_id, TradeId::new("3"));
+        assert_eq!(closed_lot.quantity, Quantity::from(100));
+        assert_eq!(position.lots.len(), 1);
+        assert_ne!(position.lots[0].lot_id, TradeId::new(expected_lot_id));
+        assert_eq!(
+            position.lots_realized_pnl(),
+            Some(Money::from(expected_pnl))
+        );
+        // Average cost realized PnL is unchanged by the lot matching method
+        assert_eq!(position.realized_pnl, Some(Money::from("200 USD")));
+    }
+
+    #[rstest]
+    fn test_lot_matching_partial_close_across_lots(audusd_sim: CurrencyPair) {
+        let audusd_sim = InstrumentAny::CurrencyPair(audusd_sim);
+        let mut position = lot_position(&audusd_sim, LotMatchingMethod::Fifo);
+
+        position.apply(&lot_fill(
+            &audusd_sim,
+            "3",
+            OrderSide::Sell,
+            150,
+            "13.00",
+            5,
+        ));
+
+        assert_eq!(position.closed_lots.len(), 2);
+        assert_eq!(position.closed_lots[1].lot_id, TradeId::new("2"));
+        assert_eq!(position.closed_lots[1].quantity, Quantity::from(50));
+        assert_eq!(position.lots.len(), 1);
+        assert_eq!(position.lots[0].quantity, Quantity::from(100));
+        assert_eq!(position.lots[0].remaining_qty, Quantity::from(50));
+        assert_eq!(position.lots_realized_pnl(), Some(Money::from("350 USD")));
+        assert_eq!(
+            position.lots_unrealized_pnl(Price::from("14.00")),
+            Some(Money::from("100 USD"))
+        );
+    }
+
+    #[rstest]
+    fn test_lot_matching_flip_opens_opposite_lot(audusd_sim: CurrencyPair) {
+        let audusd_sim = InstrumentAny::CurrencyPair(audusd_sim);
+        let mut position = lot_position(&audusd_sim, LotMatchingMethod::Fifo);
+
+        position.apply(&lot_fill(
+            &audusd_sim,
+            "3",
+            OrderSide::Sell,
+            250,
+            "13.00",
+            5,
+        ));
+
+        assert_eq!(position.side, PositionSide::Short);
+        assert_eq!(position.closed_lots.len(), 2);
+        assert_eq!(position.lots.len(), 1);
+        assert_eq!(position.lots[0].lot_id, TradeId::new("3"));
+        assert_eq!(position.lots[0].side, OrderSide::Sell);
+        assert_eq!(position.lots[0].remaining_qty, Quantity::from(50));
+        assert_eq!(
+            position.lots_unrealized_pnl(Price::from("12.00")),
+            Some(Money::from("50 USD"))
+        );
+    }
+
+    #[rstest]
+    fn test_apply_with_lots_closes_specific_lot(audusd_sim: CurrencyPair) {
+        let audusd_sim = InstrumentAny::CurrencyPair(audusd_sim);
+        let mut position = lot_position(&audusd_sim, LotMatchingMethod::Fifo);
+
+        position.apply_with_lots(
+            &lot_fill(&audusd_sim, "3", OrderSide::Sell, 150, "13.00", 5),
+            &[TradeId::new("2")],
+        );
+
+        // Specified lot closed first, then the remainder by FIFO
+        assert_eq!(position.closed_lots[0].lot_id, TradeId::new("2"));
+        assert_eq!(position.closed_lots[0].quantity, Quantity::from(100));
+        assert_eq!(position.closed_lots[1].lot_id, TradeId::new("1"));
+        assert_eq!(position.closed_lots[1].quantity, Quantity::from(50));
+        assert_eq!(position.lots_realized_pnl(), Some(Money::from("250 USD")));
+    }
+
+    #[rstest]
+    fn test_closed_lot_holding_period(audusd_sim: CurrencyPair) {
+        let audusd_sim = InstrumentAny::CurrencyPair(audusd_sim);
+        let mut position = lot_position(&audusd_sim, LotMatchingMethod::Fifo);
+
+        position.apply(&lot_fill(
+            &audusd_sim,
+            "3",
+            OrderSide::Sell,
+            100,
+            "13.00",
+            5,
+        ));
+
+        assert_eq!(position.closed_lots[0].holding_period_ns(), 4);
+    }
+
+    #[rstest]
+    fn test_set_lot_matching_rebuilds_lots(audusd_sim: CurrencyPair) {
+        let audusd_sim = InstrumentAny::CurrencyPair(audusd_sim);
+        let mut position = lot_position(&audusd_sim, LotMatchingMethod::Fifo);
+        position.apply(&lot_fill(
+            &audusd_sim,
+            "3",
+            OrderSide::Sell,
+            100,
+            "13.00",
+            5,
+        ));
+
+        position.set_lot_matching(Some(LotMatchingMethod::Lifo));
+
+        assert_eq!(position.closed_lots.len(), 1);
+        assert_eq!(position.closed_lots[0].lot_id, TradeId::new("2"));
+        assert_eq!(position.lots_realized_pnl(), Some(Money::from("100 USD")));
+
+        position.set_lot_matching(None);
+
+        assert!(position.lots.is_empty());
+        assert!(position.closed_lots.is_empty());
+        assert_eq!(position.lots_realized_pnl(), None);
+    }
+
+    #[rstest]
+    fn test_set_lot_matching_keeps_specific_lots(audusd_sim: CurrencyPair) {
+        let audusd_sim = InstrumentAny::CurrencyPair(audusd_sim);
+        let mut position = lot_position(&audusd_sim, LotMatchingMethod::Fifo);
+        position.apply_with_lots(
+            &lot_fill(&audusd_sim, "3", OrderSide::Sell, 150, "13.00", 5),
+            &[TradeId::new("1")],
+        );
+
+        position.set_lot_matching(Some(LotMatchingMethod::Lifo));
+
+        // Specified lot still closed first, then the remainder by LIFO
+        assert_eq!(position.closed_lots.len(), 2);
+        assert_eq!(position.closed_lots[0].lot_id, TradeId::new("1"));
+        assert_eq!(position.closed_lots[0].quantity, Quantity::from(100));
+        assert_eq!(position.closed_lots[1].lot_id, TradeId::new("2"));
+        assert_eq!(position.closed_lots[1].quantity, Quantity::from(50));
+        assert_eq!(position.lots[0].lot_id, TradeId::new("2"));
+        assert_eq!(position.lots[0].remaining_qty, Quantity::from(50));
+    }
+
+    #[rstest]
+    fn test_lots_cleared_when_position_reopened(audusd_sim: CurrencyPair) {
+        let audusd_sim = InstrumentAny::CurrencyPair(audusd_sim);
+        let mut position = lot_position(&audusd_sim, LotMatchingMethod::Fifo);
+        position.apply(&lot_fill(
+            &audusd_sim,
+            "3",
+            OrderSide::Sell,
+            200,
+            "13.00",
+            5,
+        ));
+        assert!(position.is_closed());
+
+        position.apply(&lot_fill(&audusd_sim, "4", OrderSide::Buy, 100, "11.00", 6));
+
+        assert_eq!(position.lot_matching, Some(LotMatchingMethod::Fifo));
+        assert!(position.closed_lots.is_empty());
+        assert_eq!(position.lots.len(), 1);
+        assert_eq!(position.lots[0].lot_id, TradeId::new("4"));
+    }
 }
@@ -164,9 +164,18 @@ impl MarginAccount {
     ) -> PyResult<Money> {
         let instrument_type = pyobject_to_instrument_any(py, instrument)?;
         match instrument_type {
+            InstrumentAny::Cfd(inst) => {
+                Ok(self.calculate_initial_margin(inst, quantity, price, use_quote_for_inverse))
+            }
+            InstrumentAny::Commodity(inst) => {
+                Ok(self.calculate_initial_margin(inst, quantity, price, use_quote_for_inverse))
+            }
             InstrumentAny::CryptoFuture(inst) => {
                 Ok(self.calculate_initial_margin(inst, quantity, price, use_quote_for_inverse))
             }
+            InstrumentAny::CryptoOption(inst) => {
+                Ok(self.calculate_initial_margin(inst, quantity, price, use_quote_for_inverse))
+            }
             InstrumentAny::CryptoPerpetual(inst) => {
                 Ok(self.calculate_initial_margin(inst, quantity, price, use_quote_for_inverse))
             }
@@ -198,9 +207,18 @@ impl MarginAccount {
     ) -> PyResult<Money> {
         let instrument_type = pyobject_to_instrument_any(py, instrument)?;
         match instrument_type {
+            InstrumentAny::Cfd(inst) => {
+                Ok(self.calculate_maintenance_margin(inst, quantity, price, use_quote_for_inverse))
+            }
+            InstrumentAny::Commodity(inst) => {
+                Ok(self.calculate_maintenance_margin(inst, quantity, price, use_quote_for_inverse))
+            }
             InstrumentAny::CryptoFuture(inst) => {
                 Ok(self.calculate_maintenance_margin(inst, quantity, price, use_quote_for_inverse))
             }
+            InstrumentAny::CryptoOption(inst) => {
+                Ok(self.calculate_maintenance_margin(inst, quantity, price, use_quote_for_inverse))
+            }
             InstrumentAny::CryptoPerpetual(inst) => {
                 Ok(self.calculate_maintenance_margin(inst, quantity, price, use_quote_for_inverse))
             }
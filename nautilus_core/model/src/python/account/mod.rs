@@ -21,7 +21,9 @@ use nautilus_core::python::to_pyvalue_err;
 use pyo3::{IntoPy, PyObject, PyResult, Python};
 
 use crate::{
-    accounts::{any::AccountAny, cash::CashAccount, margin::MarginAccount},
+    accounts::{
+        any::AccountAny, betting::BettingAccount, cash::CashAccount, margin::MarginAccount,
+    },
     enums::AccountType,
 };
 
@@ -35,6 +37,9 @@ pub fn convert_pyobject_to_account_any(py: Python, account: PyObject) -> PyResul
     } else if account_type == AccountType::Margin {
         let margin = account.extract::<MarginAccount>(py)?;
         Ok(AccountAny::Margin(margin))
+    } else if account_type == AccountType::Betting {
+        let betting = account.extract::<BettingAccount>(py)?;
+        Ok(AccountAny::Betting(betting))
     } else {
         Err(to_pyvalue_err("Unsupported account type"))
     }
@@ -44,5 +49,6 @@ pub fn convert_account_any_to_pyobject(py: Python, account: AccountAny) -> PyRes
     match account {
         AccountAny::Cash(account) => Ok(account.into_py(py)),
         AccountAny::Margin(account) => Ok(account.into_py(py)),
+        AccountAny::Betting(account) => Ok(account.into_py(py)),
     }
 }
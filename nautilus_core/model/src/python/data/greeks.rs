@@ -16,8 +16,8 @@
 use pyo3::prelude::*;
 
 use crate::data::greeks::{
-    black_scholes_greeks, imply_vol, imply_vol_and_greeks, BlackScholesGreeksResult,
-    ImplyVolAndGreeksResult,
+    american_option_greeks, black76_greeks, black_scholes_greeks, imply_vol, imply_vol_and_greeks,
+    imply_vol_black76, BlackScholesGreeksResult, ImplyVolAndGreeksResult,
 };
 
 #[pymethods]
@@ -173,3 +173,49 @@ pub fn py_imply_vol_and_greeks(
     let result = imply_vol_and_greeks(s, r, b, is_call, k, t, price, multiplier);
     Ok(result)
 }
+
+#[pyfunction]
+#[pyo3(name = "black76_greeks")]
+pub fn py_black76_greeks(
+    f: f64,
+    r: f64,
+    sigma: f64,
+    is_call: bool,
+    k: f64,
+    t: f64,
+    multiplier: f64,
+) -> PyResult<BlackScholesGreeksResult> {
+    let result = black76_greeks(f, r, sigma, is_call, k, t, multiplier);
+    Ok(result)
+}
+
+#[pyfunction]
+#[pyo3(name = "imply_vol_black76")]
+pub fn py_imply_vol_black76(
+    f: f64,
+    r: f64,
+    is_call: bool,
+    k: f64,
+    t: f64,
+    price: f64,
+) -> PyResult<f64> {
+    let vol = imply_vol_black76(f, r, is_call, k, t, price);
+    Ok(vol)
+}
+
+#[pyfunction]
+#[pyo3(name = "american_option_greeks")]
+#[allow(clippy::too_many_arguments)]
+pub fn py_american_option_greeks(
+    s: f64,
+    r: f64,
+    b: f64,
+    sigma: f64,
+    is_call: bool,
+    k: f64,
+    t: f64,
+    multiplier: f64,
+) -> PyResult<BlackScholesGreeksResult> {
+    let result = american_option_greeks(s, r, b, sigma, is_call, k, t, multiplier);
+    Ok(result)
+}
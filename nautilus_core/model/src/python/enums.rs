@@ -23,10 +23,10 @@ use pyo3::{prelude::*, types::PyType, PyTypeInfo};
 use crate::{
     enums::{
         AccountType, AggregationSource, AggressorSide, AssetClass, BarAggregation, BookAction,
-        BookType, ContingencyType, CurrencyType, InstrumentClass, InstrumentCloseType,
-        LiquiditySide, MarketStatus, MarketStatusAction, OmsType, OptionKind, OrderSide,
-        OrderStatus, OrderType, PositionSide, PriceType, RecordFlag, TimeInForce, TradingState,
-        TrailingOffsetType, TriggerType,
+        BookType, ContingencyType, CorporateActionType, CurrencyType, InstrumentClass,
+        InstrumentCloseType, LiquiditySide, LotMatchingMethod, MarketStatus, MarketStatusAction,
+        OmsType, OptionKind, OrderSide, OrderStatus, OrderType, PositionSide, PriceType,
+        RecordFlag, TimeInForce, TradingState, TrailingOffsetType, TriggerType,
     },
     python::common::EnumIterator,
 };
@@ -726,6 +726,75 @@ impl ContingencyType {
     }
 }
 
+#[pymethods]
+impl CorporateActionType {
+    #[new]
+    fn py_new(py: Python<'_>, value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let t = Self::type_object(py);
+        Self::py_from_str(&t, value)
+    }
+
+    fn __hash__(&self) -> isize {
+        *self as isize
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "<{}.{}: '{}'>",
+            stringify!(CorporateActionType),
+            self.name(),
+            self.value(),
+        )
+    }
+
+    fn __str__(&self) -> String {
+        self.to_string()
+    }
+
+    #[getter]
+    #[must_use]
+    pub fn name(&self) -> String {
+        self.to_string()
+    }
+
+    #[getter]
+    #[must_use]
+    pub fn value(&self) -> u8 {
+        *self as u8
+    }
+
+    #[classmethod]
+    fn variants(_: &Bound<'_, PyType>, py: Python<'_>) -> EnumIterator {
+        EnumIterator::new::<Self>(py)
+    }
+
+    #[classmethod]
+    #[pyo3(name = "from_str")]
+    fn py_from_str(_: &Bound<'_, PyType>, data: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let data_str: &str = data.extract()?;
+        let tokenized = data_str.to_uppercase();
+        Self::from_str(&tokenized).map_err(to_pyvalue_err)
+    }
+
+    #[classattr]
+    #[pyo3(name = "SPLIT")]
+    fn py_split() -> Self {
+        Self::Split
+    }
+
+    #[classattr]
+    #[pyo3(name = "REVERSE_SPLIT")]
+    fn py_reverse_split() -> Self {
+        Self::ReverseSplit
+    }
+
+    #[classattr]
+    #[pyo3(name = "CASH_DIVIDEND")]
+    fn py_cash_dividend() -> Self {
+        Self::CashDividend
+    }
+}
+
 #[pymethods]
 impl CurrencyType {
     #[new]
@@ -927,6 +996,75 @@ impl LiquiditySide {
     }
 }
 
+#[pymethods]
+impl LotMatchingMethod {
+    #[new]
+    fn py_new(py: Python<'_>, value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let t = Self::type_object(py);
+        Self::py_from_str(&t, value)
+    }
+
+    fn __hash__(&self) -> isize {
+        *self as isize
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "<{}.{}: '{}'>",
+            stringify!(LotMatchingMethod),
+            self.name(),
+            self.value(),
+        )
+    }
+
+    fn __str__(&self) -> String {
+        self.to_string()
+    }
+
+    #[getter]
+    #[must_use]
+    pub fn name(&self) -> String {
+        self.to_string()
+    }
+
+    #[getter]
+    #[must_use]
+    pub fn value(&self) -> u8 {
+        *self as u8
+    }
+
+    #[classmethod]
+    fn variants(_: &Bound<'_, PyType>, py: Python<'_>) -> EnumIterator {
+        EnumIterator::new::<Self>(py)
+    }
+
+    #[classmethod]
+    #[pyo3(name = "from_str")]
+    fn py_from_str(_: &Bound<'_, PyType>, data: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let data_str: &str = data.extract()?;
+        let tokenized = data_str.to_uppercase();
+        Self::from_str(&tokenized).map_err(to_pyvalue_err)
+    }
+
+    #[classattr]
+    #[pyo3(name = "FIFO")]
+    fn py_fifo() -> Self {
+        Self::Fifo
+    }
+
+    #[classattr]
+    #[pyo3(name = "LIFO")]
+    fn py_lifo() -> Self {
+        Self::Lifo
+    }
+
+    #[classattr]
+    #[pyo3(name = "HIGHEST_COST")]
+    fn py_highest_cost() -> Self {
+        Self::HighestCost
+    }
+}
+
 #[pymethods]
 impl MarketStatus {
     #[new]
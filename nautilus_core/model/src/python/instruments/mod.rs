@@ -19,18 +19,23 @@ use nautilus_core::python::to_pyvalue_err;
 use pyo3::{IntoPy, PyObject, PyResult, Python};
 
 use crate::instruments::{
-    BettingInstrument, BinaryOption, CryptoFuture, CryptoPerpetual, CurrencyPair, Equity,
-    FuturesContract, FuturesSpread, InstrumentAny, OptionContract, OptionSpread,
+    BettingInstrument, BinaryOption, Cfd, Commodity, CryptoFuture, CryptoOption, CryptoPerpetual,
+    CurrencyPair, Equity, FuturesContract, FuturesSpread, IndexInstrument, InstrumentAny,
+    OptionContract, OptionSpread,
 };
 
 pub mod betting;
 pub mod binary_option;
+pub mod cfd;
+pub mod commodity;
 pub mod crypto_future;
+pub mod crypto_option;
 pub mod crypto_perpetual;
 pub mod currency_pair;
 pub mod equity;
 pub mod futures_contract;
 pub mod futures_spread;
+pub mod index_instrument;
 pub mod option_contract;
 pub mod option_spread;
 
@@ -38,12 +43,16 @@ pub fn instrument_any_to_pyobject(py: Python, instrument: InstrumentAny) -> PyRe
     match instrument {
         InstrumentAny::Betting(inst) => Ok(inst.into_py(py)),
         InstrumentAny::BinaryOption(inst) => Ok(inst.into_py(py)),
+        InstrumentAny::Cfd(inst) => Ok(inst.into_py(py)),
+        InstrumentAny::Commodity(inst) => Ok(inst.into_py(py)),
         InstrumentAny::CryptoFuture(inst) => Ok(inst.into_py(py)),
+        InstrumentAny::CryptoOption(inst) => Ok(inst.into_py(py)),
         InstrumentAny::CryptoPerpetual(inst) => Ok(inst.into_py(py)),
         InstrumentAny::CurrencyPair(inst) => Ok(inst.into_py(py)),
         InstrumentAny::Equity(inst) => Ok(inst.into_py(py)),
         InstrumentAny::FuturesContract(inst) => Ok(inst.into_py(py)),
         InstrumentAny::FuturesSpread(inst) => Ok(inst.into_py(py)),
+        InstrumentAny::IndexInstrument(inst) => Ok(inst.into_py(py)),
         InstrumentAny::OptionContract(inst) => Ok(inst.into_py(py)),
         InstrumentAny::OptionSpread(inst) => Ok(inst.into_py(py)),
     }
@@ -57,9 +66,16 @@ pub fn pyobject_to_instrument_any(py: Python, instrument: PyObject) -> PyResult<
         stringify!(BinaryOption) => Ok(InstrumentAny::BinaryOption(
             instrument.extract::<BinaryOption>(py)?,
         )),
+        stringify!(Cfd) => Ok(InstrumentAny::Cfd(instrument.extract::<Cfd>(py)?)),
+        stringify!(Commodity) => Ok(InstrumentAny::Commodity(
+            instrument.extract::<Commodity>(py)?,
+        )),
         stringify!(CryptoFuture) => Ok(InstrumentAny::CryptoFuture(
             instrument.extract::<CryptoFuture>(py)?,
         )),
+        stringify!(CryptoOption) => Ok(InstrumentAny::CryptoOption(
+            instrument.extract::<CryptoOption>(py)?,
+        )),
         stringify!(CryptoPerpetual) => Ok(InstrumentAny::CryptoPerpetual(
             instrument.extract::<CryptoPerpetual>(py)?,
         )),
@@ -73,6 +89,9 @@ pub fn pyobject_to_instrument_any(py: Python, instrument: PyObject) -> PyResult<
         stringify!(FuturesSpread) => Ok(InstrumentAny::FuturesSpread(
             instrument.extract::<FuturesSpread>(py)?,
         )),
+        stringify!(IndexInstrument) => Ok(InstrumentAny::IndexInstrument(
+            instrument.extract::<IndexInstrument>(py)?,
+        )),
         stringify!(OptionContract) => Ok(InstrumentAny::OptionContract(
             instrument.extract::<OptionContract>(py)?,
         )),
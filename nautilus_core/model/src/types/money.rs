@@ -24,7 +24,7 @@ use std::{
 };
 
 use nautilus_core::correctness::{check_in_range_inclusive_f64, FAILED};
-use rust_decimal::Decimal;
+use rust_decimal::{prelude::ToPrimitive, Decimal, RoundingStrategy};
 use serde::{Deserialize, Deserializer, Serialize};
 use thousands::Separable;
 
@@ -101,6 +101,34 @@ impl Money {
         Self::new_checked(amount, currency).expect(FAILED)
     }
 
+    /// Creates a new [`Money`] instance from the given decimal `amount` with correctness checking.
+    ///
+    /// The `amount` is rounded to the `currency` precision, with midpoint values rounded away
+    /// from zero (consistent with [`Money::new`]), and is otherwise converted exactly.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `amount` is invalid outside the representable range [{MONEY_MIN}, {MONEY_MAX}].
+    pub fn from_decimal(amount: Decimal, currency: Currency) -> anyhow::Result<Self> {
+        let amount = amount.round_dp_with_strategy(
+            u32::from(currency.precision),
+            RoundingStrategy::MidpointAwayFromZero,
+        );
+        check_in_range_inclusive_f64(
+            amount.to_f64().unwrap_or_default(),
+            MONEY_MIN,
+            MONEY_MAX,
+            "amount",
+        )?;
+
+        let raw = amount.mantissa() * 10_i128.pow(u32::from(FIXED_PRECISION) - amount.scale());
+        #[allow(clippy::useless_conversion)] // Required for precision modes
+        let raw = MoneyRaw::try_from(raw)?;
+
+        Ok(Self { raw, currency })
+    }
+
     /// Creates a new [`Money`] instance from the given `raw` fixed-point value and the specified `currency`.
     #[must_use]
     pub fn from_raw(raw: MoneyRaw, currency: Currency) -> Self {
@@ -445,6 +473,28 @@ mod tests {
         assert!(money.is_ok());
     }
 
+    #[rstest]
+    #[case(dec!(1000.00), Currency::USD(), "1000.00 USD")]
+    #[case(dec!(1.005), Currency::USD(), "1.01 USD")]
+    #[case(dec!(-1.005), Currency::USD(), "-1.01 USD")]
+    #[case(dec!(1.00499999999), Currency::USD(), "1.00 USD")]
+    #[case(dec!(0.123456785), Currency::BTC(), "0.12345679 BTC")]
+    fn test_from_decimal(
+        #[case] amount: Decimal,
+        #[case] currency: Currency,
+        #[case] expected: &str,
+    ) {
+        let money = Money::from_decimal(amount, currency).unwrap();
+        assert_eq!(money, Money::from(expected));
+        assert_eq!(money.to_string(), expected);
+    }
+
+    #[rstest]
+    fn test_from_decimal_out_of_range() {
+        let amount = Decimal::from_f64_retain(MONEY_MAX).unwrap() + dec!(1);
+        assert!(Money::from_decimal(amount, Currency::USD()).is_err());
+    }
+
     #[rstest]
     fn test_money_is_zero() {
         let zero_usd = Money::new(0.0, Currency::USD());
@@ -31,7 +31,7 @@ use nautilus_core::{
     correctness::{check_in_range_inclusive_f64, FAILED},
     parsing::precision_from_str,
 };
-use rust_decimal::Decimal;
+use rust_decimal::{prelude::ToPrimitive, Decimal, RoundingStrategy};
 use serde::{Deserialize, Deserializer, Serialize};
 use thousands::Separable;
 
@@ -129,6 +129,33 @@ impl Quantity {
         Self::new_checked(value, precision).expect(FAILED)
     }
 
+    /// Creates a new [`Quantity`] instance from the given decimal `value` with correctness checking.
+    ///
+    /// The `value` is rounded to the `precision`, with midpoint values rounded away from zero
+    /// (consistent with [`Quantity::new`]), and is otherwise converted exactly.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `value` is invalid outside the representable range [0, {QUANTITY_MAX}].
+    /// - If `precision` is invalid outside the representable range [0, {FIXED_PRECISION}].
+    pub fn from_decimal(value: Decimal, precision: u8) -> anyhow::Result<Self> {
+        check_fixed_precision(precision)?;
+        let value = value
+            .round_dp_with_strategy(u32::from(precision), RoundingStrategy::MidpointAwayFromZero);
+        check_in_range_inclusive_f64(
+            value.to_f64().unwrap_or_default(),
+            QUANTITY_MIN,
+            QUANTITY_MAX,
+            "value",
+        )?;
+
+        let raw = value.mantissa() * 10_i128.pow(u32::from(FIXED_PRECISION) - value.scale());
+        let raw = QuantityRaw::try_from(raw)?;
+
+        Ok(Self { raw, precision })
+    }
+
     /// Creates a new [`Quantity`] instance from the given `raw` fixed-point value and `precision`.
     pub fn from_raw(raw: QuantityRaw, precision: u8) -> Self {
         check_fixed_precision(precision).expect(FAILED);
@@ -563,6 +590,22 @@ mod tests {
         assert!(approx_eq!(f64, qty.as_f64(), 0.00812, epsilon = 0.000_001));
     }
 
+    #[rstest]
+    #[case(dec!(123456789.123456789), 9, dec!(123456789.123456789))]
+    #[case(dec!(1.25), 1, dec!(1.3))]
+    #[case(dec!(1.2499), 1, dec!(1.2))]
+    #[case(dec!(10), 2, dec!(10.00))]
+    fn test_from_decimal(#[case] value: Decimal, #[case] precision: u8, #[case] expected: Decimal) {
+        let qty = Quantity::from_decimal(value, precision).unwrap();
+        assert_eq!(qty.precision, precision);
+        assert_eq!(qty.as_decimal(), expected);
+    }
+
+    #[rstest]
+    fn test_from_decimal_negative_error() {
+        assert!(Quantity::from_decimal(dec!(-1), 0).is_err());
+    }
+
     #[rstest]
     fn test_check_quantity_positive_ok() {
         let qty = Quantity::new(10.0, 0);
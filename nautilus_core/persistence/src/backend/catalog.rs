@@ -13,15 +13,22 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::path::PathBuf;
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
 
 use datafusion::{arrow::record_batch::RecordBatch, error::Result};
 use heck::ToSnakeCase;
 use itertools::Itertools;
 use log::info;
 use nautilus_core::UnixNanos;
-use nautilus_model::data::{
-    Bar, Data, GetTsInit, OrderBookDelta, OrderBookDepth10, QuoteTick, TradeTick,
+use nautilus_model::{
+    data::{
+        Bar, Data, FundingRateUpdate, GetTsInit, IndexPriceUpdate, MarkPriceUpdate, OrderBookDelta,
+        OrderBookDepth10, QuoteTick, TradeTick,
+    },
+    instruments::InstrumentAny,
 };
 use nautilus_serialization::{
     arrow::{DecodeDataFromRecordBatch, EncodeToRecordBatch},
@@ -37,6 +44,15 @@ pub struct ParquetDataCatalog {
     session: DataBackendSession,
 }
 
+impl std::fmt::Debug for ParquetDataCatalog {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct(stringify!(ParquetDataCatalog))
+            .field("base_path", &self.base_path)
+            .field("batch_size", &self.batch_size)
+            .finish()
+    }
+}
+
 impl ParquetDataCatalog {
     #[must_use]
     pub fn new(base_path: PathBuf, batch_size: Option<usize>) -> Self {
@@ -55,10 +71,21 @@ impl ParquetDataCatalog {
             path = path.join(id);
         }
 
-        std::fs::create_dir_all(&path).expect("Failed to create directory");
-        let file_path = path.join("data.parquet");
+        path.join("data.parquet")
+    }
+
+    /// Returns the path to write data to, creating its directory if needed.
+    fn make_write_path(
+        &self,
+        type_name: PathBuf,
+        instrument_id: Option<&String>,
+    ) -> std::io::Result<PathBuf> {
+        let file_path = self.make_path(type_name, instrument_id);
+        if let Some(dir) = file_path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
         info!("Created directory path: {:?}", file_path);
-        file_path
+        Ok(file_path)
     }
 
     fn check_ascending_timestamps<T: GetTsInit>(data: &[T], type_name: &str) {
@@ -100,7 +127,9 @@ impl ParquetDataCatalog {
         Self::check_ascending_timestamps(&data, &type_name);
 
         let json_path = path.unwrap_or_else(|| {
-            let path = self.make_path(T::path_prefix(), None);
+            let path = self
+                .make_write_path(T::path_prefix(), None)
+                .expect("Failed to create directory");
             path.with_extension("json")
         });
 
@@ -146,7 +175,10 @@ impl ParquetDataCatalog {
         let batch = batches.first().expect("Expected at least one batch");
         let schema = batch.schema();
         let instrument_id = schema.metadata.get("instrument_id");
-        let path = path.unwrap_or_else(|| self.make_path(T::path_prefix(), instrument_id));
+        let path = path.unwrap_or_else(|| {
+            self.make_write_path(T::path_prefix(), instrument_id)
+                .expect("Failed to create directory")
+        });
 
         // Write all batches to parquet file
         info!(
@@ -195,29 +227,157 @@ impl ParquetDataCatalog {
     {
         let mut paths = Vec::new();
         for instrument_id in &instrument_ids {
-            paths.push(self.make_path(T::path_prefix(), Some(instrument_id)));
+            paths.push((
+                self.make_path(T::path_prefix(), Some(instrument_id)),
+                Self::make_table_name(&T::path_prefix(), Some(instrument_id)),
+            ));
         }
 
         // If no specific instrument_id is selected query all files for the data type
         if paths.is_empty() {
-            paths.push(self.make_path(T::path_prefix(), None));
+            paths.push((
+                self.make_path(T::path_prefix(), None),
+                Self::make_table_name(&T::path_prefix(), None),
+            ));
         }
 
-        for path in &paths {
-            let path = path.to_str().unwrap();
-            let query = build_query(path, start, end, where_clause);
-            self.session.add_file::<T>(path, path, Some(&query))?;
+        for (path, table_name) in paths.iter().filter(|(path, _)| path.exists()) {
+            let query = build_query(table_name, start, end, where_clause);
+            self.session
+                .add_file::<T>(table_name, path.to_str().unwrap(), Some(&query))?;
         }
 
         Ok(self.session.get_query_result())
     }
 
+    /// Query data loaded in the catalog, returning only the values of type `T`
+    /// in ascending `ts_init` order.
+    ///
+    /// Partitions which have not been written yet are skipped.
+    pub fn query_typed_data<T>(
+        &mut self,
+        instrument_ids: Vec<String>,
+        start: Option<UnixNanos>,
+        end: Option<UnixNanos>,
+        where_clause: Option<&str>,
+    ) -> Result<Vec<T>>
+    where
+        T: DecodeDataFromRecordBatch + CatalogPathPrefix + TryFrom<Data>,
+    {
+        let query_result = self.query_directory::<T>(instrument_ids, start, end, where_clause)?;
+        Ok(query_result
+            .filter_map(|data| T::try_from(data).ok())
+            .collect())
+    }
+
+    /// Writes the `instruments` to the catalog as JSON, with the versions of each
+    /// instrument stored in a partition for its ID in ascending `ts_init` order.
+    ///
+    /// Any versions previously written for the same instrument IDs are replaced.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if a partition cannot be written.
+    pub fn write_instruments(
+        &self,
+        instruments: Vec<InstrumentAny>,
+    ) -> std::io::Result<Vec<PathBuf>> {
+        let mut partitions: BTreeMap<String, Vec<InstrumentAny>> = BTreeMap::new();
+        for instrument in instruments {
+            partitions
+                .entry(instrument.id().to_string())
+                .or_default()
+                .push(instrument);
+        }
+
+        let mut paths = Vec::with_capacity(partitions.len());
+        for (instrument_id, mut versions) in partitions {
+            versions.sort_by_key(InstrumentAny::ts_init);
+            let path = self
+                .make_write_path(PathBuf::from(INSTRUMENTS_PATH_PREFIX), Some(&instrument_id))?
+                .with_extension("json");
+            info!(
+                "Writing {} versions of instrument {instrument_id} to {path:?}",
+                versions.len()
+            );
+            let file = std::fs::File::create(&path)?;
+            serde_json::to_writer(file, &versions)?;
+            paths.push(path);
+        }
+
+        Ok(paths)
+    }
+
+    /// Query instrument definitions written to the catalog, returning the versions with a
+    /// `ts_init` within the optional `start` and `end` bounds in ascending `ts_init` order.
+    ///
+    /// All instruments are queried if no `instrument_ids` are given.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if a partition cannot be read or decoded.
+    pub fn query_instruments(
+        &self,
+        instrument_ids: Vec<String>,
+        start: Option<UnixNanos>,
+        end: Option<UnixNanos>,
+    ) -> std::io::Result<Vec<InstrumentAny>> {
+        let base_path = self.base_path.join("data").join(INSTRUMENTS_PATH_PREFIX);
+        let paths: Vec<PathBuf> = if instrument_ids.is_empty() {
+            // Instrument IDs may contain path separators, so partitions can be nested
+            let mut paths = Vec::new();
+            find_files(&base_path, "data.json", &mut paths)?;
+            paths
+        } else {
+            instrument_ids
+                .iter()
+                .map(|instrument_id| base_path.join(instrument_id).join("data.json"))
+                .collect()
+        };
+
+        let mut instruments = Vec::new();
+        for path in paths.iter().filter(|path| path.exists()) {
+            let json = std::fs::read_to_string(path)?;
+            let versions: Vec<InstrumentAny> = serde_json::from_str(&json)?;
+            instruments.extend(versions.into_iter().filter(|instrument| {
+                let ts_init = instrument.ts_init();
+                start.is_none_or(|start| ts_init >= start) && end.is_none_or(|end| ts_init <= end)
+            }));
+        }
+
+        // Stable sort preserves the order of versions with equal timestamps
+        instruments.sort_by_key(InstrumentAny::ts_init);
+        Ok(instruments)
+    }
+
+    /// Returns a SQL-safe table name for the given data type prefix and partition.
+    fn make_table_name(type_name: &Path, instrument_id: Option<&String>) -> String {
+        let mut table_name = type_name.to_string_lossy().to_string();
+        if let Some(id) = instrument_id {
+            table_name = format!("{table_name}_{id}");
+        }
+
+        table_name
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() {
+                    c.to_ascii_lowercase()
+                } else {
+                    '_'
+                }
+            })
+            .collect()
+    }
+
     pub fn write_data_enum(&self, data: Vec<Data>) {
         let mut delta: Vec<OrderBookDelta> = Vec::new();
         let mut depth10: Vec<OrderBookDepth10> = Vec::new();
         let mut quote: Vec<QuoteTick> = Vec::new();
         let mut trade: Vec<TradeTick> = Vec::new();
         let mut bar: Vec<Bar> = Vec::new();
+        let mut mark_price: Vec<MarkPriceUpdate> = Vec::new();
+        let mut index_price: Vec<IndexPriceUpdate> = Vec::new();
+        let mut funding_rate: Vec<FundingRateUpdate> = Vec::new();
 
         for d in data.iter().cloned() {
             match d {
@@ -236,6 +396,15 @@ impl ParquetDataCatalog {
                 Data::Bar(d) => {
                     bar.push(d);
                 }
+                Data::MarkPrice(d) => {
+                    mark_price.push(d);
+                }
+                Data::IndexPrice(d) => {
+                    index_price.push(d);
+                }
+                Data::FundingRate(d) => {
+                    funding_rate.push(d);
+                }
                 Data::Deltas(_) => continue,
             }
         }
@@ -245,9 +414,34 @@ impl ParquetDataCatalog {
         let _ = self.write_to_parquet(quote, None, None, None);
         let _ = self.write_to_parquet(trade, None, None, None);
         let _ = self.write_to_parquet(bar, None, None, None);
+        let _ = self.write_to_parquet(mark_price, None, None, None);
+        let _ = self.write_to_parquet(index_price, None, None, None);
+        let _ = self.write_to_parquet(funding_rate, None, None, None);
+    }
+}
+
+/// Recursively collects the paths of all files named `file_name` under the `dir`.
+fn find_files(dir: &Path, file_name: &str, paths: &mut Vec<PathBuf>) -> std::io::Result<()> {
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+        Err(e) => return Err(e),
+    };
+
+    for entry in entries {
+        let path = entry?.path();
+        if path.is_dir() {
+            find_files(&path, file_name, paths)?;
+        } else if path.file_name().is_some_and(|name| name == file_name) {
+            paths.push(path);
+        }
     }
+    Ok(())
 }
 
+/// The path prefix for instrument definitions written to a catalog.
+const INSTRUMENTS_PATH_PREFIX: &str = "instruments";
+
 pub trait CatalogPathPrefix {
     fn path_prefix() -> PathBuf;
 }
@@ -267,3 +461,6 @@ impl_catalog_path_prefix!(TradeTick, "trades");
 impl_catalog_path_prefix!(OrderBookDelta, "order_book_deltas");
 impl_catalog_path_prefix!(OrderBookDepth10, "order_book_depths");
 impl_catalog_path_prefix!(Bar, "bars");
+impl_catalog_path_prefix!(MarkPriceUpdate, "mark_prices");
+impl_catalog_path_prefix!(IndexPriceUpdate, "index_prices");
+impl_catalog_path_prefix!(FundingRateUpdate, "funding_rates");
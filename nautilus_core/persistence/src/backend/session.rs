@@ -124,6 +124,8 @@ impl DataBackendSession {
             }]],
             ..Default::default()
         };
+        // Replace any previous registration so the same table can be queried again
+        self.session_ctx.deregister_table(table_name)?;
         self.runtime.block_on(self.session_ctx.register_parquet(
             table_name,
             file_path,
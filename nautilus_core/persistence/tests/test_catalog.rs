@@ -496,3 +496,16 @@ fn test_catalog_export_functionality() {
         assert_eq!(original, final_quote, "Quotes don't match");
     }
 }
+
+#[rstest]
+fn test_query_typed_data_does_not_create_directories() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let mut catalog = ParquetDataCatalog::new(temp_dir.path().to_path_buf(), None);
+
+    let quotes = catalog
+        .query_typed_data::<QuoteTick>(vec!["AUD/USD.SIM".to_string()], None, None, None)
+        .unwrap();
+
+    assert!(quotes.is_empty());
+    assert!(!temp_dir.path().join("data").exists());
+}
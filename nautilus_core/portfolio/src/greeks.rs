@@ -0,0 +1,530 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides the greeks of option and futures positions for aggregation by the `Portfolio`.
+
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    ops::{Add, AddAssign, Mul},
+};
+
+use nautilus_common::cache::Cache;
+use nautilus_core::{datetime::NANOSECONDS_IN_SECOND, UnixNanos};
+use nautilus_model::{
+    data::greeks::{black76_greeks, black_scholes_greeks, imply_vol_black76},
+    enums::{OptionKind, PriceType},
+    identifiers::InstrumentId,
+    instruments::{InstrumentAny, OptionContract},
+};
+use ustr::Ustr;
+
+const SECONDS_IN_YEAR: f64 = 365.25 * 86_400.0;
+
+/// Configuration for the greeks aggregated by the `Portfolio`.
+#[derive(Clone, Debug)]
+pub struct PortfolioGreeksConfig {
+    /// The risk-free interest rate used to price options.
+    pub interest_rate: f64,
+    /// The volatilities by underlying, used in place of volatilities implied from option quotes.
+    pub vols: HashMap<Ustr, f64>,
+    /// The volatility for options with no configured volatility or quote to imply one from.
+    pub default_vol: Option<f64>,
+    /// The underlying instrument IDs by underlying symbol, used in place of looking up the
+    /// instrument with the underlying symbol in the cache (e.g. where it trades on several venues).
+    pub underlying_ids: HashMap<Ustr, InstrumentId>,
+    /// The interval (nanoseconds) after which cached greeks are recalculated, as time to expiry
+    /// decays, even if no prices or positions have changed.
+    pub recalc_interval_ns: u64,
+}
+
+impl Default for PortfolioGreeksConfig {
+    /// Creates a new default [`PortfolioGreeksConfig`] instance.
+    fn default() -> Self {
+        Self {
+            interest_rate: 0.05,
+            vols: HashMap::new(),
+            default_vol: None,
+            underlying_ids: HashMap::new(),
+            recalc_interval_ns: 60 * NANOSECONDS_IN_SECOND,
+        }
+    }
+}
+
+/// Represents the aggregated greeks of a set of positions.
+///
+/// Greeks are scaled by the instrument multiplier and position quantity, with vega per 1% change
+/// in volatility and theta per calendar day.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct PortfolioGreeks {
+    pub delta: f64,
+    pub gamma: f64,
+    pub vega: f64,
+    pub theta: f64,
+}
+
+impl Add for PortfolioGreeks {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            delta: self.delta + rhs.delta,
+            gamma: self.gamma + rhs.gamma,
+            vega: self.vega + rhs.vega,
+            theta: self.theta + rhs.theta,
+        }
+    }
+}
+
+impl AddAssign for PortfolioGreeks {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Mul<f64> for PortfolioGreeks {
+    type Output = Self;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        Self {
+            delta: self.delta * rhs,
+            gamma: self.gamma * rhs,
+            vega: self.vega * rhs,
+            theta: self.theta * rhs,
+        }
+    }
+}
+
+impl Display for PortfolioGreeks {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}(delta={:.2}, gamma={:.2}, vega={:.2}, theta={:.2})",
+            stringify!(PortfolioGreeks),
+            self.delta,
+            self.gamma,
+            self.vega,
+            self.theta,
+        )
+    }
+}
+
+/// Returns the underlying the greeks of the given `instrument` are aggregated under, or `None`
+/// if the instrument is neither an option nor a futures contract.
+///
+/// Futures contracts are their own underlying (keyed by symbol), so that they aggregate with
+/// the options on them.
+#[must_use]
+pub fn greeks_underlying(instrument: &InstrumentAny) -> Option<Ustr> {
+    match instrument {
+        InstrumentAny::OptionContract(option) => Some(option.underlying),
+        InstrumentAny::FuturesContract(future) => Some(future.id.symbol.inner()),
+        _ => None,
+    }
+}
+
+/// Returns the ID of the instrument whose price the greeks of the given `instrument` depend on,
+/// or `None` if the instrument has no greeks or its underlying cannot be resolved.
+///
+/// Futures contracts are their own underlying. The underlying of an option is the configured
+/// underlying ID for its underlying symbol, else the only instrument in the cache with the
+/// underlying symbol (on any venue).
+#[must_use]
+pub fn greeks_underlying_id(
+    cache: &Cache,
+    instrument: &InstrumentAny,
+    config: &PortfolioGreeksConfig,
+) -> Option<InstrumentId> {
+    match instrument {
+        InstrumentAny::OptionContract(option) => resolve_underlying_id(cache, option, config),
+        InstrumentAny::FuturesContract(future) => Some(future.id),
+        _ => None,
+    }
+}
+
+fn resolve_underlying_id(
+    cache: &Cache,
+    option: &OptionContract,
+    config: &PortfolioGreeksConfig,
+) -> Option<InstrumentId> {
+    if let Some(instrument_id) = config.underlying_ids.get(&option.underlying) {
+        return Some(*instrument_id);
+    }
+
+    let mut candidates = cache
+        .instrument_ids(None)
+        .into_iter()
+        .filter(|instrument_id| instrument_id.symbol.inner() == option.underlying);
+    match (candidates.next(), candidates.next()) {
+        (Some(instrument_id), None) => Some(*instrument_id),
+        (Some(_), Some(_)) => {
+            log::warn!(
+                "Cannot resolve underlying {} of {}: several instruments, configure `underlying_ids`",
+                option.underlying,
+                option.id,
+            );
+            None
+        }
+        _ => {
+            log::debug!(
+                "Cannot resolve underlying {} of {}: no instrument",
+                option.underlying,
+                option.id,
+            );
+            None
+        }
+    }
+}
+
+/// Calculates the greeks of one unit of the given `instrument` at time `ts_now`, returning
+/// `None` if the instrument has no greeks or the inputs to price it are missing.
+///
+/// Futures have a delta of their multiplier. Options on futures are priced with the Black-76
+/// model, taking the futures price as the forward, and options on any other underlying (e.g.
+/// equities) with the Black-Scholes model, carrying the spot price to the forward at the interest
+/// rate. The underlying price is the mid, else the last trade. The volatility is the configured
+/// volatility for the underlying, else the volatility implied from the option mid price, else the
+/// configured default volatility.
+#[must_use]
+pub fn instrument_greeks(
+    cache: &Cache,
+    instrument: &InstrumentAny,
+    config: &PortfolioGreeksConfig,
+    ts_now: UnixNanos,
+) -> Option<PortfolioGreeks> {
+    match instrument {
+        InstrumentAny::OptionContract(option) => option_greeks(cache, option, config, ts_now),
+        InstrumentAny::FuturesContract(future) => Some(PortfolioGreeks {
+            delta: future.multiplier.as_f64(),
+            ..Default::default()
+        }),
+        _ => None,
+    }
+}
+
+fn option_greeks(
+    cache: &Cache,
+    option: &OptionContract,
+    config: &PortfolioGreeksConfig,
+    ts_now: UnixNanos,
+) -> Option<PortfolioGreeks> {
+    let seconds_to_expiry = (option.expiration_ns.as_u64() as f64 - ts_now.as_u64() as f64)
+        / NANOSECONDS_IN_SECOND as f64;
+    let t = seconds_to_expiry / SECONDS_IN_YEAR;
+    if t <= 0.0 {
+        log::debug!("Cannot calculate greeks: {} has expired", option.id);
+        return None;
+    }
+
+    let underlying_id = resolve_underlying_id(cache, option, config)?;
+    let Some(underlying_price) = cache
+        .price(&underlying_id, PriceType::Mid)
+        .or_else(|| cache.price(&underlying_id, PriceType::Last))
+    else {
+        log::debug!("Cannot calculate greeks: no prices for {underlying_id}");
+        return None;
+    };
+
+    let is_futures_underlying = matches!(
+        cache.instrument(&underlying_id),
+        Some(InstrumentAny::FuturesContract(_))
+    );
+    let s = underlying_price.as_f64();
+    let r = config.interest_rate;
+    // Spot underlyings carry to the forward at the interest rate (no dividends)
+    let b = if is_futures_underlying { 0.0 } else { r };
+    let forward = s * (b * t).exp();
+    let is_call = option.option_kind == OptionKind::Call;
+    let k = option.strike_price.as_f64();
+
+    let vol = config
+        .vols
+        .get(&option.underlying)
+        .copied()
+        .or_else(|| {
+            let price = cache.price(&option.id, PriceType::Mid)?.as_f64();
+            let vol = imply_vol_black76(forward, r, is_call, k, t, price);
+            (vol.is_finite() && vol > 0.0 && vol < f64::MAX).then_some(vol)
+        })
+        .or(config.default_vol);
+    let Some(vol) = vol else {
+        log::debug!("Cannot calculate greeks: no volatility for {}", option.id);
+        return None;
+    };
+
+    let multiplier = option.multiplier.as_f64();
+    let greeks = if is_futures_underlying {
+        black76_greeks(s, r, vol, is_call, k, t, multiplier)
+    } else {
+        black_scholes_greeks(s, r, b, vol, is_call, k, t, multiplier)
+    };
+
+    Some(PortfolioGreeks {
+        delta: greeks.delta,
+        gamma: greeks.gamma,
+        vega: greeks.vega,
+        theta: greeks.theta,
+    })
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_common::cache::Cache;
+    use nautilus_core::UnixNanos;
+    use nautilus_model::{
+        data::{
+            greeks::{black76_greeks, black_scholes_greeks},
+            QuoteTick,
+        },
+        identifiers::InstrumentId,
+        instruments::{
+            stubs::{audusd_sim, equity_aapl, futures_contract_es, option_contract_appl},
+            Equity, InstrumentAny, OptionContract,
+        },
+        types::{Price, Quantity},
+    };
+    use rstest::rstest;
+    use ustr::Ustr;
+
+    use super::{
+        greeks_underlying, greeks_underlying_id, instrument_greeks, PortfolioGreeks,
+        PortfolioGreeksConfig,
+    };
+
+    const YEAR_NS: u64 = 31_557_600 * 1_000_000_000;
+
+    fn option() -> OptionContract {
+        OptionContract {
+            expiration_ns: UnixNanos::from(YEAR_NS),
+            ..option_contract_appl()
+        }
+    }
+
+    fn cache_with_underlying() -> Cache {
+        let mut cache = Cache::default();
+        cache
+            .add_instrument(InstrumentAny::Equity(equity_aapl()))
+            .unwrap();
+        cache
+    }
+
+    fn quote(instrument_id: &str, bid: &str, ask: &str) -> QuoteTick {
+        QuoteTick::new(
+            instrument_id.into(),
+            Price::from(bid),
+            Price::from(ask),
+            Quantity::from(1),
+            Quantity::from(1),
+            UnixNanos::default(),
+            UnixNanos::default(),
+        )
+    }
+
+    #[rstest]
+    fn test_greeks_underlying() {
+        let future = InstrumentAny::FuturesContract(futures_contract_es(None, None));
+        let option = InstrumentAny::OptionContract(option());
+        let fx = InstrumentAny::CurrencyPair(audusd_sim());
+
+        assert_eq!(greeks_underlying(&future), Some(Ustr::from("ESZ21")));
+        assert_eq!(greeks_underlying(&option), Some(Ustr::from("AAPL")));
+        assert_eq!(greeks_underlying(&fx), None);
+    }
+
+    #[rstest]
+    fn test_futures_greeks_is_multiplier_delta() {
+        let cache = Cache::default();
+        let future = InstrumentAny::FuturesContract(futures_contract_es(None, None));
+
+        let greeks = instrument_greeks(
+            &cache,
+            &future,
+            &PortfolioGreeksConfig::default(),
+            UnixNanos::default(),
+        );
+
+        assert_eq!(
+            greeks,
+            Some(PortfolioGreeks {
+                delta: 1.0,
+                ..Default::default()
+            })
+        );
+    }
+
+    #[rstest]
+    fn test_option_greeks_with_configured_vol() {
+        let mut cache = cache_with_underlying();
+        cache
+            .add_quote(quote("AAPL.XNAS", "149.90", "150.10"))
+            .unwrap();
+        let config = PortfolioGreeksConfig {
+            vols: [(Ustr::from("AAPL"), 0.3)].into_iter().collect(),
+            ..Default::default()
+        };
+
+        let greeks = instrument_greeks(
+            &cache,
+            &InstrumentAny::OptionContract(option()),
+            &config,
+            UnixNanos::default(),
+        )
+        .unwrap();
+
+        let expected = black_scholes_greeks(150.0, 0.05, 0.05, 0.3, true, 149.0, 1.0, 1.0);
+        assert!((greeks.delta - expected.delta).abs() < 1e-12);
+        assert!((greeks.gamma - expected.gamma).abs() < 1e-12);
+        assert!((greeks.vega - expected.vega).abs() < 1e-12);
+        assert!((greeks.theta - expected.theta).abs() < 1e-12);
+    }
+
+    #[rstest]
+    fn test_option_greeks_with_vol_implied_from_quote() {
+        let mut cache = cache_with_underlying();
+        let option = option();
+        let expected = black_scholes_greeks(150.0, 0.05, 0.05, 0.25, true, 149.0, 1.0, 1.0);
+        let mid = Price::new(expected.price, 4);
+        cache
+            .add_quote(quote("AAPL.XNAS", "150.00", "150.00"))
+            .unwrap();
+        cache
+            .add_quote(QuoteTick::new(
+                option.id,
+                mid,
+                mid,
+                Quantity::from(1),
+                Quantity::from(1),
+                UnixNanos::default(),
+                UnixNanos::default(),
+            ))
+            .unwrap();
+
+        let greeks = instrument_greeks(
+            &cache,
+            &InstrumentAny::OptionContract(option),
+            &PortfolioGreeksConfig::default(),
+            UnixNanos::default(),
+        )
+        .unwrap();
+
+        assert!((greeks.delta - expected.delta).abs() < 1e-4);
+        assert!((greeks.vega - expected.vega).abs() < 1e-4);
+    }
+
+    #[rstest]
+    fn test_option_greeks_with_missing_inputs_returns_none() {
+        let mut cache = Cache::default();
+        let option = InstrumentAny::OptionContract(option());
+        let config = PortfolioGreeksConfig::default();
+
+        // No underlying instrument
+        cache
+            .add_quote(quote("AAPL.XNAS", "149.90", "150.10"))
+            .unwrap();
+        assert!(instrument_greeks(&cache, &option, &config, UnixNanos::default()).is_none());
+
+        // No underlying price
+        let mut cache = cache_with_underlying();
+        assert!(instrument_greeks(&cache, &option, &config, UnixNanos::default()).is_none());
+
+        // No option quote or default volatility
+        cache
+            .add_quote(quote("AAPL.XNAS", "149.90", "150.10"))
+            .unwrap();
+        assert!(instrument_greeks(&cache, &option, &config, UnixNanos::default()).is_none());
+
+        let config = PortfolioGreeksConfig {
+            default_vol: Some(0.2),
+            ..Default::default()
+        };
+        assert!(instrument_greeks(&cache, &option, &config, UnixNanos::default()).is_some());
+
+        // Expired
+        assert!(instrument_greeks(&cache, &option, &config, UnixNanos::from(YEAR_NS)).is_none());
+    }
+
+    #[rstest]
+    fn test_greeks_underlying_id() {
+        let mut cache = cache_with_underlying();
+        let option = InstrumentAny::OptionContract(option());
+        let future = InstrumentAny::FuturesContract(futures_contract_es(None, None));
+        let config = PortfolioGreeksConfig::default();
+
+        assert_eq!(
+            greeks_underlying_id(&cache, &option, &config),
+            Some(InstrumentId::from("AAPL.XNAS"))
+        );
+        assert_eq!(
+            greeks_underlying_id(&cache, &future, &config),
+            Some(future.id())
+        );
+
+        // Ambiguous without a configured underlying ID
+        cache
+            .add_instrument(InstrumentAny::Equity(Equity {
+                id: InstrumentId::from("AAPL.ARCX"),
+                ..equity_aapl()
+            }))
+            .unwrap();
+        assert_eq!(greeks_underlying_id(&cache, &option, &config), None);
+
+        let config = PortfolioGreeksConfig {
+            underlying_ids: [(Ustr::from("AAPL"), InstrumentId::from("AAPL.ARCX"))]
+                .into_iter()
+                .collect(),
+            ..Default::default()
+        };
+        assert_eq!(
+            greeks_underlying_id(&cache, &option, &config),
+            Some(InstrumentId::from("AAPL.ARCX"))
+        );
+    }
+
+    #[rstest]
+    fn test_option_on_futures_greeks_uses_black76() {
+        let mut cache = Cache::default();
+        let future = futures_contract_es(None, None);
+        cache
+            .add_instrument(InstrumentAny::FuturesContract(future))
+            .unwrap();
+        cache
+            .add_quote(quote(&future.id.to_string(), "4499.75", "4500.25"))
+            .unwrap();
+        let option = OptionContract {
+            underlying: future.id.symbol.inner(),
+            strike_price: Price::from("4400.00"),
+            ..option()
+        };
+        let config = PortfolioGreeksConfig {
+            default_vol: Some(0.2),
+            ..Default::default()
+        };
+
+        let greeks = instrument_greeks(
+            &cache,
+            &InstrumentAny::OptionContract(option),
+            &config,
+            UnixNanos::default(),
+        )
+        .unwrap();
+
+        let expected = black76_greeks(4500.0, 0.05, 0.2, true, 4400.0, 1.0, 1.0);
+        assert!((greeks.delta - expected.delta).abs() < 1e-12);
+        assert!((greeks.theta - expected.theta).abs() < 1e-12);
+    }
+}
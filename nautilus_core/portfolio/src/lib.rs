@@ -21,8 +21,10 @@
 #![deny(rustdoc::broken_intra_doc_links)]
 #![deny(clippy::missing_errors_doc)]
 
+pub mod greeks;
 pub mod manager;
 pub mod portfolio;
 
 // Re-exports
+pub use greeks::{PortfolioGreeks, PortfolioGreeksConfig};
 pub use portfolio::Portfolio;
@@ -20,13 +20,20 @@ use std::{cell::RefCell, rc::Rc};
 use nautilus_common::{cache::Cache, clock::Clock};
 use nautilus_core::{UnixNanos, UUID4};
 use nautilus_model::{
-    accounts::{any::AccountAny, base::Account, cash::CashAccount, margin::MarginAccount},
-    enums::{AccountType, OrderSide, OrderSideSpecified, PriceType},
+    accounts::{
+        any::AccountAny,
+        base::Account,
+        betting::{BettingAccount, MarketBet},
+        cash::CashAccount,
+        margin::MarginAccount,
+    },
+    enums::{AccountType, OrderSide, OrderSideSpecified, PositionSide, PriceType},
     events::{AccountState, OrderFilled},
-    instruments::InstrumentAny,
+    identifiers::InstrumentId,
+    instruments::{BettingInstrument, InstrumentAny},
     orders::OrderAny,
     position::Position,
-    types::{AccountBalance, Money},
+    types::{fixed::FIXED_PRECISION, AccountBalance, Money, Price},
 };
 use rust_decimal::{prelude::ToPrimitive, Decimal};
 pub struct AccountsManager {
@@ -106,6 +113,18 @@ impl AccountsManager {
                 .map(|(updated_margin_account, state)| {
                     (AccountAny::Margin(updated_margin_account), state)
                 }),
+            AccountAny::Betting(betting_account) => {
+                match self.update_market_locked(&betting_account, instrument, orders_open, ts_event)
+                {
+                    Ok((updated_betting_account, state)) => {
+                        Some((AccountAny::Betting(updated_betting_account), state))
+                    }
+                    Err(e) => {
+                        log::error!("Cannot update market locked balance: {e}");
+                        None
+                    }
+                }
+            }
         }
     }
 
@@ -147,12 +166,30 @@ impl AccountsManager {
                     instrument.make_price(position.avg_px_open),
                     None,
                 ),
+                InstrumentAny::Cfd(i) => account.calculate_maintenance_margin(
+                    i,
+                    position.quantity,
+                    instrument.make_price(position.avg_px_open),
+                    None,
+                ),
+                InstrumentAny::Commodity(i) => account.calculate_maintenance_margin(
+                    i,
+                    position.quantity,
+                    instrument.make_price(position.avg_px_open),
+                    None,
+                ),
                 InstrumentAny::CryptoFuture(i) => account.calculate_maintenance_margin(
                     i,
                     position.quantity,
                     instrument.make_price(position.avg_px_open),
                     None,
                 ),
+                InstrumentAny::CryptoOption(i) => account.calculate_maintenance_margin(
+                    i,
+                    position.quantity,
+                    instrument.make_price(position.avg_px_open),
+                    None,
+                ),
                 InstrumentAny::CryptoPerpetual(i) => account.calculate_maintenance_margin(
                     i,
                     position.quantity,
@@ -183,6 +220,12 @@ impl AccountsManager {
                     instrument.make_price(position.avg_px_open),
                     None,
                 ),
+                InstrumentAny::IndexInstrument(i) => account.calculate_maintenance_margin(
+                    i,
+                    position.quantity,
+                    instrument.make_price(position.avg_px_open),
+                    None,
+                ),
                 InstrumentAny::OptionContract(i) => account.calculate_maintenance_margin(
                     i,
                     position.quantity,
@@ -326,6 +369,110 @@ impl AccountsManager {
         ))
     }
 
+    /// Settles the market of the given betting `instrument` on the `account` with the `winner`
+    /// selection, returning the updated account and its state.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the account has no balance in the instrument currency.
+    pub fn settle_market(
+        &self,
+        account: &BettingAccount,
+        instrument: &BettingInstrument,
+        winner: Option<InstrumentId>,
+        ts_event: UnixNanos,
+    ) -> anyhow::Result<(BettingAccount, AccountState)> {
+        let mut account = account.clone();
+        let net_pnl = account.settle_market(instrument.market_id, winner, instrument.currency)?;
+
+        log::info!(
+            "Settled market_id={} winner={:?} net_pnl={}",
+            instrument.market_id,
+            winner,
+            net_pnl
+        );
+
+        Ok((
+            account.clone(),
+            self.generate_account_state(AccountAny::Betting(account), ts_event),
+        ))
+    }
+
+    fn update_market_locked(
+        &self,
+        account: &BettingAccount,
+        instrument: InstrumentAny,
+        orders_open: Vec<&OrderAny>,
+        ts_event: UnixNanos,
+    ) -> anyhow::Result<(BettingAccount, AccountState)> {
+        let InstrumentAny::Betting(betting_instrument) = &instrument else {
+            anyhow::bail!("{} is not a betting instrument", instrument.id());
+        };
+
+        let mut account = account.clone();
+        let mut bets = Vec::new();
+
+        for order in orders_open {
+            anyhow::ensure!(
+                order.instrument_id() == instrument.id(),
+                "order {} not for instrument {}",
+                order.client_order_id(),
+                instrument.id()
+            );
+            anyhow::ensure!(
+                order.is_open(),
+                "order {} is not open",
+                order.client_order_id()
+            );
+
+            let Some(price) = order.price().or_else(|| order.trigger_price()) else {
+                continue;
+            };
+
+            bets.push(MarketBet::new(
+                order.instrument_id(),
+                order.order_side_specified(),
+                order.leaves_qty(),
+                price,
+            ));
+        }
+
+        // Matched bets are netted into the open positions on the instrument
+        let matched_bets = self
+            .cache
+            .borrow()
+            .positions_open(None, Some(&instrument.id()), None, None)
+            .into_iter()
+            .filter_map(|position| {
+                let side = match position.side {
+                    PositionSide::Long => OrderSideSpecified::Buy,
+                    PositionSide::Short => OrderSideSpecified::Sell,
+                    _ => return None,
+                };
+                Some(MarketBet::new(
+                    instrument.id(),
+                    side,
+                    position.quantity,
+                    Price::new(position.avg_px_open, FIXED_PRECISION),
+                ))
+            })
+            .collect();
+
+        let locked = account.update_market_bets(betting_instrument, bets, matched_bets);
+
+        log::info!(
+            "{} market_id={} balance_locked={}",
+            instrument.id(),
+            betting_instrument.market_id,
+            locked
+        );
+
+        Ok((
+            account.clone(),
+            self.generate_account_state(AccountAny::Betting(account), ts_event),
+        ))
+    }
+
     fn update_margin_init(
         &self,
         account: &MarginAccount,
@@ -363,9 +510,18 @@ impl AccountsManager {
                 InstrumentAny::BinaryOption(i) => {
                     account.calculate_initial_margin(i, order.quantity(), price?, None)
                 }
+                InstrumentAny::Cfd(i) => {
+                    account.calculate_initial_margin(i, order.quantity(), price?, None)
+                }
+                InstrumentAny::Commodity(i) => {
+                    account.calculate_initial_margin(i, order.quantity(), price?, None)
+                }
                 InstrumentAny::CryptoFuture(i) => {
                     account.calculate_initial_margin(i, order.quantity(), price?, None)
                 }
+                InstrumentAny::CryptoOption(i) => {
+                    account.calculate_initial_margin(i, order.quantity(), price?, None)
+                }
                 InstrumentAny::CryptoPerpetual(i) => {
                     account.calculate_initial_margin(i, order.quantity(), price?, None)
                 }
@@ -381,6 +537,9 @@ impl AccountsManager {
                 InstrumentAny::FuturesSpread(i) => {
                     account.calculate_initial_margin(i, order.quantity(), price?, None)
                 }
+                InstrumentAny::IndexInstrument(i) => {
+                    account.calculate_initial_margin(i, order.quantity(), price?, None)
+                }
                 InstrumentAny::OptionContract(i) => {
                     account.calculate_initial_margin(i, order.quantity(), price?, None)
                 }
@@ -536,6 +695,12 @@ impl AccountsManager {
                     margin.update_commissions(comm);
                 }
             }
+            AccountAny::Betting(mut betting) => {
+                betting.update_balances(balances);
+                if let Some(comm) = commission {
+                    betting.update_commissions(comm);
+                }
+            }
         }
     }
 
@@ -650,6 +815,12 @@ impl AccountsManager {
                     margin.update_commissions(commission);
                 }
             }
+            AccountAny::Betting(mut betting) => {
+                betting.update_balances(new_balances);
+                if let Some(commission) = commission {
+                    betting.update_commissions(commission);
+                }
+            }
         }
     }
 
@@ -677,6 +848,17 @@ impl AccountsManager {
                 self.clock.borrow().timestamp_ns(),
                 margin_account.base_currency(),
             ),
+            AccountAny::Betting(betting_account) => AccountState::new(
+                betting_account.id,
+                AccountType::Betting,
+                betting_account.balances.clone().into_values().collect(),
+                vec![],
+                false,
+                UUID4::new(),
+                ts_event,
+                self.clock.borrow().timestamp_ns(),
+                betting_account.base_currency(),
+            ),
         }
     }
 
@@ -700,3 +882,188 @@ impl AccountsManager {
         }
     }
 }
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use nautilus_common::{cache::Cache, clock::TestClock};
+    use nautilus_core::UnixNanos;
+    use nautilus_model::{
+        accounts::{any::AccountAny, base::Account, stubs::betting_account, BettingAccount},
+        enums::{OmsType, OrderSide, OrderType},
+        events::account::stubs::betting_account_state,
+        identifiers::{AccountId, VenueOrderId},
+        instruments::{stubs::betting, BettingInstrument, InstrumentAny},
+        orders::{stubs::TestOrderEventStubs, OrderAny, OrderTestBuilder},
+        position::Position,
+        types::{Money, Price, Quantity},
+    };
+    use rstest::rstest;
+    use rust_decimal::Decimal;
+
+    use super::AccountsManager;
+
+    fn manager(cache: Cache) -> AccountsManager {
+        AccountsManager::new(
+            Rc::new(RefCell::new(TestClock::new())),
+            Rc::new(RefCell::new(cache)),
+        )
+    }
+
+    fn open_order(instrument: &InstrumentAny, side: OrderSide, quantity: &str) -> OrderAny {
+        let mut order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(instrument.id())
+            .side(side)
+            .price(Price::from("3.00"))
+            .quantity(Quantity::from(quantity))
+            .build();
+        let account_id = AccountId::from("BETFAIR-001");
+        let submitted = TestOrderEventStubs::order_submitted(&order, account_id);
+        order.apply(submitted).unwrap();
+        let accepted =
+            TestOrderEventStubs::order_accepted(&order, account_id, VenueOrderId::from("1"));
+        order.apply(accepted).unwrap();
+        order
+    }
+
+    #[rstest]
+    fn test_update_orders_locks_matched_liability_with_betting_account(betting: BettingInstrument) {
+        let instrument = InstrumentAny::Betting(betting);
+        let account = betting_account(betting_account_state());
+
+        // Matched lay of 10 at odds of 3.00 has a liability of 20
+        let order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(instrument.id())
+            .side(OrderSide::Buy)
+            .price(Price::from("3.00"))
+            .quantity(Quantity::from(10))
+            .build();
+        let filled = TestOrderEventStubs::order_filled(
+            &order,
+            &instrument,
+            None,
+            None,
+            Some(Price::from("3.00")),
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        let mut cache = Cache::default();
+        cache
+            .add_position(Position::new(&instrument, filled.into()), OmsType::Netting)
+            .unwrap();
+        let manager = manager(cache);
+
+        // Open back of 4 at odds of 3.00 only loses when the matched lay wins, so the worst
+        // outcome is still the selection winning for a loss of 20
+        let order = open_order(&instrument, OrderSide::Sell, "4");
+        let (account, _) = manager
+            .update_orders(
+                &AccountAny::Betting(account),
+                instrument.clone(),
+                vec![&order],
+                UnixNanos::default(),
+            )
+            .unwrap();
+
+        let AccountAny::Betting(account) = account else {
+            panic!("Expected betting account");
+        };
+        assert_eq!(
+            account.market_locked(&betting.market_id),
+            Some(Money::from("20 GBP"))
+        );
+        assert_eq!(account.balance_free(None), Some(Money::from("980 GBP")));
+
+        // The matched lay stays locked with no open orders
+        let (account, _) = manager
+            .update_orders(
+                &AccountAny::Betting(account),
+                instrument,
+                vec![],
+                UnixNanos::default(),
+            )
+            .unwrap();
+
+        assert_eq!(
+            account.balances_locked().values().next(),
+            Some(&Money::from("20 GBP"))
+        );
+    }
+
+    #[rstest]
+    fn test_update_orders_with_order_for_other_instrument_returns_none(betting: BettingInstrument) {
+        let instrument = InstrumentAny::Betting(betting);
+        let other = InstrumentAny::Betting(BettingInstrument {
+            id: "1-123456789-50214-None.BETFAIR".into(),
+            ..betting
+        });
+        let account = AccountAny::Betting(betting_account(betting_account_state()));
+        let order = open_order(&other, OrderSide::Sell, "4");
+
+        let result = manager(Cache::default()).update_orders(
+            &account,
+            instrument,
+            vec![&order],
+            UnixNanos::default(),
+        );
+
+        assert!(result.is_none());
+    }
+
+    #[rstest]
+    fn test_settle_market_with_betting_account(betting: BettingInstrument) {
+        let mut account: BettingAccount = betting_account(betting_account_state());
+        account.set_commission_rate(Decimal::new(5, 2)).unwrap();
+        let instrument = InstrumentAny::Betting(betting);
+        let order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(instrument.id())
+            .side(OrderSide::Sell)
+            .price(Price::from("3.00"))
+            .quantity(Quantity::from(10))
+            .build();
+        let filled = TestOrderEventStubs::order_filled(
+            &order,
+            &instrument,
+            None,
+            None,
+            Some(Price::from("3.00")),
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        let mut cache = Cache::default();
+        cache
+            .add_position(Position::new(&instrument, filled.into()), OmsType::Netting)
+            .unwrap();
+        let manager = manager(cache);
+        let (account, _) = manager
+            .update_orders(
+                &AccountAny::Betting(account),
+                instrument,
+                vec![],
+                UnixNanos::default(),
+            )
+            .unwrap();
+        let AccountAny::Betting(account) = account else {
+            panic!("Expected betting account");
+        };
+
+        let (account, state) = manager
+            .settle_market(&account, &betting, Some(betting.id), UnixNanos::default())
+            .unwrap();
+
+        // Winnings of 20 less 5% commission
+        assert_eq!(account.balance_total(None), Some(Money::from("1019 GBP")));
+        assert_eq!(account.balance_locked(None), Some(Money::from("0 GBP")));
+        assert_eq!(state.balances[0].total, Money::from("1019 GBP"));
+    }
+}
@@ -42,6 +42,7 @@ use nautilus_common::{
         MessageBus,
     },
 };
+use nautilus_core::UnixNanos;
 use nautilus_model::{
     accounts::AccountAny,
     data::{Bar, Data, QuoteTick},
@@ -60,7 +61,13 @@ use rust_decimal::{
 use ustr::Ustr;
 use uuid::Uuid;
 
-use crate::manager::AccountsManager;
+use crate::{
+    greeks::{
+        greeks_underlying, greeks_underlying_id, instrument_greeks, PortfolioGreeks,
+        PortfolioGreeksConfig,
+    },
+    manager::AccountsManager,
+};
 
 struct UpdateQuoteTickHandler {
     id: Ustr,
@@ -170,6 +177,10 @@ struct PortfolioState {
     net_positions: HashMap<InstrumentId, Decimal>,
     pending_calcs: HashSet<InstrumentId>,
     bar_close_prices: HashMap<InstrumentId, Price>,
+    greeks_config: PortfolioGreeksConfig,
+    greeks: Option<HashMap<Ustr, Option<PortfolioGreeks>>>,
+    greeks_ts: UnixNanos,
+    greeks_instruments: HashSet<InstrumentId>,
     initialized: bool,
 }
 
@@ -201,6 +212,10 @@ impl PortfolioState {
             net_positions: HashMap::new(),
             pending_calcs: HashSet::new(),
             bar_close_prices: HashMap::new(),
+            greeks_config: PortfolioGreeksConfig::default(),
+            greeks: None,
+            greeks_ts: UnixNanos::default(),
+            greeks_instruments: HashSet::new(),
             initialized: false,
         }
     }
@@ -211,6 +226,8 @@ impl PortfolioState {
         self.unrealized_pnls.clear();
         self.realized_pnls.clear();
         self.pending_calcs.clear();
+        self.greeks = None;
+        self.greeks_instruments.clear();
         self.analyzer.reset();
         log::debug!("READY");
     }
@@ -399,6 +416,10 @@ impl Portfolio {
                     log::warn!("Initial margins not applicable for cash account");
                     HashMap::new()
                 }
+                AccountAny::Betting(_) => {
+                    log::warn!("Initial margins not applicable for betting account");
+                    HashMap::new()
+                }
             },
         )
     }
@@ -419,6 +440,10 @@ impl Portfolio {
                     log::warn!("Maintenance margins not applicable for cash account");
                     HashMap::new()
                 }
+                AccountAny::Betting(_) => {
+                    log::warn!("Maintenance margins not applicable for betting account");
+                    HashMap::new()
+                }
             },
         )
     }
@@ -727,8 +752,47 @@ impl Portfolio {
         true
     }
 
+    #[must_use]
+    pub fn greeks_config(&self) -> PortfolioGreeksConfig {
+        self.inner.borrow().greeks_config.clone()
+    }
+
+    /// Returns the aggregated greeks of the open positions on the given `underlying`, or `None`
+    /// if there are none or the greeks of any position cannot be calculated.
+    #[must_use]
+    pub fn greeks(&self, underlying: &Ustr) -> Option<PortfolioGreeks> {
+        self.greeks_map().get(underlying).copied().flatten()
+    }
+
+    /// Returns the aggregated greeks of the open positions per underlying, omitting any
+    /// underlying for which the greeks of a position cannot be calculated.
+    #[must_use]
+    pub fn greeks_by_underlying(&self) -> HashMap<Ustr, PortfolioGreeks> {
+        self.greeks_map()
+            .into_iter()
+            .filter_map(|(underlying, greeks)| Some((underlying, greeks?)))
+            .collect()
+    }
+
+    /// Returns the aggregated greeks of all open option and futures positions, or `None` if the
+    /// greeks of any position cannot be calculated.
+    #[must_use]
+    pub fn total_greeks(&self) -> Option<PortfolioGreeks> {
+        self.greeks_map()
+            .into_values()
+            .try_fold(PortfolioGreeks::default(), |total, greeks| {
+                Some(total + greeks?)
+            })
+    }
+
     // -- COMMANDS --------------------------------------------------------------------------------
 
+    pub fn set_greeks_config(&mut self, config: PortfolioGreeksConfig) {
+        let mut inner = self.inner.borrow_mut();
+        inner.greeks_config = config;
+        inner.greeks = None;
+    }
+
     pub fn initialize_orders(&mut self) {
         let mut initialized = true;
         let orders_and_instruments = {
@@ -868,7 +932,7 @@ impl Portfolio {
                 };
 
             let account = match account {
-                AccountAny::Cash(_) => continue,
+                AccountAny::Cash(_) | AccountAny::Betting(_) => continue,
                 AccountAny::Margin(margin_account) => margin_account,
             };
 
@@ -978,6 +1042,76 @@ impl Portfolio {
         }
     }
 
+    fn greeks_map(&self) -> HashMap<Ustr, Option<PortfolioGreeks>> {
+        let ts_now = self.clock.borrow().timestamp_ns();
+        {
+            let inner = self.inner.borrow();
+            if let Some(greeks) = &inner.greeks {
+                // Recalculate once stale, as the time to expiry decays
+                let age = ts_now.as_u64().saturating_sub(inner.greeks_ts.as_u64());
+                if age < inner.greeks_config.recalc_interval_ns {
+                    return greeks.clone();
+                }
+            }
+        }
+
+        let (greeks, instrument_ids) = self.calculate_greeks(ts_now);
+        let mut inner = self.inner.borrow_mut();
+        inner.greeks = Some(greeks.clone());
+        inner.greeks_ts = ts_now;
+        inner.greeks_instruments = instrument_ids;
+        greeks
+    }
+
+    fn calculate_greeks(
+        &self,
+        ts_now: UnixNanos,
+    ) -> (
+        HashMap<Ustr, Option<PortfolioGreeks>>,
+        HashSet<InstrumentId>,
+    ) {
+        let borrowed_cache = self.cache.borrow();
+        let config = self.inner.borrow().greeks_config.clone();
+
+        let mut greeks: HashMap<Ustr, Option<PortfolioGreeks>> = HashMap::new();
+        let mut instrument_ids = HashSet::new();
+
+        for position in borrowed_cache.positions_open(None, None, None, None) {
+            let instrument =
+                if let Some(instrument) = borrowed_cache.instrument(&position.instrument_id) {
+                    instrument
+                } else {
+                    log::error!(
+                        "Cannot calculate greeks: no instrument for {}",
+                        position.instrument_id
+                    );
+                    continue;
+                };
+
+            let Some(underlying) = greeks_underlying(instrument) else {
+                continue; // No greeks for instrument
+            };
+
+            // Track the instruments whose quotes change the greeks
+            instrument_ids.insert(position.instrument_id);
+            if let Some(underlying_id) = greeks_underlying_id(&borrowed_cache, instrument, &config)
+            {
+                instrument_ids.insert(underlying_id);
+            }
+
+            let position_greeks = instrument_greeks(&borrowed_cache, instrument, &config, ts_now)
+                .map(|unit_greeks| unit_greeks * position.signed_qty);
+            let total = greeks
+                .entry(underlying)
+                .or_insert(Some(PortfolioGreeks::default()));
+            *total = total
+                .zip(position_greeks)
+                .map(|(total, greeks)| total + greeks);
+        }
+
+        (greeks, instrument_ids)
+    }
+
     fn calculate_unrealized_pnl(&mut self, instrument_id: &InstrumentId) -> Option<Money> {
         let borrowed_cache = self.cache.borrow();
 
@@ -1207,6 +1341,13 @@ fn update_quote_tick(
     inner: Rc<RefCell<PortfolioState>>,
     quote: &QuoteTick,
 ) {
+    {
+        let mut inner = inner.borrow_mut();
+        if inner.greeks_instruments.contains(&quote.instrument_id) {
+            inner.greeks = None;
+        }
+    }
+
     update_instrument_id(cache, msgbus, clock.clone(), inner, &quote.instrument_id);
 }
 
@@ -1311,7 +1452,7 @@ fn update_instrument_id(
         portfolio_clone.calculate_unrealized_pnl(instrument_id);
 
     if result_init.is_some()
-        && (matches!(account, AccountAny::Cash(_))
+        && (matches!(account, AccountAny::Cash(_) | AccountAny::Betting(_))
             || (result_maint.is_some() && result_unrealized_pnl.is_some()))
     {
         inner.borrow_mut().pending_calcs.remove(instrument_id);
@@ -1357,6 +1498,11 @@ fn update_order(
                 return;
             }
         }
+        AccountAny::Betting(betting_account) => {
+            if !betting_account.base.calculate_account_state {
+                return;
+            }
+        }
     }
 
     match event {
@@ -1459,6 +1605,7 @@ fn update_position(
     event: &PositionEvent,
 ) {
     let instrument_id = event.instrument_id();
+    inner.borrow_mut().greeks = None;
 
     let positions_open: Vec<Position> = {
         let borrowed_cache = cache.borrow();
@@ -1575,7 +1722,7 @@ mod tests {
     use nautilus_common::{cache::Cache, clock::TestClock, msgbus::MessageBus};
     use nautilus_core::{UnixNanos, UUID4};
     use nautilus_model::{
-        data::{Bar, BarType, QuoteTick},
+        data::{greeks::black_scholes_greeks, Bar, BarType, QuoteTick},
         enums::{AccountType, LiquiditySide, OmsType, OrderSide, OrderType},
         events::{
             account::stubs::cash_account_state,
@@ -1585,11 +1732,15 @@ mod tests {
         },
         identifiers::{
             stubs::{account_id, uuid4},
-            AccountId, ClientOrderId, PositionId, StrategyId, Symbol, TradeId, VenueOrderId,
+            AccountId, ClientOrderId, InstrumentId, PositionId, StrategyId, Symbol, TradeId,
+            VenueOrderId,
         },
         instruments::{
-            stubs::{audusd_sim, currency_pair_btcusdt, default_fx_ccy, ethusdt_bitmex},
-            CryptoPerpetual, CurrencyPair, InstrumentAny,
+            stubs::{
+                audusd_sim, currency_pair_btcusdt, default_fx_ccy, equity_aapl, ethusdt_bitmex,
+                futures_contract_es, option_contract_appl,
+            },
+            CryptoPerpetual, CurrencyPair, Equity, InstrumentAny, OptionContract,
         },
         orders::{OrderAny, OrderTestBuilder},
         position::Position,
@@ -1597,8 +1748,10 @@ mod tests {
     };
     use rstest::{fixture, rstest};
     use rust_decimal::{prelude::FromPrimitive, Decimal};
+    use ustr::Ustr;
 
     use super::Portfolio;
+    use crate::greeks::{PortfolioGreeks, PortfolioGreeksConfig};
 
     #[fixture]
     fn msgbus() -> MessageBus {
@@ -1905,6 +2058,61 @@ mod tests {
         }
     }
 
+    fn add_greeks_instruments(portfolio: &Portfolio) -> (InstrumentAny, InstrumentAny) {
+        let option = InstrumentAny::OptionContract(OptionContract {
+            expiration_ns: UnixNanos::from(31_557_600 * 1_000_000_000), // One year
+            ..option_contract_appl()
+        });
+        let future = InstrumentAny::FuturesContract(futures_contract_es(None, None));
+
+        let mut borrowed_cache = portfolio.cache.borrow_mut();
+        borrowed_cache
+            .add_instrument(InstrumentAny::Equity(equity_aapl()))
+            .unwrap();
+        borrowed_cache.add_instrument(option.clone()).unwrap();
+        borrowed_cache.add_instrument(future.clone()).unwrap();
+        (option, future)
+    }
+
+    fn add_open_position(
+        portfolio: &Portfolio,
+        instrument: &InstrumentAny,
+        side: OrderSide,
+        quantity: &str,
+        position_id: &str,
+    ) {
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument.id())
+            .side(side)
+            .quantity(Quantity::from(quantity))
+            .build();
+
+        let mut fill = fill_order(&order);
+        fill.position_id = Some(PositionId::new(position_id));
+        fill.order_side = side;
+        fill.last_qty = Quantity::from(quantity);
+        fill.last_px = Price::from("1.00");
+
+        let position = Position::new(instrument, fill);
+        portfolio
+            .cache
+            .borrow_mut()
+            .add_position(position, OmsType::Hedging)
+            .unwrap();
+    }
+
+    fn get_underlying_quote(bid: &str, ask: &str) -> QuoteTick {
+        QuoteTick::new(
+            InstrumentId::from("AAPL.XNAS"),
+            Price::from(bid),
+            Price::from(ask),
+            Quantity::from(1),
+            Quantity::from(1),
+            0.into(),
+            0.into(),
+        )
+    }
+
     // Tests
     #[rstest]
     fn test_account_when_account_returns_the_account_facade(mut portfolio: Portfolio) {
@@ -3492,4 +3700,164 @@ mod tests {
         // FIX: TODO: should not be empty
         assert_eq!(portfolio.margins_maint(&Venue::from("SIM")), HashMap::new());
     }
+
+    #[rstest]
+    fn test_greeks_when_no_positions_returns_none(portfolio: Portfolio) {
+        assert!(portfolio.greeks(&Ustr::from("AAPL")).is_none());
+        assert!(portfolio.greeks_by_underlying().is_empty());
+        assert_eq!(portfolio.total_greeks(), Some(PortfolioGreeks::default()));
+    }
+
+    #[rstest]
+    fn test_greeks_aggregates_option_and_futures_positions(mut portfolio: Portfolio) {
+        let (option, future) = add_greeks_instruments(&portfolio);
+        let quote = get_underlying_quote("149.90", "150.10");
+        portfolio.cache.borrow_mut().add_quote(quote).unwrap();
+        portfolio.set_greeks_config(PortfolioGreeksConfig {
+            vols: [(Ustr::from("AAPL"), 0.3)].into_iter().collect(),
+            ..Default::default()
+        });
+
+        add_open_position(&portfolio, &option, OrderSide::Buy, "10", "P-1");
+        add_open_position(&portfolio, &option, OrderSide::Sell, "4", "P-2");
+        add_open_position(&portfolio, &future, OrderSide::Sell, "2", "P-3");
+
+        let unit_greeks = black_scholes_greeks(150.0, 0.05, 0.05, 0.3, true, 149.0, 1.0, 1.0);
+        let aapl_greeks = portfolio.greeks(&Ustr::from("AAPL")).unwrap();
+        let total_greeks = portfolio.total_greeks().unwrap();
+
+        assert!((aapl_greeks.delta - 6.0 * unit_greeks.delta).abs() < 1e-9);
+        assert!((aapl_greeks.gamma - 6.0 * unit_greeks.gamma).abs() < 1e-9);
+        assert!((aapl_greeks.vega - 6.0 * unit_greeks.vega).abs() < 1e-9);
+        assert!((aapl_greeks.theta - 6.0 * unit_greeks.theta).abs() < 1e-9);
+        assert_eq!(
+            portfolio.greeks(&Ustr::from("ESZ21")),
+            Some(PortfolioGreeks {
+                delta: -2.0,
+                ..Default::default()
+            })
+        );
+        assert_eq!(portfolio.greeks_by_underlying().len(), 2);
+        assert!((total_greeks.delta - (aapl_greeks.delta - 2.0)).abs() < 1e-9);
+        assert!((total_greeks.vega - aapl_greeks.vega).abs() < 1e-9);
+    }
+
+    #[rstest]
+    fn test_greeks_update_on_underlying_quote(mut portfolio: Portfolio) {
+        let (option, _) = add_greeks_instruments(&portfolio);
+        let quote = get_underlying_quote("149.90", "150.10");
+        portfolio.cache.borrow_mut().add_quote(quote).unwrap();
+        portfolio.set_greeks_config(PortfolioGreeksConfig {
+            default_vol: Some(0.3),
+            ..Default::default()
+        });
+        add_open_position(&portfolio, &option, OrderSide::Buy, "1", "P-1");
+        let initial_delta = portfolio.greeks(&Ustr::from("AAPL")).unwrap().delta;
+
+        let quote = get_underlying_quote("159.90", "160.10");
+        portfolio.cache.borrow_mut().add_quote(quote).unwrap();
+        portfolio.update_quote_tick(&quote);
+
+        let expected = black_scholes_greeks(160.0, 0.05, 0.05, 0.3, true, 149.0, 1.0, 1.0);
+        let delta = portfolio.greeks(&Ustr::from("AAPL")).unwrap().delta;
+        assert!(delta > initial_delta);
+        assert!((delta - expected.delta).abs() < 1e-9);
+    }
+
+    #[rstest]
+    fn test_greeks_recalculated_as_time_passes(
+        msgbus: MessageBus,
+        simple_cache: Cache,
+        clock: TestClock,
+    ) {
+        let clock = Rc::new(RefCell::new(clock));
+        let mut portfolio = Portfolio::new(
+            Rc::new(RefCell::new(msgbus)),
+            Rc::new(RefCell::new(simple_cache)),
+            clock.clone(),
+            true,
+        );
+        let (option, _) = add_greeks_instruments(&portfolio);
+        let quote = get_underlying_quote("149.90", "150.10");
+        portfolio.cache.borrow_mut().add_quote(quote).unwrap();
+        portfolio.set_greeks_config(PortfolioGreeksConfig {
+            default_vol: Some(0.3),
+            ..Default::default()
+        });
+        add_open_position(&portfolio, &option, OrderSide::Buy, "1", "P-1");
+        let initial_theta = portfolio.greeks(&Ustr::from("AAPL")).unwrap().theta;
+
+        // Within the recalculation interval the cached greeks are returned
+        let recalc_interval_ns = portfolio.greeks_config().recalc_interval_ns;
+        clock
+            .borrow_mut()
+            .advance_time(UnixNanos::from(recalc_interval_ns - 1), true);
+        assert_eq!(
+            portfolio.greeks(&Ustr::from("AAPL")).unwrap().theta,
+            initial_theta
+        );
+
+        // Half a year later the greeks reflect the shorter time to expiry
+        clock
+            .borrow_mut()
+            .advance_time(UnixNanos::from(31_557_600 * 500_000_000), true);
+        let expected = black_scholes_greeks(150.0, 0.05, 0.05, 0.3, true, 149.0, 0.5, 1.0);
+        let theta = portfolio.greeks(&Ustr::from("AAPL")).unwrap().theta;
+        assert_ne!(theta, initial_theta);
+        assert!((theta - expected.theta).abs() < 1e-9);
+    }
+
+    #[rstest]
+    fn test_greeks_when_underlying_on_several_venues_uses_configured_underlying(
+        mut portfolio: Portfolio,
+    ) {
+        let (option, _) = add_greeks_instruments(&portfolio);
+        let other_venue = InstrumentId::from("AAPL.ARCX");
+        {
+            let mut borrowed_cache = portfolio.cache.borrow_mut();
+            borrowed_cache
+                .add_instrument(InstrumentAny::Equity(Equity {
+                    id: other_venue,
+                    ..equity_aapl()
+                }))
+                .unwrap();
+            borrowed_cache
+                .add_quote(get_underlying_quote("149.90", "150.10"))
+                .unwrap();
+        }
+        portfolio.set_greeks_config(PortfolioGreeksConfig {
+            default_vol: Some(0.3),
+            ..Default::default()
+        });
+        add_open_position(&portfolio, &option, OrderSide::Buy, "1", "P-1");
+
+        // Ambiguous underlying
+        assert!(portfolio.greeks(&Ustr::from("AAPL")).is_none());
+
+        portfolio.set_greeks_config(PortfolioGreeksConfig {
+            default_vol: Some(0.3),
+            underlying_ids: [(Ustr::from("AAPL"), InstrumentId::from("AAPL.XNAS"))]
+                .into_iter()
+                .collect(),
+            ..Default::default()
+        });
+        let expected = black_scholes_greeks(150.0, 0.05, 0.05, 0.3, true, 149.0, 1.0, 1.0);
+        let delta = portfolio.greeks(&Ustr::from("AAPL")).unwrap().delta;
+        assert!((delta - expected.delta).abs() < 1e-9);
+    }
+
+    #[rstest]
+    fn test_greeks_when_option_cannot_be_priced(portfolio: Portfolio) {
+        let (option, future) = add_greeks_instruments(&portfolio);
+        add_open_position(&portfolio, &option, OrderSide::Buy, "1", "P-1");
+        add_open_position(&portfolio, &future, OrderSide::Buy, "1", "P-2");
+
+        // No underlying price or volatility for the option
+        assert!(portfolio.greeks(&Ustr::from("AAPL")).is_none());
+        assert!(portfolio.total_greeks().is_none());
+        assert_eq!(
+            portfolio.greeks_by_underlying().keys().collect::<Vec<_>>(),
+            vec![&Ustr::from("ESZ21")]
+        );
+    }
 }
@@ -28,7 +28,7 @@ use nautilus_common::{
 use nautilus_core::UUID4;
 use nautilus_execution::messages::{ModifyOrder, SubmitOrder, SubmitOrderList, TradingCommand};
 use nautilus_model::{
-    accounts::{Account, AccountAny},
+    accounts::{Account, AccountAny, BettingAccount},
     enums::{InstrumentClass, OrderSide, OrderStatus, TradingState},
     events::{OrderDenied, OrderEventAny, OrderModifyRejected},
     identifiers::InstrumentId,
@@ -527,6 +527,14 @@ impl RiskEngine {
         ////////////////////////////////////////////////////////////////////////////////
         // VALIDATION CHECKS
         ////////////////////////////////////////////////////////////////////////////////
+        if let InstrumentAny::IndexInstrument(_) = instrument {
+            self.deny_order(
+                order,
+                &format!("Instrument {} is a non-tradable index", instrument.id()),
+            );
+            return false; // Denied
+        }
+
         if !self.check_order_price(instrument.clone(), order.clone())
             || !self.check_order_quantity(instrument, order)
         {
@@ -607,6 +615,14 @@ impl RiskEngine {
         let cash_account = match account {
             AccountAny::Cash(cash_account) => cash_account,
             AccountAny::Margin(_) => return true, // TODO: Determine risk controls for margin
+            AccountAny::Betting(betting_account) => {
+                return self.check_orders_risk_betting(
+                    &instrument,
+                    &orders,
+                    &betting_account,
+                    max_notional,
+                );
+            }
         };
         let free = cash_account.balance_free(Some(instrument.quote_currency()));
         if self.config.debug {
@@ -839,6 +855,95 @@ impl RiskEngine {
         true // Passed
     }
 
+    /// Checks the liability of each bet, and the cumulative liability of the bets, against the
+    /// free balance of the betting account.
+    ///
+    /// Bets are checked without offsetting other open bets on the same market.
+    fn check_orders_risk_betting(
+        &self,
+        instrument: &InstrumentAny,
+        orders: &[OrderAny],
+        account: &BettingAccount,
+        max_notional: Option<Money>,
+    ) -> bool {
+        let free = account.balance_free(Some(instrument.quote_currency()));
+        if self.config.debug {
+            log::debug!("Free balance: {:?}", free);
+        }
+
+        let mut cum_liability: Option<Money> = None;
+        for order in orders {
+            let price = if let Some(price) = order.price().or_else(|| order.trigger_price()) {
+                price
+            } else {
+                log::warn!(
+                    "Cannot check {} order risk: no price was set",
+                    order.order_type()
+                );
+                continue;
+            };
+
+            // Check MAX notional per order limit
+            let notional = instrument.calculate_notional_value(order.quantity(), price, None);
+            if let Some(max_notional_value) = max_notional {
+                if notional > max_notional_value {
+                    self.deny_order(
+                        order.clone(),
+                        &format!(
+                            "NOTIONAL_EXCEEDS_MAX_PER_ORDER: max_notional={max_notional_value:?}, notional={notional:?}"
+                        ),
+                    );
+                    return false; // Denied
+                }
+            }
+
+            let balance_impact = account.balance_impact(
+                instrument,
+                order.quantity(),
+                price,
+                order.order_side_specified(),
+            );
+            let liability = Money::from_raw(-balance_impact.raw, balance_impact.currency);
+
+            if self.config.debug {
+                log::debug!("Liability: {}", liability);
+            }
+
+            if let Some(free_val) = free {
+                if liability > free_val {
+                    self.deny_order(
+                        order.clone(),
+                        &format!(
+                            "LIABILITY_EXCEEDS_FREE_BALANCE: free={free_val:?}, liability={liability:?}"
+                        ),
+                    );
+                    return false; // Denied
+                }
+            }
+
+            let cum_liability = cum_liability.get_or_insert(Money::zero(liability.currency));
+            cum_liability.raw += liability.raw;
+
+            if self.config.debug {
+                log::debug!("Cumulative liability: {:?}", cum_liability);
+            }
+
+            if let Some(free_val) = free {
+                if *cum_liability > free_val {
+                    self.deny_order(
+                        order.clone(),
+                        &format!(
+                            "CUM_LIABILITY_EXCEEDS_FREE_BALANCE: free={free_val}, cum_liability={cum_liability}"
+                        ),
+                    );
+                    return false; // Denied
+                }
+            }
+        }
+
+        true // Passed
+    }
+
     fn check_price(&self, instrument: &InstrumentAny, price: Option<Price>) -> Option<String> {
         let price_val = price?;
 
@@ -1090,14 +1195,15 @@ mod tests {
     };
     use nautilus_model::{
         accounts::{
-            stubs::{cash_account, margin_account},
+            stubs::{betting_account, cash_account, margin_account},
             AccountAny,
         },
         data::{stubs::quote_audusd, QuoteTick},
         enums::{AccountType, LiquiditySide, OrderSide, OrderType, TradingState},
         events::{
-            account::stubs::cash_account_state_million_usd, AccountState, OrderAccepted,
-            OrderDenied, OrderEventAny, OrderEventType, OrderFilled, OrderSubmitted,
+            account::stubs::{betting_account_state, cash_account_state_million_usd},
+            AccountState, OrderAccepted, OrderDenied, OrderEventAny, OrderEventType, OrderFilled,
+            OrderSubmitted,
         },
         identifiers::{
             stubs::{
@@ -1108,8 +1214,10 @@ mod tests {
             Symbol, TradeId, TraderId, VenueOrderId,
         },
         instruments::{
-            stubs::{audusd_sim, crypto_perpetual_ethusdt, xbtusd_bitmex},
-            CryptoPerpetual, CurrencyPair, InstrumentAny,
+            stubs::{
+                audusd_sim, betting, crypto_perpetual_ethusdt, index_instrument_spx, xbtusd_bitmex,
+            },
+            BettingInstrument, CryptoPerpetual, CurrencyPair, IndexInstrument, InstrumentAny,
         },
         orders::{OrderAny, OrderList, OrderTestBuilder},
         types::{fixed::FIXED_PRECISION, AccountBalance, Currency, Money, Price, Quantity},
@@ -2143,6 +2251,78 @@ mod tests {
         );
     }
 
+    #[rstest]
+    fn test_submit_order_for_index_instrument_then_denies(
+        mut msgbus: MessageBus,
+        strategy_id_ema_cross: StrategyId,
+        client_id_binance: ClientId,
+        trader_id: TraderId,
+        client_order_id: ClientOrderId,
+        index_instrument_spx: IndexInstrument,
+        venue_order_id: VenueOrderId,
+        process_order_event_handler: ShareableMessageHandler,
+        cash_account_state_million_usd: AccountState,
+        mut simple_cache: Cache,
+    ) {
+        msgbus.register(
+            msgbus.switchboard.exec_engine_process,
+            process_order_event_handler.clone(),
+        );
+
+        simple_cache
+            .add_instrument(InstrumentAny::IndexInstrument(index_instrument_spx))
+            .unwrap();
+
+        simple_cache
+            .add_account(AccountAny::Cash(cash_account(
+                cash_account_state_million_usd,
+            )))
+            .unwrap();
+
+        let mut risk_engine = get_risk_engine(
+            Rc::new(RefCell::new(msgbus)),
+            Some(Rc::new(RefCell::new(simple_cache))),
+            None,
+            None,
+            false,
+        );
+        let order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(index_instrument_spx.id)
+            .side(OrderSide::Buy)
+            .price(Price::from("5000.00"))
+            .quantity(Quantity::from("1"))
+            .build();
+
+        let submit_order = SubmitOrder::new(
+            trader_id,
+            client_id_binance,
+            strategy_id_ema_cross,
+            index_instrument_spx.id,
+            client_order_id,
+            venue_order_id,
+            order,
+            None,
+            None,
+            UUID4::new(),
+            risk_engine.clock.borrow().timestamp_ns(),
+        )
+        .unwrap();
+
+        risk_engine.execute(TradingCommand::SubmitOrder(submit_order));
+        let saved_process_messages =
+            get_process_order_event_handler_messages(process_order_event_handler);
+        assert_eq!(saved_process_messages.len(), 1);
+
+        assert_eq!(
+            saved_process_messages.first().unwrap().event_type(),
+            OrderEventType::Denied
+        );
+        assert_eq!(
+            saved_process_messages.first().unwrap().message().unwrap(),
+            Ustr::from("Instrument SPX.CBOE is a non-tradable index")
+        );
+    }
+
     #[rstest]
     fn test_submit_order_when_invalid_price_precision_then_denies(
         mut msgbus: MessageBus,
@@ -4506,6 +4686,76 @@ mod tests {
         assert_eq!(saved_process_messages.len(), 0); // Currently, it executes because check_orders_risk returns true for margin_account
     }
 
+    #[rstest]
+    fn test_submit_order_when_lay_liability_over_free_balance_then_denies_with_betting_account(
+        mut msgbus: MessageBus,
+        strategy_id_ema_cross: StrategyId,
+        client_id_binance: ClientId,
+        trader_id: TraderId,
+        client_order_id: ClientOrderId,
+        betting: BettingInstrument,
+        venue_order_id: VenueOrderId,
+        process_order_event_handler: ShareableMessageHandler,
+        betting_account_state: AccountState,
+        mut simple_cache: Cache,
+    ) {
+        msgbus.register(
+            msgbus.switchboard.exec_engine_process,
+            process_order_event_handler.clone(),
+        );
+
+        let instrument = InstrumentAny::Betting(betting);
+        simple_cache.add_instrument(instrument.clone()).unwrap();
+
+        simple_cache
+            .add_account(AccountAny::Betting(betting_account(betting_account_state)))
+            .unwrap();
+
+        let mut risk_engine = get_risk_engine(
+            Rc::new(RefCell::new(msgbus)),
+            Some(Rc::new(RefCell::new(simple_cache))),
+            None,
+            None,
+            false,
+        );
+        // Laying 100 at odds of 20.00 has a liability of 1900
+        let order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(instrument.id())
+            .side(OrderSide::Buy)
+            .price(Price::from("20.00"))
+            .quantity(Quantity::from("100"))
+            .build();
+
+        let submit_order = SubmitOrder::new(
+            trader_id,
+            client_id_binance,
+            strategy_id_ema_cross,
+            instrument.id(),
+            client_order_id,
+            venue_order_id,
+            order,
+            None,
+            None,
+            UUID4::new(),
+            risk_engine.clock.borrow().timestamp_ns(),
+        )
+        .unwrap();
+
+        risk_engine.execute(TradingCommand::SubmitOrder(submit_order));
+        let saved_process_messages =
+            get_process_order_event_handler_messages(process_order_event_handler);
+        assert_eq!(saved_process_messages.len(), 1);
+
+        assert_eq!(
+            saved_process_messages.first().unwrap().event_type(),
+            OrderEventType::Denied
+        );
+        assert_eq!(
+            saved_process_messages.first().unwrap().message().unwrap(),
+            Ustr::from("LIABILITY_EXCEEDS_FREE_BALANCE: free=Money(1000.00, GBP), liability=Money(1900.00, GBP)")
+        );
+    }
+
     #[rstest]
     fn test_submit_order_for_less_than_max_cum_transaction_value_adausdt_with_crypto_cash_account(
         mut msgbus: MessageBus,
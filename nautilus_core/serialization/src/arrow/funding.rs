@@ -0,0 +1,159 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{collections::HashMap, str::FromStr, sync::Arc};
+
+use arrow::{
+    array::{Float64Array, UInt64Array},
+    datatypes::{DataType, Field, Schema},
+    error::ArrowError,
+    record_batch::RecordBatch,
+};
+use nautilus_model::{data::FundingRateUpdate, identifiers::InstrumentId};
+
+use super::{extract_column, DecodeDataFromRecordBatch, EncodingError, KEY_INSTRUMENT_ID};
+use crate::arrow::{ArrowSchemaProvider, Data, DecodeFromRecordBatch, EncodeToRecordBatch};
+
+impl ArrowSchemaProvider for FundingRateUpdate {
+    fn get_schema(metadata: Option<HashMap<String, String>>) -> Schema {
+        let fields = vec![
+            Field::new("rate", DataType::Float64, false),
+            Field::new("ts_event", DataType::UInt64, false),
+            Field::new("ts_init", DataType::UInt64, false),
+        ];
+
+        match metadata {
+            Some(metadata) => Schema::new_with_metadata(fields, metadata),
+            None => Schema::new(fields),
+        }
+    }
+}
+
+fn parse_metadata(metadata: &HashMap<String, String>) -> Result<InstrumentId, EncodingError> {
+    let instrument_id_str = metadata
+        .get(KEY_INSTRUMENT_ID)
+        .ok_or_else(|| EncodingError::MissingMetadata(KEY_INSTRUMENT_ID))?;
+    let instrument_id = InstrumentId::from_str(instrument_id_str)
+        .map_err(|e| EncodingError::ParseError(KEY_INSTRUMENT_ID, e.to_string()))?;
+
+    Ok(instrument_id)
+}
+
+impl EncodeToRecordBatch for FundingRateUpdate {
+    fn encode_batch(
+        metadata: &HashMap<String, String>,
+        data: &[Self],
+    ) -> Result<RecordBatch, ArrowError> {
+        let mut rate_builder = Float64Array::builder(data.len());
+        let mut ts_event_builder = UInt64Array::builder(data.len());
+        let mut ts_init_builder = UInt64Array::builder(data.len());
+
+        for update in data {
+            rate_builder.append_value(update.rate);
+            ts_event_builder.append_value(update.ts_event.as_u64());
+            ts_init_builder.append_value(update.ts_init.as_u64());
+        }
+
+        RecordBatch::try_new(
+            Self::get_schema(Some(metadata.clone())).into(),
+            vec![
+                Arc::new(rate_builder.finish()),
+                Arc::new(ts_event_builder.finish()),
+                Arc::new(ts_init_builder.finish()),
+            ],
+        )
+    }
+
+    fn metadata(&self) -> HashMap<String, String> {
+        FundingRateUpdate::get_metadata(&self.instrument_id)
+    }
+}
+
+impl DecodeFromRecordBatch for FundingRateUpdate {
+    fn decode_batch(
+        metadata: &HashMap<String, String>,
+        record_batch: RecordBatch,
+    ) -> Result<Vec<Self>, EncodingError> {
+        let instrument_id = parse_metadata(metadata)?;
+        let cols = record_batch.columns();
+
+        let rate_values = extract_column::<Float64Array>(cols, "rate", 0, DataType::Float64)?;
+        let ts_event_values = extract_column::<UInt64Array>(cols, "ts_event", 1, DataType::UInt64)?;
+        let ts_init_values = extract_column::<UInt64Array>(cols, "ts_init", 2, DataType::UInt64)?;
+
+        let result: Result<Vec<Self>, EncodingError> = (0..record_batch.num_rows())
+            .map(|row| {
+                Ok(Self {
+                    instrument_id,
+                    rate: rate_values.value(row),
+                    ts_event: ts_event_values.value(row).into(),
+                    ts_init: ts_init_values.value(row).into(),
+                })
+            })
+            .collect();
+
+        result
+    }
+}
+
+impl DecodeDataFromRecordBatch for FundingRateUpdate {
+    fn decode_data_batch(
+        metadata: &HashMap<String, String>,
+        record_batch: RecordBatch,
+    ) -> Result<Vec<Data>, EncodingError> {
+        let updates: Vec<Self> = Self::decode_batch(metadata, record_batch)?;
+        Ok(updates.into_iter().map(Data::from).collect())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_get_schema_map() {
+        let arrow_schema = FundingRateUpdate::get_schema_map();
+        let mut expected_map = HashMap::new();
+        expected_map.insert("rate".to_string(), "Float64".to_string());
+        expected_map.insert("ts_event".to_string(), "UInt64".to_string());
+        expected_map.insert("ts_init".to_string(), "UInt64".to_string());
+        assert_eq!(arrow_schema, expected_map);
+    }
+
+    #[rstest]
+    fn test_encode_decode_round_trip() {
+        let instrument_id = InstrumentId::from("BTCUSDT-PERP.BINANCE");
+        let data = vec![
+            FundingRateUpdate::new(instrument_id, 0.0001, 1.into(), 3.into()),
+            FundingRateUpdate::new(instrument_id, -0.000_156_8, 2.into(), 4.into()),
+        ];
+        let metadata = FundingRateUpdate::get_metadata(&instrument_id);
+        let record_batch = FundingRateUpdate::encode_batch(&metadata, &data).unwrap();
+
+        assert_eq!(record_batch.num_columns(), 3);
+        assert_eq!(record_batch.num_rows(), 2);
+
+        let decoded_data = FundingRateUpdate::decode_batch(&metadata, record_batch).unwrap();
+
+        assert_eq!(decoded_data, data);
+    }
+}
@@ -18,6 +18,8 @@
 pub mod bar;
 pub mod delta;
 pub mod depth;
+pub mod funding;
+pub mod prices;
 pub mod quote;
 pub mod trade;
 
@@ -35,8 +37,14 @@ use arrow::{
 };
 use nautilus_model::{
     data::{
-        bar::Bar, delta::OrderBookDelta, depth::OrderBookDepth10, quote::QuoteTick,
-        trade::TradeTick, Data,
+        bar::Bar,
+        delta::OrderBookDelta,
+        depth::OrderBookDepth10,
+        funding::FundingRateUpdate,
+        prices::{IndexPriceUpdate, MarkPriceUpdate},
+        quote::QuoteTick,
+        trade::TradeTick,
+        Data,
     },
     types::{price::PriceRaw, quantity::QuantityRaw},
 };
@@ -238,3 +246,45 @@ pub fn bars_to_arrow_record_batch_bytes(data: Vec<Bar>) -> Result<RecordBatch, E
     let metadata = first.metadata();
     Bar::encode_batch(&metadata, &data).map_err(EncodingError::ArrowError)
 }
+
+pub fn mark_prices_to_arrow_record_batch_bytes(
+    data: Vec<MarkPriceUpdate>,
+) -> Result<RecordBatch, EncodingError> {
+    if data.is_empty() {
+        return Err(EncodingError::EmptyData);
+    }
+
+    // Take first element and extract metadata
+    // SAFETY: Unwrap safe as already checked that `data` not empty
+    let first = data.first().unwrap();
+    let metadata = first.metadata();
+    MarkPriceUpdate::encode_batch(&metadata, &data).map_err(EncodingError::ArrowError)
+}
+
+pub fn index_prices_to_arrow_record_batch_bytes(
+    data: Vec<IndexPriceUpdate>,
+) -> Result<RecordBatch, EncodingError> {
+    if data.is_empty() {
+        return Err(EncodingError::EmptyData);
+    }
+
+    // Take first element and extract metadata
+    // SAFETY: Unwrap safe as already checked that `data` not empty
+    let first = data.first().unwrap();
+    let metadata = first.metadata();
+    IndexPriceUpdate::encode_batch(&metadata, &data).map_err(EncodingError::ArrowError)
+}
+
+pub fn funding_rates_to_arrow_record_batch_bytes(
+    data: Vec<FundingRateUpdate>,
+) -> Result<RecordBatch, EncodingError> {
+    if data.is_empty() {
+        return Err(EncodingError::EmptyData);
+    }
+
+    // Take first element and extract metadata
+    // SAFETY: Unwrap safe as already checked that `data` not empty
+    let first = data.first().unwrap();
+    let metadata = first.metadata();
+    FundingRateUpdate::encode_batch(&metadata, &data).map_err(EncodingError::ArrowError)
+}
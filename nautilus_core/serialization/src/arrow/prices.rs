@@ -0,0 +1,336 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{collections::HashMap, str::FromStr, sync::Arc};
+
+use arrow::{
+    array::{FixedSizeBinaryArray, FixedSizeBinaryBuilder, UInt64Array},
+    datatypes::{DataType, Field, Schema},
+    error::ArrowError,
+    record_batch::RecordBatch,
+};
+use nautilus_model::{
+    data::{IndexPriceUpdate, MarkPriceUpdate},
+    identifiers::InstrumentId,
+    types::{fixed::PRECISION_BYTES, Price},
+};
+
+use super::{
+    extract_column, get_raw_price, DecodeDataFromRecordBatch, EncodingError, KEY_INSTRUMENT_ID,
+    KEY_PRICE_PRECISION,
+};
+use crate::arrow::{ArrowSchemaProvider, Data, DecodeFromRecordBatch, EncodeToRecordBatch};
+
+fn parse_metadata(metadata: &HashMap<String, String>) -> Result<(InstrumentId, u8), EncodingError> {
+    let instrument_id_str = metadata
+        .get(KEY_INSTRUMENT_ID)
+        .ok_or_else(|| EncodingError::MissingMetadata(KEY_INSTRUMENT_ID))?;
+    let instrument_id = InstrumentId::from_str(instrument_id_str)
+        .map_err(|e| EncodingError::ParseError(KEY_INSTRUMENT_ID, e.to_string()))?;
+
+    let price_precision = metadata
+        .get(KEY_PRICE_PRECISION)
+        .ok_or_else(|| EncodingError::MissingMetadata(KEY_PRICE_PRECISION))?
+        .parse::<u8>()
+        .map_err(|e| EncodingError::ParseError(KEY_PRICE_PRECISION, e.to_string()))?;
+
+    Ok((instrument_id, price_precision))
+}
+
+impl ArrowSchemaProvider for MarkPriceUpdate {
+    fn get_schema(metadata: Option<HashMap<String, String>>) -> Schema {
+        let fields = vec![
+            Field::new("value", DataType::FixedSizeBinary(PRECISION_BYTES), false),
+            Field::new("ts_event", DataType::UInt64, false),
+            Field::new("ts_init", DataType::UInt64, false),
+        ];
+
+        match metadata {
+            Some(metadata) => Schema::new_with_metadata(fields, metadata),
+            None => Schema::new(fields),
+        }
+    }
+}
+
+impl EncodeToRecordBatch for MarkPriceUpdate {
+    fn encode_batch(
+        metadata: &HashMap<String, String>,
+        data: &[Self],
+    ) -> Result<RecordBatch, ArrowError> {
+        let mut value_builder = FixedSizeBinaryBuilder::with_capacity(data.len(), PRECISION_BYTES);
+        let mut ts_event_builder = UInt64Array::builder(data.len());
+        let mut ts_init_builder = UInt64Array::builder(data.len());
+
+        for update in data {
+            value_builder
+                .append_value(update.value.raw.to_le_bytes())
+                .unwrap();
+            ts_event_builder.append_value(update.ts_event.as_u64());
+            ts_init_builder.append_value(update.ts_init.as_u64());
+        }
+
+        RecordBatch::try_new(
+            Self::get_schema(Some(metadata.clone())).into(),
+            vec![
+                Arc::new(value_builder.finish()),
+                Arc::new(ts_event_builder.finish()),
+                Arc::new(ts_init_builder.finish()),
+            ],
+        )
+    }
+
+    fn metadata(&self) -> HashMap<String, String> {
+        MarkPriceUpdate::get_metadata(&self.instrument_id, self.value.precision)
+    }
+}
+
+impl DecodeFromRecordBatch for MarkPriceUpdate {
+    fn decode_batch(
+        metadata: &HashMap<String, String>,
+        record_batch: RecordBatch,
+    ) -> Result<Vec<Self>, EncodingError> {
+        let (instrument_id, price_precision) = parse_metadata(metadata)?;
+        let cols = record_batch.columns();
+
+        let value_values = extract_column::<FixedSizeBinaryArray>(
+            cols,
+            "value",
+            0,
+            DataType::FixedSizeBinary(PRECISION_BYTES),
+        )?;
+        let ts_event_values = extract_column::<UInt64Array>(cols, "ts_event", 1, DataType::UInt64)?;
+        let ts_init_values = extract_column::<UInt64Array>(cols, "ts_init", 2, DataType::UInt64)?;
+
+        assert_eq!(
+            value_values.value_length(),
+            PRECISION_BYTES,
+            "Price precision uses {PRECISION_BYTES} byte value"
+        );
+
+        let result: Result<Vec<Self>, EncodingError> = (0..record_batch.num_rows())
+            .map(|row| {
+                Ok(Self {
+                    instrument_id,
+                    value: Price::from_raw(get_raw_price(value_values.value(row)), price_precision),
+                    ts_event: ts_event_values.value(row).into(),
+                    ts_init: ts_init_values.value(row).into(),
+                })
+            })
+            .collect();
+
+        result
+    }
+}
+
+impl DecodeDataFromRecordBatch for MarkPriceUpdate {
+    fn decode_data_batch(
+        metadata: &HashMap<String, String>,
+        record_batch: RecordBatch,
+    ) -> Result<Vec<Data>, EncodingError> {
+        let updates: Vec<Self> = Self::decode_batch(metadata, record_batch)?;
+        Ok(updates.into_iter().map(Data::from).collect())
+    }
+}
+
+impl ArrowSchemaProvider for IndexPriceUpdate {
+    fn get_schema(metadata: Option<HashMap<String, String>>) -> Schema {
+        let fields = vec![
+            Field::new("value", DataType::FixedSizeBinary(PRECISION_BYTES), false),
+            Field::new("ts_event", DataType::UInt64, false),
+            Field::new("ts_init", DataType::UInt64, false),
+        ];
+
+        match metadata {
+            Some(metadata) => Schema::new_with_metadata(fields, metadata),
+            None => Schema::new(fields),
+        }
+    }
+}
+
+impl EncodeToRecordBatch for IndexPriceUpdate {
+    fn encode_batch(
+        metadata: &HashMap<String, String>,
+        data: &[Self],
+    ) -> Result<RecordBatch, ArrowError> {
+        let mut value_builder = FixedSizeBinaryBuilder::with_capacity(data.len(), PRECISION_BYTES);
+        let mut ts_event_builder = UInt64Array::builder(data.len());
+        let mut ts_init_builder = UInt64Array::builder(data.len());
+
+        for update in data {
+            value_builder
+                .append_value(update.value.raw.to_le_bytes())
+                .unwrap();
+            ts_event_builder.append_value(update.ts_event.as_u64());
+            ts_init_builder.append_value(update.ts_init.as_u64());
+        }
+
+        RecordBatch::try_new(
+            Self::get_schema(Some(metadata.clone())).into(),
+            vec![
+                Arc::new(value_builder.finish()),
+                Arc::new(ts_event_builder.finish()),
+                Arc::new(ts_init_builder.finish()),
+            ],
+        )
+    }
+
+    fn metadata(&self) -> HashMap<String, String> {
+        IndexPriceUpdate::get_metadata(&self.instrument_id, self.value.precision)
+    }
+}
+
+impl DecodeFromRecordBatch for IndexPriceUpdate {
+    fn decode_batch(
+        metadata: &HashMap<String, String>,
+        record_batch: RecordBatch,
+    ) -> Result<Vec<Self>, EncodingError> {
+        let (instrument_id, price_precision) = parse_metadata(metadata)?;
+        let cols = record_batch.columns();
+
+        let value_values = extract_column::<FixedSizeBinaryArray>(
+            cols,
+            "value",
+            0,
+            DataType::FixedSizeBinary(PRECISION_BYTES),
+        )?;
+        let ts_event_values = extract_column::<UInt64Array>(cols, "ts_event", 1, DataType::UInt64)?;
+        let ts_init_values = extract_column::<UInt64Array>(cols, "ts_init", 2, DataType::UInt64)?;
+
+        assert_eq!(
+            value_values.value_length(),
+            PRECISION_BYTES,
+            "Price precision uses {PRECISION_BYTES} byte value"
+        );
+
+        let result: Result<Vec<Self>, EncodingError> = (0..record_batch.num_rows())
+            .map(|row| {
+                Ok(Self {
+                    instrument_id,
+                    value: Price::from_raw(get_raw_price(value_values.value(row)), price_precision),
+                    ts_event: ts_event_values.value(row).into(),
+                    ts_init: ts_init_values.value(row).into(),
+                })
+            })
+            .collect();
+
+        result
+    }
+}
+
+impl DecodeDataFromRecordBatch for IndexPriceUpdate {
+    fn decode_data_batch(
+        metadata: &HashMap<String, String>,
+        record_batch: RecordBatch,
+    ) -> Result<Vec<Data>, EncodingError> {
+        let updates: Vec<Self> = Self::decode_batch(metadata, record_batch)?;
+        Ok(updates.into_iter().map(Data::from).collect())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use arrow::array::Array;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_get_schema_map() {
+        let arrow_schema = MarkPriceUpdate::get_schema_map();
+        let mut expected_map = HashMap::new();
+
+        let fixed_size_binary = format!("FixedSizeBinary({PRECISION_BYTES})");
+        expected_map.insert("value".to_string(), fixed_size_binary);
+        expected_map.insert("ts_event".to_string(), "UInt64".to_string());
+        expected_map.insert("ts_init".to_string(), "UInt64".to_string());
+        assert_eq!(arrow_schema, expected_map);
+        assert_eq!(IndexPriceUpdate::get_schema_map(), arrow_schema);
+    }
+
+    #[rstest]
+    fn test_encode_mark_price_update() {
+        let instrument_id = InstrumentId::from("BTCUSDT-PERP.BINANCE");
+        let data = vec![
+            MarkPriceUpdate::new(instrument_id, Price::from("100000.10"), 1.into(), 3.into()),
+            MarkPriceUpdate::new(instrument_id, Price::from("100000.20"), 2.into(), 4.into()),
+        ];
+        let metadata = MarkPriceUpdate::get_metadata(&instrument_id, 2);
+        let record_batch = MarkPriceUpdate::encode_batch(&metadata, &data).unwrap();
+
+        let columns = record_batch.columns();
+        let value_values = columns[0]
+            .as_any()
+            .downcast_ref::<FixedSizeBinaryArray>()
+            .unwrap();
+        let ts_event_values = columns[1].as_any().downcast_ref::<UInt64Array>().unwrap();
+        let ts_init_values = columns[2].as_any().downcast_ref::<UInt64Array>().unwrap();
+
+        assert_eq!(columns.len(), 3);
+        assert_eq!(value_values.len(), 2);
+        assert_eq!(
+            get_raw_price(value_values.value(0)),
+            Price::from("100000.10").raw
+        );
+        assert_eq!(
+            get_raw_price(value_values.value(1)),
+            Price::from("100000.20").raw
+        );
+        assert_eq!(ts_event_values.value(0), 1);
+        assert_eq!(ts_event_values.value(1), 2);
+        assert_eq!(ts_init_values.value(0), 3);
+        assert_eq!(ts_init_values.value(1), 4);
+    }
+
+    #[rstest]
+    fn test_index_price_update_round_trip() {
+        let instrument_id = InstrumentId::from("BTCUSDT-PERP.BINANCE");
+        let data = vec![
+            IndexPriceUpdate::new(instrument_id, Price::from("99990.00"), 1.into(), 3.into()),
+            IndexPriceUpdate::new(instrument_id, Price::from("99995.50"), 2.into(), 4.into()),
+        ];
+        let metadata = IndexPriceUpdate::get_metadata(&instrument_id, 2);
+        let record_batch = IndexPriceUpdate::encode_batch(&metadata, &data).unwrap();
+
+        let decoded_data = IndexPriceUpdate::decode_batch(&metadata, record_batch).unwrap();
+
+        assert_eq!(decoded_data, data);
+    }
+
+    #[rstest]
+    fn test_decode_batch_missing_price_precision_error() {
+        let instrument_id = InstrumentId::from("BTCUSDT-PERP.BINANCE");
+        let data = vec![MarkPriceUpdate::new(
+            instrument_id,
+            Price::from("100000.10"),
+            1.into(),
+            3.into(),
+        )];
+        let mut metadata = MarkPriceUpdate::get_metadata(&instrument_id, 2);
+        let record_batch = MarkPriceUpdate::encode_batch(&metadata, &data).unwrap();
+        metadata.remove(KEY_PRICE_PRECISION);
+
+        let result = MarkPriceUpdate::decode_batch(&metadata, record_batch);
+
+        assert!(matches!(
+            result,
+            Err(EncodingError::MissingMetadata(KEY_PRICE_PRECISION))
+        ));
+    }
+}
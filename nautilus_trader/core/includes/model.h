@@ -1282,6 +1282,81 @@ typedef struct Bar_t {
     uint64_t ts_init;
 } Bar_t;
 
+/**
+ * Represents a mark price update for an instrument.
+ *
+ * The mark price is the reference price a venue uses for unrealized PnL, margin and
+ * liquidation calculations, and is typically derived from an index price plus a basis.
+ */
+typedef struct MarkPriceUpdate_t {
+    /**
+     * The instrument ID for the mark price.
+     */
+    struct InstrumentId_t instrument_id;
+    /**
+     * The mark price value.
+     */
+    struct Price_t value;
+    /**
+     * UNIX timestamp (nanoseconds) when the price event occurred.
+     */
+    uint64_t ts_event;
+    /**
+     * UNIX timestamp (nanoseconds) when the struct was initialized.
+     */
+    uint64_t ts_init;
+} MarkPriceUpdate_t;
+
+/**
+ * Represents an index price update for an instrument.
+ *
+ * The index price is the underlying reference price (usually a weighted spot price across
+ * several venues) which perpetual and futures contracts are anchored to.
+ */
+typedef struct IndexPriceUpdate_t {
+    /**
+     * The instrument ID for the index price.
+     */
+    struct InstrumentId_t instrument_id;
+    /**
+     * The index price value.
+     */
+    struct Price_t value;
+    /**
+     * UNIX timestamp (nanoseconds) when the price event occurred.
+     */
+    uint64_t ts_event;
+    /**
+     * UNIX timestamp (nanoseconds) when the struct was initialized.
+     */
+    uint64_t ts_init;
+} IndexPriceUpdate_t;
+
+/**
+ * Represents a funding rate update for a perpetual swap instrument.
+ *
+ * The rate is expressed as a fraction of position notional per funding interval (e.g. a rate of
+ * 0.0001 is 0.01%). A positive rate means longs pay shorts, a negative rate means shorts pay longs.
+ */
+typedef struct FundingRateUpdate_t {
+    /**
+     * The instrument ID for the funding rate.
+     */
+    struct InstrumentId_t instrument_id;
+    /**
+     * The funding rate for the current interval.
+     */
+    double rate;
+    /**
+     * UNIX timestamp (nanoseconds) when the funding rate event occurred.
+     */
+    uint64_t ts_event;
+    /**
+     * UNIX timestamp (nanoseconds) when the struct was initialized.
+     */
+    uint64_t ts_init;
+} FundingRateUpdate_t;
+
 /**
  * A built-in Nautilus data type.
  *
@@ -1295,6 +1370,9 @@ typedef enum Data_t_Tag {
     QUOTE,
     TRADE,
     BAR,
+    MARK_PRICE,
+    INDEX_PRICE,
+    FUNDING_RATE,
 } Data_t_Tag;
 
 typedef struct Data_t {
@@ -1318,6 +1396,15 @@ typedef struct Data_t {
         struct {
             struct Bar_t bar;
         };
+        struct {
+            struct MarkPriceUpdate_t mark_price;
+        };
+        struct {
+            struct IndexPriceUpdate_t index_price;
+        };
+        struct {
+            struct FundingRateUpdate_t funding_rate;
+        };
     };
 } Data_t;
 
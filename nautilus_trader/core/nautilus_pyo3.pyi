@@ -788,6 +788,11 @@ class ContingencyType(Enum):
     OTO = "OTO"
     OUO = "OUO"
 
+class CorporateActionType(Enum):
+    SPLIT = "SPLIT"
+    REVERSE_SPLIT = "REVERSE_SPLIT"
+    CASH_DIVIDEND = "CASH_DIVIDEND"
+
 class CurrencyType(Enum):
     CRYPTO = "CRYPTO"
     FIAT = "FIAT"
@@ -804,6 +809,11 @@ class LiquiditySide(Enum):
     TAKER = "TAKER"
     NO_LIQUIDITY_SIDE = "NO_LIQUIDITY_SIDE"
 
+class LotMatchingMethod(Enum):
+    FIFO = "FIFO"
+    LIFO = "LIFO"
+    HIGHEST_COST = "HIGHEST_COST"
+
 class MarketStatus(Enum):
     OPEN = "OPEN"
     CLOSED = "CLOSED"
@@ -1809,6 +1819,148 @@ class BinaryOption:
     def from_dict(cls, values: dict[str, str]) -> BinaryOption: ...
     def to_dict(self) -> dict[str, Any]: ...
 
+class Cfd:
+    def __init__(
+        self,
+        id: InstrumentId,
+        raw_symbol: Symbol,
+        asset_class: AssetClass,
+        quote_currency: Currency,
+        price_precision: int,
+        size_precision: int,
+        price_increment: Price,
+        size_increment: Quantity,
+        ts_event: int,
+        ts_init: int,
+        base_currency: Currency | None = None,
+        lot_size: Quantity | None = None,
+        max_quantity: Quantity | None = None,
+        min_quantity: Quantity | None = None,
+        max_notional: Money | None = None,
+        min_notional: Money | None = None,
+        max_price: Price | None = None,
+        min_price: Price | None = None,
+        margin_init: Decimal | None = None,
+        margin_maint: Decimal | None = None,
+        maker_fee: Decimal | None = None,
+        taker_fee: Decimal | None = None,
+    ) -> None: ...
+    @property
+    def id(self) -> InstrumentId: ...
+    @property
+    def raw_symbol(self) -> Symbol: ...
+    @property
+    def asset_class(self) -> AssetClass: ...
+    @property
+    def quote_currency(self) -> Currency: ...
+    @property
+    def price_precision(self) -> int: ...
+    @property
+    def size_precision(self) -> int: ...
+    @property
+    def price_increment(self) -> Price: ...
+    @property
+    def size_increment(self) -> Quantity: ...
+    @property
+    def base_currency(self) -> Currency | None: ...
+    @property
+    def lot_size(self) -> Quantity | None: ...
+    @property
+    def max_quantity(self) -> Quantity | None: ...
+    @property
+    def min_quantity(self) -> Quantity | None: ...
+    @property
+    def max_notional(self) -> Money | None: ...
+    @property
+    def min_notional(self) -> Money | None: ...
+    @property
+    def max_price(self) -> Price | None: ...
+    @property
+    def min_price(self) -> Price | None: ...
+    @property
+    def margin_init(self) -> Decimal: ...
+    @property
+    def margin_maint(self) -> Decimal: ...
+    @property
+    def maker_fee(self) -> Decimal: ...
+    @property
+    def taker_fee(self) -> Decimal: ...
+    @property
+    def ts_event(self) -> int: ...
+    @property
+    def ts_init(self) -> int: ...
+    @classmethod
+    def from_dict(cls, values: dict[str, str]) -> Cfd: ...
+    def to_dict(self) -> dict[str, Any]: ...
+
+class Commodity:
+    def __init__(
+        self,
+        id: InstrumentId,
+        raw_symbol: Symbol,
+        quote_currency: Currency,
+        price_precision: int,
+        size_precision: int,
+        price_increment: Price,
+        size_increment: Quantity,
+        ts_event: int,
+        ts_init: int,
+        lot_size: Quantity | None = None,
+        max_quantity: Quantity | None = None,
+        min_quantity: Quantity | None = None,
+        max_notional: Money | None = None,
+        min_notional: Money | None = None,
+        max_price: Price | None = None,
+        min_price: Price | None = None,
+        margin_init: Decimal | None = None,
+        margin_maint: Decimal | None = None,
+        maker_fee: Decimal | None = None,
+        taker_fee: Decimal | None = None,
+    ) -> None: ...
+    @property
+    def id(self) -> InstrumentId: ...
+    @property
+    def raw_symbol(self) -> Symbol: ...
+    @property
+    def quote_currency(self) -> Currency: ...
+    @property
+    def price_precision(self) -> int: ...
+    @property
+    def size_precision(self) -> int: ...
+    @property
+    def price_increment(self) -> Price: ...
+    @property
+    def size_increment(self) -> Quantity: ...
+    @property
+    def lot_size(self) -> Quantity | None: ...
+    @property
+    def max_quantity(self) -> Quantity | None: ...
+    @property
+    def min_quantity(self) -> Quantity | None: ...
+    @property
+    def max_notional(self) -> Money | None: ...
+    @property
+    def min_notional(self) -> Money | None: ...
+    @property
+    def max_price(self) -> Price | None: ...
+    @property
+    def min_price(self) -> Price | None: ...
+    @property
+    def margin_init(self) -> Decimal: ...
+    @property
+    def margin_maint(self) -> Decimal: ...
+    @property
+    def maker_fee(self) -> Decimal: ...
+    @property
+    def taker_fee(self) -> Decimal: ...
+    @property
+    def ts_event(self) -> int: ...
+    @property
+    def ts_init(self) -> int: ...
+    @classmethod
+    def from_dict(cls, values: dict[str, str]) -> Commodity: ...
+    def to_dict(self) -> dict[str, Any]: ...
+
 class CryptoFuture:
     def __init__(
         self,
@@ -1882,6 +2034,99 @@ class CryptoFuture:
     def from_dict(cls, values: dict[str, str]) -> CryptoFuture: ...
     def to_dict(self) -> dict[str, Any]: ...
 
+class CryptoOption:
+    def __init__(
+        self,
+        id: InstrumentId,
+        raw_symbol: Symbol,
+        underlying: Currency,
+        quote_currency: Currency,
+        settlement_currency: Currency,
+        is_inverse: bool,
+        option_kind: OptionKind,
+        strike_price: Price,
+        activation_ns: int,
+        expiration_ns: int,
+        price_precision: int,
+        size_precision: int,
+        price_increment: Price,
+        size_increment: Quantity,
+        ts_event: int,
+        ts_init: int,
+        multiplier: Quantity | None = None,
+        lot_size: Quantity | None = None,
+        max_quantity: Quantity | None = None,
+        min_quantity: Quantity | None = None,
+        max_notional: Money | None = None,
+        min_notional: Money | None = None,
+        max_price: Price | None = None,
+        min_price: Price | None = None,
+        margin_init: Decimal | None = None,
+        margin_maint: Decimal | None = None,
+        maker_fee: Decimal | None = None,
+        taker_fee: Decimal | None = None,
+    ) -> None: ...
+    @property
+    def id(self) -> InstrumentId: ...
+    @property
+    def raw_symbol(self) -> Symbol: ...
+    @property
+    def underlying(self) -> Currency: ...
+    @property
+    def quote_currency(self) -> Currency: ...
+    @property
+    def settlement_currency(self) -> Currency: ...
+    @property
+    def is_inverse(self) -> bool: ...
+    @property
+    def option_kind(self) -> OptionKind: ...
+    @property
+    def strike_price(self) -> Price: ...
+    @property
+    def activation_ns(self) -> int: ...
+    @property
+    def expiration_ns(self) -> int: ...
+    @property
+    def price_precision(self) -> int: ...
+    @property
+    def size_precision(self) -> int: ...
+    @property
+    def price_increment(self) -> Price: ...
+    @property
+    def size_increment(self) -> Quantity: ...
+    @property
+    def multiplier(self) -> Quantity: ...
+    @property
+    def lot_size(self) -> Quantity: ...
+    @property
+    def max_quantity(self) -> Quantity | None: ...
+    @property
+    def min_quantity(self) -> Quantity | None: ...
+    @property
+    def max_notional(self) -> Money | None: ...
+    @property
+    def min_notional(self) -> Money | None: ...
+    @property
+    def max_price(self) -> Price | None: ...
+    @property
+    def min_price(self) -> Price | None: ...
+    @property
+    def margin_init(self) -> Decimal: ...
+    @property
+    def margin_maint(self) -> Decimal: ...
+    @property
+    def maker_fee(self) -> Decimal: ...
+    @property
+    def taker_fee(self) -> Decimal: ...
+    @property
+    def ts_event(self) -> int: ...
+    @property
+    def ts_init(self) -> int: ...
+    def is_quanto(self) -> bool: ...
+    @classmethod
+    def from_dict(cls, values: dict[str, str]) -> CryptoOption: ...
+    def to_dict(self) -> dict[str, Any]: ...
+
 class CryptoPerpetual:
     def __init__(
         self,
@@ -2266,6 +2511,41 @@ class FuturesSpread:
     def from_dict(cls, values: dict[str, str]) -> FuturesSpread: ...
     def to_dict(self) -> dict[str, Any]: ...
 
+class IndexInstrument:
+    def __init__(
+        self,
+        id: InstrumentId,
+        raw_symbol: Symbol,
+        currency: Currency,
+        price_precision: int,
+        size_precision: int,
+        price_increment: Price,
+        size_increment: Quantity,
+        ts_event: int,
+        ts_init: int,
+    ) -> None: ...
+    @property
+    def id(self) -> InstrumentId: ...
+    @property
+    def raw_symbol(self) -> Symbol: ...
+    @property
+    def currency(self) -> Currency: ...
+    @property
+    def price_precision(self) -> int: ...
+    @property
+    def size_precision(self) -> int: ...
+    @property
+    def price_increment(self) -> Price: ...
+    @property
+    def size_increment(self) -> Quantity: ...
+    @property
+    def ts_event(self) -> int: ...
+    @property
+    def ts_init(self) -> int: ...
+    @classmethod
+    def from_dict(cls, values: dict[str, str]) -> IndexInstrument: ...
+    def to_dict(self) -> dict[str, Any]: ...
+
 class OptionContract:
     def __init__(
         self,
@@ -2465,11 +2745,15 @@ class SyntheticInstrument:
     def to_dict(self) -> dict[str, Any]: ...
 
 Instrument: TypeAlias = Union[
+    Cfd,
+    Commodity,
     CryptoFuture,
+    CryptoOption,
     CryptoPerpetual,
     CurrencyPair,
     Equity,
     FuturesContract,
+    IndexInstrument,
     OptionContract,
     SyntheticInstrument,
 ]
@@ -4772,6 +5056,114 @@ def imply_vol_and_greeks(
     """
 
 
+def black76_greeks(
+    f: float,
+    r: float,
+    sigma: float,
+    is_call: bool,
+    k: float,
+    t: float,
+    multiplier: float,
+) -> BlackScholesGreeksResult:
+    """
+    Calculate the Black-76 Greeks for an option on a futures contract.
+
+    Parameters
+    ----------
+    f : float
+        The current price of the underlying futures contract.
+    r : float
+        The risk-free interest rate.
+    sigma : float
+        The volatility of the underlying futures contract.
+    is_call : bool
+        Whether the option is a call (True) or a put (False).
+    k : float
+        The strike price of the option.
+    t : float
+        The time to expiration of the option in years.
+    multiplier : float
+        The multiplier for the option contract.
+
+    Returns
+    -------
+    BlackScholesGreeksResult
+        A named tuple containing the calculated option price, delta, gamma, vega, and theta.
+    """
+
+
+def imply_vol_black76(
+    f: float,
+    r: float,
+    is_call: bool,
+    k: float,
+    t: float,
+    price: float,
+) -> float:
+    """
+    Calculate the Black-76 implied volatility for an option on a futures contract.
+
+    Parameters
+    ----------
+    f : float
+        The current price of the underlying futures contract.
+    r : float
+        The risk-free interest rate.
+    is_call : bool
+        Whether the option is a call (True) or a put (False).
+    k : float
+        The strike price of the option.
+    t : float
+        The time to expiration of the option in years.
+    price : float
+        The current market price of the option.
+
+    Returns
+    -------
+    float
+        An implied volatility value.
+    """
+
+
+def american_option_greeks(
+    s: float,
+    r: float,
+    b: float,
+    sigma: float,
+    is_call: bool,
+    k: float,
+    t: float,
+    multiplier: float,
+) -> BlackScholesGreeksResult:
+    """
+    Calculate the Greeks for an American option using the Barone-Adesi and Whaley approximation.
+
+    Parameters
+    ----------
+    s : float
+        The current price of the underlying asset.
+    r : float
+        The risk-free interest rate.
+    b : float
+        The cost of carry of the underlying asset.
+    sigma : float
+        The volatility of the underlying asset.
+    is_call : bool
+        Whether the option is a call (True) or a put (False).
+    k : float
+        The strike price of the option.
+    t : float
+        The time to expiration of the option in years.
+    multiplier : float
+        The multiplier for the option contract.
+
+    Returns
+    -------
+    BlackScholesGreeksResult
+        A named tuple containing the calculated option price, delta, gamma, vega, and theta.
+    """
+
+
 class GreeksData(Data):
     instrument_id: InstrumentId
     is_call: bool
@@ -712,6 +712,48 @@ cdef extern from "../includes/model.h":
         # UNIX timestamp (nanoseconds) when the struct was initialized.
         uint64_t ts_init;
 
+    # Represents a mark price update for an instrument.
+    #
+    # The mark price is the reference price a venue uses for unrealized PnL, margin and
+    # liquidation calculations, and is typically derived from an index price plus a basis.
+    cdef struct MarkPriceUpdate_t:
+        # The instrument ID for the mark price.
+        InstrumentId_t instrument_id;
+        # The mark price value.
+        Price_t value;
+        # UNIX timestamp (nanoseconds) when the price event occurred.
+        uint64_t ts_event;
+        # UNIX timestamp (nanoseconds) when the struct was initialized.
+        uint64_t ts_init;
+
+    # Represents an index price update for an instrument.
+    #
+    # The index price is the underlying reference price (usually a weighted spot price across
+    # several venues) which perpetual and futures contracts are anchored to.
+    cdef struct IndexPriceUpdate_t:
+        # The instrument ID for the index price.
+        InstrumentId_t instrument_id;
+        # The index price value.
+        Price_t value;
+        # UNIX timestamp (nanoseconds) when the price event occurred.
+        uint64_t ts_event;
+        # UNIX timestamp (nanoseconds) when the struct was initialized.
+        uint64_t ts_init;
+
+    # Represents a funding rate update for a perpetual swap instrument.
+    #
+    # The rate is expressed as a fraction of position notional per funding interval (e.g. a rate of
+    # 0.0001 is 0.01%). A positive rate means longs pay shorts, a negative rate means shorts pay longs.
+    cdef struct FundingRateUpdate_t:
+        # The instrument ID for the funding rate.
+        InstrumentId_t instrument_id;
+        # The funding rate for the current interval.
+        double rate;
+        # UNIX timestamp (nanoseconds) when the funding rate event occurred.
+        uint64_t ts_event;
+        # UNIX timestamp (nanoseconds) when the struct was initialized.
+        uint64_t ts_init;
+
     # A built-in Nautilus data type.
     #
     # Not recommended for storing large amounts of data, as the largest variant is significantly
@@ -723,6 +765,9 @@ cdef extern from "../includes/model.h":
         QUOTE,
         TRADE,
         BAR,
+        MARK_PRICE,
+        INDEX_PRICE,
+        FUNDING_RATE,
 
     cdef struct Data_t:
         Data_t_Tag tag;
@@ -732,6 +777,9 @@ cdef extern from "../includes/model.h":
         QuoteTick_t quote;
         TradeTick_t trade;
         Bar_t bar;
+        MarkPriceUpdate_t mark_price;
+        IndexPriceUpdate_t index_price;
+        FundingRateUpdate_t funding_rate;
 
     # Represents a valid trader ID.
     cdef struct TraderId_t:
@@ -354,6 +354,57 @@ cdef class InstrumentClose(Data):
     cdef dict to_dict_c(InstrumentClose obj)
 
 
+cdef class MarkPriceUpdate(Data):
+    cdef readonly InstrumentId instrument_id
+    """The instrument ID.\n\n:returns: `InstrumentId`"""
+    cdef readonly Price value
+    """The mark price value.\n\n:returns: `Price`"""
+    cdef readonly uint64_t ts_event
+    """UNIX timestamp (nanoseconds) when the data event occurred.\n\n:returns: `uint64_t`"""
+    cdef readonly uint64_t ts_init
+    """UNIX timestamp (nanoseconds) when the object was initialized.\n\n:returns: `uint64_t`"""
+
+    @staticmethod
+    cdef MarkPriceUpdate from_dict_c(dict values)
+
+    @staticmethod
+    cdef dict to_dict_c(MarkPriceUpdate obj)
+
+
+cdef class IndexPriceUpdate(Data):
+    cdef readonly InstrumentId instrument_id
+    """The instrument ID.\n\n:returns: `InstrumentId`"""
+    cdef readonly Price value
+    """The index price value.\n\n:returns: `Price`"""
+    cdef readonly uint64_t ts_event
+    """UNIX timestamp (nanoseconds) when the data event occurred.\n\n:returns: `uint64_t`"""
+    cdef readonly uint64_t ts_init
+    """UNIX timestamp (nanoseconds) when the object was initialized.\n\n:returns: `uint64_t`"""
+
+    @staticmethod
+    cdef IndexPriceUpdate from_dict_c(dict values)
+
+    @staticmethod
+    cdef dict to_dict_c(IndexPriceUpdate obj)
+
+
+cdef class FundingRateUpdate(Data):
+    cdef readonly InstrumentId instrument_id
+    """The instrument ID.\n\n:returns: `InstrumentId`"""
+    cdef readonly double rate
+    """The funding rate for the current interval.\n\n:returns: `double`"""
+    cdef readonly uint64_t ts_event
+    """UNIX timestamp (nanoseconds) when the data event occurred.\n\n:returns: `uint64_t`"""
+    cdef readonly uint64_t ts_init
+    """UNIX timestamp (nanoseconds) when the object was initialized.\n\n:returns: `uint64_t`"""
+
+    @staticmethod
+    cdef FundingRateUpdate from_dict_c(dict values)
+
+    @staticmethod
+    cdef dict to_dict_c(FundingRateUpdate obj)
+
+
 cdef class QuoteTick(Data):
     cdef QuoteTick_t _mem
 
@@ -44,8 +44,11 @@ from nautilus_trader.core.rust.model cimport BookAction
 from nautilus_trader.core.rust.model cimport BookOrder_t
 from nautilus_trader.core.rust.model cimport Data_t
 from nautilus_trader.core.rust.model cimport Data_t_Tag
+from nautilus_trader.core.rust.model cimport FundingRateUpdate_t
+from nautilus_trader.core.rust.model cimport IndexPriceUpdate_t
 from nautilus_trader.core.rust.model cimport InstrumentCloseType
 from nautilus_trader.core.rust.model cimport MarketStatusAction
+from nautilus_trader.core.rust.model cimport MarkPriceUpdate_t
 from nautilus_trader.core.rust.model cimport OrderSide
 from nautilus_trader.core.rust.model cimport Price_t
 from nautilus_trader.core.rust.model cimport PriceRaw
@@ -193,6 +196,33 @@ cdef inline Bar bar_from_mem_c(Bar_t mem):
     return bar
 
 
+cdef inline MarkPriceUpdate mark_price_from_mem_c(MarkPriceUpdate_t mem):
+    return MarkPriceUpdate(
+        InstrumentId.from_mem_c(mem.instrument_id),
+        Price.from_mem_c(mem.value),
+        mem.ts_event,
+        mem.ts_init,
+    )
+
+
+cdef inline IndexPriceUpdate index_price_from_mem_c(IndexPriceUpdate_t mem):
+    return IndexPriceUpdate(
+        InstrumentId.from_mem_c(mem.instrument_id),
+        Price.from_mem_c(mem.value),
+        mem.ts_event,
+        mem.ts_init,
+    )
+
+
+cdef inline FundingRateUpdate funding_rate_from_mem_c(FundingRateUpdate_t mem):
+    return FundingRateUpdate(
+        InstrumentId.from_mem_c(mem.instrument_id),
+        mem.rate,
+        mem.ts_event,
+        mem.ts_init,
+    )
+
+
 # SAFETY: Do NOT deallocate the capsule here
 cpdef list capsule_to_list(capsule):
     cdef CVec* data = <CVec*>PyCapsule_GetPointer(capsule, NULL)
@@ -213,6 +243,12 @@ cpdef list capsule_to_list(capsule):
             objects.append(trade_from_mem_c(ptr[i].trade))
         elif ptr[i].tag == Data_t_Tag.BAR:
             objects.append(bar_from_mem_c(ptr[i].bar))
+        elif ptr[i].tag == Data_t_Tag.MARK_PRICE:
+            objects.append(mark_price_from_mem_c(ptr[i].mark_price))
+        elif ptr[i].tag == Data_t_Tag.INDEX_PRICE:
+            objects.append(index_price_from_mem_c(ptr[i].index_price))
+        elif ptr[i].tag == Data_t_Tag.FUNDING_RATE:
+            objects.append(funding_rate_from_mem_c(ptr[i].funding_rate))
 
     return objects
 
@@ -233,6 +269,12 @@ cpdef Data capsule_to_data(capsule):
         return trade_from_mem_c(ptr.trade)
     elif ptr.tag == Data_t_Tag.BAR:
         return bar_from_mem_c(ptr.bar)
+    elif ptr.tag == Data_t_Tag.MARK_PRICE:
+        return mark_price_from_mem_c(ptr.mark_price)
+    elif ptr.tag == Data_t_Tag.INDEX_PRICE:
+        return index_price_from_mem_c(ptr.index_price)
+    elif ptr.tag == Data_t_Tag.FUNDING_RATE:
+        return funding_rate_from_mem_c(ptr.funding_rate)
     else:
         raise RuntimeError("Invalid data element to convert from `PyCapsule`")
 
@@ -3587,6 +3629,291 @@ cdef class InstrumentClose(Data):
         return InstrumentClose.to_dict_c(obj)
 
 
+cdef class MarkPriceUpdate(Data):
+    """
+    Represents a mark price update for an instrument.
+
+    Parameters
+    ----------
+    instrument_id : InstrumentId
+        The instrument ID.
+    value : Price
+        The mark price value.
+    ts_event : uint64_t
+        UNIX timestamp (nanoseconds) when the price event occurred.
+    ts_init : uint64_t
+        UNIX timestamp (nanoseconds) when the object was initialized.
+
+    """
+
+    def __init__(
+        self,
+        InstrumentId instrument_id not None,
+        Price value not None,
+        uint64_t ts_event,
+        uint64_t ts_init,
+    ) -> None:
+        self.instrument_id = instrument_id
+        self.value = value
+        self.ts_event = ts_event
+        self.ts_init = ts_init
+
+    def __eq__(self, MarkPriceUpdate other) -> bool:
+        return MarkPriceUpdate.to_dict_c(self) == MarkPriceUpdate.to_dict_c(other)
+
+    def __hash__(self) -> int:
+        return hash(frozenset(MarkPriceUpdate.to_dict_c(self)))
+
+    def __repr__(self) -> str:
+        return (
+            f"{type(self).__name__}("
+            f"instrument_id={self.instrument_id}, "
+            f"value={self.value}, "
+            f"ts_event={self.ts_event}, "
+            f"ts_init={self.ts_init})"
+        )
+
+    @staticmethod
+    cdef MarkPriceUpdate from_dict_c(dict values):
+        Condition.not_none(values, "values")
+        return MarkPriceUpdate(
+            instrument_id=InstrumentId.from_str_c(values["instrument_id"]),
+            value=Price.from_str_c(values["value"]),
+            ts_event=values["ts_event"],
+            ts_init=values["ts_init"],
+        )
+
+    @staticmethod
+    cdef dict to_dict_c(MarkPriceUpdate obj):
+        Condition.not_none(obj, "obj")
+        return {
+            "type": "MarkPriceUpdate",
+            "instrument_id": obj.instrument_id.to_str(),
+            "value": str(obj.value),
+            "ts_event": obj.ts_event,
+            "ts_init": obj.ts_init,
+        }
+
+    @staticmethod
+    def from_dict(dict values) -> MarkPriceUpdate:
+        """
+        Return a mark price update from the given dict values.
+
+        Parameters
+        ----------
+        values : dict[str, object]
+            The values for initialization.
+
+        Returns
+        -------
+        MarkPriceUpdate
+
+        """
+        return MarkPriceUpdate.from_dict_c(values)
+
+    @staticmethod
+    def to_dict(MarkPriceUpdate obj):
+        """
+        Return a dictionary representation of this object.
+
+        Returns
+        -------
+        dict[str, object]
+
+        """
+        return MarkPriceUpdate.to_dict_c(obj)
+
+
+cdef class IndexPriceUpdate(Data):
+    """
+    Represents an index price update for an instrument.
+
+    Parameters
+    ----------
+    instrument_id : InstrumentId
+        The instrument ID.
+    value : Price
+        The index price value.
+    ts_event : uint64_t
+        UNIX timestamp (nanoseconds) when the price event occurred.
+    ts_init : uint64_t
+        UNIX timestamp (nanoseconds) when the object was initialized.
+
+    """
+
+    def __init__(
+        self,
+        InstrumentId instrument_id not None,
+        Price value not None,
+        uint64_t ts_event,
+        uint64_t ts_init,
+    ) -> None:
+        self.instrument_id = instrument_id
+        self.value = value
+        self.ts_event = ts_event
+        self.ts_init = ts_init
+
+    def __eq__(self, IndexPriceUpdate other) -> bool:
+        return IndexPriceUpdate.to_dict_c(self) == IndexPriceUpdate.to_dict_c(other)
+
+    def __hash__(self) -> int:
+        return hash(frozenset(IndexPriceUpdate.to_dict_c(self)))
+
+    def __repr__(self) -> str:
+        return (
+            f"{type(self).__name__}("
+            f"instrument_id={self.instrument_id}, "
+            f"value={self.value}, "
+            f"ts_event={self.ts_event}, "
+            f"ts_init={self.ts_init})"
+        )
+
+    @staticmethod
+    cdef IndexPriceUpdate from_dict_c(dict values):
+        Condition.not_none(values, "values")
+        return IndexPriceUpdate(
+            instrument_id=InstrumentId.from_str_c(values["instrument_id"]),
+            value=Price.from_str_c(values["value"]),
+            ts_event=values["ts_event"],
+            ts_init=values["ts_init"],
+        )
+
+    @staticmethod
+    cdef dict to_dict_c(IndexPriceUpdate obj):
+        Condition.not_none(obj, "obj")
+        return {
+            "type": "IndexPriceUpdate",
+            "instrument_id": obj.instrument_id.to_str(),
+            "value": str(obj.value),
+            "ts_event": obj.ts_event,
+            "ts_init": obj.ts_init,
+        }
+
+    @staticmethod
+    def from_dict(dict values) -> IndexPriceUpdate:
+        """
+        Return an index price update from the given dict values.
+
+        Parameters
+        ----------
+        values : dict[str, object]
+            The values for initialization.
+
+        Returns
+        -------
+        IndexPriceUpdate
+
+        """
+        return IndexPriceUpdate.from_dict_c(values)
+
+    @staticmethod
+    def to_dict(IndexPriceUpdate obj):
+        """
+        Return a dictionary representation of this object.
+
+        Returns
+        -------
+        dict[str, object]
+
+        """
+        return IndexPriceUpdate.to_dict_c(obj)
+
+
+cdef class FundingRateUpdate(Data):
+    """
+    Represents a funding rate update for a perpetual swap instrument.
+
+    Parameters
+    ----------
+    instrument_id : InstrumentId
+        The instrument ID.
+    rate : double
+        The funding rate for the current interval.
+    ts_event : uint64_t
+        UNIX timestamp (nanoseconds) when the funding rate event occurred.
+    ts_init : uint64_t
+        UNIX timestamp (nanoseconds) when the object was initialized.
+
+    """
+
+    def __init__(
+        self,
+        InstrumentId instrument_id not None,
+        double rate,
+        uint64_t ts_event,
+        uint64_t ts_init,
+    ) -> None:
+        self.instrument_id = instrument_id
+        self.rate = rate
+        self.ts_event = ts_event
+        self.ts_init = ts_init
+
+    def __eq__(self, FundingRateUpdate other) -> bool:
+        return FundingRateUpdate.to_dict_c(self) == FundingRateUpdate.to_dict_c(other)
+
+    def __hash__(self) -> int:
+        return hash(frozenset(FundingRateUpdate.to_dict_c(self)))
+
+    def __repr__(self) -> str:
+        return (
+            f"{type(self).__name__}("
+            f"instrument_id={self.instrument_id}, "
+            f"rate={self.rate}, "
+            f"ts_event={self.ts_event}, "
+            f"ts_init={self.ts_init})"
+        )
+
+    @staticmethod
+    cdef FundingRateUpdate from_dict_c(dict values):
+        Condition.not_none(values, "values")
+        return FundingRateUpdate(
+            instrument_id=InstrumentId.from_str_c(values["instrument_id"]),
+            rate=values["rate"],
+            ts_event=values["ts_event"],
+            ts_init=values["ts_init"],
+        )
+
+    @staticmethod
+    cdef dict to_dict_c(FundingRateUpdate obj):
+        Condition.not_none(obj, "obj")
+        return {
+            "type": "FundingRateUpdate",
+            "instrument_id": obj.instrument_id.to_str(),
+            "rate": obj.rate,
+            "ts_event": obj.ts_event,
+            "ts_init": obj.ts_init,
+        }
+
+    @staticmethod
+    def from_dict(dict values) -> FundingRateUpdate:
+        """
+        Return a funding rate update from the given dict values.
+
+        Parameters
+        ----------
+        values : dict[str, object]
+            The values for initialization.
+
+        Returns
+        -------
+        FundingRateUpdate
+
+        """
+        return FundingRateUpdate.from_dict_c(values)
+
+    @staticmethod
+    def to_dict(FundingRateUpdate obj):
+        """
+        Return a dictionary representation of this object.
+
+        Returns
+        -------
+        dict[str, object]
+
+        """
+        return FundingRateUpdate.to_dict_c(obj)
+
+
 cdef class QuoteTick(Data):
     """
     Represents a single quote tick in a market.